@@ -13,7 +13,6 @@ use std::io::{Read, BufRead, Write, stdin, stdout, BufReader, BufWriter};
 use std::env;
 use std::fs::File;
 use std::slice;
-use std::mem;
 use std::u8;
 
 const TAPE_SIZE: usize = 30000;
@@ -188,12 +187,13 @@ impl Program {
         })
     }
 
-    fn run(self, state: &mut State) -> Result<(), &'static str> {
-        let f: extern "win64" fn(*mut State, *mut u8, *mut u8, *const u8) -> u8 =
-            unsafe { mem::transmute(self.code.ptr(self.start)) };
+    fn run<'a>(self, state: &mut State<'a>) -> Result<(), &'static str> {
+        let f = unsafe {
+            self.code.func::<extern "win64" fn(*mut State<'a>, *mut u8, *mut u8, *const u8) -> u8>(self.start)
+        };
         let start = state.tape.as_mut_ptr();
         let end = unsafe { start.offset(TAPE_SIZE as isize) };
-        let res = f(state, start, start, end);
+        let res = f.call((state, start, start, end));
         if res == 0 {
             Ok(())
         } else if res == 1 {
@@ -2,7 +2,9 @@ use crate::common::Size;
 use super::ast::Modifier;
 
 use lazy_static::lazy_static;
+use bitflags::bitflags;
 use std::collections::{HashMap, hash_map};
+use std::fmt::{self, Display};
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Matcher {
@@ -189,11 +191,123 @@ pub struct Opdata {
     /// A set of matchers capable of matching the instruction encoding that this instruction represents.
     pub matchers: &'static [Matcher],
     /// A sequence of encoder commands that check the matched instruction on validity and whose output gets orred together with the original template at runtime.
-    pub commands: &'static [Command]
+    pub commands: &'static [Command],
+    /// The architecture extensions required to use this encoding.
+    pub features: Features
+}
+
+bitflags! {
+    pub struct Features: u32 {
+        const BASE    = 0x0000_0000;
+        const CRC     = 0x0000_0001;
+        const LSE     = 0x0000_0002;
+        const RDM     = 0x0000_0004;
+        const LOR     = 0x0000_0008;
+        const AES     = 0x0000_0010;
+        const SHA2    = 0x0000_0020;
+        const SHA3    = 0x0000_0040;
+        const SM4     = 0x0000_0080;
+        const FP16    = 0x0000_0100;
+        const FHM     = 0x0000_0200;
+        const DOTPROD = 0x0000_0400;
+        const RAS     = 0x0000_0800;
+        const SPE     = 0x0000_1000;
+        const PAUTH   = 0x0000_2000;
+        const JSCVT   = 0x0000_4000;
+        const FCMA    = 0x0000_8000;
+        const RCPC    = 0x0001_0000;
+        const RCPC2   = 0x0002_0000;
+        const FLAGM   = 0x0004_0000;
+        const TRACE   = 0x0008_0000;
+        const SB      = 0x0010_0000;
+        const PREDRES = 0x0020_0000;
+
+        // the extensions that are mandatory in each architecture version
+        const V8_1A = Self::CRC.bits | Self::LSE.bits | Self::RDM.bits | Self::LOR.bits;
+        const V8_2A = Self::V8_1A.bits | Self::RAS.bits;
+        const V8_3A = Self::V8_2A.bits | Self::PAUTH.bits | Self::JSCVT.bits | Self::FCMA.bits | Self::RCPC.bits;
+        const V8_4A = Self::V8_3A.bits | Self::DOTPROD.bits | Self::RCPC2.bits | Self::FLAGM.bits | Self::TRACE.bits;
+        const V8_5A = Self::V8_4A.bits | Self::SB.bits | Self::PREDRES.bits;
+    }
+}
+
+impl Features {
+    const fn make(bits: u32) -> Features {
+        Features { bits }
+    }
+
+    pub fn from_str(name: &str) -> Option<Features> {
+        match name {
+            "crc"     => Some(Features::CRC),
+            "lse"     => Some(Features::LSE),
+            "rdm"     => Some(Features::RDM),
+            "lor"     => Some(Features::LOR),
+            "aes"     => Some(Features::AES),
+            "sha2"    => Some(Features::SHA2),
+            "crypto"  => Some(Features::AES | Features::SHA2),
+            "sha3"    => Some(Features::SHA3),
+            "sm4"     => Some(Features::SM4),
+            "fp16"    => Some(Features::FP16),
+            "fhm"     => Some(Features::FHM),
+            "dotprod" => Some(Features::DOTPROD),
+            "ras"     => Some(Features::RAS),
+            "spe"     => Some(Features::SPE),
+            "pauth"   => Some(Features::PAUTH),
+            "jsconv"  => Some(Features::JSCVT),
+            "fcma"    => Some(Features::FCMA),
+            "rcpc"    => Some(Features::RCPC),
+            "rcpc2"   => Some(Features::RCPC2),
+            "flagm"   => Some(Features::FLAGM),
+            "trace"   => Some(Features::TRACE),
+            "sb"      => Some(Features::SB),
+            "predres" => Some(Features::PREDRES),
+            "v8_1a"   => Some(Features::V8_1A),
+            "v8_2a"   => Some(Features::V8_2A),
+            "v8_3a"   => Some(Features::V8_3A),
+            "v8_4a"   => Some(Features::V8_4A),
+            "v8_5a"   => Some(Features::V8_5A),
+            _ => None
+        }
+    }
+}
+
+impl Display for Features {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut keys = Vec::new();
+        if self.contains(Features::CRC)     { keys.push("crc"); }
+        if self.contains(Features::LSE)     { keys.push("lse"); }
+        if self.contains(Features::RDM)     { keys.push("rdm"); }
+        if self.contains(Features::LOR)     { keys.push("lor"); }
+        if self.contains(Features::AES)     { keys.push("aes"); }
+        if self.contains(Features::SHA2)    { keys.push("sha2"); }
+        if self.contains(Features::SHA3)    { keys.push("sha3"); }
+        if self.contains(Features::SM4)     { keys.push("sm4"); }
+        if self.contains(Features::FP16)    { keys.push("fp16"); }
+        if self.contains(Features::FHM)     { keys.push("fhm"); }
+        if self.contains(Features::DOTPROD) { keys.push("dotprod"); }
+        if self.contains(Features::RAS)     { keys.push("ras"); }
+        if self.contains(Features::SPE)     { keys.push("spe"); }
+        if self.contains(Features::PAUTH)   { keys.push("pauth"); }
+        if self.contains(Features::JSCVT)   { keys.push("jsconv"); }
+        if self.contains(Features::FCMA)    { keys.push("fcma"); }
+        if self.contains(Features::RCPC)    { keys.push("rcpc"); }
+        if self.contains(Features::RCPC2)   { keys.push("rcpc2"); }
+        if self.contains(Features::FLAGM)   { keys.push("flagm"); }
+        if self.contains(Features::TRACE)   { keys.push("trace"); }
+        if self.contains(Features::SB)      { keys.push("sb"); }
+        if self.contains(Features::PREDRES) { keys.push("predres"); }
+        for (i, k) in keys.into_iter().enumerate() {
+            if i != 0 {
+                f.write_str(", ")?;
+            }
+            f.write_str(k)?;
+        }
+        Ok(())
+    }
 }
 
 macro_rules! SingleOp {
-    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ] ) => {
+    ( $base:expr, [ $( $matcher:expr ),* ], [ $( $command:expr ),* ], [ $( $feature:ident ),* ] ) => {
         {
             const MATCHERS: &'static [Matcher] = {
                 #[allow(unused_imports)]
@@ -213,18 +327,19 @@ macro_rules! SingleOp {
                 base: $base,
                 matchers: MATCHERS,
                 commands: COMMANDS,
+                features: Features::make(0 $( | Features::$feature.bits )* ),
             }
         }
     }
 }
 
 macro_rules! Ops {
-    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] ; )+ ] )* ) => {
+    ( $( $name:tt = [ $( $base:tt = [ $( $matcher:expr ),* ] => [ $( $command:expr ),* ] $( , $( $feature:ident )|+ )? ; )+ ] )* ) => {
         [ $(
             (
                 $name,
                 &[ $(
-                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ] )
+                    SingleOp!( $base, [ $( $matcher ),* ], [ $( $command ),* ], [ $( $( $feature ),+ )? ] )
                 ),+ ] as &[_]
             )
         ),* ]
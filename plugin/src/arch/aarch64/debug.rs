@@ -207,6 +207,10 @@ pub fn format_opdata(name: &str, data: &Opdata) -> Vec<String> {
             buf.push_str(&c);
         }
 
+        if !data.features.is_empty() {
+            write!(buf, " ({})", data.features).unwrap();
+        }
+
         forms.push(buf);
     }
 
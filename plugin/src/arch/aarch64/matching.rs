@@ -10,7 +10,7 @@ use crate::common::{Size, JumpKind};
 use crate::parse_helpers::{as_ident, as_number, as_float};
 
 /// Try finding an appropriate definition that matches the given instruction / arguments.
-pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+pub(super) fn match_instruction(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
     // sanitize our arg list to remove any structures that cannot be matched on
     let args = sanitize_args(args)?;
 
@@ -23,16 +23,27 @@ pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, a
     };
 
     // matching loop
+    let mut missing_features = None;
     for data in opdata {
-        if let Some(mut ctx) = match_args(&args, data) {
+        if let Some(mut match_data) = match_args(&args, data) {
+
+            // skip encodings that require features that are not indicated to be available
+            if !ctx.features.contains(data.features) {
+                missing_features.get_or_insert(data.features - ctx.features);
+                continue;
+            }
 
             // flatten the arg list for the encoding vm
-            flatten_args(args, data, &mut ctx);
+            flatten_args(args, data, &mut match_data);
 
-            return Ok(ctx);
+            return Ok(match_data);
         }
     }
 
+    if let Some(features) = missing_features {
+        return Err(Some(format!("This instruction uses features that are not indicated to be available: {}", features)));
+    }
+
     Err(Some(
         format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", &name, format_opdata_list(&name, opdata))
     ))
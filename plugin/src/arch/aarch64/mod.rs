@@ -20,17 +20,18 @@ pub use debug::create_opmap;
 pub use debug::extract_opmap;
 
 struct Context<'a, 'b: 'a> {
-    pub state: &'a mut State<'b>
+    pub state: &'a mut State<'b>,
+    pub features: aarch64data::Features
 }
 
 #[derive(Clone, Debug)]
 pub struct ArchAarch64 {
-
+    features: aarch64data::Features
 }
 
 impl Default for ArchAarch64 {
     fn default() -> ArchAarch64 {
-        ArchAarch64 { }
+        ArchAarch64 { features: aarch64data::Features::all() }
     }
 }
 
@@ -40,9 +41,17 @@ impl Arch for ArchAarch64 {
     }
 
     fn set_features(&mut self, features: &[syn::Ident]) {
-        if let Some(feature) = features.first() {
-            emit_error!(feature, "Arch aarch64 has no known features");
+        let mut new_features = aarch64data::Features::empty();
+        for ident in features {
+            new_features |= match aarch64data::Features::from_str(&ident.to_string()) {
+                Some(feature) => feature,
+                None => {
+                    emit_error!(ident, "Architecture aarch64 does not support feature '{}'", ident);
+                    continue;
+                }
+            }
         }
+        self.features = new_features;
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
@@ -70,7 +79,8 @@ impl Arch for ArchAarch64 {
 
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
         let mut ctx = Context {
-            state
+            state,
+            features: self.features
         };
 
         let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
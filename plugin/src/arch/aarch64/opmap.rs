@@ -77,16 +77,16 @@ Ops!(
     0b10010000_00000000_00000000_00000000 = [X, Offset] => [R(0), Offset(ADRP)];
 ]
 "aesd" = [
-    0b01001110_00101000_01011000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01011000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
 ]
 "aese" = [
-    0b01001110_00101000_01001000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01001000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
 ]
 "aesimc" = [
-    0b01001110_00101000_01111000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01111000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
 ]
 "aesmc" = [
-    0b01001110_00101000_01101000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)];
+    0b01001110_00101000_01101000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5)], AES;
 ]
 "and" = [
     // AND (vector)
@@ -122,19 +122,19 @@ Ops!(
     0b11010101_00001000_01111000_00000000 = [Ident, X] => [LitList(5, "AT_OPS"), R(0)];
 ]
 "autda" = [
-    0b11011010_11000001_00011000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00011000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "autdb" = [
-    0b11011010_11000001_00011100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00011100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "autdza" = [
-    0b11011010_11000001_00111011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00111011_11100000 = [X] => [R(0)], PAUTH;
 ]
 "autdzb" = [
-    0b11011010_11000001_00111111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00111111_11100000 = [X] => [R(0)], PAUTH;
 ]
 "autia" = [
-    0b11011010_11000001_00010000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00010000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "autia1716" = [
     0b11010101_00000011_00100001_10011111 = [] => [];
@@ -146,7 +146,7 @@ Ops!(
     0b11010101_00000011_00100011_10011111 = [] => [];
 ]
 "autib" = [
-    0b11011010_11000001_00010100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00010100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "autib1716" = [
     0b11010101_00000011_00100001_11011111 = [] => [];
@@ -158,10 +158,10 @@ Ops!(
     0b11010101_00000011_00100011_11011111 = [] => [];
 ]
 "autiza" = [
-    0b11011010_11000001_00110011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00110011_11100000 = [X] => [R(0)], PAUTH;
 ]
 "autizb" = [
-    0b11011010_11000001_00110111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00110111_11100000 = [X] => [R(0)], PAUTH;
 ]
 "b" = [
     // B.cond
@@ -170,7 +170,7 @@ Ops!(
     0b00010100_00000000_00000000_00000000 = [Offset] => [Offset(B)];
 ]
 "bcax" = [
-    0b11001110_00100000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_00100000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], SHA3;
 ]
 "bfc" = [
     0b00110011_00000000_00000011_11100000 = [W, Imm, Imm] => [R(0), Unegmod(16, 5), BUsum(5), Urange(10, 1, 32)];
@@ -215,31 +215,31 @@ Ops!(
     0b11010110_00111111_00000000_00000000 = [X] => [R(5)];
 ]
 "blraa" = [
-    0b11010111_00111111_00001000_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00111111_00001000_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
 ]
 "blraaz" = [
-    0b11010110_00111111_00001000_00011111 = [X] => [R(5)];
+    0b11010110_00111111_00001000_00011111 = [X] => [R(5)], PAUTH;
 ]
 "blrab" = [
-    0b11010111_00111111_00001100_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00111111_00001100_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
 ]
 "blrabz" = [
-    0b11010110_00111111_00001100_00011111 = [X] => [R(5)];
+    0b11010110_00111111_00001100_00011111 = [X] => [R(5)], PAUTH;
 ]
 "br" = [
     0b11010110_00011111_00000000_00000000 = [X] => [R(5)];
 ]
 "braa" = [
-    0b11010111_00011111_00001000_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00011111_00001000_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
 ]
 "braaz" = [
-    0b11010110_00011111_00001000_00011111 = [X] => [R(5)];
+    0b11010110_00011111_00001000_00011111 = [X] => [R(5)], PAUTH;
 ]
 "brab" = [
-    0b11010111_00011111_00001100_00000000 = [X, XSP] => [R(5), R(0)];
+    0b11010111_00011111_00001100_00000000 = [X, XSP] => [R(5), R(0)], PAUTH;
 ]
 "brabz" = [
-    0b11010110_00011111_00001100_00011111 = [X] => [R(5)];
+    0b11010110_00011111_00001100_00011111 = [X] => [R(5)], PAUTH;
 ]
 "brk" = [
     0b11010100_00100000_00000000_00000000 = [Imm] => [Ubits(5, 16)];
@@ -248,60 +248,60 @@ Ops!(
     0b00101110_01100000_00011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "cas" = [
-    0b10001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_10100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_10100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casa" = [
-    0b10001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_11100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_11100000_01111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casab" = [
-    0b00001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casah" = [
-    0b01001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_11100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casal" = [
-    0b10001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_11100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_11100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casalb" = [
-    0b00001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casalh" = [
-    0b01001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_11100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casb" = [
-    0b00001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "cash" = [
-    0b01001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_10100000_01111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casl" = [
-    0b10001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11001000_10100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11001000_10100000_11111100_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "caslb" = [
-    0b00001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "caslh" = [
-    0b01001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01001000_10100000_11111100_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "casp" = [
-    0b00001000_00100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_00100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_00100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_00100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
 ]
 "caspa" = [
-    0b00001000_01100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_01100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_01100000_01111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_01100000_01111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
 ]
 "caspal" = [
-    0b00001000_01100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_01100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_01100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_01100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
 ]
 "caspl" = [
-    0b00001000_00100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
-    0b01001000_00100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)];
+    0b00001000_00100000_11111100_00000000 = [W, W, W, W, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
+    0b01001000_00100000_11111100_00000000 = [X, X, X, X, RefBase] => [REven(16), RNext, REven(0), RNext, R(5)], LSE;
 ]
 "cbnz" = [
     0b00110101_00000000_00000000_00000000 = [W, Offset] => [R(0), Offset(BCOND)];
@@ -328,10 +328,10 @@ Ops!(
     0b11111010_01000000_00000000_00000000 = [X, X, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
 ]
 "cfinv" = [
-    0b11010101_00000000_01000000_00011111 = [] => [];
+    0b11010101_00000000_01000000_00011111 = [] => [], FLAGM;
 ]
 "cfp" = [
-    0b11010101_00001011_01110011_10000000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_10000000 = [Lit("rctx"), X] => [R(0)], PREDRES;
 ]
 "cinc" = [
     0b00011010_10000000_00000100_00000000 = [W, W, Cond] => [R(0), R(5), C, R(16), CondInv(12)];
@@ -472,31 +472,31 @@ Ops!(
     0b00001110_00100000_01011000_00000000 = [V(BYTE), V(BYTE)] => [R(0), R(5), Rwidth(30)];
 ]
 "cpp" = [
-    0b11010101_00001011_01110011_11100000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_11100000 = [Lit("rctx"), X] => [R(0)], PREDRES;
 ]
 "crc32b" = [
-    0b00011010_11000000_01000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01000000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32cb" = [
-    0b00011010_11000000_01010000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01010000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32ch" = [
-    0b00011010_11000000_01010100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01010100_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32cw" = [
-    0b00011010_11000000_01011000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01011000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32cx" = [
-    0b10011010_11000000_01011100_00000000 = [W, W, X] => [R(0), R(5), R(16)];
+    0b10011010_11000000_01011100_00000000 = [W, W, X] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32h" = [
-    0b00011010_11000000_01000100_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01000100_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32w" = [
-    0b00011010_11000000_01001000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
+    0b00011010_11000000_01001000_00000000 = [W, W, W] => [R(0), R(5), R(16)], CRC;
 ]
 "crc32x" = [
-    0b10011010_11000000_01001100_00000000 = [W, W, X] => [R(0), R(5), R(16)];
+    0b10011010_11000000_01001100_00000000 = [W, W, X] => [R(0), R(5), R(16)], CRC;
 ]
 "csdb" = [
     0b11010101_00000011_00100010_10011111 = [] => [];
@@ -565,7 +565,7 @@ Ops!(
     0b00001110_00001000_00001100_00000000 = [VStatic(QWORD, 2), X] => [R(0), Rwidth(30), R(5)];
 ]
 "dvp" = [
-    0b11010101_00001011_01110011_10100000 = [Lit("rctx"), X] => [R(0)];
+    0b11010101_00001011_01110011_10100000 = [Lit("rctx"), X] => [R(0)], PREDRES;
 ]
 "eon" = [
     0b01001010_00100000_00000000_00000000 = [W, W, W, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 5)];
@@ -582,19 +582,19 @@ Ops!(
     0b11001010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
 ]
 "eor3" = [
-    0b11001110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_00000000_00000000_00000000 = [VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16), R(10)], SHA3;
 ]
 "eret" = [
     0b11010110_10011111_00000011_11100000 = [] => [];
 ]
 "eretaa" = [
-    0b11010110_10011111_00001011_11111111 = [] => [];
+    0b11010110_10011111_00001011_11111111 = [] => [], PAUTH;
 ]
 "eretab" = [
-    0b11010110_10011111_00001111_11111111 = [] => [];
+    0b11010110_10011111_00001111_11111111 = [] => [], PAUTH;
 ]
 "esb" = [
-    0b11010101_00000011_00100010_00011111 = [] => [];
+    0b11010101_00000011_00100010_00011111 = [] => [], RAS;
 ]
 "ext" = [
     0b00101110_00000000_00000000_00000000 = [VStatic(BYTE, 8), VStatic(BYTE, 8), VStatic(BYTE, 8), Imm] => [R(0), R(5), R(16), Ubits(11, 3)];
@@ -605,166 +605,166 @@ Ops!(
     0b10010011_11000000_00000000_00000000 = [X, X, X, Imm] => [R(0), R(5), R(16), Ubits(10, 6)];
 ]
 "fabd" = [
-    0b01111110_11000000_00010100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_11000000_00010100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01111110_10100000_11010100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01111110_11100000_11010100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_10100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_11100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fabs" = [
     // FABS (vector)
-    0b00001110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100000_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100000_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FABS (scalar)
-    0b00011110_11100000_11000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100000_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100000_11000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100000_11000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "facge" = [
-    0b01111110_01000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_01000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01111110_00100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01111110_01100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_01000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "facgt" = [
-    0b01111110_11000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_11000000_00101100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01111110_10100000_11101100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01111110_11100000_11101100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00101100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_10100000_11101100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_11100000_11101100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fadd" = [
     // FADD (vector)
-    0b00001110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FADD (scalar)
-    0b00011110_11100000_00101000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_00101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_00101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "faddp" = [
     // FADDP (scalar)
-    0b01011110_00110000_11011000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11011000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
     0b01111110_00110000_11011000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
     0b01111110_01110000_11011000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
     // FADDP (vector)
-    0b00101110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fcadd" = [
-    0b00101110_01000000_11100100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
-    0b00101110_10000000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
-    0b00101110_11000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)];
+    0b00101110_01000000_11100100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA | FP16;
+    0b00101110_10000000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA;
+    0b00101110_11000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(12, &[90, 270]), Rwidth(30)], FCMA;
 ]
 "fccmp" = [
-    0b00011110_11100000_00000100_00000000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_11100000_00000100_00000000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], FP16;
     0b00011110_00100000_00000100_00000000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
     0b00011110_01100000_00000100_00000000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
 ]
 "fccmpe" = [
-    0b00011110_11100000_00000100_00010000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
+    0b00011110_11100000_00000100_00010000 = [H, H, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)], FP16;
     0b00011110_00100000_00000100_00010000 = [S, S, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
     0b00011110_01100000_00000100_00010000 = [D, D, Imm, Cond] => [R(5), R(16), Ubits(0, 4), Cond(12)];
 ]
 "fcmeq" = [
     // FCMEQ (register)
-    0b01011110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01011110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01011110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FCMEQ (zero)
-    0b01011110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
     0b01011110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
     0b01011110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100000_11011000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100000_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
 ]
 "fcmge" = [
     // FCMGE (register)
-    0b01111110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_01000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01111110_00100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01111110_01100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FCMGE (zero)
-    0b01111110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
+    0b01111110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
     0b01111110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
     0b01111110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00101110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100000_11001000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100000_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
 ]
 "fcmgt" = [
     // FCMGT (register)
-    0b01111110_11000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01111110_11000000_00100100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01111110_10100000_11100100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01111110_11100000_11100100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00101110_11000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00100100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_10100000_11100100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_11100000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FCMGT (zero)
-    0b01011110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11111000_11001000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
     0b01011110_10100000_11001000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
     0b01011110_11100000_11001000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111000_11001000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100000_11001000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100000_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
 ]
 "fcmla" = [
     // FCMLA (by element)
-    0b00101111_01000000_00010000_00000000 = [VStatic(WORD, 4), VStatic(WORD, 4), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[21]), Ulist(13, &[0, 90, 180, 270])];
-    0b01101111_01000000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[11, 21]), Ulist(13, &[0, 90, 180, 270])];
-    0b00101111_10000000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD), Imm] => [R(0), R(5), R(16), Ufields(&[11]), Ulist(13, &[0, 90, 180, 270]), Rwidth(30)];
+    0b00101111_01000000_00010000_00000000 = [VStatic(WORD, 4), VStatic(WORD, 4), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[21]), Ulist(13, &[0, 90, 180, 270])], FCMA | FP16;
+    0b01101111_01000000_00010000_00000000 = [VStatic(WORD, 8), VStatic(WORD, 8), VElement(WORD), Imm] => [R(0), R(5), R(16), Ufields(&[11, 21]), Ulist(13, &[0, 90, 180, 270])], FCMA | FP16;
+    0b00101111_10000000_00010000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD), Imm] => [R(0), R(5), R(16), Ufields(&[11]), Ulist(13, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
     // FCMLA
-    0b00101110_01000000_11000100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
-    0b00101110_10000000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
-    0b00101110_11000000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)];
+    0b00101110_01000000_11000100_00000000 = [V(WORD), V(WORD), V(WORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA | FP16;
+    0b00101110_10000000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
+    0b00101110_11000000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ulist(11, &[0, 90, 180, 270]), Rwidth(30)], FCMA;
 ]
 "fcmle" = [
-    0b01111110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
+    0b01111110_11111000_11011000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
     0b01111110_10100000_11011000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
     0b01111110_11100000_11011000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00101110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111000_11011000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100000_11011000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100000_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
 ]
 "fcmlt" = [
-    0b01011110_11111000_11101000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)];
+    0b01011110_11111000_11101000_00000000 = [H, H, LitFloat(0.0)] => [R(0), R(5)], FP16;
     0b01011110_10100000_11101000_00000000 = [S, S, LitFloat(0.0)] => [R(0), R(5)];
     0b01011110_11100000_11101000_00000000 = [D, D, LitFloat(0.0)] => [R(0), R(5)];
-    0b00001110_11111000_11101000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111000_11101000_00000000 = [V(WORD), V(WORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100000_11101000_00000000 = [V(DWORD), V(DWORD), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100000_11101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), LitFloat(0.0)] => [R(0), R(5), Rwidth(30)];
 ]
 "fcmp" = [
-    0b00011110_11100000_00100000_00000000 = [H, H] => [R(5), R(16)];
-    0b00011110_11100000_00100000_00001000 = [H, LitFloat(0.0)] => [R(5)];
+    0b00011110_11100000_00100000_00000000 = [H, H] => [R(5), R(16)], FP16;
+    0b00011110_11100000_00100000_00001000 = [H, LitFloat(0.0)] => [R(5)], FP16;
     0b00011110_00100000_00100000_00000000 = [S, S] => [R(5), R(16)];
     0b00011110_00100000_00100000_00001000 = [S, LitFloat(0.0)] => [R(5)];
     0b00011110_01100000_00100000_00000000 = [D, D] => [R(5), R(16)];
     0b00011110_01100000_00100000_00001000 = [D, LitFloat(0.0)] => [R(5)];
 ]
 "fcmpe" = [
-    0b00011110_11100000_00100000_00010000 = [H, H] => [R(5), R(16)];
-    0b00011110_11100000_00100000_00011000 = [H, LitFloat(0.0)] => [R(5)];
+    0b00011110_11100000_00100000_00010000 = [H, H] => [R(5), R(16)], FP16;
+    0b00011110_11100000_00100000_00011000 = [H, LitFloat(0.0)] => [R(5)], FP16;
     0b00011110_00100000_00100000_00010000 = [S, S] => [R(5), R(16)];
     0b00011110_00100000_00100000_00011000 = [S, LitFloat(0.0)] => [R(5)];
     0b00011110_01100000_00100000_00010000 = [D, D] => [R(5), R(16)];
     0b00011110_01100000_00100000_00011000 = [D, LitFloat(0.0)] => [R(5)];
 ]
 "fcsel" = [
-    0b00011110_11100000_00001100_00000000 = [H, H, H, Cond] => [R(0), R(5), R(16), Cond(12)];
+    0b00011110_11100000_00001100_00000000 = [H, H, H, Cond] => [R(0), R(5), R(16), Cond(12)], FP16;
     0b00011110_00100000_00001100_00000000 = [S, S, S, Cond] => [R(0), R(5), R(16), Cond(12)];
     0b00011110_01100000_00001100_00000000 = [D, D, D, Cond] => [R(0), R(5), R(16), Cond(12)];
 ]
@@ -778,15 +778,15 @@ Ops!(
 ]
 "fcvtas" = [
     // FCVTAS (vector)
-    0b01011110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTAS (scalar)
-    0b00011110_11100100_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100100_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11100100_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100100_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00100100_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00100100_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01100100_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -794,15 +794,15 @@ Ops!(
 ]
 "fcvtau" = [
     // FCVTAU (vector)
-    0b01111110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_01111001_11001000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_00100001_11001000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_01100001_11001000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_11001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_11001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_11001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTAU (scalar)
-    0b00011110_11100101_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100101_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11100101_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100101_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00100101_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00100101_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01100101_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -818,15 +818,15 @@ Ops!(
 ]
 "fcvtms" = [
     // FCVTMS (vector)
-    0b01011110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTMS (scalar)
-    0b00011110_11110000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11110000_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11110000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11110000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00110000_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00110000_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01110000_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -834,15 +834,15 @@ Ops!(
 ]
 "fcvtmu" = [
     // FCVTMU (vector)
-    0b01111110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_01111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_00100001_10111000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_01100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTMU (scalar)
-    0b00011110_11110001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11110001_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11110001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11110001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00110001_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00110001_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01110001_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -856,15 +856,15 @@ Ops!(
 ]
 "fcvtns" = [
     // FCVTNS (vector)
-    0b01011110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTNS (scalar)
-    0b00011110_11100000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100000_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11100000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00100000_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00100000_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01100000_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -872,15 +872,15 @@ Ops!(
 ]
 "fcvtnu" = [
     // FCVTNU (vector)
-    0b01111110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_01111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_00100001_10101000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_01100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTNU (scalar)
-    0b00011110_11100001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100001_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11100001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00100001_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00100001_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01100001_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -888,15 +888,15 @@ Ops!(
 ]
 "fcvtps" = [
     // FCVTPS (vector)
-    0b01011110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTPS (scalar)
-    0b00011110_11101000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11101000_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11101000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11101000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00101000_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00101000_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01101000_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -904,15 +904,15 @@ Ops!(
 ]
 "fcvtpu" = [
     // FCVTPU (vector)
-    0b01111110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_11111001_10101000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_10100001_10101000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_11100001_10101000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_10101000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100001_10101000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100001_10101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTPU (scalar)
-    0b00011110_11101001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11101001_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11101001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11101001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00101001_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00101001_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01101001_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -927,29 +927,29 @@ Ops!(
 ]
 "fcvtzs" = [
     // FCVTZS (vector, fixed-point)
-    0b01011111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01011111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
     0b01011111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
     0b01011111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
-    0b00001111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
     0b00001111_00100000_11111100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
     0b00001111_01000000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
     // FCVTZS (vector, integer)
-    0b01011110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTZS (scalar, fixed-point)
-    0b00011110_11011000_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
-    0b10011110_11011000_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b00011110_11011000_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b10011110_11011000_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
     0b00011110_00011000_00000000_00000000 = [W, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b10011110_00011000_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     0b00011110_01011000_00000000_00000000 = [W, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b10011110_01011000_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     // FCVTZS (scalar, integer)
-    0b00011110_11111000_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11111000_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11111000_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11111000_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00111000_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00111000_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01111000_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -957,29 +957,29 @@ Ops!(
 ]
 "fcvtzu" = [
     // FCVTZU (vector, fixed-point)
-    0b01111111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_11111100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
     0b01111111_00000000_11111100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
     0b01111111_00000000_11111100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
-    0b00101111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00010000_11111100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
     0b00101111_00100000_11111100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
     0b00101111_01000000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
     // FCVTZU (vector, integer)
-    0b01111110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_11111001_10111000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_10100001_10111000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_11100001_10111000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_10111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100001_10111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100001_10111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FCVTZU (scalar, fixed-point)
-    0b00011110_11011001_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
-    0b10011110_11011001_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b00011110_11011001_00000000_00000000 = [W, H, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
+    0b10011110_11011001_00000000_00000000 = [X, H, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
     0b00011110_00011001_00000000_00000000 = [W, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b10011110_00011001_00000000_00000000 = [X, S, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     0b00011110_01011001_00000000_00000000 = [W, D, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b10011110_01011001_00000000_00000000 = [X, D, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     // FCVTZU (scalar, integer)
-    0b00011110_11111001_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11111001_00000000_00000000 = [X, H] => [R(0), R(5)];
+    0b00011110_11111001_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11111001_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
     0b00011110_00111001_00000000_00000000 = [W, S] => [R(0), R(5)];
     0b10011110_00111001_00000000_00000000 = [X, S] => [R(0), R(5)];
     0b00011110_01111001_00000000_00000000 = [W, D] => [R(0), R(5)];
@@ -987,388 +987,388 @@ Ops!(
 ]
 "fdiv" = [
     // FDIV (vector)
-    0b00101110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FDIV (scalar)
-    0b00011110_11100000_00011000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_00011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_00011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fjcvtzs" = [
-    0b00011110_01111110_00000000_00000000 = [W, D] => [R(0), R(5)];
+    0b00011110_01111110_00000000_00000000 = [W, D] => [R(0), R(5)], JSCVT;
 ]
 "fmadd" = [
-    0b00011111_11000000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11000000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
     0b00011111_00000000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
     0b00011111_01000000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
 ]
 "fmax" = [
     // FMAX (vector)
-    0b00001110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FMAX (scalar)
-    0b00011110_11100000_01001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_01001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_01001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fmaxnm" = [
     // FMAXNM (vector)
-    0b00001110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FMAXNM (scalar)
-    0b00011110_11100000_01101000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01101000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_01101000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_01101000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fmaxnmp" = [
     // FMAXNMP (scalar)
-    0b01011110_00110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
     0b01111110_00110000_11001000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
     0b01111110_01110000_11001000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
     // FMAXNMP (vector)
-    0b00101110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fmaxnmv" = [
-    0b00001110_00110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00110000_11001000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
 ]
 "fmaxp" = [
     // FMAXP (scalar)
-    0b01011110_00110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)];
+    0b01011110_00110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
     0b01111110_00110000_11111000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
     0b01111110_01110000_11111000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
     // FMAXP (vector)
-    0b00101110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fmaxv" = [
-    0b00001110_00110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_00110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00110000_11111000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
 ]
 "fmin" = [
     // FMIN (vector)
-    0b00001110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_10100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_11100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FMIN (scalar)
-    0b00011110_11100000_01011000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01011000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_01011000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_01011000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fminnm" = [
     // FMINNM (vector)
-    0b00001110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_10100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_11100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FMINNM (scalar)
-    0b00011110_11100000_01111000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_01111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_01111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_01111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fminnmp" = [
     // FMINNMP (scalar)
-    0b01011110_10110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)];
+    0b01011110_10110000_11001000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
     0b01111110_10110000_11001000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
     0b01111110_11110000_11001000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
     // FMINNMP (vector)
-    0b00101110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_10100000_11000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_11100000_11000100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fminnmv" = [
-    0b00001110_10110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_11001000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10110000_11001000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
 ]
 "fminp" = [
     // FMINP (scalar)
-    0b01011110_10110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)];
+    0b01011110_10110000_11111000_00000000 = [H, VStatic(WORD, 2)] => [R(0), R(5)], FP16;
     0b01111110_10110000_11111000_00000000 = [S, VStatic(DWORD, 2)] => [R(0), R(5)];
     0b01111110_11110000_11111000_00000000 = [D, VStatic(QWORD, 2)] => [R(0), R(5)];
     // FMINP (vector)
-    0b00101110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_11000000_00110100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_10100000_11110100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_11100000_11110100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fminv" = [
-    0b00001110_10110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_10110000_11111000_00000000 = [H, V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10110000_11111000_00000000 = [S, VStatic(DWORD, 4)] => [R(0), R(5), Rwidth(30)];
 ]
 "fmla" = [
     // FMLA (by element)
-    0b01011111_00000000_00010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_00000000_00010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
     0b01011111_10000000_00010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
     0b01011111_11000000_00010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_00010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_00000000_00010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
     0b00001111_10000000_00010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
     0b00001111_11000000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
     // FMLA (vector)
-    0b00001110_01000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fmlal" = [
     // FMLAL, FMLAL2 (by element)
-    0b00001111_10000000_00000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_00000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01001111_10000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
     // FMLAL, FMLAL2 (vector)
-    0b00001110_00100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)];
-    0b01001110_00100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_00100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01001110_00100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
 ]
 "fmlal2" = [
     // FMLAL, FMLAL2 (by element)
-    0b00101111_10000000_10000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_10000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01101111_10000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
     // FMLAL, FMLAL2 (vector)
-    0b00101110_00100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)];
-    0b01101110_00100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_00100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01101110_00100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
 ]
 "fmls" = [
     // FMLS (by element)
-    0b01011111_00000000_01010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_00000000_01010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
     0b01011111_10000000_01010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
     0b01011111_11000000_01010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_01010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_00000000_01010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
     0b00001111_10000000_01010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
     0b00001111_11000000_01010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
     // FMLS (vector)
-    0b00001110_11000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_10100000_11001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_11100000_11001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fmlsl" = [
     // FMLSL, FMLSL2 (by element)
-    0b00001111_10000000_01000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01001111_10000000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00001111_10000000_01000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01001111_10000000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
     // FMLSL, FMLSL2 (vector)
-    0b00001110_10100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)];
-    0b01001110_10100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00001110_10100000_11101100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01001110_10100000_11101100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
 ]
 "fmlsl2" = [
     // FMLSL, FMLSL2 (by element)
-    0b00101111_10000000_11000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01101111_10000000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b00101111_10000000_11000000_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
+    0b01101111_10000000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FHM;
     // FMLSL, FMLSL2 (vector)
-    0b00101110_10100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)];
-    0b01101110_10100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)];
+    0b00101110_10100000_11001100_00000000 = [VStatic(DWORD, 2), VStatic(WORD, 2), VStatic(WORD, 2)] => [R(0), R(5), R(16)], FHM;
+    0b01101110_10100000_11001100_00000000 = [VStatic(DWORD, 4), VStatic(WORD, 4), VStatic(WORD, 4)] => [R(0), R(5), R(16)], FHM;
 ]
 "fmov" = [
     // FMOV (vector, immediate)
-    0b00001111_00000000_11111100_00000000 = [V(WORD), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)];
+    0b00001111_00000000_11111100_00000000 = [V(WORD), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)], FP16;
     0b00001111_00000000_11110100_00000000 = [V(DWORD), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE), Rwidth(30)];
     0b01101111_00000000_11110100_00000000 = [VStatic(QWORD, 2), Imm] => [R(0), Special(5, SPLIT_FLOAT_IMMEDIATE)];
     // FMOV (register)
-    0b00011110_11100000_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100000_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100000_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100000_01000000_00000000 = [D, D] => [R(0), R(5)];
     // FMOV (general)
-    0b00011110_11100110_00000000_00000000 = [W, H] => [R(0), R(5)];
-    0b10011110_11100110_00000000_00000000 = [X, H] => [R(0), R(5)];
-    0b00011110_11100111_00000000_00000000 = [H, W] => [R(0), R(5)];
+    0b00011110_11100110_00000000_00000000 = [W, H] => [R(0), R(5)], FP16;
+    0b10011110_11100110_00000000_00000000 = [X, H] => [R(0), R(5)], FP16;
+    0b00011110_11100111_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
     0b00011110_00100111_00000000_00000000 = [S, W] => [R(0), R(5)];
     0b00011110_00100110_00000000_00000000 = [W, S] => [R(0), R(5)];
-    0b10011110_11100111_00000000_00000000 = [H, X] => [R(0), R(5)];
+    0b10011110_11100111_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
     0b10011110_01100111_00000000_00000000 = [D, X] => [R(0), R(5)];
     0b10011110_10101111_00000000_00000000 = [VElementStatic(QWORD, 1), X] => [R(0), R(5)];
     0b10011110_01100110_00000000_00000000 = [X, D] => [R(0), R(5)];
     0b10011110_10101110_00000000_00000000 = [X, VElementStatic(QWORD, 1)] => [R(0), R(5)];
     // FMOV (scalar, immediate)
-    0b00011110_11100000_00010000_00000000 = [H, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
+    0b00011110_11100000_00010000_00000000 = [H, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)], FP16;
     0b00011110_00100000_00010000_00000000 = [S, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
     0b00011110_01100000_00010000_00000000 = [D, Imm] => [R(0), Special(13, FLOAT_IMMEDIATE)];
 ]
 "fmsub" = [
-    0b00011111_11000000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11000000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
     0b00011111_00000000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
     0b00011111_01000000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
 ]
 "fmul" = [
     // FMUL (by element)
-    0b01011111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01011111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
     0b01011111_10000000_10010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
     0b01011111_11000000_10010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00001111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00001111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
     0b00001111_10000000_10010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
     0b00001111_11000000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
     // FMUL (vector)
-    0b00101110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00101110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00101110_00100000_11011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00101110_01100000_11011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FMUL (scalar)
-    0b00011110_11100000_00001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_00001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_00001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "fmulx" = [
     // FMULX (by element)
-    0b01111111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
+    0b01111111_00000000_10010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], FP16;
     0b01111111_10000000_10010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
     0b01111111_11000000_10010000_00000000 = [D, D, VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11])];
-    0b00101111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
+    0b00101111_00000000_10010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], FP16;
     0b00101111_10000000_10010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
     0b00101111_11000000_10010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VElement(QWORD)] => [R(0), R(5), R(16), Ufields(&[11]), Rwidth(30)];
     // FMULX
-    0b01011110_01000000_00011100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_01000000_00011100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01011110_00100000_11011100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01011110_01100000_11011100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00011100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11011100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11011100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fneg" = [
     // FNEG (vector)
-    0b00101110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111000_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100000_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100000_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FNEG (scalar)
-    0b00011110_11100001_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100001_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100001_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100001_01000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "fnmadd" = [
-    0b00011111_11100000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11100000_00000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
     0b00011111_00100000_00000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
     0b00011111_01100000_00000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
 ]
 "fnmsub" = [
-    0b00011111_11100000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)];
+    0b00011111_11100000_10000000_00000000 = [H, H, H, H] => [R(0), R(5), R(16), R(10)], FP16;
     0b00011111_00100000_10000000_00000000 = [S, S, S, S] => [R(0), R(5), R(16), R(10)];
     0b00011111_01100000_10000000_00000000 = [D, D, D, D] => [R(0), R(5), R(16), R(10)];
 ]
 "fnmul" = [
-    0b00011110_11100000_10001000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_10001000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_10001000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_10001000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
 "frecpe" = [
-    0b01011110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
 ]
 "frecps" = [
-    0b01011110_01000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_01000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01011110_00100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01011110_01100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_01000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_00100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_01100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "frecpx" = [
-    0b01011110_11111001_11111000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_11111001_11111000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_10100001_11111000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_11100001_11111000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frinta" = [
     // FRINTA (vector)
-    0b00101110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTA (scalar)
-    0b00011110_11100110_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100110_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100110_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100110_01000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frinti" = [
     // FRINTI (vector)
-    0b00101110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTI (scalar)
-    0b00011110_11100111_11000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100111_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100111_11000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100111_11000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frintm" = [
     // FRINTM (vector)
-    0b00001110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTM (scalar)
-    0b00011110_11100101_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100101_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100101_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100101_01000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frintn" = [
     // FRINTN (vector)
-    0b00001110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTN (scalar)
-    0b00011110_11100100_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100100_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100100_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100100_01000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frintp" = [
     // FRINTP (vector)
-    0b00001110_11111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10001000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100001_10001000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100001_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTP (scalar)
-    0b00011110_11100100_11000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100100_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100100_11000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100100_11000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frintx" = [
     // FRINTX (vector)
-    0b00101110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTX (scalar)
-    0b00011110_11100111_01000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100111_01000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100111_01000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100111_01000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frintz" = [
     // FRINTZ (vector)
-    0b00001110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_11111001_10011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_10100001_10011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_11100001_10011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FRINTZ (scalar)
-    0b00011110_11100101_11000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100101_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100101_11000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100101_11000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "frsqrte" = [
-    0b01111110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_11111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_10100001_11011000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_11100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
 ]
 "frsqrts" = [
-    0b01011110_11000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b01011110_11000000_00111100_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b01011110_10100000_11111100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b01011110_11100000_11111100_00000000 = [D, D, D] => [R(0), R(5), R(16)];
-    0b00001110_11000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00111100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_10100000_11111100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_11100000_11111100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "fsqrt" = [
     // FSQRT (vector)
-    0b00101110_11111001_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_11111001_11111000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_10100001_11111000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_11100001_11111000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // FSQRT (scalar)
-    0b00011110_11100001_11000000_00000000 = [H, H] => [R(0), R(5)];
+    0b00011110_11100001_11000000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b00011110_00100001_11000000_00000000 = [S, S] => [R(0), R(5)];
     0b00011110_01100001_11000000_00000000 = [D, D] => [R(0), R(5)];
 ]
 "fsub" = [
     // FSUB (vector)
-    0b00001110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b00001110_11000000_00010100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], FP16;
     0b00001110_10100000_11010100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
     0b00001110_11100000_11010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
     // FSUB (scalar)
-    0b00011110_11100000_00111000_00000000 = [H, H, H] => [R(0), R(5), R(16)];
+    0b00011110_11100000_00111000_00000000 = [H, H, H] => [R(0), R(5), R(16)], FP16;
     0b00011110_00100000_00111000_00000000 = [S, S, S] => [R(0), R(5), R(16)];
     0b00011110_01100000_00111000_00000000 = [D, D, D] => [R(0), R(5), R(16)];
 ]
@@ -1632,75 +1632,75 @@ Ops!(
     0b00001101_11100000_11101100_00000000 = [RegList(4, QWORD), RefBase, X] => [R(0), R(5), RNoZr(16), Rwidth(30)];
 ]
 "ldadd" = [
-    0b10111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldadda" = [
-    0b10111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddab" = [
-    0b00111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddah" = [
-    0b01111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddal" = [
-    0b10111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddalb" = [
-    0b00111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddalh" = [
-    0b01111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddb" = [
-    0b00111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddh" = [
-    0b01111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddl" = [
-    0b10111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddlb" = [
-    0b00111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldaddlh" = [
-    0b01111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldapr" = [
-    0b10111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11111000_10111111_11000000_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
+    0b11111000_10111111_11000000_00000000 = [X, RefBase] => [R(0), R(5)], RCPC;
 ]
 "ldaprb" = [
-    0b00111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
 ]
 "ldaprh" = [
-    0b01111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01111000_10111111_11000000_00000000 = [W, RefBase] => [R(0), R(5)], RCPC;
 ]
 "ldapur" = [
-    0b10011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11011001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b11011001_01000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldapurb" = [
-    0b00011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldapurh" = [
-    0b01011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_01000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldapursb" = [
-    0b00011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b00011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b00011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldapursh" = [
-    0b01011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b01011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_11000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b01011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldapursw" = [
-    0b10011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_10000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "ldar" = [
     0b10001000_11011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
@@ -1727,94 +1727,94 @@ Ops!(
     0b01001000_01011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
 ]
 "ldclr" = [
-    0b10111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclra" = [
-    0b10111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrab" = [
-    0b00111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrah" = [
-    0b01111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclral" = [
-    0b10111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclralb" = [
-    0b00111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclralh" = [
-    0b01111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrb" = [
-    0b00111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrh" = [
-    0b01111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrl" = [
-    0b10111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrlb" = [
-    0b00111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldclrlh" = [
-    0b01111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeor" = [
-    0b10111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeora" = [
-    0b10111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorab" = [
-    0b00111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorah" = [
-    0b01111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeoral" = [
-    0b10111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeoralb" = [
-    0b00111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeoralh" = [
-    0b01111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorb" = [
-    0b00111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorh" = [
-    0b01111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorl" = [
-    0b10111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorlb" = [
-    0b00111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldeorlh" = [
-    0b01111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldlar" = [
-    0b10001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_11011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+    0b11001000_11011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], LOR;
 ]
 "ldlarb" = [
-    0b00001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
 ]
 "ldlarh" = [
-    0b01001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_11011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
 ]
 "ldnp" = [
     // LDNP (SIMD&FP)
@@ -1891,12 +1891,12 @@ Ops!(
     0b11111000_01100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 3])];
 ]
 "ldraa" = [
-    0b11111000_00100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
-    0b11111000_00100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
+    0b11111000_00100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+    0b11111000_00100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
 ]
 "ldrab" = [
-    0b11111000_10100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
-    0b11111000_10100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A];
+    0b11111000_10100000_00000100_00000000 = [X, RefOffset] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
+    0b11111000_10100000_00001100_00000000 = [X, RefPre] => [R(0), R(5), BSscaled(10, 3), Sslice(12, 9, 3), Sslice(22, 1, 12), A], PAUTH;
 ]
 "ldrb" = [
     // LDRB (immediate)
@@ -1949,124 +1949,124 @@ Ops!(
     0b10111000_10100000_00001000_00000000 = [X, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 2])];
 ]
 "ldset" = [
-    0b10111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldseta" = [
-    0b10111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetab" = [
-    0b00111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetah" = [
-    0b01111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetal" = [
-    0b10111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetalb" = [
-    0b00111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetalh" = [
-    0b01111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetb" = [
-    0b00111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldseth" = [
-    0b01111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetl" = [
-    0b10111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_00110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetlb" = [
-    0b00111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsetlh" = [
-    0b01111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_00110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmax" = [
-    0b10111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxa" = [
-    0b10111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxab" = [
-    0b00111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxah" = [
-    0b01111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxal" = [
-    0b10111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxalb" = [
-    0b00111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxalh" = [
-    0b01111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxb" = [
-    0b00111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxh" = [
-    0b01111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxl" = [
-    0b10111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxlb" = [
-    0b00111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmaxlh" = [
-    0b01111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmin" = [
-    0b10111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsmina" = [
-    0b10111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminab" = [
-    0b00111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminah" = [
-    0b01111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminal" = [
-    0b10111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminalb" = [
-    0b00111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminalh" = [
-    0b01111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminb" = [
-    0b00111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminh" = [
-    0b01111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminl" = [
-    0b10111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01010000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminlb" = [
-    0b00111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldsminlh" = [
-    0b01111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01010000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldtr" = [
     0b10111000_01000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
@@ -2090,84 +2090,84 @@ Ops!(
     0b10111000_10000000_00001000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
 ]
 "ldumax" = [
-    0b10111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxa" = [
-    0b10111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxab" = [
-    0b00111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxah" = [
-    0b01111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxal" = [
-    0b10111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxalb" = [
-    0b00111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxalh" = [
-    0b01111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxb" = [
-    0b00111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxh" = [
-    0b01111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxl" = [
-    0b10111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01100000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxlb" = [
-    0b00111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumaxlh" = [
-    0b01111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01100000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumin" = [
-    0b10111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldumina" = [
-    0b10111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminab" = [
-    0b00111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminah" = [
-    0b01111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminal" = [
-    0b10111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminalb" = [
-    0b00111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminalh" = [
-    0b01111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminb" = [
-    0b00111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminh" = [
-    0b01111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminl" = [
-    0b10111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_01110000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminlb" = [
-    0b00111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "lduminlh" = [
-    0b01111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_01110000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "ldur" = [
     // LDUR (SIMD&FP)
@@ -2403,22 +2403,22 @@ Ops!(
     0b10101010_00000000_00000000_00000000 = [X, X, X, End, Mod(ROTATES)] => [R(0), R(5), R(16), Rotates(22), Ubits(10, 6)];
 ]
 "pacda" = [
-    0b11011010_11000001_00001000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00001000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "pacdb" = [
-    0b11011010_11000001_00001100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00001100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "pacdza" = [
-    0b11011010_11000001_00101011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00101011_11100000 = [X] => [R(0)], PAUTH;
 ]
 "pacdzb" = [
-    0b11011010_11000001_00101111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00101111_11100000 = [X] => [R(0)], PAUTH;
 ]
 "pacga" = [
-    0b10011010_11000000_00110000_00000000 = [X, X, XSP] => [R(0), R(5), R(16)];
+    0b10011010_11000000_00110000_00000000 = [X, X, XSP] => [R(0), R(5), R(16)], PAUTH;
 ]
 "pacia" = [
-    0b11011010_11000001_00000000_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00000000_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "pacia1716" = [
     0b11010101_00000011_00100001_00011111 = [] => [];
@@ -2430,7 +2430,7 @@ Ops!(
     0b11010101_00000011_00100011_00011111 = [] => [];
 ]
 "pacib" = [
-    0b11011010_11000001_00000100_00000000 = [X, XSP] => [R(0), R(5)];
+    0b11011010_11000001_00000100_00000000 = [X, XSP] => [R(0), R(5)], PAUTH;
 ]
 "pacib1716" = [
     0b11010101_00000011_00100001_01011111 = [] => [];
@@ -2442,21 +2442,21 @@ Ops!(
     0b11010101_00000011_00100011_01011111 = [] => [];
 ]
 "paciza" = [
-    0b11011010_11000001_00100011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00100011_11100000 = [X] => [R(0)], PAUTH;
 ]
 "pacizb" = [
-    0b11011010_11000001_00100111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_00100111_11100000 = [X] => [R(0)], PAUTH;
 ]
 "pmul" = [
     0b00101110_00100000_10011100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "pmull" = [
     0b00001110_00100000_11100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b00001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 1), VStatic(QWORD, 1)] => [R(0), R(5), R(16)];
+    0b00001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 1), VStatic(QWORD, 1)] => [R(0), R(5), R(16)], AES;
 ]
 "pmull2" = [
     0b01001110_00100000_11100000_00000000 = [VStatic(WORD, 8), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
-    0b01001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+    0b01001110_11100000_11100000_00000000 = [VStatic(OWORD, 1), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], AES;
 ]
 "prfm" = [
     // PRFM (literal)
@@ -2468,7 +2468,7 @@ Ops!(
     0b11111000_10000000_00000000_00000000 = [Imm, RefOffset] => [Ubits(0, 5), R(5), Sbits(12, 9)];
 ]
 "psb" = [
-    0b11010101_00000011_00100010_00111111 = [Lit("csync")] => [];
+    0b11010101_00000011_00100010_00111111 = [Lit("csync")] => [], SPE;
 ]
 "pssbb" = [
     0b11010101_00000011_00110100_10011111 = [] => [];
@@ -2484,7 +2484,7 @@ Ops!(
     0b01101110_10100000_01000000_00000000 = [VStatic(DWORD, 4), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
 ]
 "rax1" = [
-    0b11001110_01100000_10001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10001100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
 ]
 "rbit" = [
     // RBIT (vector)
@@ -2498,10 +2498,10 @@ Ops!(
     0b11010110_01011111_00000011_11000000 = [] => [];
 ]
 "retaa" = [
-    0b11010110_01011111_00001011_11111111 = [] => [];
+    0b11010110_01011111_00001011_11111111 = [] => [], PAUTH;
 ]
 "retab" = [
-    0b11010110_01011111_00001111_11111111 = [] => [];
+    0b11010110_01011111_00001111_11111111 = [] => [], PAUTH;
 ]
 "rev" = [
     0b01011010_11000000_00001000_00000000 = [W, W] => [R(0), R(5)];
@@ -2528,7 +2528,7 @@ Ops!(
     0b11011010_11000000_00001100_00000000 = [X, X] => [R(0), R(5)];
 ]
 "rmif" = [
-    0b10111010_00000000_00000100_00000000 = [X, Imm, Imm] => [R(5), Ubits(15, 6), Ubits(0, 4)];
+    0b10111010_00000000_00000100_00000000 = [X, Imm, Imm] => [R(5), Ubits(15, 6), Ubits(0, 4)], FLAGM;
 ]
 "ror" = [
     // ROR (immediate)
@@ -2628,7 +2628,7 @@ Ops!(
     0b01001110_10100000_00010000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
 ]
 "sb" = [
-    0b11010101_00000011_00110000_11111111 = [] => [];
+    0b11010101_00000011_00110000_11111111 = [] => [], SB;
 ]
 "sbc" = [
     0b01011010_00000000_00000000_00000000 = [W, W, W] => [R(0), R(5), R(16)];
@@ -2652,31 +2652,31 @@ Ops!(
 ]
 "scvtf" = [
     // SCVTF (vector, fixed-point)
-    0b01011111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01011111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
     0b01011111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
     0b01011111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
-    0b00001111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00001111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
     0b00001111_00100000_11100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
     0b00001111_01000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
     // SCVTF (vector, integer)
-    0b01011110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)];
+    0b01011110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01011110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
     0b01011110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00001110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00001110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00001110_00100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00001110_01100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // SCVTF (scalar, fixed-point)
-    0b00011110_11000010_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b00011110_11000010_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
     0b00011110_00000010_00000000_00000000 = [S, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b00011110_01000010_00000000_00000000 = [D, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
-    0b10011110_11000010_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b10011110_11000010_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
     0b10011110_00000010_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     0b10011110_01000010_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     // SCVTF (scalar, integer)
-    0b00011110_11100010_00000000_00000000 = [H, W] => [R(0), R(5)];
+    0b00011110_11100010_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
     0b00011110_00100010_00000000_00000000 = [S, W] => [R(0), R(5)];
     0b00011110_01100010_00000000_00000000 = [D, W] => [R(0), R(5)];
-    0b10011110_11100010_00000000_00000000 = [H, X] => [R(0), R(5)];
+    0b10011110_11100010_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
     0b10011110_00100010_00000000_00000000 = [S, X] => [R(0), R(5)];
     0b10011110_01100010_00000000_00000000 = [D, X] => [R(0), R(5)];
 ]
@@ -2686,17 +2686,17 @@ Ops!(
 ]
 "sdot" = [
     // SDOT (by element)
-    0b00001111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01001111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00001111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    0b01001111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
     // SDOT (vector)
-    0b00001110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b01001110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b00001110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], DOTPROD;
+    0b01001110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], DOTPROD;
 ]
 "setf16" = [
-    0b00111010_00000000_01001000_00001101 = [W] => [R(5)];
+    0b00111010_00000000_01001000_00001101 = [W] => [R(5)], FLAGM;
 ]
 "setf8" = [
-    0b00111010_00000000_00001000_00001101 = [W] => [R(5)];
+    0b00111010_00000000_00001000_00001101 = [W] => [R(5)], FLAGM;
 ]
 "sev" = [
     0b11010101_00000011_00100000_10011111 = [] => [];
@@ -2705,46 +2705,46 @@ Ops!(
     0b11010101_00000011_00100000_10111111 = [] => [];
 ]
 "sha1c" = [
-    0b01011110_00000000_00000000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00000000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha1h" = [
-    0b01011110_00101000_00001000_00000000 = [S, S] => [R(0), R(5)];
+    0b01011110_00101000_00001000_00000000 = [S, S] => [R(0), R(5)], SHA2;
 ]
 "sha1m" = [
-    0b01011110_00000000_00100000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00100000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha1p" = [
-    0b01011110_00000000_00010000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00010000_00000000 = [Q, S, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha1su0" = [
-    0b01011110_00000000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_00110000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha1su1" = [
-    0b01011110_00101000_00011000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01011110_00101000_00011000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SHA2;
 ]
 "sha256h" = [
-    0b01011110_00000000_01000000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01000000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha256h2" = [
-    0b01011110_00000000_01010000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01010000_00000000 = [Q, Q, VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha256su0" = [
-    0b01011110_00101000_00101000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b01011110_00101000_00101000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SHA2;
 ]
 "sha256su1" = [
-    0b01011110_00000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b01011110_00000000_01100000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SHA2;
 ]
 "sha512h" = [
-    0b11001110_01100000_10000000_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10000000_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
 ]
 "sha512h2" = [
-    0b11001110_01100000_10000100_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10000100_00000000 = [Q, Q, VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
 ]
 "sha512su0" = [
-    0b11001110_11000000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)];
+    0b11001110_11000000_10000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5)], SHA3;
 ]
 "sha512su1" = [
-    0b11001110_01100000_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_10001000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16)], SHA3;
 ]
 "shadd" = [
     0b00001110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
@@ -2791,31 +2791,31 @@ Ops!(
     0b00101111_01000000_01010100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Ubits(16, 6), Rwidth(30)];
 ]
 "sm3partw1" = [
-    0b11001110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
 ]
 "sm3partw2" = [
-    0b11001110_01100000_11000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
 ]
 "sm3ss1" = [
-    0b11001110_01000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16), R(10)];
+    0b11001110_01000000_00000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16), R(10)], SM4;
 ]
 "sm3tt1a" = [
-    0b11001110_01000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10000000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
 ]
 "sm3tt1b" = [
-    0b11001110_01000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
 ]
 "sm3tt2a" = [
-    0b11001110_01000000_10001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
 ]
 "sm3tt2b" = [
-    0b11001110_01000000_10001100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)];
+    0b11001110_01000000_10001100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VElement(DWORD)] => [R(0), R(5), R(16), Ubits(12, 2)], SM4;
 ]
 "sm4e" = [
-    0b11001110_11000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)];
+    0b11001110_11000000_10000100_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5)], SM4;
 ]
 "sm4ekey" = [
-    0b11001110_01100000_11001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)];
+    0b11001110_01100000_11001000_00000000 = [VStatic(DWORD, 4), VStatic(DWORD, 4), VStatic(DWORD, 4)] => [R(0), R(5), R(16)], SM4;
 ]
 "smaddl" = [
     0b10011011_00100000_00000000_00000000 = [X, W, W, X] => [R(0), R(5), R(16), R(10)];
@@ -3029,27 +3029,27 @@ Ops!(
 ]
 "sqrdmlah" = [
     // SQRDMLAH (by element)
-    0b01111111_01000000_11010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01111111_10000000_11010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00101111_01000000_11010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_11010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01111111_01000000_11010000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], RDM;
+    0b01111111_10000000_11010000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])], RDM;
+    0b00101111_01000000_11010000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], RDM;
+    0b00101111_10000000_11010000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], RDM;
     // SQRDMLAH (vector)
-    0b01111110_01000000_10000100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10000000_10000100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00101110_01000000_10000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10000000_10000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_10000100_00000000 = [H, H, H] => [R(0), R(5), R(16)], RDM;
+    0b01111110_10000000_10000100_00000000 = [S, S, S] => [R(0), R(5), R(16)], RDM;
+    0b00101110_01000000_10000100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+    0b00101110_10000000_10000100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
 ]
 "sqrdmlsh" = [
     // SQRDMLSH (by element)
-    0b01111111_01000000_11110000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])];
-    0b01111111_10000000_11110000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b00101111_01000000_11110000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)];
-    0b00101111_10000000_11110000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)];
+    0b01111111_01000000_11110000_00000000 = [H, H, VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20])], RDM;
+    0b01111111_10000000_11110000_00000000 = [S, S, VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21])], RDM;
+    0b00101111_01000000_11110000_00000000 = [V(WORD), V(WORD), VElement(WORD)] => [R(0), R(5), R4(16), Ufields(&[11, 21, 20]), Rwidth(30)], RDM;
+    0b00101111_10000000_11110000_00000000 = [V(DWORD), V(DWORD), VElement(DWORD)] => [R(0), R(5), R(16), Ufields(&[11, 21]), Rwidth(30)], RDM;
     // SQRDMLSH (vector)
-    0b01111110_01000000_10001100_00000000 = [H, H, H] => [R(0), R(5), R(16)];
-    0b01111110_10000000_10001100_00000000 = [S, S, S] => [R(0), R(5), R(16)];
-    0b00101110_01000000_10001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)];
-    0b00101110_10000000_10001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)];
+    0b01111110_01000000_10001100_00000000 = [H, H, H] => [R(0), R(5), R(16)], RDM;
+    0b01111110_10000000_10001100_00000000 = [S, S, S] => [R(0), R(5), R(16)], RDM;
+    0b00101110_01000000_10001100_00000000 = [V(WORD), V(WORD), V(WORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
+    0b00101110_10000000_10001100_00000000 = [V(DWORD), V(DWORD), V(DWORD)] => [R(0), R(5), R(16), Rwidth(30)], RDM;
 ]
 "sqrdmulh" = [
     // SQRDMULH (by element)
@@ -3452,74 +3452,74 @@ Ops!(
     0b00001101_10100000_10100100_00000000 = [RegListElement(4, QWORD), RefBase, X] => [R(0), Ufields(&[30]), R(5), RNoZr(16)];
 ]
 "stadd" = [
-    0b10111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "staddb" = [
-    0b00111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "staddh" = [
-    0b01111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "staddl" = [
-    0b10111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "staddlb" = [
-    0b00111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "staddlh" = [
-    0b01111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclr" = [
-    0b10111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclrb" = [
-    0b00111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclrh" = [
-    0b01111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclrl" = [
-    0b10111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclrlb" = [
-    0b00111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stclrlh" = [
-    0b01111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "steor" = [
-    0b10111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "steorb" = [
-    0b00111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "steorh" = [
-    0b01111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "steorl" = [
-    0b10111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "steorlb" = [
-    0b00111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "steorlh" = [
-    0b01111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stllr" = [
-    0b10001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
-    0b11001000_10011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)];
+    0b10001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
+    0b11001000_10011111_01111100_00000000 = [X, RefBase] => [R(0), R(5)], LOR;
 ]
 "stllrb" = [
-    0b00001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b00001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
 ]
 "stllrh" = [
-    0b01001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)];
+    0b01001000_10011111_01111100_00000000 = [W, RefBase] => [R(0), R(5)], LOR;
 ]
 "stlr" = [
     0b10001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
@@ -3532,14 +3532,14 @@ Ops!(
     0b01001000_10011111_11111100_00000000 = [W, RefBase] => [R(0), R(5)];
 ]
 "stlur" = [
-    0b10011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
-    0b11011001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b10011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
+    0b11011001_00000000_00000000_00000000 = [X, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "stlurb" = [
-    0b00011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b00011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "stlurh" = [
-    0b01011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
+    0b01011001_00000000_00000000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)], RCPC2;
 ]
 "stlxp" = [
     0b10001000_00100000_10000000_00000000 = [W, W, W, RefBase] => [R(16), R(0), R(10), R(5)];
@@ -3634,64 +3634,64 @@ Ops!(
     0b01111000_00100000_00001000_00000000 = [W, RefIndex] => [R(0), R(5), R(16), ExtendsX(13), Ulist(12, &[0, 1])];
 ]
 "stset" = [
-    0b10111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsetb" = [
-    0b00111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stseth" = [
-    0b01111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsetl" = [
-    0b10111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_00110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsetlb" = [
-    0b00111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsetlh" = [
-    0b01111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_00110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmax" = [
-    0b10111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmaxb" = [
-    0b00111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmaxh" = [
-    0b01111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmaxl" = [
-    0b10111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01000000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmaxlb" = [
-    0b00111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmaxlh" = [
-    0b01111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01000000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsmin" = [
-    0b10111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsminb" = [
-    0b00111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsminh" = [
-    0b01111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsminl" = [
-    0b10111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01010000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsminlb" = [
-    0b00111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stsminlh" = [
-    0b01111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01010000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "sttr" = [
     0b10111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
@@ -3704,44 +3704,44 @@ Ops!(
     0b01111000_00000000_00001000_00000000 = [W, RefOffset] => [R(0), R(5), Sbits(12, 9)];
 ]
 "stumax" = [
-    0b10111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumaxb" = [
-    0b00111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumaxh" = [
-    0b01111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumaxl" = [
-    0b10111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01100000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumaxlb" = [
-    0b00111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumaxlh" = [
-    0b01111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01100000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stumin" = [
-    0b10111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_00100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_00100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stuminb" = [
-    0b00111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stuminh" = [
-    0b01111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_00100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stuminl" = [
-    0b10111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
-    0b11111000_01100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)];
+    0b10111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
+    0b11111000_01100000_01110000_00011111 = [X, RefBase] => [R(16), R(5)], LSE;
 ]
 "stuminlb" = [
-    0b00111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b00111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stuminlh" = [
-    0b01111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)];
+    0b01111000_01100000_01110000_00011111 = [W, RefBase] => [R(16), R(5)], LSE;
 ]
 "stur" = [
     // STUR (SIMD&FP)
@@ -3828,44 +3828,44 @@ Ops!(
     0b11010100_00000000_00000000_00000001 = [Imm] => [Ubits(5, 16)];
 ]
 "swp" = [
-    0b10111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_00100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_00100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpa" = [
-    0b10111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_10100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_10100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpab" = [
-    0b00111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpah" = [
-    0b01111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_10100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpal" = [
-    0b10111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_11100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_11100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpalb" = [
-    0b00111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpalh" = [
-    0b01111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_11100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpb" = [
-    0b00111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swph" = [
-    0b01111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_00100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swpl" = [
-    0b10111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
-    0b11111000_01100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)];
+    0b10111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
+    0b11111000_01100000_10000000_00000000 = [X, X, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swplb" = [
-    0b00111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b00111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "swplh" = [
-    0b01111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)];
+    0b01111000_01100000_10000000_00000000 = [W, W, RefBase] => [R(16), R(0), R(5)], LSE;
 ]
 "sxtb" = [
     0b00010011_00000000_00011100_00000000 = [W, W] => [R(0), R(5)];
@@ -3930,7 +3930,7 @@ Ops!(
     0b00001110_11000000_01101000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), R(16), Rwidth(30)];
 ]
 "tsb" = [
-    0b11010101_00000011_00100010_01011111 = [Lit("csync")] => [];
+    0b11010101_00000011_00100010_01011111 = [Lit("csync")] => [], TRACE;
 ]
 "tst" = [
     // TST (immediate)
@@ -4019,31 +4019,31 @@ Ops!(
 ]
 "ucvtf" = [
     // UCVTF (vector, fixed-point)
-    0b01111111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)];
+    0b01111111_00000000_11100100_00000000 = [H, H, Imm] => [R(0), R(5), BUrange(1, 16), Usub(16, 5, 32)], FP16;
     0b01111111_00000000_11100100_00000000 = [S, S, Imm] => [R(0), R(5), BUrange(1, 32), Usub(16, 6, 64)];
     0b01111111_00000000_11100100_00000000 = [D, D, Imm] => [R(0), R(5), BUrange(1, 64), Usub(16, 7, 128)];
-    0b00101111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)];
+    0b00101111_00010000_11100100_00000000 = [V(WORD), V(WORD), Imm] => [R(0), R(5), Usub(16, 4, 16), Rwidth(30)], FP16;
     0b00101111_00100000_11100100_00000000 = [V(DWORD), V(DWORD), Imm] => [R(0), R(5), Usub(16, 5, 32), Rwidth(30)];
     0b00101111_01000000_11100100_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), Usub(16, 6, 64), Rwidth(30)];
     // UCVTF (vector, integer)
-    0b01111110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)];
+    0b01111110_01111001_11011000_00000000 = [H, H] => [R(0), R(5)], FP16;
     0b01111110_00100001_11011000_00000000 = [S, S] => [R(0), R(5)];
     0b01111110_01100001_11011000_00000000 = [D, D] => [R(0), R(5)];
-    0b00101110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)];
+    0b00101110_01111001_11011000_00000000 = [V(WORD), V(WORD)] => [R(0), R(5), Rwidth(30)], FP16;
     0b00101110_00100001_11011000_00000000 = [V(DWORD), V(DWORD)] => [R(0), R(5), Rwidth(30)];
     0b00101110_01100001_11011000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2)] => [R(0), R(5), Rwidth(30)];
     // UCVTF (scalar, fixed-point)
-    0b00011110_11000011_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
+    0b00011110_11000011_00000000_00000000 = [H, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)], FP16;
     0b00011110_00000011_00000000_00000000 = [S, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
     0b00011110_01000011_00000000_00000000 = [D, W, Imm] => [R(0), R(5), BUrange(1, 32), Usub(10, 6, 64)];
-    0b10011110_11000011_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
+    0b10011110_11000011_00000000_00000000 = [H, X, Imm] => [R(0), R(5), Usub(10, 6, 64)], FP16;
     0b10011110_00000011_00000000_00000000 = [S, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     0b10011110_01000011_00000000_00000000 = [D, X, Imm] => [R(0), R(5), Usub(10, 6, 64)];
     // UCVTF (scalar, integer)
-    0b00011110_11100011_00000000_00000000 = [H, W] => [R(0), R(5)];
+    0b00011110_11100011_00000000_00000000 = [H, W] => [R(0), R(5)], FP16;
     0b00011110_00100011_00000000_00000000 = [S, W] => [R(0), R(5)];
     0b00011110_01100011_00000000_00000000 = [D, W] => [R(0), R(5)];
-    0b10011110_11100011_00000000_00000000 = [H, X] => [R(0), R(5)];
+    0b10011110_11100011_00000000_00000000 = [H, X] => [R(0), R(5)], FP16;
     0b10011110_00100011_00000000_00000000 = [S, X] => [R(0), R(5)];
     0b10011110_01100011_00000000_00000000 = [D, X] => [R(0), R(5)];
 ]
@@ -4056,11 +4056,11 @@ Ops!(
 ]
 "udot" = [
     // UDOT (by element)
-    0b00101111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
-    0b01101111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])];
+    0b00101111_10000000_11100000_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
+    0b01101111_10000000_11100000_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStaticElement(BYTE, 4)] => [R(0), R(5), R(16), Ufields(&[11, 21])], DOTPROD;
     // UDOT (vector)
-    0b00101110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)];
-    0b01101110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)];
+    0b00101110_10000000_10010100_00000000 = [VStatic(DWORD, 2), VStatic(BYTE, 8), VStatic(BYTE, 8)] => [R(0), R(5), R(16)], DOTPROD;
+    0b01101110_10000000_10010100_00000000 = [VStatic(DWORD, 4), VStatic(BYTE, 16), VStatic(BYTE, 16)] => [R(0), R(5), R(16)], DOTPROD;
 ]
 "uhadd" = [
     0b00101110_00100000_00000100_00000000 = [V(BYTE), V(BYTE), V(BYTE)] => [R(0), R(5), R(16), Rwidth(30)];
@@ -4393,13 +4393,13 @@ Ops!(
     0b11010101_00000011_00100000_01111111 = [] => [];
 ]
 "xar" = [
-    0b11001110_10000000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ubits(10, 6)];
+    0b11001110_10000000_00000000_00000000 = [VStatic(QWORD, 2), VStatic(QWORD, 2), VStatic(QWORD, 2), Imm] => [R(0), R(5), R(16), Ubits(10, 6)], SHA3;
 ]
 "xpacd" = [
-    0b11011010_11000001_01000111_11100000 = [X] => [R(0)];
+    0b11011010_11000001_01000111_11100000 = [X] => [R(0)], PAUTH;
 ]
 "xpaci" = [
-    0b11011010_11000001_01000011_11100000 = [X] => [R(0)];
+    0b11011010_11000001_01000011_11100000 = [X] => [R(0)], PAUTH;
 ]
 "xpaclri" = [
     0b11010101_00000011_00100000_11111111 = [] => [];
@@ -2,7 +2,7 @@ use syn::{parse, Token};
 
 use lazy_static::lazy_static;
 
-use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOpt, ParseOptExt};
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOpt, ParseOptExt, dynamic_register};
 use crate::common::Size;
 
 use super::Context;
@@ -275,7 +275,7 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
         let expr: syn::Expr = inner.parse()?;
 
         size = s;
-        kind = RegKind::Dynamic(family, expr);
+        kind = RegKind::Dynamic(family, dynamic_register("aarch64", typed_register(family), "u32", &expr));
     } else {
         unreachable!();
     };
@@ -349,6 +349,15 @@ fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Opti
     }
 }
 
+// The typed register in `dynasmrt::aarch64` that dynamic registers of `family` are checked against.
+fn typed_register(family: RegFamily) -> &'static str {
+    match family {
+        RegFamily::INTEGER   => "X",
+        RegFamily::INTEGERSP => "XSP",
+        RegFamily::SIMD      => "V",
+    }
+}
+
 impl ParseOpt for ModifyExpr {
     fn parse(input: parse::ParseStream) -> parse::Result<Option<Self>> {
         let modifier: Modifier = match input.parse() {
@@ -0,0 +1,314 @@
+use lazy_static::lazy_static;
+use std::collections::{HashMap, hash_map};
+
+/// The operands an instruction encoding accepts, and how they are encoded.
+/// Offsets are bit offsets into the encoding template. Thumb-2 encodings are written with
+/// their first halfword in the upper 16 bits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    // a general purpose register
+    R(u8),
+    // a general purpose register encoded in multiple fields
+    Rs(&'static [u8]),
+    // a general purpose register from r0 to r7, in the 3-bit encoding used by 16-bit thumb instructions
+    RLow(u8),
+    // a base register that can be followed by a ! to request writeback
+    RWb(u8),
+    // a general purpose register that has to be the register after the previous one. It is not encoded.
+    RNext,
+
+    // single precision registers, in the split Vd, Vn and Vm fields
+    Sd,
+    Sn,
+    Sm,
+    // double precision registers, in the split Vd, Vn and Vm fields
+    Dd,
+    Dn,
+    Dm,
+
+    // an immediate
+    Imm(Imm),
+    // an arm modified immediate: an 8-bit value rotated by an even amount of bits
+    ModImm,
+    // a thumb modified immediate
+    TModImm,
+    // an optional shift by an immediate, in the arm encoding
+    Shift,
+    // an optional shift by an immediate, in the thumb encoding
+    TShift,
+    // a shift by a register, in the arm encoding
+    RegShift,
+    // an optional rotation by 8, 16 or 24 bits, encoded at the offset
+    Rotation(u8),
+    // the least significant bit of a bitfield
+    Lsb(Imm),
+    // the width of a bitfield. It is encoded as the most significant bit if the flag is set,
+    // and as width - 1 otherwise.
+    Width(Imm, bool),
+
+    // a memory reference
+    Mem(MemKind),
+    // a list of general purpose registers, encoded as a 16-bit mask
+    RegList,
+    // a list of consecutive single (false) or double (true) precision registers
+    VRegList(bool),
+    // a relocation target
+    Target(Relocation),
+
+    // an optional memory barrier option
+    Barrier,
+    // a floating point immediate
+    FImm,
+    // the immediate zero
+    Zero,
+    // a literal, like the name of a special purpose register
+    Lit(&'static str),
+    // the first condition of an if-then block
+    ItCond,
+}
+
+/// The addressing modes of memory references
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemKind {
+    // arm word and byte accesses: 12-bit immediate or shifted register offset, with any indexing mode
+    A2,
+    // arm halfword, signed byte and doubleword accesses: split 8-bit immediate or register offset, with any indexing mode
+    A3,
+    // vfp loads and stores: 8-bit immediate scaled by 4, offset mode only
+    V,
+    // exclusive accesses without offset
+    Ex,
+    // thumb accesses with an unsigned 12-bit immediate offset
+    T12,
+    // thumb accesses with a negative 8-bit immediate offset, or pre / post indexing
+    T8,
+    // thumb accesses with a register offset shifted left by 0 to 3
+    TReg,
+    // thumb doubleword accesses: 8-bit immediate scaled by 4, with any indexing mode
+    TD,
+    // thumb exclusive word accesses: unsigned 8-bit immediate offset scaled by 4
+    TEx,
+}
+
+/// A description of an immediate operand: its range, and how its bits are scattered over the encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Imm {
+    /// The smallest allowed value
+    pub min: i64,
+    /// The largest allowed value
+    pub max: i64,
+    /// A list of fields of the immediate: (lowest bit in the immediate, amount of bits, offset in the encoding)
+    pub fields: &'static [(u8, u8, u8)],
+}
+
+macro_rules! imm {
+    ($min:expr, $max:expr, [ $( $field:expr ),* ]) => {
+        Imm {
+            min: $min,
+            max: $max,
+            fields: &[ $( $field ),* ]
+        }
+    }
+}
+
+// arm immediates
+pub const IMM16: Imm = imm!(0, 0xFFFF, [(12, 4, 16), (0, 12, 0)]);
+pub const IMM16_SPLIT: Imm = imm!(0, 0xFFFF, [(4, 12, 8), (0, 4, 0)]);
+pub const IMM24: Imm = imm!(0, 0xFF_FFFF, [(0, 24, 0)]);
+// arm shift amounts. Right shifts by 32 are encoded as 0.
+pub const LSL5: Imm = imm!(0, 31, [(0, 5, 7)]);
+pub const SHR5: Imm = imm!(1, 32, [(0, 5, 7)]);
+pub const ROR5: Imm = imm!(1, 31, [(0, 5, 7)]);
+// arm bitfields
+pub const LSB5: Imm = imm!(0, 31, [(0, 5, 7)]);
+pub const WIDTH5: Imm = imm!(1, 32, [(0, 5, 16)]);
+
+// thumb immediates
+pub const IMM8: Imm = imm!(0, 0xFF, [(0, 8, 0)]);
+pub const T_IMM12: Imm = imm!(0, 0xFFF, [(11, 1, 26), (8, 3, 12), (0, 8, 0)]);
+pub const T_IMM16: Imm = imm!(0, 0xFFFF, [(12, 4, 16), (11, 1, 26), (8, 3, 12), (0, 8, 0)]);
+// thumb shift amounts
+pub const T_LSL5: Imm = imm!(0, 31, [(2, 3, 12), (0, 2, 6)]);
+pub const T_SHR5: Imm = imm!(1, 32, [(2, 3, 12), (0, 2, 6)]);
+pub const T_ROR5: Imm = imm!(1, 31, [(2, 3, 12), (0, 2, 6)]);
+// thumb bitfields
+pub const T_LSB5: Imm = imm!(0, 31, [(2, 3, 12), (0, 2, 6)]);
+pub const T_WIDTH5: Imm = imm!(1, 32, [(0, 5, 0)]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // b, bl: 24 bits, word aligned
+    BRANCH = 0,
+    // ldr, ldrb literal: 12 bits with a sign bit
+    LDR = 1,
+    // adr: a modified immediate that is added or subtracted
+    ADR = 2,
+    // vldr literal: 8 bits, word aligned, with a sign bit
+    VLDR = 3,
+    // thumb b.w, bl: 24 bits, halfword aligned
+    TBRANCH = 4,
+    // thumb conditional b.w: 20 bits, halfword aligned
+    TBCOND = 5,
+    // thumb cbz, cbnz: 7 bits, halfword aligned, forward only
+    TCBZ = 6,
+    // thumb ldr, ldrb literal: 12 bits with a sign bit
+    TLDR = 7,
+    // thumb adr: 12 bits with a sign bit
+    TADR = 8,
+    // thumb vldr literal: 8 bits, word aligned, with a sign bit
+    TVLDR = 9,
+    // 8-bit literal
+    LITERAL8 = 11,
+    // 16-bit literal
+    LITERAL16 = 12,
+    // 32-bit literal
+    LITERAL32 = 14,
+    // 64-bit literal
+    LITERAL64 = 18,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. Conditional arm encodings leave the condition field zeroed.
+    pub base: u32,
+    /// The operands of this instruction, in order
+    pub operands: &'static [Operand],
+}
+
+impl Opdata {
+    /// The size of the encoding in bytes. Arm instructions are always 4 bytes, thumb instructions
+    /// are 2 bytes unless their first halfword marks them as 32-bit.
+    pub fn size(&self, thumb: bool) -> u8 {
+        if thumb && self.base <= 0xFFFF {
+            2
+        } else {
+            4
+        }
+    }
+
+    /// If this is an arm encoding that takes a condition
+    pub fn is_conditional(&self) -> bool {
+        self.base & 0xF000_0000 == 0
+    }
+
+    /// If this is the conditional thumb branch form
+    pub fn is_thumb_conditional(&self) -> bool {
+        self.operands.contains(&Operand::Target(Relocation::TBCOND))
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $operand:expr ),* ] ) => {
+        {
+            const OPERANDS: &'static [Operand] = {
+                #[allow(unused_imports)]
+                use self::Operand::*;
+                #[allow(unused_imports)]
+                use self::MemKind::*;
+                &[ $(
+                    $operand
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                operands: OPERANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $operand:expr ),* ] ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $operand ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str, thumb: bool) -> Option<&'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.get(&name).cloned()
+    } else {
+        ARM_OPMAP.get(&name).cloned()
+    }
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics(thumb: bool) -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    if thumb {
+        THUMB_OPMAP.keys()
+    } else {
+        ARM_OPMAP.keys()
+    }
+}
+
+lazy_static! {
+    static ref ARM_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_arm.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    static ref THUMB_OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap_thumb.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of the condition codes that can be appended to mnemonics
+    pub static ref COND_MAP: HashMap<&'static str, u8> = {
+        static MAP: &[(&str, u8)] = &[
+            ("eq", 0x0),
+            ("ne", 0x1),
+            ("cs", 0x2),
+            ("hs", 0x2),
+            ("cc", 0x3),
+            ("lo", 0x3),
+            ("mi", 0x4),
+            ("pl", 0x5),
+            ("vs", 0x6),
+            ("vc", 0x7),
+            ("hi", 0x8),
+            ("ls", 0x9),
+            ("ge", 0xA),
+            ("lt", 0xB),
+            ("gt", 0xC),
+            ("le", 0xD),
+            ("al", 0xE),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of the options of the memory barrier instructions
+    pub static ref BARRIER_MAP: HashMap<&'static str, u8> = {
+        static MAP: &[(&str, u8)] = &[
+            ("sy", 0xF),
+            ("st", 0xE),
+            ("ld", 0xD),
+            ("ish", 0xB),
+            ("ishst", 0xA),
+            ("ishld", 0x9),
+            ("nsh", 0x7),
+            ("nshst", 0x6),
+            ("nshld", 0x5),
+            ("osh", 0x3),
+            ("oshst", 0x2),
+            ("oshld", 0x1),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -0,0 +1,137 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+/// An arm register, either chosen statically or dynamically
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub family: RegFamily,
+    pub kind: RegKind
+}
+
+// Register id without indication of its usage. Either a static register encoding or an expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(u8),
+    Dynamic(syn::Expr)
+}
+
+// register family. INTEGER = rn and its special names. SINGLE = sn. DOUBLE = dn
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    INTEGER = 0,
+    SINGLE  = 1,
+    DOUBLE  = 2,
+}
+
+impl Register {
+    pub fn new_static(family: RegFamily, code: u8) -> Register {
+        Register {
+            family,
+            kind: RegKind::Static(code)
+        }
+    }
+
+    pub fn new_dynamic(family: RegFamily, expr: syn::Expr) -> Register {
+        Register {
+            family,
+            kind: RegKind::Dynamic(expr)
+        }
+    }
+
+    /// The static encoding of this register, if it is known at compile time
+    pub fn static_code(&self) -> Option<u8> {
+        match self.kind {
+            RegKind::Static(code) => Some(code),
+            RegKind::Dynamic(_) => None
+        }
+    }
+}
+
+/// The kinds of shifts that can be applied to a register operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShiftKind {
+    LSL = 0,
+    LSR = 1,
+    ASR = 2,
+    ROR = 3,
+    RRX = 4,
+}
+
+/// The amount of a shift
+#[derive(Debug, Clone)]
+pub enum ShiftAmount {
+    Immediate(syn::Expr),
+    Register(Register)
+}
+
+/// The offset inside of a memory reference, or after it for post-indexed references
+#[derive(Debug, Clone)]
+pub enum RefOffset {
+    Immediate(syn::Expr),
+    Register {
+        reg: Register,
+        negated: bool,
+        shift: Option<(ShiftKind, Option<syn::Expr>)>
+    }
+}
+
+/// The indexing mode of a memory reference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RefMode {
+    // [base, offset]
+    Offset,
+    // [base, offset]!
+    PreIndexed,
+    // [base], offset
+    PostIndexed
+}
+
+/**
+ * Parsed ast
+ */
+
+/// An instruction, with the full dot-separated mnemonic
+#[derive(Debug)]
+pub struct Instruction {
+    pub name: String,
+    pub span: Span
+}
+
+/// The possible arguments of an arm instruction
+#[derive(Debug)]
+pub enum RawArg {
+    // A register
+    Direct {
+        reg: Register
+    },
+    // A register followed by !, requesting writeback
+    Writeback {
+        reg: Register
+    },
+    // A shift applied to the previous register
+    Shift {
+        kind: ShiftKind,
+        amount: Option<ShiftAmount>
+    },
+    // A memory reference
+    Reference {
+        base: Register,
+        offset: Option<RefOffset>,
+        mode: RefMode
+    },
+    // A list of registers: {r0, r4-r7}
+    RegList {
+        items: Vec<Register>
+    },
+    // A label or other relocation target
+    JumpTarget {
+        jump: Jump
+    },
+    // An immediate, or a literal like a condition, barrier option or special purpose register
+    Immediate {
+        value: syn::Expr
+    }
+}
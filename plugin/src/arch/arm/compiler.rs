@@ -0,0 +1,607 @@
+use super::matching::{MatchData, cond_name};
+use super::armdata::{Operand, MemKind, Imm, COND_MAP, BARRIER_MAP};
+use super::armdata::{LSL5, SHR5, ROR5, T_LSL5, T_SHR5, T_ROR5};
+use super::encoding_helpers::{encode_modified_immediate, encode_thumb_modified_immediate, encode_floating_point_immediate};
+use super::{Context, ItState};
+use super::ast::{RawArg, Register, RegKind, ShiftKind, ShiftAmount, RefOffset, RefMode};
+
+use crate::common::{Stmt, Size, delimited, bitmask};
+use crate::parse_helpers::{as_ident, as_number, as_signed_number, as_float};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+
+pub(super) fn compile_instruction(ctx: &mut Context, name: &str, data: MatchData) -> Result<(), Option<String>> {
+    let opdata = data.data;
+    let thumb = ctx.thumb;
+
+    // if-then blocks only change the state of the assembler
+    if opdata.operands == [Operand::ItCond] {
+        return compile_it(ctx, name, data);
+    }
+
+    let mut statics: Vec<(u8, u32)> = Vec::new();
+    let mut dynamics: Vec<(u8, TokenStream)> = Vec::new();
+    let mut relocations = Vec::new();
+
+    // the condition code
+    if thumb {
+        if opdata.is_thumb_conditional() {
+            statics.push((22, u32::from(data.cond.expect("conditional branch without condition"))));
+        }
+    } else if opdata.is_conditional() {
+        statics.push((28, u32::from(data.cond.unwrap_or(0xE))));
+    } else if data.cond.map_or(false, |cond| cond != 0xE) {
+        return Err(Some(format!("'{}': instruction cannot be conditional", name)));
+    }
+
+    // some operands depend on the previous one
+    let mut last_reg: Option<Register> = None;
+    let mut last_lsb: Option<syn::Expr> = None;
+
+    let mut args = data.args.into_iter().peekable();
+
+    for operand in opdata.operands {
+        // handle omitted optional operands
+        let present = match (*operand, args.peek()) {
+            (Operand::Shift, Some(RawArg::Shift { .. })) |
+            (Operand::TShift, Some(RawArg::Shift { .. })) |
+            (Operand::Rotation(_), Some(RawArg::Shift { .. })) |
+            (Operand::Barrier, Some(RawArg::Immediate { .. })) => true,
+            (Operand::Shift, _) |
+            (Operand::TShift, _) |
+            (Operand::Rotation(_), _) => false,
+            (Operand::Barrier, _) => {
+                statics.push((0, 0xF));
+                false
+            },
+            _ => true
+        };
+        if !present {
+            continue;
+        }
+
+        let arg = args.next().expect("Not enough arguments matched");
+
+        match (*operand, arg) {
+            (Operand::R(offset), RawArg::Direct { reg }) => {
+                encode_register(&reg, offset, 0xF, &mut statics, &mut dynamics);
+                last_reg = Some(reg);
+            },
+            (Operand::Rs(offsets), RawArg::Direct { reg }) => for &offset in offsets {
+                encode_register(&reg, offset, 0xF, &mut statics, &mut dynamics);
+            },
+            (Operand::RLow(offset), RawArg::Direct { reg }) => encode_register(&reg, offset, 0x7, &mut statics, &mut dynamics),
+            (Operand::RWb(offset), RawArg::Direct { reg }) => encode_register(&reg, offset, 0xF, &mut statics, &mut dynamics),
+            (Operand::RWb(offset), RawArg::Writeback { reg }) => {
+                encode_register(&reg, offset, 0xF, &mut statics, &mut dynamics);
+                statics.push((21, 1));
+            },
+            (Operand::RNext, RawArg::Direct { reg }) => {
+                if let (Some(first), Some(second)) = (last_reg.as_ref().and_then(Register::static_code), reg.static_code()) {
+                    if second != first + 1 {
+                        return Err(Some(format!("'{}': the second register has to be the register after the first", name)));
+                    }
+                }
+            },
+
+            (Operand::Sd, RawArg::Direct { reg }) => encode_vfp_register(&reg, false, (12, 22), &mut statics, &mut dynamics),
+            (Operand::Sn, RawArg::Direct { reg }) => encode_vfp_register(&reg, false, (16, 7), &mut statics, &mut dynamics),
+            (Operand::Sm, RawArg::Direct { reg }) => encode_vfp_register(&reg, false, (0, 5), &mut statics, &mut dynamics),
+            (Operand::Dd, RawArg::Direct { reg }) => encode_vfp_register(&reg, true, (12, 22), &mut statics, &mut dynamics),
+            (Operand::Dn, RawArg::Direct { reg }) => encode_vfp_register(&reg, true, (16, 7), &mut statics, &mut dynamics),
+            (Operand::Dm, RawArg::Direct { reg }) => encode_vfp_register(&reg, true, (0, 5), &mut statics, &mut dynamics),
+
+            (Operand::Imm(imm), RawArg::Immediate { value }) => encode_immediate(&value, imm, &mut statics, &mut dynamics)?,
+            (Operand::ModImm, RawArg::Immediate { value }) => encode_modimm(&value, false, &mut statics, &mut dynamics)?,
+            (Operand::TModImm, RawArg::Immediate { value }) => encode_modimm(&value, true, &mut statics, &mut dynamics)?,
+
+            (Operand::Shift, RawArg::Shift { kind, amount }) |
+            (Operand::TShift, RawArg::Shift { kind, amount }) => {
+                let amount = match amount {
+                    Some(ShiftAmount::Immediate(amount)) => Some(amount),
+                    _ => None
+                };
+                encode_shift(kind, amount.as_ref(), thumb, &mut statics, &mut dynamics)?;
+            },
+            (Operand::RegShift, RawArg::Shift { kind, amount: Some(ShiftAmount::Register(reg)) }) => {
+                statics.push((5, kind as u32));
+                encode_register(&reg, 8, 0xF, &mut statics, &mut dynamics);
+            },
+            (Operand::Rotation(offset), RawArg::Shift { amount: Some(ShiftAmount::Immediate(amount)), .. }) => {
+                if let Some(value) = as_number(&amount) {
+                    if value % 8 != 0 || value > 24 {
+                        emit_error!(amount, "Invalid rotation, expected 8, 16 or 24");
+                        return Err(None);
+                    }
+                    statics.push((offset, (value / 8) as u32));
+                } else {
+                    dynamics.push((offset, quote_spanned!{ amount.span()=>
+                        ((#amount) as u32 >> 3) & 3
+                    }));
+                }
+            },
+            (Operand::Lsb(imm), RawArg::Immediate { value }) => {
+                encode_immediate(&value, imm, &mut statics, &mut dynamics)?;
+                last_lsb = Some(value);
+            },
+            (Operand::Width(imm, msb), RawArg::Immediate { value }) => {
+                let lsb = last_lsb.take().expect("bitfield width without lsb");
+                let offset = imm.fields[0].2;
+
+                if let (Some(lsb), Some(width)) = (as_signed_number(&lsb), as_signed_number(&value)) {
+                    if width < 1 || width > 32 - lsb {
+                        emit_error!(value, "Invalid bitfield width");
+                        return Err(None);
+                    }
+                    let encoded = if msb { lsb + width - 1 } else { width - 1 };
+                    statics.push((offset, encoded as u32));
+                } else if msb {
+                    dynamics.push((offset, quote_spanned!{ value.span()=>
+                        ((#lsb) as u32).wrapping_add((#value) as u32).wrapping_sub(1) & 0x1F
+                    }));
+                } else {
+                    dynamics.push((offset, quote_spanned!{ value.span()=>
+                        ((#value) as u32).wrapping_sub(1) & 0x1F
+                    }));
+                }
+            },
+
+            (Operand::Mem(kind), RawArg::Reference { base, offset, mode }) => {
+                encode_register(&base, 16, 0xF, &mut statics, &mut dynamics);
+                encode_reference(kind, offset, mode, &mut statics, &mut dynamics)?;
+            },
+            (Operand::RegList, RawArg::RegList { items }) => {
+                let mut mask = 0u32;
+                for reg in items {
+                    match reg.kind {
+                        RegKind::Static(code) => mask |= 1 << code,
+                        RegKind::Dynamic(expr) => dynamics.push((0, quote_spanned!{ expr.span()=>
+                            1u32 << (#expr & 0xF)
+                        }))
+                    }
+                }
+                statics.push((0, mask));
+            },
+            (Operand::VRegList(double), RawArg::RegList { items }) => {
+                let mut codes = Vec::new();
+                for reg in &items {
+                    match reg.static_code() {
+                        Some(code) => codes.push(code),
+                        None => return Err(Some(format!("'{}': floating point register lists cannot contain dynamic registers", name)))
+                    }
+                }
+
+                if codes.windows(2).any(|pair| pair[1] != pair[0] + 1) {
+                    return Err(Some(format!("'{}': floating point register lists have to be consecutive", name)));
+                }
+                if double && codes.len() > 16 {
+                    return Err(Some(format!("'{}': too many registers", name)));
+                }
+
+                encode_vfp_register(&items[0], double, (12, 22), &mut statics, &mut dynamics);
+                statics.push((0, codes.len() as u32 * if double { 2 } else { 1 }));
+            },
+            (Operand::Target(relocation), RawArg::JumpTarget { jump }) => {
+                // what kind of relocation is it
+                let data = [relocation.to_id()];
+
+                // encode the complete relocation
+                relocations.push(jump.encode(&data));
+            },
+
+            (Operand::Barrier, RawArg::Immediate { value }) => {
+                if let Some(&option) = as_ident(&value).and_then(|ident| BARRIER_MAP.get(&*ident.to_string().to_lowercase())) {
+                    statics.push((0, u32::from(option)));
+                } else if let Some(option) = as_number(&value).filter(|&option| option <= 0xF) {
+                    statics.push((0, option as u32));
+                } else {
+                    emit_error!(value, "Invalid barrier option");
+                    return Err(None);
+                }
+            },
+            (Operand::FImm, RawArg::Immediate { value }) => {
+                let float = if let Some(value) = as_float(&value) {
+                    value
+                } else if let Some(value) = as_signed_number(&value) {
+                    value as f64
+                } else {
+                    emit_error!(value, "Floating point immediates have to be known at compile time");
+                    return Err(None);
+                };
+
+                if let Some(encoded) = encode_floating_point_immediate(float as f32).filter(|_| float as f32 as f64 == float) {
+                    statics.push((16, u32::from(encoded >> 4)));
+                    statics.push((0, u32::from(encoded & 0xF)));
+                } else {
+                    emit_error!(value, "Unrepresentable floating point immediate");
+                    return Err(None);
+                }
+            },
+            (Operand::Zero, _) |
+            (Operand::Lit(_), _) => (),
+
+            _ => panic!("Invalid argument processor")
+        }
+    }
+
+    // sanity
+    if args.next().is_some() {
+        panic!("Not enough operand processors");
+    }
+
+    emit_encoding(ctx, opdata.base, opdata.size(thumb), statics, dynamics);
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+// it{x{y{z}}} firstcond: starts an if-then block. In arm state these only exist for source compatibility.
+fn compile_it(ctx: &mut Context, name: &str, data: MatchData) -> Result<(), Option<String>> {
+    let value = match data.args.into_iter().next() {
+        Some(RawArg::Immediate { value }) => value,
+        _ => panic!("Invalid argument processor")
+    };
+
+    let first = match as_ident(&value).and_then(|ident| COND_MAP.get(&*ident.to_string().to_lowercase())) {
+        Some(&cond) => cond,
+        None => {
+            emit_error!(value, "Unknown condition code");
+            return Err(None);
+        }
+    };
+
+    let pattern = &name[2..];
+    if first == 0xE && pattern.contains('e') {
+        return Err(Some(format!("'{}': the inverse of condition {} cannot be used", name, cond_name(first))));
+    }
+
+    let mut conds = vec![first];
+    let mut mask = 1u16 << (3 - pattern.len());
+    for (i, c) in pattern.chars().enumerate() {
+        let bit = if c == 't' { first & 1 } else { !first & 1 };
+        conds.push(if c == 't' { first } else { first ^ 1 });
+        mask |= u16::from(bit) << (3 - i);
+    }
+
+    if ctx.thumb {
+        ctx.it.set(ItState::new(&conds));
+        ctx.state.stmts.push(Stmt::u16(0xBF00 | u16::from(first) << 4 | mask));
+    }
+
+    Ok(())
+}
+
+// emit the statement encoding an instruction of the given size. 32-bit thumb instructions are
+// stored as two halfwords, the first of which is kept in the upper half of the encoding template.
+fn emit_encoding(ctx: &mut Context, base: u32, size: u8, statics: Vec<(u8, u32)>, dynamics: Vec<(u8, TokenStream)>) {
+    let swap = ctx.thumb && size == 4;
+
+    // apply all statics to bits
+    let mut bits = base;
+    for (offset, value) in statics {
+        bits |= value << offset;
+    }
+
+    let (size, ty) = match size {
+        2 => (Size::WORD, quote!(u16)),
+        4 => (Size::DWORD, quote!(u32)),
+        _ => unreachable!()
+    };
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let mut res = quote!{
+            (#bits as #ty)
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        if swap {
+            res = quote!{
+                (#res).rotate_left(16)
+            };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        if swap {
+            bits = bits.rotate_left(16);
+        }
+        ctx.state.stmts.push(Stmt::Const(u64::from(bits), size));
+    }
+}
+
+fn encode_register(reg: &Register, offset: u8, mask: u32, statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) {
+    match reg.kind {
+        RegKind::Static(code) => statics.push((offset, u32::from(code) & mask)),
+        RegKind::Dynamic(ref expr) => dynamics.push((offset, quote_spanned!{ expr.span()=>
+            #expr & #mask
+        }))
+    }
+}
+
+// vfp registers are split over a 4-bit field and a 1-bit field. Single precision registers keep their
+// lowest bit in the 1-bit field, double precision registers their highest bit.
+fn encode_vfp_register(reg: &Register, double: bool, (field, bit): (u8, u8), statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) {
+    match reg.kind {
+        RegKind::Static(code) => {
+            let code = u32::from(code);
+            if double {
+                statics.push((field, code & 0xF));
+                statics.push((bit, code >> 4));
+            } else {
+                statics.push((field, code >> 1));
+                statics.push((bit, code & 1));
+            }
+        },
+        RegKind::Dynamic(ref expr) => {
+            let span = expr.span();
+            if double {
+                dynamics.push((field, quote_spanned!{ span=> #expr & 0xF }));
+                dynamics.push((bit, quote_spanned!{ span=> (#expr >> 4) & 1 }));
+            } else {
+                dynamics.push((field, quote_spanned!{ span=> (#expr >> 1) & 0xF }));
+                dynamics.push((bit, quote_spanned!{ span=> #expr & 1 }));
+            }
+        }
+    }
+}
+
+fn encode_immediate(expr: &syn::Expr, imm: Imm, statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        if value > imm.max {
+            emit_error!(expr, "Value too large");
+            return Err(None);
+        } else if value < imm.min {
+            emit_error!(expr, "Value too small");
+            return Err(None);
+        }
+
+        for &(low, bits, offset) in imm.fields {
+            statics.push((offset, ((value >> low) as u32) & bitmask(bits)));
+        }
+    } else {
+        // scatter the fields of the immediate at runtime
+        let span = expr.span();
+        dynamics.push((0, scatter_fields(quote_spanned!{ span=> (#expr) as u32 }, imm.fields)));
+    }
+
+    Ok(())
+}
+
+// generates code that scatters the bits of a value at runtime
+fn scatter_fields(value: TokenStream, fields: &[(u8, u8, u8)]) -> TokenStream {
+    let mut res = quote!{ 0u32 };
+    for &(low, bits, offset) in fields {
+        let mask = bitmask(bits);
+        res = quote!{
+            #res | (((imm >> #low) & #mask) << #offset)
+        };
+    }
+
+    quote!{
+        {
+            let imm: u32 = #value;
+            #res
+        }
+    }
+}
+
+// modified immediates are either validated at compile time, or encoded at runtime by the runtime helpers
+fn encode_modimm(expr: &syn::Expr, thumb: bool, statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    let fields: &[(u8, u8, u8)] = if thumb {
+        &[(11, 1, 26), (8, 3, 12), (0, 8, 0)]
+    } else {
+        &[(0, 12, 0)]
+    };
+
+    if let Some(value) = as_signed_number(expr) {
+        if value < -0x8000_0000 || value > 0xFFFF_FFFF {
+            emit_error!(expr, "Value out of range");
+            return Err(None);
+        }
+
+        let encoded = if thumb {
+            encode_thumb_modified_immediate(value as u32)
+        } else {
+            encode_modified_immediate(value as u32)
+        };
+
+        let encoded = match encoded {
+            Some(encoded) => u32::from(encoded),
+            None => {
+                emit_error!(expr, "Impossible modified immediate");
+                return Err(None);
+            }
+        };
+
+        for &(low, bits, offset) in fields {
+            statics.push((offset, (encoded >> low) & bitmask(bits)));
+        }
+    } else {
+        let span = expr.span();
+        let value = if thumb {
+            quote_spanned!{ span=>
+                u32::from(dynasmrt::arm::encode_thumb_modified_immediate((#expr) as u32).expect("Impossible modified immediate"))
+            }
+        } else {
+            quote_spanned!{ span=>
+                u32::from(dynasmrt::arm::encode_modified_immediate((#expr) as u32).expect("Impossible modified immediate"))
+            }
+        };
+        dynamics.push((0, scatter_fields(value, fields)));
+    }
+
+    Ok(())
+}
+
+// encodes a shift by an immediate. Arm keeps the shift type at bit 5 and the amount at bit 7,
+// thumb keeps the type at bit 4 and splits the amount.
+fn encode_shift(kind: ShiftKind, amount: Option<&syn::Expr>, thumb: bool, statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    let type_offset = if thumb { 4 } else { 5 };
+
+    let amount = match amount {
+        Some(amount) => amount,
+        None => {
+            // rrx is encoded as ror #0
+            statics.push((type_offset, ShiftKind::ROR as u32));
+            return Ok(());
+        }
+    };
+
+    statics.push((type_offset, kind as u32));
+
+    let imm = match (kind, thumb) {
+        (ShiftKind::LSL, false) => LSL5,
+        (ShiftKind::LSR, false) |
+        (ShiftKind::ASR, false) => SHR5,
+        (ShiftKind::ROR, false) => ROR5,
+        (ShiftKind::LSL, true) => T_LSL5,
+        (ShiftKind::LSR, true) |
+        (ShiftKind::ASR, true) => T_SHR5,
+        (ShiftKind::ROR, true) => T_ROR5,
+        (ShiftKind::RRX, _) => unreachable!()
+    };
+
+    // right shifts by 32 are encoded as 0, which the field masks take care of
+    encode_immediate(amount, imm, statics, dynamics)
+}
+
+// encodes the offset and indexing mode of a memory reference
+fn encode_reference(kind: MemKind, offset: Option<RefOffset>, mode: RefMode, statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    match kind {
+        MemKind::A2 | MemKind::A3 => {
+            // P, W
+            match mode {
+                RefMode::Offset => statics.push((24, 1)),
+                RefMode::PreIndexed => statics.extend(&[(24, 1), (21, 1)]),
+                RefMode::PostIndexed => ()
+            }
+
+            match offset {
+                None => {
+                    statics.push((23, 1));
+                    if kind == MemKind::A3 {
+                        statics.push((22, 1));
+                    }
+                },
+                Some(RefOffset::Immediate(value)) => if kind == MemKind::A2 {
+                    encode_signed_offset(&value, 0xFFF, 0, 23, &[(0, 12, 0)], statics, dynamics)?;
+                } else {
+                    statics.push((22, 1));
+                    encode_signed_offset(&value, 0xFF, 0, 23, &[(4, 4, 8), (0, 4, 0)], statics, dynamics)?;
+                },
+                Some(RefOffset::Register { reg, negated, shift }) => {
+                    if !negated {
+                        statics.push((23, 1));
+                    }
+                    if kind == MemKind::A2 {
+                        statics.push((25, 1));
+                    }
+                    encode_register(&reg, 0, 0xF, statics, dynamics);
+
+                    if let Some((kind, amount)) = shift {
+                        encode_shift(kind, amount.as_ref(), false, statics, dynamics)?;
+                    }
+                }
+            }
+        },
+        MemKind::V => match offset {
+            None => statics.push((23, 1)),
+            Some(RefOffset::Immediate(value)) => encode_signed_offset(&value, 0x3FC, 2, 23, &[(0, 8, 0)], statics, dynamics)?,
+            Some(RefOffset::Register { .. }) => unreachable!()
+        },
+        MemKind::Ex => (),
+        MemKind::T12 => if let Some(RefOffset::Immediate(value)) = offset {
+            encode_immediate(&value, Imm { min: 0, max: 0xFFF, fields: &[(0, 12, 0)] }, statics, dynamics)?;
+        },
+        MemKind::T8 => {
+            // P, W
+            match mode {
+                RefMode::Offset => statics.push((10, 1)),
+                RefMode::PreIndexed => statics.extend(&[(10, 1), (8, 1)]),
+                RefMode::PostIndexed => statics.push((8, 1))
+            }
+
+            match offset {
+                None => statics.push((9, 1)),
+                Some(RefOffset::Immediate(value)) => encode_signed_offset(&value, 0xFF, 0, 9, &[(0, 8, 0)], statics, dynamics)?,
+                Some(RefOffset::Register { .. }) => unreachable!()
+            }
+        },
+        MemKind::TReg => if let Some(RefOffset::Register { reg, shift, .. }) = offset {
+            encode_register(&reg, 0, 0xF, statics, dynamics);
+
+            if let Some((_, Some(amount))) = shift {
+                encode_immediate(&amount, Imm { min: 0, max: 3, fields: &[(0, 2, 4)] }, statics, dynamics)?;
+            }
+        },
+        MemKind::TD => {
+            // P, W
+            match mode {
+                RefMode::Offset => statics.push((24, 1)),
+                RefMode::PreIndexed => statics.extend(&[(24, 1), (21, 1)]),
+                RefMode::PostIndexed => statics.push((21, 1))
+            }
+
+            match offset {
+                None => statics.push((23, 1)),
+                Some(RefOffset::Immediate(value)) => encode_signed_offset(&value, 0x3FC, 2, 23, &[(0, 8, 0)], statics, dynamics)?,
+                Some(RefOffset::Register { .. }) => unreachable!()
+            }
+        },
+        MemKind::TEx => if let Some(RefOffset::Immediate(value)) = offset {
+            if let Some(value) = as_signed_number(&value) {
+                if value < 0 || value > 0x3FC || value & 3 != 0 {
+                    return Err(Some("Invalid offset, expected a multiple of 4 between 0 and 1020".into()));
+                }
+                statics.push((0, (value >> 2) as u32));
+            } else {
+                dynamics.push((0, quote_spanned!{ value.span()=>
+                    ((#value) as u32 >> 2) & 0xFF
+                }));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// encodes an offset as a magnitude and a sign bit that is set for positive offsets
+fn encode_signed_offset(expr: &syn::Expr, max: i64, scale: u8, sign_offset: u8, fields: &'static [(u8, u8, u8)], statics: &mut Vec<(u8, u32)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        if value.abs() > max {
+            emit_error!(expr, "Offset out of range");
+            return Err(None);
+        } else if value & ((1 << scale) - 1) != 0 {
+            emit_error!(expr, "Unrepresentable offset");
+            return Err(None);
+        }
+
+        if value >= 0 {
+            statics.push((sign_offset, 1));
+        }
+        let magnitude = (value.abs() >> scale) as u32;
+        for &(low, bits, offset) in fields {
+            statics.push((offset, (magnitude >> low) & bitmask(bits)));
+        }
+    } else {
+        let span = expr.span();
+        let scattered = scatter_fields(quote_spanned!{ span=> magnitude >> #scale }, fields);
+        dynamics.push((0, quote_spanned!{ span=>
+            {
+                let offset = (#expr) as i32;
+                let magnitude = offset.unsigned_abs();
+                (u32::from(offset >= 0) << #sign_offset) | #scattered
+            }
+        }));
+    }
+
+    Ok(())
+}
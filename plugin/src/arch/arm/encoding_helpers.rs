@@ -0,0 +1,55 @@
+/// Encodes a value as an arm modified immediate: an 8-bit value rotated right by an even amount of bits.
+/// Returns the 12-bit encoding.
+pub fn encode_modified_immediate(value: u32) -> Option<u16> {
+    for rotation in 0 .. 16 {
+        let unrotated = value.rotate_left(rotation * 2);
+        if unrotated <= 0xFF {
+            return Some((rotation << 8 | unrotated) as u16);
+        }
+    }
+    None
+}
+
+/// Encodes a value as a thumb modified immediate: an 8-bit value that is either replicated over the word
+/// in one of several patterns, or has its most significant bit set and is rotated right.
+/// Returns the 12-bit i:imm3:imm8 encoding.
+pub fn encode_thumb_modified_immediate(value: u32) -> Option<u16> {
+    let low = value & 0xFF;
+    let high = (value >> 8) & 0xFF;
+
+    if value == low {
+        return Some(low as u16);
+    } else if value == low * 0x0001_0001 {
+        return Some((0x100 | low) as u16);
+    } else if value == high * 0x0100_0100 {
+        return Some((0x200 | high) as u16);
+    } else if value == low * 0x0101_0101 {
+        return Some((0x300 | low) as u16);
+    }
+
+    // a rotated value 1bcdefgh
+    let rotation = value.leading_zeros();
+    let unrotated = value.rotate_left(rotation + 8);
+    if unrotated >= 0x80 && unrotated <= 0xFF {
+        let amount = rotation + 8;
+        Some((amount << 7 | (unrotated & 0x7F)) as u16)
+    } else {
+        None
+    }
+}
+
+pub fn encode_floating_point_immediate(value: f32) -> Option<u8> {
+    // vfp immediates are encoded as
+    // abcdefgh => aBbbbbbc defgh000 00000000 00000000
+    // where B = !b
+    // which means we can just slice out "a" and "bcdefgh" and assume the rest was correct
+
+    let bits = value.to_bits();
+
+    let check = (bits >> 25) & 0x3F;
+    if (check == 0b10_0000 || check == 0b01_1111) && (bits & 0x7_FFFF) == 0 {
+        Some((((bits >> 24) & 0x80) | ((bits >> 19) & 0x7F)) as u8)
+    } else {
+        None
+    }
+}
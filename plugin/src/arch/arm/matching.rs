@@ -0,0 +1,283 @@
+use proc_macro_error::emit_error;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, RegFamily, ShiftKind, ShiftAmount, RefOffset, RefMode};
+use super::armdata::{Opdata, Operand, MemKind, COND_MAP, get_mnemonic_data};
+
+use crate::common::JumpKind;
+use crate::parse_helpers::{as_ident, as_number, as_signed_number, as_float};
+
+/// The result of matching an instruction: the encoding to use and the arguments to encode with it.
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<RawArg>,
+    /// The condition the instruction was suffixed with, if any
+    pub cond: Option<u8>
+}
+
+/// Try finding an appropriate definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+    // validate that only relative jumps are allowed (no extern relocations)
+    for arg in &args {
+        if let RawArg::JumpTarget { ref jump } = *arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error!(jump.span(), "Extern relocations are not allowed in {}", if ctx.thumb { "thumb" } else { "arm" });
+                return Err(None);
+            }
+        }
+    }
+
+    // split off the width qualifier
+    let mut name = &*instruction.name;
+    let mut wide = None;
+    if name.ends_with(".w") {
+        name = &name[.. name.len() - 2];
+        wide = Some(true);
+    } else if name.ends_with(".n") {
+        if !ctx.thumb {
+            return Err(Some("The .n qualifier can only be used in thumb state".into()));
+        }
+        name = &name[.. name.len() - 2];
+        wide = Some(false);
+    }
+
+    // get the possible encodings, and the condition code the mnemonic was suffixed with
+    let (name, cond, opdata) = if let Some(o) = lookup_mnemonic(name, ctx.thumb) {
+        o
+    } else {
+        return Err(Some(format!("Unknown instruction mnemonic '{}'", instruction.name)));
+    };
+
+    // determine which condition the instruction has to be encoded with
+    let it_cond = if ctx.thumb { ctx.it.get().current() } else { None };
+    if it_cond.is_some() && opdata[0].operands == [Operand::ItCond] {
+        return Err(Some("IT blocks cannot be nested".into()));
+    }
+    let branch_cond = match (it_cond, cond) {
+        (Some(expected), cond) => if cond.unwrap_or(0xE) != expected {
+            return Err(Some(format!("Instructions inside an IT block have to use the condition of the block ({})", cond_name(expected))));
+        } else {
+            false
+        },
+        (None, Some(cond)) if ctx.thumb && cond != 0xE => true,
+        _ => false
+    };
+
+    // matching loop
+    for data in opdata {
+        if ctx.thumb {
+            // conditional branches are the only conditional instructions outside of IT blocks
+            if data.is_thumb_conditional() != branch_cond {
+                continue;
+            }
+            if let Some(wide) = wide {
+                if (data.size(true) == 4) != wide {
+                    continue;
+                }
+            }
+        }
+
+        if match_args(&args, data) {
+            return Ok(MatchData {
+                data,
+                args,
+                cond
+            });
+        }
+    }
+
+    if branch_cond {
+        return Err(Some(format!("'{}': conditional instructions have to be placed inside an IT block in thumb state", instruction.name)));
+    }
+
+    Err(Some(
+        format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", instruction.name, format_opdata_list(&name, opdata))
+    ))
+}
+
+// looks up a mnemonic, which can be suffixed by a condition code before its data type qualifiers, like addeq or vaddne.f32
+fn lookup_mnemonic(name: &str, thumb: bool) -> Option<(String, Option<u8>, &'static [Opdata])> {
+    if let Some(data) = get_mnemonic_data(name, thumb) {
+        return Some((name.to_string(), None, data));
+    }
+
+    let (head, tail) = match name.find('.') {
+        Some(i) => name.split_at(i),
+        None => (name, "")
+    };
+
+    if head.len() > 2 && head.is_char_boundary(head.len() - 2) {
+        let (base, suffix) = head.split_at(head.len() - 2);
+        if let Some(&cond) = COND_MAP.get(suffix) {
+            let base = format!("{}{}", base, tail);
+            if let Some(data) = get_mnemonic_data(&base, thumb) {
+                return Some((base, Some(cond), data));
+            }
+        }
+    }
+
+    None
+}
+
+/// The name of a condition code, for error messages
+pub(super) fn cond_name(cond: u8) -> &'static str {
+    ["eq", "ne", "cs", "cc", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le", "al"][cond as usize]
+}
+
+fn match_args(args: &[RawArg], data: &Opdata) -> bool {
+    let mut args = args.iter().peekable();
+
+    for operand in data.operands {
+        // optional operands can be omitted
+        let optional = match *operand {
+            Operand::Shift |
+            Operand::TShift |
+            Operand::Rotation(_) |
+            Operand::Barrier => true,
+            _ => false
+        };
+
+        let arg = match args.peek() {
+            Some(arg) => *arg,
+            None => if optional {
+                continue;
+            } else {
+                return false;
+            }
+        };
+
+        if match_operand(arg, operand) {
+            args.next();
+        } else if !optional {
+            return false;
+        }
+    }
+
+    args.next().is_none()
+}
+
+fn match_operand(arg: &RawArg, operand: &Operand) -> bool {
+    match (arg, *operand) {
+        (RawArg::Direct { reg, .. }, Operand::R(_)) |
+        (RawArg::Direct { reg, .. }, Operand::Rs(_)) |
+        (RawArg::Direct { reg, .. }, Operand::RWb(_)) |
+        (RawArg::Writeback { reg, .. }, Operand::RWb(_)) |
+        (RawArg::Direct { reg, .. }, Operand::RNext) => reg.family == RegFamily::INTEGER,
+        (RawArg::Direct { reg, .. }, Operand::RLow(_)) => reg.family == RegFamily::INTEGER && reg.static_code().map_or(true, |code| code < 8),
+        (RawArg::Direct { reg, .. }, Operand::Sd) |
+        (RawArg::Direct { reg, .. }, Operand::Sn) |
+        (RawArg::Direct { reg, .. }, Operand::Sm) => reg.family == RegFamily::SINGLE,
+        (RawArg::Direct { reg, .. }, Operand::Dd) |
+        (RawArg::Direct { reg, .. }, Operand::Dn) |
+        (RawArg::Direct { reg, .. }, Operand::Dm) => reg.family == RegFamily::DOUBLE,
+
+        (RawArg::Shift { kind, amount }, Operand::Shift) |
+        (RawArg::Shift { kind, amount }, Operand::TShift) => match amount {
+            None => *kind == ShiftKind::RRX,
+            Some(ShiftAmount::Immediate(_)) => true,
+            Some(ShiftAmount::Register(_)) => false,
+        },
+        (RawArg::Shift { kind, amount: Some(ShiftAmount::Register(reg)) }, Operand::RegShift) => *kind != ShiftKind::RRX && reg.family == RegFamily::INTEGER,
+        (RawArg::Shift { kind: ShiftKind::ROR, amount: Some(ShiftAmount::Immediate(_)) }, Operand::Rotation(_)) => true,
+
+        (RawArg::Reference { base, offset, mode }, Operand::Mem(kind)) => base.family == RegFamily::INTEGER && match_reference(offset, *mode, kind),
+
+        (RawArg::RegList { items }, Operand::RegList) => !items.is_empty() && items.iter().all(|reg| reg.family == RegFamily::INTEGER),
+        (RawArg::RegList { items }, Operand::VRegList(double)) => !items.is_empty() && items.iter().all(|reg| reg.family == if double { RegFamily::DOUBLE } else { RegFamily::SINGLE }),
+
+        (RawArg::JumpTarget { .. }, Operand::Target(_)) => true,
+
+        (RawArg::Immediate { value }, Operand::Lit(lit)) => as_ident(value).map_or(false, |ident| ident.to_string().to_lowercase() == lit),
+        (RawArg::Immediate { value }, Operand::Zero) => as_number(value) == Some(0) || as_float(value) == Some(0.0),
+        (RawArg::Immediate { .. }, Operand::Imm(_)) |
+        (RawArg::Immediate { .. }, Operand::ModImm) |
+        (RawArg::Immediate { .. }, Operand::TModImm) |
+        (RawArg::Immediate { .. }, Operand::Lsb(_)) |
+        (RawArg::Immediate { .. }, Operand::Width(_, _)) |
+        (RawArg::Immediate { .. }, Operand::Barrier) |
+        (RawArg::Immediate { .. }, Operand::FImm) |
+        (RawArg::Immediate { .. }, Operand::ItCond) => true,
+
+        _ => false
+    }
+}
+
+// checks if the offset and indexing mode of a memory reference can be encoded in the given addressing mode
+fn match_reference(offset: &Option<RefOffset>, mode: RefMode, kind: MemKind) -> bool {
+    match (kind, offset) {
+        (MemKind::A2, _) => true,
+        (MemKind::A3, Some(RefOffset::Register { shift, .. })) => shift.is_none(),
+        (MemKind::A3, _) => true,
+        (MemKind::V, Some(RefOffset::Register { .. })) => false,
+        (MemKind::V, _) => mode == RefMode::Offset,
+        (MemKind::Ex, None) => mode == RefMode::Offset,
+        (MemKind::Ex, Some(RefOffset::Immediate(value))) => mode == RefMode::Offset && as_number(value) == Some(0),
+        (MemKind::Ex, _) => false,
+
+        // unsigned offsets use the 12-bit form, everything else uses the 8-bit form. Offsets that are only
+        // known at runtime are assumed to be positive.
+        (MemKind::T12, None) => mode == RefMode::Offset,
+        (MemKind::T12, Some(RefOffset::Immediate(value))) => mode == RefMode::Offset && as_signed_number(value).map_or(true, |v| v >= 0),
+        (MemKind::T8, Some(RefOffset::Immediate(value))) => mode != RefMode::Offset || as_signed_number(value).map_or(false, |v| v < 0),
+        (MemKind::T8, None) => mode == RefMode::PreIndexed,
+        (MemKind::TReg, Some(RefOffset::Register { negated, shift, .. })) => mode == RefMode::Offset && !negated && match shift {
+            None => true,
+            Some((ShiftKind::LSL, _)) => true,
+            Some(_) => false
+        },
+        (MemKind::TD, Some(RefOffset::Register { .. })) => false,
+        (MemKind::TD, _) => true,
+        (MemKind::TEx, Some(RefOffset::Register { .. })) => false,
+        (MemKind::TEx, _) => mode == RefMode::Offset,
+        _ => false
+    }
+}
+
+/// Formats the list of possible forms of an instruction for error messages
+fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    let mut forms = Vec::new();
+
+    for data in data {
+        let operands: Vec<&str> = data.operands.iter().map(|operand| match *operand {
+            Operand::R(_) |
+            Operand::Rs(_) |
+            Operand::RNext => "r",
+            Operand::RLow(_) => "r0-r7",
+            Operand::RWb(_) => "r[!]",
+            Operand::Sd |
+            Operand::Sn |
+            Operand::Sm => "s",
+            Operand::Dd |
+            Operand::Dn |
+            Operand::Dm => "d",
+            Operand::Imm(_) |
+            Operand::Lsb(_) |
+            Operand::Width(_, _) => "#imm",
+            Operand::ModImm |
+            Operand::TModImm => "#modimm",
+            Operand::Shift |
+            Operand::TShift => "[shift #imm]",
+            Operand::RegShift => "shift r",
+            Operand::Rotation(_) => "[ror #imm]",
+            Operand::Mem(_) => "[r, offset]",
+            Operand::RegList => "{r, ...}",
+            Operand::VRegList(false) => "{s, ...}",
+            Operand::VRegList(true) => "{d, ...}",
+            Operand::Target(_) => "label",
+            Operand::Barrier => "[option]",
+            Operand::FImm => "#fimm",
+            Operand::Zero => "#0",
+            Operand::Lit(lit) => lit,
+            Operand::ItCond => "cond",
+        }).collect();
+
+        if operands.is_empty() {
+            forms.push(format!(">>> {}", name));
+        } else {
+            forms.push(format!(">>> {} {}", name, operands.join(", ")));
+        }
+    }
+
+    forms.join("\n")
+}
@@ -0,0 +1,168 @@
+use syn::parse;
+use proc_macro_error::emit_error;
+
+mod ast;
+mod parser;
+mod matching;
+mod compiler;
+mod armdata;
+mod encoding_helpers;
+
+use crate::State;
+use crate::common::{Size, Stmt, Jump};
+use crate::arch::Arch;
+use self::armdata::Relocation;
+
+use std::cell::Cell;
+
+struct Context<'a, 'b: 'a> {
+    pub state: &'a mut State<'b>,
+    pub thumb: bool,
+    pub it: &'a Cell<ItState>
+}
+
+/// The conditions of the remaining instructions of the current thumb if-then block
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ItState {
+    conds: [u8; 4],
+    len: u8,
+    pos: u8
+}
+
+impl ItState {
+    fn new(conds: &[u8]) -> ItState {
+        let mut state = ItState {
+            conds: [0; 4],
+            len: conds.len() as u8,
+            pos: 0
+        };
+        state.conds[.. conds.len()].copy_from_slice(conds);
+        state
+    }
+
+    /// The condition the next instruction has to use, if it is inside an if-then block
+    fn current(&self) -> Option<u8> {
+        if self.pos < self.len {
+            Some(self.conds[self.pos as usize])
+        } else {
+            None
+        }
+    }
+
+    fn advance(&mut self) {
+        if self.pos < self.len {
+            self.pos += 1;
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ArchArm {
+    it: Cell<ItState>
+}
+
+impl Arch for ArchArm {
+    fn name(&self) -> &str {
+        "arm"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Arch arm has no known features");
+        }
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        static_reloc(stmts, reloc, size)
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            thumb: false,
+            it: &self.it
+        };
+        compile(&mut ctx, input)
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct ArchThumb {
+    it: Cell<ItState>
+}
+
+impl Arch for ArchThumb {
+    fn name(&self) -> &str {
+        "thumb"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Arch thumb has no known features");
+        }
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        static_reloc(stmts, reloc, size)
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state,
+            thumb: true,
+            it: &self.it
+        };
+        compile(&mut ctx, input)
+    }
+}
+
+fn static_reloc(stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+    let span = reloc.span();
+
+    let relocation = match size {
+        Size::BYTE => Relocation::LITERAL8,
+        Size::WORD => Relocation::LITERAL16,
+        Size::DWORD => Relocation::LITERAL32,
+        Size::QWORD => Relocation::LITERAL64,
+        _ => {
+            emit_error!(span, "Relocation of unsupported size for the current target architecture");
+            return;
+        }
+    };
+    let data = [relocation.to_id()];
+
+    stmts.push(Stmt::Const(0, size));
+    stmts.push(reloc.encode(&data));
+}
+
+fn compile(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<()> {
+    let (instruction, args) = parser::parse_instruction(ctx, input)?;
+    let span = instruction.span;
+
+    let it = ctx.it.get();
+
+    let result = matching::match_instruction(ctx, &instruction, args).and_then(|match_data|
+        compiler::compile_instruction(ctx, &instruction.name, match_data)
+    );
+
+    // every instruction inside an if-then block uses up one of its conditions, even if it was invalid
+    if it.current().is_some() {
+        let mut next = it;
+        next.advance();
+        ctx.it.set(next);
+    }
+
+    if let Err(Some(e)) = result {
+        emit_error!(span, e);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,650 @@
+Ops!(
+// Data processing
+"and" = [
+    0x02000000 = [R(12), R(16), ModImm];
+    0x00000000 = [R(12), R(16), R(0), Shift];
+    0x00000010 = [R(12), R(16), R(0), RegShift];
+]
+"ands" = [
+    0x02100000 = [R(12), R(16), ModImm];
+    0x00100000 = [R(12), R(16), R(0), Shift];
+    0x00100010 = [R(12), R(16), R(0), RegShift];
+]
+"eor" = [
+    0x02200000 = [R(12), R(16), ModImm];
+    0x00200000 = [R(12), R(16), R(0), Shift];
+    0x00200010 = [R(12), R(16), R(0), RegShift];
+]
+"eors" = [
+    0x02300000 = [R(12), R(16), ModImm];
+    0x00300000 = [R(12), R(16), R(0), Shift];
+    0x00300010 = [R(12), R(16), R(0), RegShift];
+]
+"sub" = [
+    0x02400000 = [R(12), R(16), ModImm];
+    0x00400000 = [R(12), R(16), R(0), Shift];
+    0x00400010 = [R(12), R(16), R(0), RegShift];
+]
+"subs" = [
+    0x02500000 = [R(12), R(16), ModImm];
+    0x00500000 = [R(12), R(16), R(0), Shift];
+    0x00500010 = [R(12), R(16), R(0), RegShift];
+]
+"rsb" = [
+    0x02600000 = [R(12), R(16), ModImm];
+    0x00600000 = [R(12), R(16), R(0), Shift];
+    0x00600010 = [R(12), R(16), R(0), RegShift];
+]
+"rsbs" = [
+    0x02700000 = [R(12), R(16), ModImm];
+    0x00700000 = [R(12), R(16), R(0), Shift];
+    0x00700010 = [R(12), R(16), R(0), RegShift];
+]
+"add" = [
+    0x02800000 = [R(12), R(16), ModImm];
+    0x00800000 = [R(12), R(16), R(0), Shift];
+    0x00800010 = [R(12), R(16), R(0), RegShift];
+]
+"adds" = [
+    0x02900000 = [R(12), R(16), ModImm];
+    0x00900000 = [R(12), R(16), R(0), Shift];
+    0x00900010 = [R(12), R(16), R(0), RegShift];
+]
+"adc" = [
+    0x02A00000 = [R(12), R(16), ModImm];
+    0x00A00000 = [R(12), R(16), R(0), Shift];
+    0x00A00010 = [R(12), R(16), R(0), RegShift];
+]
+"adcs" = [
+    0x02B00000 = [R(12), R(16), ModImm];
+    0x00B00000 = [R(12), R(16), R(0), Shift];
+    0x00B00010 = [R(12), R(16), R(0), RegShift];
+]
+"sbc" = [
+    0x02C00000 = [R(12), R(16), ModImm];
+    0x00C00000 = [R(12), R(16), R(0), Shift];
+    0x00C00010 = [R(12), R(16), R(0), RegShift];
+]
+"sbcs" = [
+    0x02D00000 = [R(12), R(16), ModImm];
+    0x00D00000 = [R(12), R(16), R(0), Shift];
+    0x00D00010 = [R(12), R(16), R(0), RegShift];
+]
+"rsc" = [
+    0x02E00000 = [R(12), R(16), ModImm];
+    0x00E00000 = [R(12), R(16), R(0), Shift];
+    0x00E00010 = [R(12), R(16), R(0), RegShift];
+]
+"rscs" = [
+    0x02F00000 = [R(12), R(16), ModImm];
+    0x00F00000 = [R(12), R(16), R(0), Shift];
+    0x00F00010 = [R(12), R(16), R(0), RegShift];
+]
+"orr" = [
+    0x03800000 = [R(12), R(16), ModImm];
+    0x01800000 = [R(12), R(16), R(0), Shift];
+    0x01800010 = [R(12), R(16), R(0), RegShift];
+]
+"orrs" = [
+    0x03900000 = [R(12), R(16), ModImm];
+    0x01900000 = [R(12), R(16), R(0), Shift];
+    0x01900010 = [R(12), R(16), R(0), RegShift];
+]
+"bic" = [
+    0x03C00000 = [R(12), R(16), ModImm];
+    0x01C00000 = [R(12), R(16), R(0), Shift];
+    0x01C00010 = [R(12), R(16), R(0), RegShift];
+]
+"bics" = [
+    0x03D00000 = [R(12), R(16), ModImm];
+    0x01D00000 = [R(12), R(16), R(0), Shift];
+    0x01D00010 = [R(12), R(16), R(0), RegShift];
+]
+"tst" = [
+    0x03100000 = [R(16), ModImm];
+    0x01100000 = [R(16), R(0), Shift];
+    0x01100010 = [R(16), R(0), RegShift];
+]
+"teq" = [
+    0x03300000 = [R(16), ModImm];
+    0x01300000 = [R(16), R(0), Shift];
+    0x01300010 = [R(16), R(0), RegShift];
+]
+"cmp" = [
+    0x03500000 = [R(16), ModImm];
+    0x01500000 = [R(16), R(0), Shift];
+    0x01500010 = [R(16), R(0), RegShift];
+]
+"cmn" = [
+    0x03700000 = [R(16), ModImm];
+    0x01700000 = [R(16), R(0), Shift];
+    0x01700010 = [R(16), R(0), RegShift];
+]
+"mov" = [
+    0x03A00000 = [R(12), ModImm];
+    0x01A00000 = [R(12), R(0), Shift];
+    0x01A00010 = [R(12), R(0), RegShift];
+]
+"movs" = [
+    0x03B00000 = [R(12), ModImm];
+    0x01B00000 = [R(12), R(0), Shift];
+    0x01B00010 = [R(12), R(0), RegShift];
+]
+"mvn" = [
+    0x03E00000 = [R(12), ModImm];
+    0x01E00000 = [R(12), R(0), Shift];
+    0x01E00010 = [R(12), R(0), RegShift];
+]
+"mvns" = [
+    0x03F00000 = [R(12), ModImm];
+    0x01F00000 = [R(12), R(0), Shift];
+    0x01F00010 = [R(12), R(0), RegShift];
+]
+"movw" = [
+    0x03000000 = [R(12), Imm(IMM16)];
+]
+"movt" = [
+    0x03400000 = [R(12), Imm(IMM16)];
+]
+// Shifts
+"lsl" = [
+    0x01A00000 = [R(12), R(0), Imm(LSL5)];
+    0x01A00010 = [R(12), R(0), R(8)];
+]
+"lsls" = [
+    0x01B00000 = [R(12), R(0), Imm(LSL5)];
+    0x01B00010 = [R(12), R(0), R(8)];
+]
+"lsr" = [
+    0x01A00020 = [R(12), R(0), Imm(SHR5)];
+    0x01A00030 = [R(12), R(0), R(8)];
+]
+"lsrs" = [
+    0x01B00020 = [R(12), R(0), Imm(SHR5)];
+    0x01B00030 = [R(12), R(0), R(8)];
+]
+"asr" = [
+    0x01A00040 = [R(12), R(0), Imm(SHR5)];
+    0x01A00050 = [R(12), R(0), R(8)];
+]
+"asrs" = [
+    0x01B00040 = [R(12), R(0), Imm(SHR5)];
+    0x01B00050 = [R(12), R(0), R(8)];
+]
+"ror" = [
+    0x01A00060 = [R(12), R(0), Imm(ROR5)];
+    0x01A00070 = [R(12), R(0), R(8)];
+]
+"rors" = [
+    0x01B00060 = [R(12), R(0), Imm(ROR5)];
+    0x01B00070 = [R(12), R(0), R(8)];
+]
+"rrx" = [
+    0x01A00060 = [R(12), R(0)];
+]
+"rrxs" = [
+    0x01B00060 = [R(12), R(0)];
+]
+// Multiplication and division
+"mul" = [
+    0x00000090 = [R(16), R(0), R(8)];
+]
+"mla" = [
+    0x00200090 = [R(16), R(0), R(8), R(12)];
+]
+"muls" = [
+    0x00100090 = [R(16), R(0), R(8)];
+]
+"mlas" = [
+    0x00300090 = [R(16), R(0), R(8), R(12)];
+]
+"mls" = [
+    0x00600090 = [R(16), R(0), R(8), R(12)];
+]
+"umull" = [
+    0x00800090 = [R(12), R(16), R(0), R(8)];
+]
+"umulls" = [
+    0x00900090 = [R(12), R(16), R(0), R(8)];
+]
+"umlal" = [
+    0x00A00090 = [R(12), R(16), R(0), R(8)];
+]
+"umlals" = [
+    0x00B00090 = [R(12), R(16), R(0), R(8)];
+]
+"smull" = [
+    0x00C00090 = [R(12), R(16), R(0), R(8)];
+]
+"smulls" = [
+    0x00D00090 = [R(12), R(16), R(0), R(8)];
+]
+"smlal" = [
+    0x00E00090 = [R(12), R(16), R(0), R(8)];
+]
+"smlals" = [
+    0x00F00090 = [R(12), R(16), R(0), R(8)];
+]
+"sdiv" = [
+    0x0710F010 = [R(16), R(0), R(8)];
+]
+"udiv" = [
+    0x0730F010 = [R(16), R(0), R(8)];
+]
+// Miscellaneous data processing
+"clz" = [
+    0x016F0F10 = [R(12), R(0)];
+]
+"rev" = [
+    0x06BF0F30 = [R(12), R(0)];
+]
+"rev16" = [
+    0x06BF0FB0 = [R(12), R(0)];
+]
+"revsh" = [
+    0x06FF0FB0 = [R(12), R(0)];
+]
+"rbit" = [
+    0x06FF0F30 = [R(12), R(0)];
+]
+"sxtb" = [
+    0x06AF0070 = [R(12), R(0), Rotation(10)];
+]
+"sxth" = [
+    0x06BF0070 = [R(12), R(0), Rotation(10)];
+]
+"uxtb" = [
+    0x06EF0070 = [R(12), R(0), Rotation(10)];
+]
+"uxth" = [
+    0x06FF0070 = [R(12), R(0), Rotation(10)];
+]
+"ubfx" = [
+    0x07E00050 = [R(12), R(0), Lsb(LSB5), Width(WIDTH5, false)];
+]
+"sbfx" = [
+    0x07A00050 = [R(12), R(0), Lsb(LSB5), Width(WIDTH5, false)];
+]
+"bfi" = [
+    0x07C00010 = [R(12), R(0), Lsb(LSB5), Width(WIDTH5, true)];
+]
+"bfc" = [
+    0x07C0001F = [R(12), Lsb(LSB5), Width(WIDTH5, true)];
+]
+// Branches
+"b" = [
+    0x0A000000 = [Target(BRANCH)];
+]
+"bl" = [
+    0x0B000000 = [Target(BRANCH)];
+]
+"bx" = [
+    0x012FFF10 = [R(0)];
+]
+"blx" = [
+    0x012FFF30 = [R(0)];
+]
+"adr" = [
+    0x020F0000 = [R(12), Target(ADR)];
+]
+// Loads and stores
+"ldr" = [
+    0x04100000 = [R(12), Mem(A2)];
+    0x051F0000 = [R(12), Target(LDR)];
+]
+"str" = [
+    0x04000000 = [R(12), Mem(A2)];
+]
+"ldrb" = [
+    0x04500000 = [R(12), Mem(A2)];
+    0x055F0000 = [R(12), Target(LDR)];
+]
+"strb" = [
+    0x04400000 = [R(12), Mem(A2)];
+]
+"ldrh" = [
+    0x001000B0 = [R(12), Mem(A3)];
+]
+"strh" = [
+    0x000000B0 = [R(12), Mem(A3)];
+]
+"ldrsb" = [
+    0x001000D0 = [R(12), Mem(A3)];
+]
+"ldrsh" = [
+    0x001000F0 = [R(12), Mem(A3)];
+]
+"ldrd" = [
+    0x000000D0 = [R(12), RNext, Mem(A3)];
+]
+"strd" = [
+    0x000000F0 = [R(12), RNext, Mem(A3)];
+]
+"ldm" = [
+    0x08900000 = [RWb(16), RegList];
+]
+"ldmia" = [
+    0x08900000 = [RWb(16), RegList];
+]
+"ldmfd" = [
+    0x08900000 = [RWb(16), RegList];
+]
+"stm" = [
+    0x08800000 = [RWb(16), RegList];
+]
+"stmia" = [
+    0x08800000 = [RWb(16), RegList];
+]
+"stmea" = [
+    0x08800000 = [RWb(16), RegList];
+]
+"ldmdb" = [
+    0x09100000 = [RWb(16), RegList];
+]
+"ldmea" = [
+    0x09100000 = [RWb(16), RegList];
+]
+"stmdb" = [
+    0x09000000 = [RWb(16), RegList];
+]
+"stmfd" = [
+    0x09000000 = [RWb(16), RegList];
+]
+"ldmib" = [
+    0x09900000 = [RWb(16), RegList];
+]
+"ldmed" = [
+    0x09900000 = [RWb(16), RegList];
+]
+"stmib" = [
+    0x09800000 = [RWb(16), RegList];
+]
+"stmfa" = [
+    0x09800000 = [RWb(16), RegList];
+]
+"ldmda" = [
+    0x08100000 = [RWb(16), RegList];
+]
+"ldmfa" = [
+    0x08100000 = [RWb(16), RegList];
+]
+"stmda" = [
+    0x08000000 = [RWb(16), RegList];
+]
+"stmed" = [
+    0x08000000 = [RWb(16), RegList];
+]
+"push" = [
+    0x092D0000 = [RegList];
+]
+"pop" = [
+    0x08BD0000 = [RegList];
+]
+"ldrex" = [
+    0x01900F9F = [R(12), Mem(Ex)];
+]
+"strex" = [
+    0x01800F90 = [R(12), R(0), Mem(Ex)];
+]
+"ldrexb" = [
+    0x01D00F9F = [R(12), Mem(Ex)];
+]
+"strexb" = [
+    0x01C00F90 = [R(12), R(0), Mem(Ex)];
+]
+"ldrexh" = [
+    0x01F00F9F = [R(12), Mem(Ex)];
+]
+"strexh" = [
+    0x01E00F90 = [R(12), R(0), Mem(Ex)];
+]
+// System
+"svc" = [
+    0x0F000000 = [Imm(IMM24)];
+]
+"bkpt" = [
+    0xE1200070 = [Imm(IMM16_SPLIT)];
+]
+"udf" = [
+    0xE7F000F0 = [Imm(IMM16_SPLIT)];
+]
+"nop" = [
+    0x0320F000 = [];
+]
+"yield" = [
+    0x0320F001 = [];
+]
+"wfe" = [
+    0x0320F002 = [];
+]
+"wfi" = [
+    0x0320F003 = [];
+]
+"sev" = [
+    0x0320F004 = [];
+]
+"dmb" = [
+    0xF57FF050 = [Barrier];
+]
+"dsb" = [
+    0xF57FF040 = [Barrier];
+]
+"isb" = [
+    0xF57FF060 = [Barrier];
+]
+"clrex" = [
+    0xF57FF01F = [];
+]
+"mrs" = [
+    0x010F0000 = [R(12), Lit("apsr")];
+]
+"msr" = [
+    0x0128F000 = [Lit("apsr_nzcvq"), R(0)];
+    0x0124F000 = [Lit("apsr_g"), R(0)];
+    0x012CF000 = [Lit("apsr_nzcvqg"), R(0)];
+]
+// If-then blocks. These emit nothing in arm state
+"it" = [
+    0x00000000 = [ItCond];
+]
+"itt" = [
+    0x00000000 = [ItCond];
+]
+"ite" = [
+    0x00000000 = [ItCond];
+]
+"ittt" = [
+    0x00000000 = [ItCond];
+]
+"itte" = [
+    0x00000000 = [ItCond];
+]
+"itet" = [
+    0x00000000 = [ItCond];
+]
+"itee" = [
+    0x00000000 = [ItCond];
+]
+"itttt" = [
+    0x00000000 = [ItCond];
+]
+"ittte" = [
+    0x00000000 = [ItCond];
+]
+"ittet" = [
+    0x00000000 = [ItCond];
+]
+"ittee" = [
+    0x00000000 = [ItCond];
+]
+"itett" = [
+    0x00000000 = [ItCond];
+]
+"itete" = [
+    0x00000000 = [ItCond];
+]
+"iteet" = [
+    0x00000000 = [ItCond];
+]
+"iteee" = [
+    0x00000000 = [ItCond];
+]
+// VFP
+"vadd.f32" = [
+    0x0E300A00 = [Sd, Sn, Sm];
+]
+"vadd.f64" = [
+    0x0E300B00 = [Dd, Dn, Dm];
+]
+"vsub.f32" = [
+    0x0E300A40 = [Sd, Sn, Sm];
+]
+"vsub.f64" = [
+    0x0E300B40 = [Dd, Dn, Dm];
+]
+"vmul.f32" = [
+    0x0E200A00 = [Sd, Sn, Sm];
+]
+"vmul.f64" = [
+    0x0E200B00 = [Dd, Dn, Dm];
+]
+"vnmul.f32" = [
+    0x0E200A40 = [Sd, Sn, Sm];
+]
+"vnmul.f64" = [
+    0x0E200B40 = [Dd, Dn, Dm];
+]
+"vdiv.f32" = [
+    0x0E800A00 = [Sd, Sn, Sm];
+]
+"vdiv.f64" = [
+    0x0E800B00 = [Dd, Dn, Dm];
+]
+"vmla.f32" = [
+    0x0E000A00 = [Sd, Sn, Sm];
+]
+"vmla.f64" = [
+    0x0E000B00 = [Dd, Dn, Dm];
+]
+"vmls.f32" = [
+    0x0E000A40 = [Sd, Sn, Sm];
+]
+"vmls.f64" = [
+    0x0E000B40 = [Dd, Dn, Dm];
+]
+"vfma.f32" = [
+    0x0EA00A00 = [Sd, Sn, Sm];
+]
+"vfma.f64" = [
+    0x0EA00B00 = [Dd, Dn, Dm];
+]
+"vfms.f32" = [
+    0x0EA00A40 = [Sd, Sn, Sm];
+]
+"vfms.f64" = [
+    0x0EA00B40 = [Dd, Dn, Dm];
+]
+"vneg.f32" = [
+    0x0EB10A40 = [Sd, Sm];
+]
+"vneg.f64" = [
+    0x0EB10B40 = [Dd, Dm];
+]
+"vabs.f32" = [
+    0x0EB00AC0 = [Sd, Sm];
+]
+"vabs.f64" = [
+    0x0EB00BC0 = [Dd, Dm];
+]
+"vsqrt.f32" = [
+    0x0EB10AC0 = [Sd, Sm];
+]
+"vsqrt.f64" = [
+    0x0EB10BC0 = [Dd, Dm];
+]
+"vmov.f32" = [
+    0x0EB00A40 = [Sd, Sm];
+    0x0EB00A00 = [Sd, FImm];
+]
+"vmov.f64" = [
+    0x0EB00B40 = [Dd, Dm];
+    0x0EB00B00 = [Dd, FImm];
+]
+"vcmp.f32" = [
+    0x0EB40A40 = [Sd, Sm];
+    0x0EB50A40 = [Sd, Zero];
+]
+"vcmp.f64" = [
+    0x0EB40B40 = [Dd, Dm];
+    0x0EB50B40 = [Dd, Zero];
+]
+"vcmpe.f32" = [
+    0x0EB40AC0 = [Sd, Sm];
+    0x0EB50AC0 = [Sd, Zero];
+]
+"vcmpe.f64" = [
+    0x0EB40BC0 = [Dd, Dm];
+    0x0EB50BC0 = [Dd, Zero];
+]
+"vcvt.f64.f32" = [
+    0x0EB70AC0 = [Dd, Sm];
+]
+"vcvt.f32.f64" = [
+    0x0EB70BC0 = [Sd, Dm];
+]
+"vcvt.f32.s32" = [
+    0x0EB80AC0 = [Sd, Sm];
+]
+"vcvt.f32.u32" = [
+    0x0EB80A40 = [Sd, Sm];
+]
+"vcvt.f64.s32" = [
+    0x0EB80BC0 = [Dd, Sm];
+]
+"vcvt.f64.u32" = [
+    0x0EB80B40 = [Dd, Sm];
+]
+"vcvt.s32.f32" = [
+    0x0EBD0AC0 = [Sd, Sm];
+]
+"vcvt.u32.f32" = [
+    0x0EBC0AC0 = [Sd, Sm];
+]
+"vcvt.s32.f64" = [
+    0x0EBD0BC0 = [Sd, Dm];
+]
+"vcvt.u32.f64" = [
+    0x0EBC0BC0 = [Sd, Dm];
+]
+"vmov" = [
+    0x0E000A10 = [Sn, R(12)];
+    0x0E100A10 = [R(12), Sn];
+    0x0C400B10 = [Dm, R(12), R(16)];
+    0x0C500B10 = [R(12), R(16), Dm];
+]
+"vldr" = [
+    0x0D100A00 = [Sd, Mem(V)];
+    0x0D100B00 = [Dd, Mem(V)];
+    0x0D1F0A00 = [Sd, Target(VLDR)];
+    0x0D1F0B00 = [Dd, Target(VLDR)];
+]
+"vstr" = [
+    0x0D000A00 = [Sd, Mem(V)];
+    0x0D000B00 = [Dd, Mem(V)];
+]
+"vpush" = [
+    0x0D2D0A00 = [VRegList(false)];
+    0x0D2D0B00 = [VRegList(true)];
+]
+"vpop" = [
+    0x0CBD0A00 = [VRegList(false)];
+    0x0CBD0B00 = [VRegList(true)];
+]
+"vmrs" = [
+    0x0EF10A10 = [R(12), Lit("fpscr")];
+    0x0EF1FA10 = [Lit("apsr_nzcv"), Lit("fpscr")];
+]
+"vmsr" = [
+    0x0EE10A10 = [Lit("fpscr"), R(12)];
+]
+)
\ No newline at end of file
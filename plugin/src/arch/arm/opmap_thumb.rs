@@ -0,0 +1,609 @@
+Ops!(
+// Data processing
+"and" = [
+    0xF0000000 = [R(8), R(16), TModImm];
+    0xEA000000 = [R(8), R(16), R(0), TShift];
+]
+"ands" = [
+    0xF0100000 = [R(8), R(16), TModImm];
+    0xEA100000 = [R(8), R(16), R(0), TShift];
+]
+"bic" = [
+    0xF0200000 = [R(8), R(16), TModImm];
+    0xEA200000 = [R(8), R(16), R(0), TShift];
+]
+"bics" = [
+    0xF0300000 = [R(8), R(16), TModImm];
+    0xEA300000 = [R(8), R(16), R(0), TShift];
+]
+"orr" = [
+    0xF0400000 = [R(8), R(16), TModImm];
+    0xEA400000 = [R(8), R(16), R(0), TShift];
+]
+"orrs" = [
+    0xF0500000 = [R(8), R(16), TModImm];
+    0xEA500000 = [R(8), R(16), R(0), TShift];
+]
+"orn" = [
+    0xF0600000 = [R(8), R(16), TModImm];
+    0xEA600000 = [R(8), R(16), R(0), TShift];
+]
+"orns" = [
+    0xF0700000 = [R(8), R(16), TModImm];
+    0xEA700000 = [R(8), R(16), R(0), TShift];
+]
+"eor" = [
+    0xF0800000 = [R(8), R(16), TModImm];
+    0xEA800000 = [R(8), R(16), R(0), TShift];
+]
+"eors" = [
+    0xF0900000 = [R(8), R(16), TModImm];
+    0xEA900000 = [R(8), R(16), R(0), TShift];
+]
+"add" = [
+    0xF1000000 = [R(8), R(16), TModImm];
+    0xEB000000 = [R(8), R(16), R(0), TShift];
+]
+"adds" = [
+    0xF1100000 = [R(8), R(16), TModImm];
+    0xEB100000 = [R(8), R(16), R(0), TShift];
+]
+"adc" = [
+    0xF1400000 = [R(8), R(16), TModImm];
+    0xEB400000 = [R(8), R(16), R(0), TShift];
+]
+"adcs" = [
+    0xF1500000 = [R(8), R(16), TModImm];
+    0xEB500000 = [R(8), R(16), R(0), TShift];
+]
+"sbc" = [
+    0xF1600000 = [R(8), R(16), TModImm];
+    0xEB600000 = [R(8), R(16), R(0), TShift];
+]
+"sbcs" = [
+    0xF1700000 = [R(8), R(16), TModImm];
+    0xEB700000 = [R(8), R(16), R(0), TShift];
+]
+"sub" = [
+    0xF1A00000 = [R(8), R(16), TModImm];
+    0xEBA00000 = [R(8), R(16), R(0), TShift];
+]
+"subs" = [
+    0xF1B00000 = [R(8), R(16), TModImm];
+    0xEBB00000 = [R(8), R(16), R(0), TShift];
+]
+"rsb" = [
+    0xF1C00000 = [R(8), R(16), TModImm];
+    0xEBC00000 = [R(8), R(16), R(0), TShift];
+]
+"rsbs" = [
+    0xF1D00000 = [R(8), R(16), TModImm];
+    0xEBD00000 = [R(8), R(16), R(0), TShift];
+]
+"tst" = [
+    0xF0100F00 = [R(16), TModImm];
+    0xEA100F00 = [R(16), R(0), TShift];
+]
+"teq" = [
+    0xF0900F00 = [R(16), TModImm];
+    0xEA900F00 = [R(16), R(0), TShift];
+]
+"cmn" = [
+    0xF1100F00 = [R(16), TModImm];
+    0xEB100F00 = [R(16), R(0), TShift];
+]
+"cmp" = [
+    0xF1B00F00 = [R(16), TModImm];
+    0xEBB00F00 = [R(16), R(0), TShift];
+]
+"mov" = [
+    0xF04F0000 = [R(8), TModImm];
+    0xEA4F0000 = [R(8), R(0), TShift];
+]
+"movs" = [
+    0xF05F0000 = [R(8), TModImm];
+    0xEA5F0000 = [R(8), R(0), TShift];
+]
+"mvn" = [
+    0xF06F0000 = [R(8), TModImm];
+    0xEA6F0000 = [R(8), R(0), TShift];
+]
+"mvns" = [
+    0xF07F0000 = [R(8), TModImm];
+    0xEA7F0000 = [R(8), R(0), TShift];
+]
+"addw" = [
+    0xF2000000 = [R(8), R(16), Imm(T_IMM12)];
+]
+"subw" = [
+    0xF2A00000 = [R(8), R(16), Imm(T_IMM12)];
+]
+"movw" = [
+    0xF2400000 = [R(8), Imm(T_IMM16)];
+]
+"movt" = [
+    0xF2C00000 = [R(8), Imm(T_IMM16)];
+]
+// Shifts
+"lsl" = [
+    0xEA4F0000 = [R(8), R(0), Imm(T_LSL5)];
+    0xFA00F000 = [R(8), R(16), R(0)];
+]
+"lsls" = [
+    0xEA5F0000 = [R(8), R(0), Imm(T_LSL5)];
+    0xFA10F000 = [R(8), R(16), R(0)];
+]
+"lsr" = [
+    0xEA4F0010 = [R(8), R(0), Imm(T_SHR5)];
+    0xFA20F000 = [R(8), R(16), R(0)];
+]
+"lsrs" = [
+    0xEA5F0010 = [R(8), R(0), Imm(T_SHR5)];
+    0xFA30F000 = [R(8), R(16), R(0)];
+]
+"asr" = [
+    0xEA4F0020 = [R(8), R(0), Imm(T_SHR5)];
+    0xFA40F000 = [R(8), R(16), R(0)];
+]
+"asrs" = [
+    0xEA5F0020 = [R(8), R(0), Imm(T_SHR5)];
+    0xFA50F000 = [R(8), R(16), R(0)];
+]
+"ror" = [
+    0xEA4F0030 = [R(8), R(0), Imm(T_ROR5)];
+    0xFA60F000 = [R(8), R(16), R(0)];
+]
+"rors" = [
+    0xEA5F0030 = [R(8), R(0), Imm(T_ROR5)];
+    0xFA70F000 = [R(8), R(16), R(0)];
+]
+"rrx" = [
+    0xEA4F0030 = [R(8), R(0)];
+]
+"rrxs" = [
+    0xEA5F0030 = [R(8), R(0)];
+]
+// Multiplication and division
+"mul" = [
+    0xFB00F000 = [R(8), R(16), R(0)];
+]
+"mla" = [
+    0xFB000000 = [R(8), R(16), R(0), R(12)];
+]
+"mls" = [
+    0xFB000010 = [R(8), R(16), R(0), R(12)];
+]
+"smull" = [
+    0xFB800000 = [R(12), R(8), R(16), R(0)];
+]
+"umull" = [
+    0xFBA00000 = [R(12), R(8), R(16), R(0)];
+]
+"smlal" = [
+    0xFBC00000 = [R(12), R(8), R(16), R(0)];
+]
+"umlal" = [
+    0xFBE00000 = [R(12), R(8), R(16), R(0)];
+]
+"sdiv" = [
+    0xFB90F0F0 = [R(8), R(16), R(0)];
+]
+"udiv" = [
+    0xFBB0F0F0 = [R(8), R(16), R(0)];
+]
+// Miscellaneous data processing
+"clz" = [
+    0xFAB0F080 = [R(8), Rs(&[16, 0])];
+]
+"rbit" = [
+    0xFA90F0A0 = [R(8), Rs(&[16, 0])];
+]
+"rev" = [
+    0xFA90F080 = [R(8), Rs(&[16, 0])];
+]
+"rev16" = [
+    0xFA90F090 = [R(8), Rs(&[16, 0])];
+]
+"revsh" = [
+    0xFA90F0B0 = [R(8), Rs(&[16, 0])];
+]
+"sxtb" = [
+    0xFA4FF080 = [R(8), R(0), Rotation(4)];
+]
+"sxth" = [
+    0xFA0FF080 = [R(8), R(0), Rotation(4)];
+]
+"uxtb" = [
+    0xFA5FF080 = [R(8), R(0), Rotation(4)];
+]
+"uxth" = [
+    0xFA1FF080 = [R(8), R(0), Rotation(4)];
+]
+"ubfx" = [
+    0xF3C00000 = [R(8), R(16), Lsb(T_LSB5), Width(T_WIDTH5, false)];
+]
+"sbfx" = [
+    0xF3400000 = [R(8), R(16), Lsb(T_LSB5), Width(T_WIDTH5, false)];
+]
+"bfi" = [
+    0xF3600000 = [R(8), R(16), Lsb(T_LSB5), Width(T_WIDTH5, true)];
+]
+"bfc" = [
+    0xF36F0000 = [R(8), Lsb(T_LSB5), Width(T_WIDTH5, true)];
+]
+// Branches
+"b" = [
+    0xF0008000 = [Target(TBCOND)];
+    0xF0009000 = [Target(TBRANCH)];
+]
+"bl" = [
+    0xF000D000 = [Target(TBRANCH)];
+]
+"bx" = [
+    0x4700 = [R(3)];
+]
+"blx" = [
+    0x4780 = [R(3)];
+]
+"cbz" = [
+    0xB100 = [RLow(0), Target(TCBZ)];
+]
+"cbnz" = [
+    0xB900 = [RLow(0), Target(TCBZ)];
+]
+"adr" = [
+    0xF20F0000 = [R(8), Target(TADR)];
+]
+// Loads and stores
+"ldr" = [
+    0xF8D00000 = [R(12), Mem(T12)];
+    0xF8500800 = [R(12), Mem(T8)];
+    0xF8500000 = [R(12), Mem(TReg)];
+    0xF85F0000 = [R(12), Target(TLDR)];
+]
+"str" = [
+    0xF8C00000 = [R(12), Mem(T12)];
+    0xF8400800 = [R(12), Mem(T8)];
+    0xF8400000 = [R(12), Mem(TReg)];
+]
+"ldrb" = [
+    0xF8900000 = [R(12), Mem(T12)];
+    0xF8100800 = [R(12), Mem(T8)];
+    0xF8100000 = [R(12), Mem(TReg)];
+    0xF81F0000 = [R(12), Target(TLDR)];
+]
+"strb" = [
+    0xF8800000 = [R(12), Mem(T12)];
+    0xF8000800 = [R(12), Mem(T8)];
+    0xF8000000 = [R(12), Mem(TReg)];
+]
+"ldrh" = [
+    0xF8B00000 = [R(12), Mem(T12)];
+    0xF8300800 = [R(12), Mem(T8)];
+    0xF8300000 = [R(12), Mem(TReg)];
+]
+"strh" = [
+    0xF8A00000 = [R(12), Mem(T12)];
+    0xF8200800 = [R(12), Mem(T8)];
+    0xF8200000 = [R(12), Mem(TReg)];
+]
+"ldrsb" = [
+    0xF9900000 = [R(12), Mem(T12)];
+    0xF9100800 = [R(12), Mem(T8)];
+    0xF9100000 = [R(12), Mem(TReg)];
+]
+"ldrsh" = [
+    0xF9B00000 = [R(12), Mem(T12)];
+    0xF9300800 = [R(12), Mem(T8)];
+    0xF9300000 = [R(12), Mem(TReg)];
+]
+"ldrd" = [
+    0xE8500000 = [R(12), R(8), Mem(TD)];
+]
+"strd" = [
+    0xE8400000 = [R(12), R(8), Mem(TD)];
+]
+"ldm" = [
+    0xE8900000 = [RWb(16), RegList];
+]
+"ldmia" = [
+    0xE8900000 = [RWb(16), RegList];
+]
+"ldmfd" = [
+    0xE8900000 = [RWb(16), RegList];
+]
+"stm" = [
+    0xE8800000 = [RWb(16), RegList];
+]
+"stmia" = [
+    0xE8800000 = [RWb(16), RegList];
+]
+"stmea" = [
+    0xE8800000 = [RWb(16), RegList];
+]
+"ldmdb" = [
+    0xE9100000 = [RWb(16), RegList];
+]
+"ldmea" = [
+    0xE9100000 = [RWb(16), RegList];
+]
+"stmdb" = [
+    0xE9000000 = [RWb(16), RegList];
+]
+"stmfd" = [
+    0xE9000000 = [RWb(16), RegList];
+]
+"push" = [
+    0xE92D0000 = [RegList];
+]
+"pop" = [
+    0xE8BD0000 = [RegList];
+]
+"ldrex" = [
+    0xE8500F00 = [R(12), Mem(TEx)];
+]
+"strex" = [
+    0xE8400000 = [R(8), R(12), Mem(TEx)];
+]
+"ldrexb" = [
+    0xE8D00F4F = [R(12), Mem(Ex)];
+]
+"strexb" = [
+    0xE8C00F40 = [R(0), R(12), Mem(Ex)];
+]
+"ldrexh" = [
+    0xE8D00F5F = [R(12), Mem(Ex)];
+]
+"strexh" = [
+    0xE8C00F50 = [R(0), R(12), Mem(Ex)];
+]
+// System
+"svc" = [
+    0xDF00 = [Imm(IMM8)];
+]
+"bkpt" = [
+    0xBE00 = [Imm(IMM8)];
+]
+"udf" = [
+    0xF7F0A000 = [Imm(IMM16)];
+]
+"nop" = [
+    0xF3AF8000 = [];
+]
+"yield" = [
+    0xF3AF8001 = [];
+]
+"wfe" = [
+    0xF3AF8002 = [];
+]
+"wfi" = [
+    0xF3AF8003 = [];
+]
+"sev" = [
+    0xF3AF8004 = [];
+]
+"dmb" = [
+    0xF3BF8F50 = [Barrier];
+]
+"dsb" = [
+    0xF3BF8F40 = [Barrier];
+]
+"isb" = [
+    0xF3BF8F60 = [Barrier];
+]
+"clrex" = [
+    0xF3BF8F2F = [];
+]
+"mrs" = [
+    0xF3EF8000 = [R(8), Lit("apsr")];
+]
+"msr" = [
+    0xF3808800 = [Lit("apsr_nzcvq"), R(16)];
+    0xF3808400 = [Lit("apsr_g"), R(16)];
+    0xF3808C00 = [Lit("apsr_nzcvqg"), R(16)];
+]
+// If-then blocks
+"it" = [
+    0xBF00 = [ItCond];
+]
+"itt" = [
+    0xBF00 = [ItCond];
+]
+"ite" = [
+    0xBF00 = [ItCond];
+]
+"ittt" = [
+    0xBF00 = [ItCond];
+]
+"itte" = [
+    0xBF00 = [ItCond];
+]
+"itet" = [
+    0xBF00 = [ItCond];
+]
+"itee" = [
+    0xBF00 = [ItCond];
+]
+"itttt" = [
+    0xBF00 = [ItCond];
+]
+"ittte" = [
+    0xBF00 = [ItCond];
+]
+"ittet" = [
+    0xBF00 = [ItCond];
+]
+"ittee" = [
+    0xBF00 = [ItCond];
+]
+"itett" = [
+    0xBF00 = [ItCond];
+]
+"itete" = [
+    0xBF00 = [ItCond];
+]
+"iteet" = [
+    0xBF00 = [ItCond];
+]
+"iteee" = [
+    0xBF00 = [ItCond];
+]
+// VFP
+"vadd.f32" = [
+    0xEE300A00 = [Sd, Sn, Sm];
+]
+"vadd.f64" = [
+    0xEE300B00 = [Dd, Dn, Dm];
+]
+"vsub.f32" = [
+    0xEE300A40 = [Sd, Sn, Sm];
+]
+"vsub.f64" = [
+    0xEE300B40 = [Dd, Dn, Dm];
+]
+"vmul.f32" = [
+    0xEE200A00 = [Sd, Sn, Sm];
+]
+"vmul.f64" = [
+    0xEE200B00 = [Dd, Dn, Dm];
+]
+"vnmul.f32" = [
+    0xEE200A40 = [Sd, Sn, Sm];
+]
+"vnmul.f64" = [
+    0xEE200B40 = [Dd, Dn, Dm];
+]
+"vdiv.f32" = [
+    0xEE800A00 = [Sd, Sn, Sm];
+]
+"vdiv.f64" = [
+    0xEE800B00 = [Dd, Dn, Dm];
+]
+"vmla.f32" = [
+    0xEE000A00 = [Sd, Sn, Sm];
+]
+"vmla.f64" = [
+    0xEE000B00 = [Dd, Dn, Dm];
+]
+"vmls.f32" = [
+    0xEE000A40 = [Sd, Sn, Sm];
+]
+"vmls.f64" = [
+    0xEE000B40 = [Dd, Dn, Dm];
+]
+"vfma.f32" = [
+    0xEEA00A00 = [Sd, Sn, Sm];
+]
+"vfma.f64" = [
+    0xEEA00B00 = [Dd, Dn, Dm];
+]
+"vfms.f32" = [
+    0xEEA00A40 = [Sd, Sn, Sm];
+]
+"vfms.f64" = [
+    0xEEA00B40 = [Dd, Dn, Dm];
+]
+"vneg.f32" = [
+    0xEEB10A40 = [Sd, Sm];
+]
+"vneg.f64" = [
+    0xEEB10B40 = [Dd, Dm];
+]
+"vabs.f32" = [
+    0xEEB00AC0 = [Sd, Sm];
+]
+"vabs.f64" = [
+    0xEEB00BC0 = [Dd, Dm];
+]
+"vsqrt.f32" = [
+    0xEEB10AC0 = [Sd, Sm];
+]
+"vsqrt.f64" = [
+    0xEEB10BC0 = [Dd, Dm];
+]
+"vmov.f32" = [
+    0xEEB00A40 = [Sd, Sm];
+    0xEEB00A00 = [Sd, FImm];
+]
+"vmov.f64" = [
+    0xEEB00B40 = [Dd, Dm];
+    0xEEB00B00 = [Dd, FImm];
+]
+"vcmp.f32" = [
+    0xEEB40A40 = [Sd, Sm];
+    0xEEB50A40 = [Sd, Zero];
+]
+"vcmp.f64" = [
+    0xEEB40B40 = [Dd, Dm];
+    0xEEB50B40 = [Dd, Zero];
+]
+"vcmpe.f32" = [
+    0xEEB40AC0 = [Sd, Sm];
+    0xEEB50AC0 = [Sd, Zero];
+]
+"vcmpe.f64" = [
+    0xEEB40BC0 = [Dd, Dm];
+    0xEEB50BC0 = [Dd, Zero];
+]
+"vcvt.f64.f32" = [
+    0xEEB70AC0 = [Dd, Sm];
+]
+"vcvt.f32.f64" = [
+    0xEEB70BC0 = [Sd, Dm];
+]
+"vcvt.f32.s32" = [
+    0xEEB80AC0 = [Sd, Sm];
+]
+"vcvt.f32.u32" = [
+    0xEEB80A40 = [Sd, Sm];
+]
+"vcvt.f64.s32" = [
+    0xEEB80BC0 = [Dd, Sm];
+]
+"vcvt.f64.u32" = [
+    0xEEB80B40 = [Dd, Sm];
+]
+"vcvt.s32.f32" = [
+    0xEEBD0AC0 = [Sd, Sm];
+]
+"vcvt.u32.f32" = [
+    0xEEBC0AC0 = [Sd, Sm];
+]
+"vcvt.s32.f64" = [
+    0xEEBD0BC0 = [Sd, Dm];
+]
+"vcvt.u32.f64" = [
+    0xEEBC0BC0 = [Sd, Dm];
+]
+"vmov" = [
+    0xEE000A10 = [Sn, R(12)];
+    0xEE100A10 = [R(12), Sn];
+    0xEC400B10 = [Dm, R(12), R(16)];
+    0xEC500B10 = [R(12), R(16), Dm];
+]
+"vldr" = [
+    0xED100A00 = [Sd, Mem(V)];
+    0xED100B00 = [Dd, Mem(V)];
+    0xED1F0A00 = [Sd, Target(TVLDR)];
+    0xED1F0B00 = [Dd, Target(TVLDR)];
+]
+"vstr" = [
+    0xED000A00 = [Sd, Mem(V)];
+    0xED000B00 = [Dd, Mem(V)];
+]
+"vpush" = [
+    0xED2D0A00 = [VRegList(false)];
+    0xED2D0B00 = [VRegList(true)];
+]
+"vpop" = [
+    0xECBD0A00 = [VRegList(false)];
+    0xECBD0B00 = [VRegList(true)];
+]
+"vmrs" = [
+    0xEEF10A10 = [R(12), Lit("fpscr")];
+    0xEEF1FA10 = [Lit("apsr_nzcv"), Lit("fpscr")];
+]
+"vmsr" = [
+    0xEEE10A10 = [Lit("fpscr"), R(12)];
+]
+)
\ No newline at end of file
@@ -0,0 +1,368 @@
+use syn::{parse, Token};
+use syn::parse::discouraged::Speculative;
+use proc_macro2::Delimiter;
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, eat_pseudo_keyword, ParseOptExt, dynamic_register};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegKind, RegFamily, ShiftKind, ShiftAmount, RefOffset, RefMode};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op, like vcvt.f32.s32 or add.w
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            // the offset of a post-indexed reference follows the reference itself: [base], offset
+            if let Some(RawArg::Reference { offset: ref mut offset @ None, ref mut mode, .. }) = args.last_mut() {
+                if *mode == RefMode::Offset {
+                    *offset = Some(parse_offset(ctx, input)?);
+                    *mode = RefMode::PostIndexed;
+                    continue;
+                }
+            }
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            name,
+            span
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    // immediate, with the optional # prefix
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+        let value: syn::Expr = input.parse()?;
+        return Ok(RawArg::Immediate {
+            value
+        });
+    }
+
+    // register list
+    if input.cursor().group(Delimiter::Brace).is_some() {
+        let inner;
+        let _ = syn::braced!(inner in input);
+        let inner = &inner;
+
+        return Ok(RawArg::RegList {
+            items: parse_reglist(ctx, inner)?
+        });
+    }
+
+    // memory reference
+    if input.cursor().group(Delimiter::Bracket).is_some() {
+        let inner;
+        let _ = syn::bracketed!(inner in input);
+        let inner = &inner;
+
+        let base = match parse_reg(ctx, inner)? {
+            Some(base) => base,
+            None => return Err(inner.error("Expected a base register"))
+        };
+
+        let offset = if inner.peek(Token![,]) {
+            let _: Token![,] = inner.parse()?;
+            Some(parse_offset(ctx, inner)?)
+        } else {
+            None
+        };
+
+        if !inner.is_empty() {
+            return Err(inner.error("Unexpected tokens in memory reference"));
+        }
+
+        let mode = if input.peek(Token![!]) {
+            let _: Token![!] = input.parse()?;
+            RefMode::PreIndexed
+        } else {
+            RefMode::Offset
+        };
+
+        return Ok(RawArg::Reference {
+            base,
+            offset,
+            mode
+        });
+    }
+
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // shift
+    if let Some(kind) = parse_shift_kind(input) {
+        let amount = if kind == ShiftKind::RRX {
+            None
+        } else if let Some(reg) = parse_reg(ctx, input)? {
+            Some(ShiftAmount::Register(reg))
+        } else {
+            Some(ShiftAmount::Immediate(parse_immediate(input)?))
+        };
+
+        return Ok(RawArg::Shift {
+            kind,
+            amount
+        });
+    }
+
+    // register
+    if let Some(reg) = parse_reg(ctx, input)? {
+        if input.peek(Token![!]) {
+            let _: Token![!] = input.parse()?;
+            return Ok(RawArg::Writeback {
+                reg
+            });
+        }
+
+        return Ok(RawArg::Direct {
+            reg
+        });
+    }
+
+    // immediate
+    Ok(RawArg::Immediate {
+        value: input.parse()?
+    })
+}
+
+// parses an immediate with an optional # prefix
+fn parse_immediate(input: parse::ParseStream) -> parse::Result<syn::Expr> {
+    if input.peek(Token![#]) {
+        let _: Token![#] = input.parse()?;
+    }
+    input.parse()
+}
+
+// parses the offset of a memory reference: an immediate, or an optionally negated register with an optional shift
+fn parse_offset(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RefOffset> {
+    if input.peek(Token![#]) {
+        return Ok(RefOffset::Immediate(parse_immediate(input)?));
+    }
+
+    let mut negated = false;
+    let mut reg = None;
+
+    if input.peek(Token![-]) || input.peek(Token![+]) {
+        let fork = input.fork();
+        if fork.peek(Token![-]) {
+            let _: Token![-] = fork.parse()?;
+            negated = true;
+        } else {
+            let _: Token![+] = fork.parse()?;
+        }
+
+        if let Some(r) = parse_reg(ctx, &fork)? {
+            input.advance_to(&fork);
+            reg = Some(r);
+        } else {
+            negated = false;
+        }
+    } else {
+        reg = parse_reg(ctx, input)?;
+    }
+
+    let reg = match reg {
+        Some(reg) => reg,
+        None => return Ok(RefOffset::Immediate(input.parse()?))
+    };
+
+    let shift = if input.peek(Token![,]) {
+        let _: Token![,] = input.parse()?;
+        let kind = match parse_shift_kind(input) {
+            Some(kind) => kind,
+            None => return Err(input.error("Expected a shift"))
+        };
+
+        if kind == ShiftKind::RRX {
+            Some((kind, None))
+        } else {
+            Some((kind, Some(parse_immediate(input)?)))
+        }
+    } else {
+        None
+    };
+
+    Ok(RefOffset::Register {
+        reg,
+        negated,
+        shift
+    })
+}
+
+fn parse_shift_kind(input: parse::ParseStream) -> Option<ShiftKind> {
+    for &(name, kind) in &[("lsl", ShiftKind::LSL), ("lsr", ShiftKind::LSR), ("asr", ShiftKind::ASR), ("ror", ShiftKind::ROR), ("rrx", ShiftKind::RRX)] {
+        if eat_pseudo_keyword(input, name) {
+            return Some(kind);
+        }
+    }
+    None
+}
+
+// parses the contents of a register list: reg ("-" reg)? ("," reg ("-" reg)?)*
+fn parse_reglist(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Vec<Register>> {
+    let mut items = Vec::new();
+
+    while !input.is_empty() {
+        let first = match parse_reg(ctx, input)? {
+            Some(reg) => reg,
+            None => return Err(input.error("Expected a register"))
+        };
+
+        if input.peek(Token![-]) {
+            let _: Token![-] = input.parse()?;
+            let last = match parse_reg(ctx, input)? {
+                Some(reg) => reg,
+                None => return Err(input.error("Expected a register"))
+            };
+
+            // ranges can only be expanded when both ends are known
+            match (&first.kind, &last.kind) {
+                (RegKind::Static(start), RegKind::Static(end)) if first.family == last.family && start <= end => {
+                    for code in *start ..= *end {
+                        items.push(Register::new_static(first.family, code));
+                    }
+                },
+                _ => return Err(input.error("Invalid register range"))
+            }
+        } else {
+            items.push(first);
+        }
+
+        if input.is_empty() {
+            break;
+        }
+        let _: Token![,] = input.parse()?;
+    }
+
+    Ok(items)
+}
+
+// tries to parse a register, either by its name or as a dynamic register family followed by an expression
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<Register>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // families are only recognized when followed by the dynamic register expression
+            if ARM_FAMILIES.contains_key(&*ident) && rest.group(Delimiter::Parenthesis).is_some() {
+                return Ok((ident, rest));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if ARM_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&(family, code)) = ARM_REGISTERS.get(&*name) {
+        Ok(Some(Register::new_static(family, code)))
+
+    } else if let Some(&family) = ARM_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(Register::new_dynamic(family, dynamic_register("arm", typed_register(family), "u32", &expr))))
+    } else {
+        unreachable!();
+    }
+}
+
+// The typed register in `dynasmrt::arm` that dynamic registers of `family` are checked against.
+fn typed_register(family: RegFamily) -> &'static str {
+    match family {
+        RegFamily::INTEGER => "R",
+        RegFamily::SINGLE  => "S",
+        RegFamily::DOUBLE  => "D",
+    }
+}
+
+lazy_static!{
+    static ref ARM_REGISTERS: HashMap<&'static str, (RegFamily, u8)> = {
+        use self::RegFamily::*;
+
+        static MAP: &[(&str, (RegFamily, u8))] = &[
+            ("r0", (INTEGER, 0)), ("r1", (INTEGER, 1)), ("r2", (INTEGER, 2)), ("r3", (INTEGER, 3)),
+            ("r4", (INTEGER, 4)), ("r5", (INTEGER, 5)), ("r6", (INTEGER, 6)), ("r7", (INTEGER, 7)),
+            ("r8", (INTEGER, 8)), ("r9", (INTEGER, 9)), ("r10", (INTEGER, 10)), ("r11", (INTEGER, 11)),
+            ("r12", (INTEGER, 12)), ("r13", (INTEGER, 13)), ("r14", (INTEGER, 14)), ("r15", (INTEGER, 15)),
+
+            ("sb", (INTEGER, 9)), ("sl", (INTEGER, 10)), ("fp", (INTEGER, 11)), ("ip", (INTEGER, 12)),
+            ("sp", (INTEGER, 13)), ("lr", (INTEGER, 14)), ("pc", (INTEGER, 15)),
+
+            ("s0", (SINGLE, 0)), ("s1", (SINGLE, 1)), ("s2", (SINGLE, 2)), ("s3", (SINGLE, 3)),
+            ("s4", (SINGLE, 4)), ("s5", (SINGLE, 5)), ("s6", (SINGLE, 6)), ("s7", (SINGLE, 7)),
+            ("s8", (SINGLE, 8)), ("s9", (SINGLE, 9)), ("s10", (SINGLE, 10)), ("s11", (SINGLE, 11)),
+            ("s12", (SINGLE, 12)), ("s13", (SINGLE, 13)), ("s14", (SINGLE, 14)), ("s15", (SINGLE, 15)),
+            ("s16", (SINGLE, 16)), ("s17", (SINGLE, 17)), ("s18", (SINGLE, 18)), ("s19", (SINGLE, 19)),
+            ("s20", (SINGLE, 20)), ("s21", (SINGLE, 21)), ("s22", (SINGLE, 22)), ("s23", (SINGLE, 23)),
+            ("s24", (SINGLE, 24)), ("s25", (SINGLE, 25)), ("s26", (SINGLE, 26)), ("s27", (SINGLE, 27)),
+            ("s28", (SINGLE, 28)), ("s29", (SINGLE, 29)), ("s30", (SINGLE, 30)), ("s31", (SINGLE, 31)),
+
+            ("d0", (DOUBLE, 0)), ("d1", (DOUBLE, 1)), ("d2", (DOUBLE, 2)), ("d3", (DOUBLE, 3)),
+            ("d4", (DOUBLE, 4)), ("d5", (DOUBLE, 5)), ("d6", (DOUBLE, 6)), ("d7", (DOUBLE, 7)),
+            ("d8", (DOUBLE, 8)), ("d9", (DOUBLE, 9)), ("d10", (DOUBLE, 10)), ("d11", (DOUBLE, 11)),
+            ("d12", (DOUBLE, 12)), ("d13", (DOUBLE, 13)), ("d14", (DOUBLE, 14)), ("d15", (DOUBLE, 15)),
+            ("d16", (DOUBLE, 16)), ("d17", (DOUBLE, 17)), ("d18", (DOUBLE, 18)), ("d19", (DOUBLE, 19)),
+            ("d20", (DOUBLE, 20)), ("d21", (DOUBLE, 21)), ("d22", (DOUBLE, 22)), ("d23", (DOUBLE, 23)),
+            ("d24", (DOUBLE, 24)), ("d25", (DOUBLE, 25)), ("d26", (DOUBLE, 26)), ("d27", (DOUBLE, 27)),
+            ("d28", (DOUBLE, 28)), ("d29", (DOUBLE, 29)), ("d30", (DOUBLE, 30)), ("d31", (DOUBLE, 31)),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref ARM_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("R", RegFamily::INTEGER),
+            ("S", RegFamily::SINGLE),
+            ("D", RegFamily::DOUBLE),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -0,0 +1,75 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+/// A loongarch64 register, either chosen statically or dynamically
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub family: RegFamily,
+    pub kind: RegKind
+}
+
+// Register id without indication of its usage. Either a static register encoding or an expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(u8),
+    Dynamic(syn::Expr)
+}
+
+// register family. INTEGER = rn and its ABI names. FLOAT = fn and its ABI names. FCC = fccn, FCSR = fcsrn.
+// LSX = vrn, the 128-bit vector registers. LASX = xrn, the 256-bit vector registers.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FLOAT   = 1,
+    FCC     = 2,
+    FCSR    = 3,
+    LSX     = 4,
+    LASX    = 5,
+}
+
+impl Register {
+    pub fn new_static(family: RegFamily, code: u8) -> Register {
+        Register {
+            family,
+            kind: RegKind::Static(code)
+        }
+    }
+
+    pub fn new_dynamic(family: RegFamily, expr: syn::Expr) -> Register {
+        Register {
+            family,
+            kind: RegKind::Dynamic(expr)
+        }
+    }
+}
+
+/**
+ * Parsed ast
+ */
+
+/// An instruction, with the full dot-separated mnemonic
+#[derive(Debug)]
+pub struct Instruction {
+    pub name: String,
+    pub span: Span
+}
+
+/// The possible arguments of a loongarch64 instruction
+#[derive(Debug)]
+pub enum RawArg {
+    // A register
+    Direct {
+        reg: Register
+    },
+    // A label or other relocation target
+    JumpTarget {
+        jump: Jump
+    },
+    // An immediate
+    Immediate {
+        value: syn::Expr
+    }
+}
@@ -0,0 +1,328 @@
+use super::matching::MatchData;
+use super::loongarch64data::{Operand, Imm, Relocation};
+use super::Context;
+use super::ast::{RawArg, Register, RegKind};
+
+use crate::common::{Stmt, Size, delimited, bitmask64};
+use crate::parse_helpers::as_signed_number;
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+
+// encodings used by the li pseudo instructions
+const ADDI_W: u32 = 0x0280_0000;
+const ORI: u32 = 0x0380_0000;
+const LU12I_W: u32 = 0x1400_0000;
+const LU32I_D: u32 = 0x1600_0000;
+const LU52I_D: u32 = 0x0300_0000;
+
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    let opdata = data.data;
+
+    // the li pseudo instructions pick their expansion depending on their immediate
+    if opdata.operands.contains(&Operand::LiW) {
+        return compile_li(ctx, data, false);
+    } else if opdata.operands.contains(&Operand::LiD) {
+        return compile_li(ctx, data, true);
+    }
+
+    let mut statics: Vec<(u8, u64)> = Vec::new();
+    let mut dynamics: Vec<(u8, TokenStream)> = Vec::new();
+    let mut relocations = Vec::new();
+
+    let mut args = data.args.into_iter();
+
+    for operand in opdata.operands {
+        let arg = args.next().expect("Not enough arguments matched");
+
+        match (*operand, arg) {
+            (Operand::R(offset), RawArg::Direct { reg, .. }) |
+            (Operand::F(offset), RawArg::Direct { reg, .. }) |
+            (Operand::Fcc(offset), RawArg::Direct { reg, .. }) |
+            (Operand::Fcsr(offset), RawArg::Direct { reg, .. }) |
+            (Operand::V(offset), RawArg::Direct { reg, .. }) |
+            (Operand::X(offset), RawArg::Direct { reg, .. }) => encode_register(&reg, offset, &mut statics, &mut dynamics),
+            (Operand::Rs(offsets), RawArg::Direct { reg, .. }) => for &offset in offsets {
+                encode_register(&reg, offset, &mut statics, &mut dynamics);
+            },
+
+            (Operand::Imm(imm), RawArg::Immediate { value }) => encode_immediate(&value, imm, &mut statics, &mut dynamics)?,
+
+            (Operand::Target(_, relocation), RawArg::JumpTarget { jump }) => {
+                // what kind of relocation is it
+                let data = [relocation.to_id()];
+
+                // encode the complete relocation
+                relocations.push(jump.encode(&data));
+            },
+            (Operand::Target(_, Relocation::PCADDU12I), RawArg::Immediate { value }) => encode_pcaddu12i_pair(&value, &mut statics, &mut dynamics)?,
+            (Operand::Target(_, Relocation::PCADDU18I), RawArg::Immediate { value }) => encode_pcaddu18i_pair(&value, &mut statics, &mut dynamics)?,
+            (Operand::Target(imm, _), RawArg::Immediate { value }) => encode_immediate(&value, imm, &mut statics, &mut dynamics)?,
+
+            _ => panic!("Invalid argument processor")
+        }
+    }
+
+    // sanity
+    if args.next().is_some() {
+        panic!("Not enough operand processors");
+    }
+
+    emit_encoding(ctx, opdata.base, opdata.size(), statics, dynamics);
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+// li.w rd, imm and li.d rd, imm expand to the shortest sequence of addi.w, ori, lu12i.w, lu32i.d and lu52i.d
+// that loads the immediate. If the immediate is not known at compile time the full sequence is emitted.
+fn compile_li(ctx: &mut Context, data: MatchData, wide: bool) -> Result<(), Option<String>> {
+    let mut args = data.args.into_iter();
+    let (reg, value) = match (args.next(), args.next()) {
+        (Some(RawArg::Direct { reg, .. }), Some(RawArg::Immediate { value })) => (reg, value),
+        _ => panic!("Invalid argument processor")
+    };
+
+    if let Some(mut number) = as_signed_number(&value) {
+        if !wide {
+            // li.w also accepts unsigned 32-bit values
+            if number >= 0x8000_0000 && number <= 0xFFFF_FFFF {
+                number -= 0x1_0000_0000;
+            }
+
+            if number > 0x7FFF_FFFF {
+                emit_error!(value, "Value too large");
+                return Err(None);
+            } else if number < -0x8000_0000 {
+                emit_error!(value, "Value too small");
+                return Err(None);
+            }
+        }
+
+        // (encoding, if the instruction reads rd)
+        let mut sequence: Vec<(u32, bool)> = Vec::new();
+
+        // load the low 32 bits, sign-extended
+        let low = i64::from(number as i32);
+        if low >= -0x800 && low < 0x800 {
+            sequence.push((ADDI_W | ((low as u32 & 0xFFF) << 10), false));
+        } else if low >= 0 && low < 0x1000 {
+            sequence.push((ORI | ((low as u32) << 10), false));
+        } else {
+            sequence.push((LU12I_W | (((low as u32 >> 12) & 0xF_FFFF) << 5), false));
+            if low & 0xFFF != 0 {
+                sequence.push((ORI | ((low as u32 & 0xFFF) << 10), true));
+            }
+        }
+
+        // bits 32 to 51, sign-extended
+        let mut current = low;
+        if current != number {
+            let middle = (number >> 32) & 0xF_FFFF;
+            if (current >> 32) & 0xF_FFFF != middle {
+                sequence.push((LU32I_D | ((middle as u32) << 5), false));
+            }
+            current = (number << 12) >> 12;
+        }
+
+        // bits 52 to 63
+        if current != number {
+            sequence.push((LU52I_D | ((((number >> 52) & 0xFFF) as u32) << 10), true));
+        }
+
+        for (base, reads_rd) in sequence {
+            let mut statics = Vec::new();
+            let mut dynamics = Vec::new();
+            encode_register(&reg, 0, &mut statics, &mut dynamics);
+            if reads_rd {
+                encode_register(&reg, 5, &mut statics, &mut dynamics);
+            }
+            emit_encoding(ctx, u64::from(base), 4, statics, dynamics);
+        }
+
+        return Ok(());
+    }
+
+    let span = value.span();
+
+    // lu12i.w rd, imm[31:12] ; ori rd, rd, imm[11:0]
+    let mut statics = Vec::new();
+    let mut dynamics = Vec::new();
+    for &offset in &[0, 32, 37] {
+        encode_register(&reg, offset, &mut statics, &mut dynamics);
+    }
+    dynamics.push((0, quote_spanned!{ span=>
+        {
+            let imm = (#value) as u64;
+            (((imm >> 12) & 0xF_FFFF) << 5) | ((imm & 0xFFF) << 42)
+        }
+    }));
+    emit_encoding(ctx, u64::from(LU12I_W) | (u64::from(ORI) << 32), 8, statics, dynamics);
+
+    if wide {
+        // lu32i.d rd, imm[51:32] ; lu52i.d rd, rd, imm[63:52]
+        let mut statics = Vec::new();
+        let mut dynamics = Vec::new();
+        for &offset in &[0, 32, 37] {
+            encode_register(&reg, offset, &mut statics, &mut dynamics);
+        }
+        dynamics.push((0, quote_spanned!{ span=>
+            {
+                let imm = (#value) as u64;
+                (((imm >> 32) & 0xF_FFFF) << 5) | (((imm >> 52) & 0xFFF) << 42)
+            }
+        }));
+        emit_encoding(ctx, u64::from(LU32I_D) | (u64::from(LU52I_D) << 32), 8, statics, dynamics);
+    }
+
+    Ok(())
+}
+
+// emit the statement encoding an instruction of the given size
+fn emit_encoding(ctx: &mut Context, base: u64, size: u8, statics: Vec<(u8, u64)>, dynamics: Vec<(u8, TokenStream)>) {
+    // apply all statics to bits
+    let mut bits = base;
+    for (offset, value) in statics {
+        bits |= value << offset;
+    }
+
+    let (size, ty) = match size {
+        4 => (Size::DWORD, quote!(u32)),
+        8 => (Size::QWORD, quote!(u64)),
+        _ => unreachable!()
+    };
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let mut res = quote!{
+            (#bits as #ty)
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        ctx.state.stmts.push(Stmt::Const(bits, size));
+    }
+}
+
+fn encode_register(reg: &Register, offset: u8, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) {
+    match reg.kind {
+        RegKind::Static(code) => statics.push((offset, u64::from(code))),
+        RegKind::Dynamic(ref expr) => dynamics.push((offset, quote_spanned!{ expr.span()=>
+            #expr & 0x1F
+        }))
+    }
+}
+
+fn encode_immediate(expr: &syn::Expr, imm: Imm, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        let value = immediate_rangecheck(expr, value, imm)? - i64::from(imm.bias);
+
+        for &(low, bits, offset) in imm.fields {
+            statics.push((offset, ((value >> low) as u64) & bitmask64(bits)));
+        }
+    } else {
+        // scatter the fields of the immediate at runtime
+        let span = expr.span();
+        let mut res = quote_spanned!{ span=> 0u32 };
+        for &(low, bits, offset) in imm.fields {
+            let mask = bitmask64(bits) as u32;
+            res = quote_spanned!{ span=>
+                #res | (((imm >> #low) & #mask) << #offset)
+            };
+        }
+
+        let bias = u32::from(imm.bias);
+        dynamics.push((0, quote_spanned!{ span=>
+            {
+                let imm = ((#expr) as u32).wrapping_sub(#bias);
+                #res
+            }
+        }));
+    }
+
+    Ok(())
+}
+
+// encodes a 32-bit offset split over a pcaddu12i and an instruction with a 12-bit immediate in the upper half of the encoding
+fn encode_pcaddu12i_pair(expr: &syn::Expr, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        if value > 0x7FFF_F7FF {
+            emit_error!(expr, "Value too large");
+            return Err(None);
+        } else if value < -0x8000_0800 {
+            emit_error!(expr, "Value too small");
+            return Err(None);
+        }
+
+        statics.push((5, (((value + 0x800) >> 12) as u64) & 0xF_FFFF));
+        statics.push((42, (value as u64) & 0xFFF));
+    } else {
+        dynamics.push((0, quote_spanned!{ expr.span()=>
+            {
+                let imm = (#expr) as u32;
+                (u64::from(imm.wrapping_add(0x800) >> 12) << 5) | (u64::from(imm & 0xFFF) << 42)
+            }
+        }));
+    }
+
+    Ok(())
+}
+
+// encodes a 38-bit offset split over a pcaddu18i and a jirl in the upper half of the encoding
+fn encode_pcaddu18i_pair(expr: &syn::Expr, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        if value & 3 != 0 {
+            emit_error!(expr, "Unrepresentable value");
+            return Err(None);
+        } else if value > 0x1F_FFFD_FFFF {
+            emit_error!(expr, "Value too large");
+            return Err(None);
+        } else if value < -0x20_0002_0000 {
+            emit_error!(expr, "Value too small");
+            return Err(None);
+        }
+
+        statics.push((5, (((value + 0x2_0000) >> 18) as u64) & 0xF_FFFF));
+        statics.push((42, ((value >> 2) as u64) & 0xFFFF));
+    } else {
+        dynamics.push((0, quote_spanned!{ expr.span()=>
+            {
+                let imm = (#expr) as i64;
+                ((((imm.wrapping_add(0x2_0000) >> 18) as u64) & 0xF_FFFF) << 5) | ((((imm >> 2) as u64) & 0xFFFF) << 42)
+            }
+        }));
+    }
+
+    Ok(())
+}
+
+// checks if a static immediate can be encoded, returning the value to encode
+fn immediate_rangecheck(expr: &syn::Expr, value: i64, imm: Imm) -> Result<i64, Option<String>> {
+    let bias = i64::from(imm.bias);
+    let (min, max) = if imm.signed {
+        (-(1i64 << (imm.bits - 1)), (1i64 << (imm.bits - 1)) - 1)
+    } else {
+        (bias, (1i64 << imm.bits) - 1 + bias)
+    };
+
+    if value & ((1 << imm.scale) - 1) != 0 {
+        emit_error!(expr, "Unrepresentable value");
+        Err(None)
+    } else if value > max {
+        emit_error!(expr, "Value too large");
+        Err(None)
+    } else if value < min {
+        emit_error!(expr, "Value too small");
+        Err(None)
+    } else {
+        Ok(value)
+    }
+}
@@ -0,0 +1,199 @@
+use lazy_static::lazy_static;
+use std::collections::{HashMap, hash_map};
+
+/// The operands an instruction encoding accepts, and how they are encoded.
+/// Offsets are bit offsets into the encoding template.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    // a general purpose register
+    R(u8),
+    // a general purpose register encoded in multiple fields
+    Rs(&'static [u8]),
+    // a floating point register
+    F(u8),
+    // a floating point condition flag register
+    Fcc(u8),
+    // a floating point control and status register
+    Fcsr(u8),
+    // a 128-bit LSX vector register
+    V(u8),
+    // a 256-bit LASX vector register
+    X(u8),
+
+    // an immediate
+    Imm(Imm),
+    // a relocation target, or an immediate offset
+    Target(Imm, Relocation),
+
+    // the immediate of the li.w pseudo instruction, which is expanded depending on its value
+    LiW,
+    // the immediate of the li.d pseudo instruction, which is expanded depending on its value
+    LiD,
+}
+
+/// A description of an immediate operand: its range, and how its bits are scattered over the encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Imm {
+    /// If the immediate is a signed value
+    pub signed: bool,
+    /// The width of the immediate, including the implied low bits
+    pub bits: u8,
+    /// The amount of low bits that have to be zero, and are not encoded
+    pub scale: u8,
+    /// A value that is subtracted from the immediate before it is encoded
+    pub bias: u8,
+    /// A list of fields of the immediate: (lowest bit in the immediate, amount of bits, offset in the encoding)
+    pub fields: &'static [(u8, u8, u8)],
+}
+
+macro_rules! imm {
+    ($signed:expr, $bits:expr, $scale:expr, [ $( $field:expr ),* ]) => {
+        Imm {
+            signed: $signed,
+            bits: $bits,
+            scale: $scale,
+            bias: 0,
+            fields: &[ $( $field ),* ]
+        }
+    }
+}
+
+// 2RI12 immediates
+pub const SI12: Imm = imm!(true, 12, 0, [(0, 12, 10)]);
+pub const UI12: Imm = imm!(false, 12, 0, [(0, 12, 10)]);
+// 2RI14 immediates of ll, sc, ldptr and stptr, a word-aligned offset
+pub const SI14: Imm = imm!(true, 16, 2, [(2, 14, 10)]);
+// 2RI16 immediates
+pub const SI16: Imm = imm!(true, 16, 0, [(0, 16, 10)]);
+// 1RI20 immediates
+pub const SI20: Imm = imm!(true, 20, 0, [(0, 20, 5)]);
+// shift amounts, bit indices and element indices
+pub const UI1: Imm = imm!(false, 1, 0, [(0, 1, 10)]);
+pub const UI2: Imm = imm!(false, 2, 0, [(0, 2, 10)]);
+pub const UI3: Imm = imm!(false, 3, 0, [(0, 3, 10)]);
+pub const UI4: Imm = imm!(false, 4, 0, [(0, 4, 10)]);
+pub const UI5: Imm = imm!(false, 5, 0, [(0, 5, 10)]);
+pub const UI6: Imm = imm!(false, 6, 0, [(0, 6, 10)]);
+pub const UI8: Imm = imm!(false, 8, 0, [(0, 8, 10)]);
+// the msb of bstrins and bstrpick
+pub const MSBW: Imm = imm!(false, 5, 0, [(0, 5, 16)]);
+pub const MSBD: Imm = imm!(false, 6, 0, [(0, 6, 16)]);
+// the shift amount of alsl, which is encoded minus one
+pub const SA2: Imm = Imm { bias: 1, ..imm!(false, 2, 0, [(0, 2, 15)]) };
+// the byte position of bytepick
+pub const BP2: Imm = imm!(false, 2, 0, [(0, 2, 15)]);
+pub const BP3: Imm = imm!(false, 3, 0, [(0, 3, 15)]);
+// the code of break, syscall and dbcl, and the hint of dbar and ibar
+pub const CODE15: Imm = imm!(false, 15, 0, [(0, 15, 0)]);
+// the hint of preld and preldx
+pub const HINT5: Imm = imm!(false, 5, 0, [(0, 5, 0)]);
+// branch offsets. The 16-bit offset is also used by jirl, where it is relative to the base register
+pub const OFFS16: Imm = imm!(true, 18, 2, [(2, 16, 10)]);
+pub const OFFS21: Imm = imm!(true, 23, 2, [(2, 16, 10), (18, 5, 0)]);
+pub const OFFS26: Imm = imm!(true, 28, 2, [(2, 16, 10), (18, 10, 0)]);
+// the offset of pcaddi
+pub const PCREL22: Imm = imm!(true, 22, 2, [(2, 20, 5)]);
+// the offset of a pcaddu12i pair. This is not a simple bitfield, and handled by the compiler.
+pub const PAIR32: Imm = imm!(true, 32, 0, []);
+// the offset of a pcaddu18i pair. This is not a simple bitfield, and handled by the compiler.
+pub const PAIR38: Imm = imm!(true, 38, 2, []);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // beq, bne, blt, bge, bltu, bgeu: 16 bits, word aligned
+    B16 = 0,
+    // beqz, bnez, bceqz, bcnez: 21 bits split over two fields, word aligned
+    B21 = 1,
+    // b, bl: 26 bits split over two fields, word aligned
+    B26 = 2,
+    // pcaddi: 20 bits, word aligned
+    PCADDI = 3,
+    // pcaddu12i followed by an instruction with a 12-bit immediate: split 32 bits, byte aligned
+    PCADDU12I = 4,
+    // pcaddu18i followed by jirl: split 38 bits, word aligned
+    PCADDU18I = 5,
+    // 8-bit literal
+    LITERAL8 = 7,
+    // 16-bit literal
+    LITERAL16 = 8,
+    // 32-bit literal
+    LITERAL32 = 10,
+    // 64-bit literal
+    LITERAL64 = 14,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. For pseudo instructions that expand to two instructions,
+    /// the second instruction is stored in the upper 32 bits.
+    pub base: u64,
+    /// The operands of this instruction, in order
+    pub operands: &'static [Operand],
+}
+
+impl Opdata {
+    /// The size of the encoding in bytes.
+    pub fn size(&self) -> u8 {
+        if self.base > 0xFFFF_FFFF {
+            8
+        } else {
+            4
+        }
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $operand:expr ),* ] ) => {
+        {
+            const OPERANDS: &'static [Operand] = {
+                #[allow(unused_imports)]
+                use self::Operand::*;
+                &[ $(
+                    $operand
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                operands: OPERANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $operand:expr ),* ] ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $operand ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+lazy_static! {
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+}
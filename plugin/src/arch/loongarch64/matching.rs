@@ -0,0 +1,102 @@
+use proc_macro_error::emit_error;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, RegFamily};
+use super::loongarch64data::{Opdata, Operand, get_mnemonic_data};
+
+use crate::common::JumpKind;
+
+/// The result of matching an instruction: the encoding to use and the arguments to encode with it.
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<RawArg>
+}
+
+/// Try finding an appropriate definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+    // validate that only relative jumps are allowed (no extern relocations)
+    for arg in &args {
+        if let RawArg::JumpTarget { ref jump } = *arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error!(jump.span(), "Extern relocations are not allowed in loongarch64");
+                return Err(None);
+            }
+        }
+    }
+
+    // get the possible encodings
+    let name = &instruction.name;
+    let opdata = if let Some(o) = get_mnemonic_data(name) {
+        o
+    } else {
+        return Err(Some(format!("Unknown instruction mnemonic '{}'", name)));
+    };
+
+    // matching loop
+    for data in opdata {
+        if match_args(&args, data) {
+            return Ok(MatchData {
+                data,
+                args
+            });
+        }
+    }
+
+    Err(Some(
+        format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", name, format_opdata_list(name, opdata))
+    ))
+}
+
+fn match_args(args: &[RawArg], data: &Opdata) -> bool {
+    args.len() == data.operands.len() && args.iter().zip(data.operands).all(|(arg, operand)| match_operand(arg, operand))
+}
+
+fn match_operand(arg: &RawArg, operand: &Operand) -> bool {
+    match (arg, *operand) {
+        (RawArg::Direct { reg, .. }, Operand::R(_)) |
+        (RawArg::Direct { reg, .. }, Operand::Rs(_)) => reg.family == RegFamily::INTEGER,
+        (RawArg::Direct { reg, .. }, Operand::F(_)) => reg.family == RegFamily::FLOAT,
+        (RawArg::Direct { reg, .. }, Operand::Fcc(_)) => reg.family == RegFamily::FCC,
+        (RawArg::Direct { reg, .. }, Operand::Fcsr(_)) => reg.family == RegFamily::FCSR,
+        (RawArg::Direct { reg, .. }, Operand::V(_)) => reg.family == RegFamily::LSX,
+        (RawArg::Direct { reg, .. }, Operand::X(_)) => reg.family == RegFamily::LASX,
+
+        (RawArg::JumpTarget { .. }, Operand::Target(_, _)) |
+        (RawArg::Immediate { .. }, Operand::Target(_, _)) |
+        (RawArg::Immediate { .. }, Operand::Imm(_)) |
+        (RawArg::Immediate { .. }, Operand::LiW) |
+        (RawArg::Immediate { .. }, Operand::LiD) => true,
+
+        _ => false
+    }
+}
+
+/// Formats the list of possible forms of an instruction for error messages
+fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    let mut forms = Vec::new();
+
+    for data in data {
+        let operands: Vec<&str> = data.operands.iter().map(|operand| match *operand {
+            Operand::R(_) |
+            Operand::Rs(_) => "r",
+            Operand::F(_) => "f",
+            Operand::Fcc(_) => "fcc",
+            Operand::Fcsr(_) => "fcsr",
+            Operand::V(_) => "vr",
+            Operand::X(_) => "xr",
+            Operand::Imm(_) |
+            Operand::LiW |
+            Operand::LiD => "imm",
+            Operand::Target(_, _) => "label",
+        }).collect();
+
+        if operands.is_empty() {
+            forms.push(format!(">>> {}", name));
+        } else {
+            forms.push(format!(">>> {} {}", name, operands.join(", ")));
+        }
+    }
+
+    forms.join("\n")
+}
@@ -0,0 +1,2412 @@
+Ops!(
+// base integer instructions: arithmetic
+"add.w" = [
+    0x00100000 = [R(0), R(5), R(10)];
+]
+"add.d" = [
+    0x00108000 = [R(0), R(5), R(10)];
+]
+"sub.w" = [
+    0x00110000 = [R(0), R(5), R(10)];
+]
+"sub.d" = [
+    0x00118000 = [R(0), R(5), R(10)];
+]
+"slt" = [
+    0x00120000 = [R(0), R(5), R(10)];
+]
+"sltu" = [
+    0x00128000 = [R(0), R(5), R(10)];
+]
+"maskeqz" = [
+    0x00130000 = [R(0), R(5), R(10)];
+]
+"masknez" = [
+    0x00138000 = [R(0), R(5), R(10)];
+]
+"nor" = [
+    0x00140000 = [R(0), R(5), R(10)];
+]
+"and" = [
+    0x00148000 = [R(0), R(5), R(10)];
+]
+"or" = [
+    0x00150000 = [R(0), R(5), R(10)];
+]
+"xor" = [
+    0x00158000 = [R(0), R(5), R(10)];
+]
+"orn" = [
+    0x00160000 = [R(0), R(5), R(10)];
+]
+"andn" = [
+    0x00168000 = [R(0), R(5), R(10)];
+]
+"sll.w" = [
+    0x00170000 = [R(0), R(5), R(10)];
+]
+"srl.w" = [
+    0x00178000 = [R(0), R(5), R(10)];
+]
+"sra.w" = [
+    0x00180000 = [R(0), R(5), R(10)];
+]
+"sll.d" = [
+    0x00188000 = [R(0), R(5), R(10)];
+]
+"srl.d" = [
+    0x00190000 = [R(0), R(5), R(10)];
+]
+"sra.d" = [
+    0x00198000 = [R(0), R(5), R(10)];
+]
+"rotr.w" = [
+    0x001B0000 = [R(0), R(5), R(10)];
+]
+"rotr.d" = [
+    0x001B8000 = [R(0), R(5), R(10)];
+]
+"mul.w" = [
+    0x001C0000 = [R(0), R(5), R(10)];
+]
+"mulh.w" = [
+    0x001C8000 = [R(0), R(5), R(10)];
+]
+"mulh.wu" = [
+    0x001D0000 = [R(0), R(5), R(10)];
+]
+"mul.d" = [
+    0x001D8000 = [R(0), R(5), R(10)];
+]
+"mulh.d" = [
+    0x001E0000 = [R(0), R(5), R(10)];
+]
+"mulh.du" = [
+    0x001E8000 = [R(0), R(5), R(10)];
+]
+"mulw.d.w" = [
+    0x001F0000 = [R(0), R(5), R(10)];
+]
+"mulw.d.wu" = [
+    0x001F8000 = [R(0), R(5), R(10)];
+]
+"div.w" = [
+    0x00200000 = [R(0), R(5), R(10)];
+]
+"mod.w" = [
+    0x00208000 = [R(0), R(5), R(10)];
+]
+"div.wu" = [
+    0x00210000 = [R(0), R(5), R(10)];
+]
+"mod.wu" = [
+    0x00218000 = [R(0), R(5), R(10)];
+]
+"div.d" = [
+    0x00220000 = [R(0), R(5), R(10)];
+]
+"mod.d" = [
+    0x00228000 = [R(0), R(5), R(10)];
+]
+"div.du" = [
+    0x00230000 = [R(0), R(5), R(10)];
+]
+"mod.du" = [
+    0x00238000 = [R(0), R(5), R(10)];
+]
+"crc.w.b.w" = [
+    0x00240000 = [R(0), R(5), R(10)];
+]
+"crc.w.h.w" = [
+    0x00248000 = [R(0), R(5), R(10)];
+]
+"crc.w.w.w" = [
+    0x00250000 = [R(0), R(5), R(10)];
+]
+"crc.w.d.w" = [
+    0x00258000 = [R(0), R(5), R(10)];
+]
+"crcc.w.b.w" = [
+    0x00260000 = [R(0), R(5), R(10)];
+]
+"crcc.w.h.w" = [
+    0x00268000 = [R(0), R(5), R(10)];
+]
+"crcc.w.w.w" = [
+    0x00270000 = [R(0), R(5), R(10)];
+]
+"crcc.w.d.w" = [
+    0x00278000 = [R(0), R(5), R(10)];
+]
+"alsl.w" = [
+    0x00040000 = [R(0), R(5), R(10), Imm(SA2)];
+]
+"alsl.wu" = [
+    0x00060000 = [R(0), R(5), R(10), Imm(SA2)];
+]
+"alsl.d" = [
+    0x002C0000 = [R(0), R(5), R(10), Imm(SA2)];
+]
+"bytepick.w" = [
+    0x00080000 = [R(0), R(5), R(10), Imm(BP2)];
+]
+"bytepick.d" = [
+    0x000C0000 = [R(0), R(5), R(10), Imm(BP3)];
+]
+"slti" = [
+    0x02000000 = [R(0), R(5), Imm(SI12)];
+]
+"sltui" = [
+    0x02400000 = [R(0), R(5), Imm(SI12)];
+]
+"addi.w" = [
+    0x02800000 = [R(0), R(5), Imm(SI12)];
+]
+"addi.d" = [
+    0x02C00000 = [R(0), R(5), Imm(SI12)];
+]
+"lu52i.d" = [
+    0x03000000 = [R(0), R(5), Imm(SI12)];
+]
+"andi" = [
+    0x03400000 = [R(0), R(5), Imm(UI12)];
+]
+"ori" = [
+    0x03800000 = [R(0), R(5), Imm(UI12)];
+]
+"xori" = [
+    0x03C00000 = [R(0), R(5), Imm(UI12)];
+]
+"addu16i.d" = [
+    0x10000000 = [R(0), R(5), Imm(SI16)];
+]
+"slli.w" = [
+    0x00408000 = [R(0), R(5), Imm(UI5)];
+]
+"slli.d" = [
+    0x00410000 = [R(0), R(5), Imm(UI6)];
+]
+"srli.w" = [
+    0x00448000 = [R(0), R(5), Imm(UI5)];
+]
+"srli.d" = [
+    0x00450000 = [R(0), R(5), Imm(UI6)];
+]
+"srai.w" = [
+    0x00488000 = [R(0), R(5), Imm(UI5)];
+]
+"srai.d" = [
+    0x00490000 = [R(0), R(5), Imm(UI6)];
+]
+"rotri.w" = [
+    0x004C8000 = [R(0), R(5), Imm(UI5)];
+]
+"rotri.d" = [
+    0x004D0000 = [R(0), R(5), Imm(UI6)];
+]
+"lu12i.w" = [
+    0x14000000 = [R(0), Imm(SI20)];
+]
+"lu32i.d" = [
+    0x16000000 = [R(0), Imm(SI20)];
+]
+"pcaddi" = [
+    0x18000000 = [R(0), Target(PCREL22, PCADDI)];
+]
+"pcalau12i" = [
+    0x1A000000 = [R(0), Imm(SI20)];
+]
+"pcaddu12i" = [
+    0x1C000000 = [R(0), Imm(SI20)];
+]
+"pcaddu18i" = [
+    0x1E000000 = [R(0), Imm(SI20)];
+]
+"bstrins.w" = [
+    0x00600000 = [R(0), R(5), Imm(MSBW), Imm(UI5)];
+]
+"bstrpick.w" = [
+    0x00608000 = [R(0), R(5), Imm(MSBW), Imm(UI5)];
+]
+"bstrins.d" = [
+    0x00800000 = [R(0), R(5), Imm(MSBD), Imm(UI6)];
+]
+"bstrpick.d" = [
+    0x00C00000 = [R(0), R(5), Imm(MSBD), Imm(UI6)];
+]
+"clo.w" = [
+    0x00001000 = [R(0), R(5)];
+]
+"clz.w" = [
+    0x00001400 = [R(0), R(5)];
+]
+"cto.w" = [
+    0x00001800 = [R(0), R(5)];
+]
+"ctz.w" = [
+    0x00001C00 = [R(0), R(5)];
+]
+"clo.d" = [
+    0x00002000 = [R(0), R(5)];
+]
+"clz.d" = [
+    0x00002400 = [R(0), R(5)];
+]
+"cto.d" = [
+    0x00002800 = [R(0), R(5)];
+]
+"ctz.d" = [
+    0x00002C00 = [R(0), R(5)];
+]
+"revb.2h" = [
+    0x00003000 = [R(0), R(5)];
+]
+"revb.4h" = [
+    0x00003400 = [R(0), R(5)];
+]
+"revb.2w" = [
+    0x00003800 = [R(0), R(5)];
+]
+"revb.d" = [
+    0x00003C00 = [R(0), R(5)];
+]
+"revh.2w" = [
+    0x00004000 = [R(0), R(5)];
+]
+"revh.d" = [
+    0x00004400 = [R(0), R(5)];
+]
+"bitrev.4b" = [
+    0x00004800 = [R(0), R(5)];
+]
+"bitrev.8b" = [
+    0x00004C00 = [R(0), R(5)];
+]
+"bitrev.w" = [
+    0x00005000 = [R(0), R(5)];
+]
+"bitrev.d" = [
+    0x00005400 = [R(0), R(5)];
+]
+"ext.w.h" = [
+    0x00005800 = [R(0), R(5)];
+]
+"ext.w.b" = [
+    0x00005C00 = [R(0), R(5)];
+]
+"rdtimel.w" = [
+    0x00006000 = [R(0), R(5)];
+]
+"rdtimeh.w" = [
+    0x00006400 = [R(0), R(5)];
+]
+"rdtime.d" = [
+    0x00006800 = [R(0), R(5)];
+]
+"cpucfg" = [
+    0x00006C00 = [R(0), R(5)];
+]
+"break" = [
+    0x002A0000 = [Imm(CODE15)];
+]
+"dbcl" = [
+    0x002A8000 = [Imm(CODE15)];
+]
+"syscall" = [
+    0x002B0000 = [Imm(CODE15)];
+]
+// base integer instructions: branches
+"beqz" = [
+    0x40000000 = [R(5), Target(OFFS21, B21)];
+]
+"bnez" = [
+    0x44000000 = [R(5), Target(OFFS21, B21)];
+]
+"bceqz" = [
+    0x48000000 = [Fcc(5), Target(OFFS21, B21)];
+]
+"bcnez" = [
+    0x48000100 = [Fcc(5), Target(OFFS21, B21)];
+]
+"jirl" = [
+    0x4C000000 = [R(0), R(5), Imm(OFFS16)];
+]
+"b" = [
+    0x50000000 = [Target(OFFS26, B26)];
+]
+"bl" = [
+    0x54000000 = [Target(OFFS26, B26)];
+]
+"beq" = [
+    0x58000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+"bne" = [
+    0x5C000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+"blt" = [
+    0x60000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+"bge" = [
+    0x64000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+"bltu" = [
+    0x68000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+"bgeu" = [
+    0x6C000000 = [R(5), R(0), Target(OFFS16, B16)];
+]
+// base integer instructions: memory access
+"ld.b" = [
+    0x28000000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.h" = [
+    0x28400000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.w" = [
+    0x28800000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.d" = [
+    0x28C00000 = [R(0), R(5), Imm(SI12)];
+]
+"st.b" = [
+    0x29000000 = [R(0), R(5), Imm(SI12)];
+]
+"st.h" = [
+    0x29400000 = [R(0), R(5), Imm(SI12)];
+]
+"st.w" = [
+    0x29800000 = [R(0), R(5), Imm(SI12)];
+]
+"st.d" = [
+    0x29C00000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.bu" = [
+    0x2A000000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.hu" = [
+    0x2A400000 = [R(0), R(5), Imm(SI12)];
+]
+"ld.wu" = [
+    0x2A800000 = [R(0), R(5), Imm(SI12)];
+]
+"preld" = [
+    0x2AC00000 = [Imm(HINT5), R(5), Imm(SI12)];
+]
+"ll.w" = [
+    0x20000000 = [R(0), R(5), Imm(SI14)];
+]
+"sc.w" = [
+    0x21000000 = [R(0), R(5), Imm(SI14)];
+]
+"ll.d" = [
+    0x22000000 = [R(0), R(5), Imm(SI14)];
+]
+"sc.d" = [
+    0x23000000 = [R(0), R(5), Imm(SI14)];
+]
+"ldptr.w" = [
+    0x24000000 = [R(0), R(5), Imm(SI14)];
+]
+"stptr.w" = [
+    0x25000000 = [R(0), R(5), Imm(SI14)];
+]
+"ldptr.d" = [
+    0x26000000 = [R(0), R(5), Imm(SI14)];
+]
+"stptr.d" = [
+    0x27000000 = [R(0), R(5), Imm(SI14)];
+]
+"ldx.b" = [
+    0x38000000 = [R(0), R(5), R(10)];
+]
+"ldx.h" = [
+    0x38040000 = [R(0), R(5), R(10)];
+]
+"ldx.w" = [
+    0x38080000 = [R(0), R(5), R(10)];
+]
+"ldx.d" = [
+    0x380C0000 = [R(0), R(5), R(10)];
+]
+"stx.b" = [
+    0x38100000 = [R(0), R(5), R(10)];
+]
+"stx.h" = [
+    0x38140000 = [R(0), R(5), R(10)];
+]
+"stx.w" = [
+    0x38180000 = [R(0), R(5), R(10)];
+]
+"stx.d" = [
+    0x381C0000 = [R(0), R(5), R(10)];
+]
+"ldx.bu" = [
+    0x38200000 = [R(0), R(5), R(10)];
+]
+"ldx.hu" = [
+    0x38240000 = [R(0), R(5), R(10)];
+]
+"ldx.wu" = [
+    0x38280000 = [R(0), R(5), R(10)];
+]
+"preldx" = [
+    0x382C0000 = [Imm(HINT5), R(5), R(10)];
+]
+"amswap.w" = [
+    0x38600000 = [R(0), R(10), R(5)];
+]
+"amswap.d" = [
+    0x38608000 = [R(0), R(10), R(5)];
+]
+"amadd.w" = [
+    0x38610000 = [R(0), R(10), R(5)];
+]
+"amadd.d" = [
+    0x38618000 = [R(0), R(10), R(5)];
+]
+"amand.w" = [
+    0x38620000 = [R(0), R(10), R(5)];
+]
+"amand.d" = [
+    0x38628000 = [R(0), R(10), R(5)];
+]
+"amor.w" = [
+    0x38630000 = [R(0), R(10), R(5)];
+]
+"amor.d" = [
+    0x38638000 = [R(0), R(10), R(5)];
+]
+"amxor.w" = [
+    0x38640000 = [R(0), R(10), R(5)];
+]
+"amxor.d" = [
+    0x38648000 = [R(0), R(10), R(5)];
+]
+"ammax.w" = [
+    0x38650000 = [R(0), R(10), R(5)];
+]
+"ammax.d" = [
+    0x38658000 = [R(0), R(10), R(5)];
+]
+"ammin.w" = [
+    0x38660000 = [R(0), R(10), R(5)];
+]
+"ammin.d" = [
+    0x38668000 = [R(0), R(10), R(5)];
+]
+"ammax.wu" = [
+    0x38670000 = [R(0), R(10), R(5)];
+]
+"ammax.du" = [
+    0x38678000 = [R(0), R(10), R(5)];
+]
+"ammin.wu" = [
+    0x38680000 = [R(0), R(10), R(5)];
+]
+"ammin.du" = [
+    0x38688000 = [R(0), R(10), R(5)];
+]
+"amswap_db.w" = [
+    0x38690000 = [R(0), R(10), R(5)];
+]
+"amswap_db.d" = [
+    0x38698000 = [R(0), R(10), R(5)];
+]
+"amadd_db.w" = [
+    0x386A0000 = [R(0), R(10), R(5)];
+]
+"amadd_db.d" = [
+    0x386A8000 = [R(0), R(10), R(5)];
+]
+"amand_db.w" = [
+    0x386B0000 = [R(0), R(10), R(5)];
+]
+"amand_db.d" = [
+    0x386B8000 = [R(0), R(10), R(5)];
+]
+"amor_db.w" = [
+    0x386C0000 = [R(0), R(10), R(5)];
+]
+"amor_db.d" = [
+    0x386C8000 = [R(0), R(10), R(5)];
+]
+"amxor_db.w" = [
+    0x386D0000 = [R(0), R(10), R(5)];
+]
+"amxor_db.d" = [
+    0x386D8000 = [R(0), R(10), R(5)];
+]
+"ammax_db.w" = [
+    0x386E0000 = [R(0), R(10), R(5)];
+]
+"ammax_db.d" = [
+    0x386E8000 = [R(0), R(10), R(5)];
+]
+"ammin_db.w" = [
+    0x386F0000 = [R(0), R(10), R(5)];
+]
+"ammin_db.d" = [
+    0x386F8000 = [R(0), R(10), R(5)];
+]
+"ammax_db.wu" = [
+    0x38700000 = [R(0), R(10), R(5)];
+]
+"ammax_db.du" = [
+    0x38708000 = [R(0), R(10), R(5)];
+]
+"ammin_db.wu" = [
+    0x38710000 = [R(0), R(10), R(5)];
+]
+"ammin_db.du" = [
+    0x38718000 = [R(0), R(10), R(5)];
+]
+"dbar" = [
+    0x38720000 = [Imm(CODE15)];
+]
+"ibar" = [
+    0x38728000 = [Imm(CODE15)];
+]
+// floating point instructions
+"fadd.s" = [
+    0x01008000 = [F(0), F(5), F(10)];
+]
+"fadd.d" = [
+    0x01010000 = [F(0), F(5), F(10)];
+]
+"fsub.s" = [
+    0x01028000 = [F(0), F(5), F(10)];
+]
+"fsub.d" = [
+    0x01030000 = [F(0), F(5), F(10)];
+]
+"fmul.s" = [
+    0x01048000 = [F(0), F(5), F(10)];
+]
+"fmul.d" = [
+    0x01050000 = [F(0), F(5), F(10)];
+]
+"fdiv.s" = [
+    0x01068000 = [F(0), F(5), F(10)];
+]
+"fdiv.d" = [
+    0x01070000 = [F(0), F(5), F(10)];
+]
+"fmax.s" = [
+    0x01088000 = [F(0), F(5), F(10)];
+]
+"fmax.d" = [
+    0x01090000 = [F(0), F(5), F(10)];
+]
+"fmin.s" = [
+    0x010A8000 = [F(0), F(5), F(10)];
+]
+"fmin.d" = [
+    0x010B0000 = [F(0), F(5), F(10)];
+]
+"fmaxa.s" = [
+    0x010C8000 = [F(0), F(5), F(10)];
+]
+"fmaxa.d" = [
+    0x010D0000 = [F(0), F(5), F(10)];
+]
+"fmina.s" = [
+    0x010E8000 = [F(0), F(5), F(10)];
+]
+"fmina.d" = [
+    0x010F0000 = [F(0), F(5), F(10)];
+]
+"fscaleb.s" = [
+    0x01108000 = [F(0), F(5), F(10)];
+]
+"fscaleb.d" = [
+    0x01110000 = [F(0), F(5), F(10)];
+]
+"fcopysign.s" = [
+    0x01128000 = [F(0), F(5), F(10)];
+]
+"fcopysign.d" = [
+    0x01130000 = [F(0), F(5), F(10)];
+]
+"fabs.s" = [
+    0x01140400 = [F(0), F(5)];
+]
+"fabs.d" = [
+    0x01140800 = [F(0), F(5)];
+]
+"fneg.s" = [
+    0x01141400 = [F(0), F(5)];
+]
+"fneg.d" = [
+    0x01141800 = [F(0), F(5)];
+]
+"flogb.s" = [
+    0x01142400 = [F(0), F(5)];
+]
+"flogb.d" = [
+    0x01142800 = [F(0), F(5)];
+]
+"fclass.s" = [
+    0x01143400 = [F(0), F(5)];
+]
+"fclass.d" = [
+    0x01143800 = [F(0), F(5)];
+]
+"fsqrt.s" = [
+    0x01144400 = [F(0), F(5)];
+]
+"fsqrt.d" = [
+    0x01144800 = [F(0), F(5)];
+]
+"frecip.s" = [
+    0x01145400 = [F(0), F(5)];
+]
+"frecip.d" = [
+    0x01145800 = [F(0), F(5)];
+]
+"frsqrt.s" = [
+    0x01146400 = [F(0), F(5)];
+]
+"frsqrt.d" = [
+    0x01146800 = [F(0), F(5)];
+]
+"fmov.s" = [
+    0x01149400 = [F(0), F(5)];
+]
+"fmov.d" = [
+    0x01149800 = [F(0), F(5)];
+]
+"frint.s" = [
+    0x011E4400 = [F(0), F(5)];
+]
+"frint.d" = [
+    0x011E4800 = [F(0), F(5)];
+]
+"fmadd.s" = [
+    0x08100000 = [F(0), F(5), F(10), F(15)];
+]
+"fmadd.d" = [
+    0x08200000 = [F(0), F(5), F(10), F(15)];
+]
+"fmsub.s" = [
+    0x08500000 = [F(0), F(5), F(10), F(15)];
+]
+"fmsub.d" = [
+    0x08600000 = [F(0), F(5), F(10), F(15)];
+]
+"fnmadd.s" = [
+    0x08900000 = [F(0), F(5), F(10), F(15)];
+]
+"fnmadd.d" = [
+    0x08A00000 = [F(0), F(5), F(10), F(15)];
+]
+"fnmsub.s" = [
+    0x08D00000 = [F(0), F(5), F(10), F(15)];
+]
+"fnmsub.d" = [
+    0x08E00000 = [F(0), F(5), F(10), F(15)];
+]
+"fcmp.caf.s" = [
+    0x0C100000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.caf.d" = [
+    0x0C200000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.saf.s" = [
+    0x0C108000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.saf.d" = [
+    0x0C208000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.clt.s" = [
+    0x0C110000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.clt.d" = [
+    0x0C210000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.slt.s" = [
+    0x0C118000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.slt.d" = [
+    0x0C218000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.ceq.s" = [
+    0x0C120000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.ceq.d" = [
+    0x0C220000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.seq.s" = [
+    0x0C128000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.seq.d" = [
+    0x0C228000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cle.s" = [
+    0x0C130000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cle.d" = [
+    0x0C230000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sle.s" = [
+    0x0C138000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sle.d" = [
+    0x0C238000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cun.s" = [
+    0x0C140000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cun.d" = [
+    0x0C240000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sun.s" = [
+    0x0C148000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sun.d" = [
+    0x0C248000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cult.s" = [
+    0x0C150000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cult.d" = [
+    0x0C250000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sult.s" = [
+    0x0C158000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sult.d" = [
+    0x0C258000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cueq.s" = [
+    0x0C160000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cueq.d" = [
+    0x0C260000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sueq.s" = [
+    0x0C168000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sueq.d" = [
+    0x0C268000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cule.s" = [
+    0x0C170000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cule.d" = [
+    0x0C270000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sule.s" = [
+    0x0C178000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sule.d" = [
+    0x0C278000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cne.s" = [
+    0x0C180000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cne.d" = [
+    0x0C280000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sne.s" = [
+    0x0C188000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sne.d" = [
+    0x0C288000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cor.s" = [
+    0x0C1A0000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cor.d" = [
+    0x0C2A0000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sor.s" = [
+    0x0C1A8000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sor.d" = [
+    0x0C2A8000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cune.s" = [
+    0x0C1C0000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.cune.d" = [
+    0x0C2C0000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sune.s" = [
+    0x0C1C8000 = [Fcc(0), F(5), F(10)];
+]
+"fcmp.sune.d" = [
+    0x0C2C8000 = [Fcc(0), F(5), F(10)];
+]
+"fsel" = [
+    0x0D000000 = [F(0), F(5), F(10), Fcc(15)];
+]
+"fcvt.s.d" = [
+    0x01191800 = [F(0), F(5)];
+]
+"fcvt.d.s" = [
+    0x01192400 = [F(0), F(5)];
+]
+"ffint.s.w" = [
+    0x011D1000 = [F(0), F(5)];
+]
+"ffint.s.l" = [
+    0x011D1800 = [F(0), F(5)];
+]
+"ffint.d.w" = [
+    0x011D2000 = [F(0), F(5)];
+]
+"ffint.d.l" = [
+    0x011D2800 = [F(0), F(5)];
+]
+"ftintrm.w.s" = [
+    0x011A0400 = [F(0), F(5)];
+]
+"ftintrm.w.d" = [
+    0x011A0800 = [F(0), F(5)];
+]
+"ftintrm.l.s" = [
+    0x011A2400 = [F(0), F(5)];
+]
+"ftintrm.l.d" = [
+    0x011A2800 = [F(0), F(5)];
+]
+"ftintrp.w.s" = [
+    0x011A4400 = [F(0), F(5)];
+]
+"ftintrp.w.d" = [
+    0x011A4800 = [F(0), F(5)];
+]
+"ftintrp.l.s" = [
+    0x011A6400 = [F(0), F(5)];
+]
+"ftintrp.l.d" = [
+    0x011A6800 = [F(0), F(5)];
+]
+"ftintrz.w.s" = [
+    0x011A8400 = [F(0), F(5)];
+]
+"ftintrz.w.d" = [
+    0x011A8800 = [F(0), F(5)];
+]
+"ftintrz.l.s" = [
+    0x011AA400 = [F(0), F(5)];
+]
+"ftintrz.l.d" = [
+    0x011AA800 = [F(0), F(5)];
+]
+"ftintrne.w.s" = [
+    0x011AC400 = [F(0), F(5)];
+]
+"ftintrne.w.d" = [
+    0x011AC800 = [F(0), F(5)];
+]
+"ftintrne.l.s" = [
+    0x011AE400 = [F(0), F(5)];
+]
+"ftintrne.l.d" = [
+    0x011AE800 = [F(0), F(5)];
+]
+"ftint.w.s" = [
+    0x011B0400 = [F(0), F(5)];
+]
+"ftint.w.d" = [
+    0x011B0800 = [F(0), F(5)];
+]
+"ftint.l.s" = [
+    0x011B2400 = [F(0), F(5)];
+]
+"ftint.l.d" = [
+    0x011B2800 = [F(0), F(5)];
+]
+"movgr2fr.w" = [
+    0x0114A400 = [F(0), R(5)];
+]
+"movgr2fr.d" = [
+    0x0114A800 = [F(0), R(5)];
+]
+"movgr2frh.w" = [
+    0x0114AC00 = [F(0), R(5)];
+]
+"movfr2gr.s" = [
+    0x0114B400 = [R(0), F(5)];
+]
+"movfr2gr.d" = [
+    0x0114B800 = [R(0), F(5)];
+]
+"movfrh2gr.s" = [
+    0x0114BC00 = [R(0), F(5)];
+]
+"movgr2fcsr" = [
+    0x0114C000 = [Fcsr(0), R(5)];
+]
+"movfcsr2gr" = [
+    0x0114C800 = [R(0), Fcsr(5)];
+]
+"movfr2cf" = [
+    0x0114D000 = [Fcc(0), F(5)];
+]
+"movcf2fr" = [
+    0x0114D400 = [F(0), Fcc(5)];
+]
+"movgr2cf" = [
+    0x0114D800 = [Fcc(0), R(5)];
+]
+"movcf2gr" = [
+    0x0114DC00 = [R(0), Fcc(5)];
+]
+"fld.s" = [
+    0x2B000000 = [F(0), R(5), Imm(SI12)];
+]
+"fst.s" = [
+    0x2B400000 = [F(0), R(5), Imm(SI12)];
+]
+"fld.d" = [
+    0x2B800000 = [F(0), R(5), Imm(SI12)];
+]
+"fst.d" = [
+    0x2BC00000 = [F(0), R(5), Imm(SI12)];
+]
+"fldx.s" = [
+    0x38300000 = [F(0), R(5), R(10)];
+]
+"fldx.d" = [
+    0x38340000 = [F(0), R(5), R(10)];
+]
+"fstx.s" = [
+    0x38380000 = [F(0), R(5), R(10)];
+]
+"fstx.d" = [
+    0x383C0000 = [F(0), R(5), R(10)];
+]
+// LSX 128-bit vector instructions
+"vld" = [
+    0x2C000000 = [V(0), R(5), Imm(SI12)];
+]
+"vst" = [
+    0x2C400000 = [V(0), R(5), Imm(SI12)];
+]
+"vldx" = [
+    0x38400000 = [V(0), R(5), R(10)];
+]
+"vstx" = [
+    0x38440000 = [V(0), R(5), R(10)];
+]
+"vseq.b" = [
+    0x70000000 = [V(0), V(5), V(10)];
+]
+"vseq.h" = [
+    0x70008000 = [V(0), V(5), V(10)];
+]
+"vseq.w" = [
+    0x70010000 = [V(0), V(5), V(10)];
+]
+"vseq.d" = [
+    0x70018000 = [V(0), V(5), V(10)];
+]
+"vsle.b" = [
+    0x70020000 = [V(0), V(5), V(10)];
+]
+"vsle.h" = [
+    0x70028000 = [V(0), V(5), V(10)];
+]
+"vsle.w" = [
+    0x70030000 = [V(0), V(5), V(10)];
+]
+"vsle.d" = [
+    0x70038000 = [V(0), V(5), V(10)];
+]
+"vslt.b" = [
+    0x70060000 = [V(0), V(5), V(10)];
+]
+"vslt.h" = [
+    0x70068000 = [V(0), V(5), V(10)];
+]
+"vslt.w" = [
+    0x70070000 = [V(0), V(5), V(10)];
+]
+"vslt.d" = [
+    0x70078000 = [V(0), V(5), V(10)];
+]
+"vadd.b" = [
+    0x700A0000 = [V(0), V(5), V(10)];
+]
+"vadd.h" = [
+    0x700A8000 = [V(0), V(5), V(10)];
+]
+"vadd.w" = [
+    0x700B0000 = [V(0), V(5), V(10)];
+]
+"vadd.d" = [
+    0x700B8000 = [V(0), V(5), V(10)];
+]
+"vsub.b" = [
+    0x700C0000 = [V(0), V(5), V(10)];
+]
+"vsub.h" = [
+    0x700C8000 = [V(0), V(5), V(10)];
+]
+"vsub.w" = [
+    0x700D0000 = [V(0), V(5), V(10)];
+]
+"vsub.d" = [
+    0x700D8000 = [V(0), V(5), V(10)];
+]
+"vsadd.b" = [
+    0x70460000 = [V(0), V(5), V(10)];
+]
+"vsadd.h" = [
+    0x70468000 = [V(0), V(5), V(10)];
+]
+"vsadd.w" = [
+    0x70470000 = [V(0), V(5), V(10)];
+]
+"vsadd.d" = [
+    0x70478000 = [V(0), V(5), V(10)];
+]
+"vssub.b" = [
+    0x70480000 = [V(0), V(5), V(10)];
+]
+"vssub.h" = [
+    0x70488000 = [V(0), V(5), V(10)];
+]
+"vssub.w" = [
+    0x70490000 = [V(0), V(5), V(10)];
+]
+"vssub.d" = [
+    0x70498000 = [V(0), V(5), V(10)];
+]
+"vmax.b" = [
+    0x70700000 = [V(0), V(5), V(10)];
+]
+"vmax.h" = [
+    0x70708000 = [V(0), V(5), V(10)];
+]
+"vmax.w" = [
+    0x70710000 = [V(0), V(5), V(10)];
+]
+"vmax.d" = [
+    0x70718000 = [V(0), V(5), V(10)];
+]
+"vmin.b" = [
+    0x70720000 = [V(0), V(5), V(10)];
+]
+"vmin.h" = [
+    0x70728000 = [V(0), V(5), V(10)];
+]
+"vmin.w" = [
+    0x70730000 = [V(0), V(5), V(10)];
+]
+"vmin.d" = [
+    0x70738000 = [V(0), V(5), V(10)];
+]
+"vmul.b" = [
+    0x70840000 = [V(0), V(5), V(10)];
+]
+"vmul.h" = [
+    0x70848000 = [V(0), V(5), V(10)];
+]
+"vmul.w" = [
+    0x70850000 = [V(0), V(5), V(10)];
+]
+"vmul.d" = [
+    0x70858000 = [V(0), V(5), V(10)];
+]
+"vmuh.b" = [
+    0x70860000 = [V(0), V(5), V(10)];
+]
+"vmuh.h" = [
+    0x70868000 = [V(0), V(5), V(10)];
+]
+"vmuh.w" = [
+    0x70870000 = [V(0), V(5), V(10)];
+]
+"vmuh.d" = [
+    0x70878000 = [V(0), V(5), V(10)];
+]
+"vmadd.b" = [
+    0x70A80000 = [V(0), V(5), V(10)];
+]
+"vmadd.h" = [
+    0x70A88000 = [V(0), V(5), V(10)];
+]
+"vmadd.w" = [
+    0x70A90000 = [V(0), V(5), V(10)];
+]
+"vmadd.d" = [
+    0x70A98000 = [V(0), V(5), V(10)];
+]
+"vmsub.b" = [
+    0x70AA0000 = [V(0), V(5), V(10)];
+]
+"vmsub.h" = [
+    0x70AA8000 = [V(0), V(5), V(10)];
+]
+"vmsub.w" = [
+    0x70AB0000 = [V(0), V(5), V(10)];
+]
+"vmsub.d" = [
+    0x70AB8000 = [V(0), V(5), V(10)];
+]
+"vdiv.b" = [
+    0x70E00000 = [V(0), V(5), V(10)];
+]
+"vdiv.h" = [
+    0x70E08000 = [V(0), V(5), V(10)];
+]
+"vdiv.w" = [
+    0x70E10000 = [V(0), V(5), V(10)];
+]
+"vdiv.d" = [
+    0x70E18000 = [V(0), V(5), V(10)];
+]
+"vmod.b" = [
+    0x70E20000 = [V(0), V(5), V(10)];
+]
+"vmod.h" = [
+    0x70E28000 = [V(0), V(5), V(10)];
+]
+"vmod.w" = [
+    0x70E30000 = [V(0), V(5), V(10)];
+]
+"vmod.d" = [
+    0x70E38000 = [V(0), V(5), V(10)];
+]
+"vsll.b" = [
+    0x70E80000 = [V(0), V(5), V(10)];
+]
+"vsll.h" = [
+    0x70E88000 = [V(0), V(5), V(10)];
+]
+"vsll.w" = [
+    0x70E90000 = [V(0), V(5), V(10)];
+]
+"vsll.d" = [
+    0x70E98000 = [V(0), V(5), V(10)];
+]
+"vsrl.b" = [
+    0x70EA0000 = [V(0), V(5), V(10)];
+]
+"vsrl.h" = [
+    0x70EA8000 = [V(0), V(5), V(10)];
+]
+"vsrl.w" = [
+    0x70EB0000 = [V(0), V(5), V(10)];
+]
+"vsrl.d" = [
+    0x70EB8000 = [V(0), V(5), V(10)];
+]
+"vsra.b" = [
+    0x70EC0000 = [V(0), V(5), V(10)];
+]
+"vsra.h" = [
+    0x70EC8000 = [V(0), V(5), V(10)];
+]
+"vsra.w" = [
+    0x70ED0000 = [V(0), V(5), V(10)];
+]
+"vsra.d" = [
+    0x70ED8000 = [V(0), V(5), V(10)];
+]
+"vrotr.b" = [
+    0x70EE0000 = [V(0), V(5), V(10)];
+]
+"vrotr.h" = [
+    0x70EE8000 = [V(0), V(5), V(10)];
+]
+"vrotr.w" = [
+    0x70EF0000 = [V(0), V(5), V(10)];
+]
+"vrotr.d" = [
+    0x70EF8000 = [V(0), V(5), V(10)];
+]
+"vsle.bu" = [
+    0x70040000 = [V(0), V(5), V(10)];
+]
+"vsle.hu" = [
+    0x70048000 = [V(0), V(5), V(10)];
+]
+"vsle.wu" = [
+    0x70050000 = [V(0), V(5), V(10)];
+]
+"vsle.du" = [
+    0x70058000 = [V(0), V(5), V(10)];
+]
+"vslt.bu" = [
+    0x70080000 = [V(0), V(5), V(10)];
+]
+"vslt.hu" = [
+    0x70088000 = [V(0), V(5), V(10)];
+]
+"vslt.wu" = [
+    0x70090000 = [V(0), V(5), V(10)];
+]
+"vslt.du" = [
+    0x70098000 = [V(0), V(5), V(10)];
+]
+"vsadd.bu" = [
+    0x704A0000 = [V(0), V(5), V(10)];
+]
+"vsadd.hu" = [
+    0x704A8000 = [V(0), V(5), V(10)];
+]
+"vsadd.wu" = [
+    0x704B0000 = [V(0), V(5), V(10)];
+]
+"vsadd.du" = [
+    0x704B8000 = [V(0), V(5), V(10)];
+]
+"vssub.bu" = [
+    0x704C0000 = [V(0), V(5), V(10)];
+]
+"vssub.hu" = [
+    0x704C8000 = [V(0), V(5), V(10)];
+]
+"vssub.wu" = [
+    0x704D0000 = [V(0), V(5), V(10)];
+]
+"vssub.du" = [
+    0x704D8000 = [V(0), V(5), V(10)];
+]
+"vmax.bu" = [
+    0x70740000 = [V(0), V(5), V(10)];
+]
+"vmax.hu" = [
+    0x70748000 = [V(0), V(5), V(10)];
+]
+"vmax.wu" = [
+    0x70750000 = [V(0), V(5), V(10)];
+]
+"vmax.du" = [
+    0x70758000 = [V(0), V(5), V(10)];
+]
+"vmin.bu" = [
+    0x70760000 = [V(0), V(5), V(10)];
+]
+"vmin.hu" = [
+    0x70768000 = [V(0), V(5), V(10)];
+]
+"vmin.wu" = [
+    0x70770000 = [V(0), V(5), V(10)];
+]
+"vmin.du" = [
+    0x70778000 = [V(0), V(5), V(10)];
+]
+"vmuh.bu" = [
+    0x70880000 = [V(0), V(5), V(10)];
+]
+"vmuh.hu" = [
+    0x70888000 = [V(0), V(5), V(10)];
+]
+"vmuh.wu" = [
+    0x70890000 = [V(0), V(5), V(10)];
+]
+"vmuh.du" = [
+    0x70898000 = [V(0), V(5), V(10)];
+]
+"vdiv.bu" = [
+    0x70E40000 = [V(0), V(5), V(10)];
+]
+"vdiv.hu" = [
+    0x70E48000 = [V(0), V(5), V(10)];
+]
+"vdiv.wu" = [
+    0x70E50000 = [V(0), V(5), V(10)];
+]
+"vdiv.du" = [
+    0x70E58000 = [V(0), V(5), V(10)];
+]
+"vmod.bu" = [
+    0x70E60000 = [V(0), V(5), V(10)];
+]
+"vmod.hu" = [
+    0x70E68000 = [V(0), V(5), V(10)];
+]
+"vmod.wu" = [
+    0x70E70000 = [V(0), V(5), V(10)];
+]
+"vmod.du" = [
+    0x70E78000 = [V(0), V(5), V(10)];
+]
+"vand.v" = [
+    0x71260000 = [V(0), V(5), V(10)];
+]
+"vor.v" = [
+    0x71268000 = [V(0), V(5), V(10)];
+]
+"vxor.v" = [
+    0x71270000 = [V(0), V(5), V(10)];
+]
+"vnor.v" = [
+    0x71278000 = [V(0), V(5), V(10)];
+]
+"vandn.v" = [
+    0x71280000 = [V(0), V(5), V(10)];
+]
+"vorn.v" = [
+    0x71288000 = [V(0), V(5), V(10)];
+]
+"vfadd.s" = [
+    0x71308000 = [V(0), V(5), V(10)];
+]
+"vfadd.d" = [
+    0x71310000 = [V(0), V(5), V(10)];
+]
+"vfsub.s" = [
+    0x71328000 = [V(0), V(5), V(10)];
+]
+"vfsub.d" = [
+    0x71330000 = [V(0), V(5), V(10)];
+]
+"vfmul.s" = [
+    0x71388000 = [V(0), V(5), V(10)];
+]
+"vfmul.d" = [
+    0x71390000 = [V(0), V(5), V(10)];
+]
+"vfdiv.s" = [
+    0x713A8000 = [V(0), V(5), V(10)];
+]
+"vfdiv.d" = [
+    0x713B0000 = [V(0), V(5), V(10)];
+]
+"vfmax.s" = [
+    0x713C8000 = [V(0), V(5), V(10)];
+]
+"vfmax.d" = [
+    0x713D0000 = [V(0), V(5), V(10)];
+]
+"vfmin.s" = [
+    0x713E8000 = [V(0), V(5), V(10)];
+]
+"vfmin.d" = [
+    0x713F0000 = [V(0), V(5), V(10)];
+]
+"vaddi.bu" = [
+    0x728A0000 = [V(0), V(5), Imm(UI5)];
+]
+"vaddi.hu" = [
+    0x728A8000 = [V(0), V(5), Imm(UI5)];
+]
+"vaddi.wu" = [
+    0x728B0000 = [V(0), V(5), Imm(UI5)];
+]
+"vaddi.du" = [
+    0x728B8000 = [V(0), V(5), Imm(UI5)];
+]
+"vsubi.bu" = [
+    0x728C0000 = [V(0), V(5), Imm(UI5)];
+]
+"vsubi.hu" = [
+    0x728C8000 = [V(0), V(5), Imm(UI5)];
+]
+"vsubi.wu" = [
+    0x728D0000 = [V(0), V(5), Imm(UI5)];
+]
+"vsubi.du" = [
+    0x728D8000 = [V(0), V(5), Imm(UI5)];
+]
+"vslli.b" = [
+    0x732C2000 = [V(0), V(5), Imm(UI3)];
+]
+"vslli.h" = [
+    0x732C4000 = [V(0), V(5), Imm(UI4)];
+]
+"vslli.w" = [
+    0x732C8000 = [V(0), V(5), Imm(UI5)];
+]
+"vslli.d" = [
+    0x732D0000 = [V(0), V(5), Imm(UI6)];
+]
+"vsrli.b" = [
+    0x73302000 = [V(0), V(5), Imm(UI3)];
+]
+"vsrli.h" = [
+    0x73304000 = [V(0), V(5), Imm(UI4)];
+]
+"vsrli.w" = [
+    0x73308000 = [V(0), V(5), Imm(UI5)];
+]
+"vsrli.d" = [
+    0x73310000 = [V(0), V(5), Imm(UI6)];
+]
+"vsrai.b" = [
+    0x73342000 = [V(0), V(5), Imm(UI3)];
+]
+"vsrai.h" = [
+    0x73344000 = [V(0), V(5), Imm(UI4)];
+]
+"vsrai.w" = [
+    0x73348000 = [V(0), V(5), Imm(UI5)];
+]
+"vsrai.d" = [
+    0x73350000 = [V(0), V(5), Imm(UI6)];
+]
+"vandi.b" = [
+    0x73D00000 = [V(0), V(5), Imm(UI8)];
+]
+"vori.b" = [
+    0x73D40000 = [V(0), V(5), Imm(UI8)];
+]
+"vxori.b" = [
+    0x73D80000 = [V(0), V(5), Imm(UI8)];
+]
+"vnori.b" = [
+    0x73DC0000 = [V(0), V(5), Imm(UI8)];
+]
+"vfsqrt.s" = [
+    0x729CE400 = [V(0), V(5)];
+]
+"vfsqrt.d" = [
+    0x729CE800 = [V(0), V(5)];
+]
+"vreplgr2vr.b" = [
+    0x729F0000 = [V(0), R(5)];
+]
+"vreplgr2vr.h" = [
+    0x729F0400 = [V(0), R(5)];
+]
+"vreplgr2vr.w" = [
+    0x729F0800 = [V(0), R(5)];
+]
+"vreplgr2vr.d" = [
+    0x729F0C00 = [V(0), R(5)];
+]
+"vinsgr2vr.b" = [
+    0x72EB8000 = [V(0), R(5), Imm(UI4)];
+]
+"vinsgr2vr.h" = [
+    0x72EBC000 = [V(0), R(5), Imm(UI3)];
+]
+"vinsgr2vr.w" = [
+    0x72EBE000 = [V(0), R(5), Imm(UI2)];
+]
+"vinsgr2vr.d" = [
+    0x72EBF000 = [V(0), R(5), Imm(UI1)];
+]
+"vpickve2gr.b" = [
+    0x72EF8000 = [R(0), V(5), Imm(UI4)];
+]
+"vpickve2gr.bu" = [
+    0x72F38000 = [R(0), V(5), Imm(UI4)];
+]
+"vpickve2gr.h" = [
+    0x72EFC000 = [R(0), V(5), Imm(UI3)];
+]
+"vpickve2gr.hu" = [
+    0x72F3C000 = [R(0), V(5), Imm(UI3)];
+]
+"vpickve2gr.w" = [
+    0x72EFE000 = [R(0), V(5), Imm(UI2)];
+]
+"vpickve2gr.wu" = [
+    0x72F3E000 = [R(0), V(5), Imm(UI2)];
+]
+"vpickve2gr.d" = [
+    0x72EFF000 = [R(0), V(5), Imm(UI1)];
+]
+"vpickve2gr.du" = [
+    0x72F3F000 = [R(0), V(5), Imm(UI1)];
+]
+"vreplvei.b" = [
+    0x72F78000 = [V(0), V(5), Imm(UI4)];
+]
+"vreplvei.h" = [
+    0x72F7C000 = [V(0), V(5), Imm(UI3)];
+]
+"vreplvei.w" = [
+    0x72F7E000 = [V(0), V(5), Imm(UI2)];
+]
+"vreplvei.d" = [
+    0x72F7F000 = [V(0), V(5), Imm(UI1)];
+]
+"vpermi.w" = [
+    0x73E40000 = [V(0), V(5), Imm(UI8)];
+]
+"vfmadd.s" = [
+    0x09100000 = [V(0), V(5), V(10), V(15)];
+]
+"vfmadd.d" = [
+    0x09200000 = [V(0), V(5), V(10), V(15)];
+]
+"vfmsub.s" = [
+    0x09500000 = [V(0), V(5), V(10), V(15)];
+]
+"vfmsub.d" = [
+    0x09600000 = [V(0), V(5), V(10), V(15)];
+]
+"vfnmadd.s" = [
+    0x09900000 = [V(0), V(5), V(10), V(15)];
+]
+"vfnmadd.d" = [
+    0x09A00000 = [V(0), V(5), V(10), V(15)];
+]
+"vfnmsub.s" = [
+    0x09D00000 = [V(0), V(5), V(10), V(15)];
+]
+"vfnmsub.d" = [
+    0x09E00000 = [V(0), V(5), V(10), V(15)];
+]
+"vfcmp.caf.s" = [
+    0x0C500000 = [V(0), V(5), V(10)];
+]
+"vfcmp.caf.d" = [
+    0x0C600000 = [V(0), V(5), V(10)];
+]
+"vfcmp.saf.s" = [
+    0x0C508000 = [V(0), V(5), V(10)];
+]
+"vfcmp.saf.d" = [
+    0x0C608000 = [V(0), V(5), V(10)];
+]
+"vfcmp.clt.s" = [
+    0x0C510000 = [V(0), V(5), V(10)];
+]
+"vfcmp.clt.d" = [
+    0x0C610000 = [V(0), V(5), V(10)];
+]
+"vfcmp.slt.s" = [
+    0x0C518000 = [V(0), V(5), V(10)];
+]
+"vfcmp.slt.d" = [
+    0x0C618000 = [V(0), V(5), V(10)];
+]
+"vfcmp.ceq.s" = [
+    0x0C520000 = [V(0), V(5), V(10)];
+]
+"vfcmp.ceq.d" = [
+    0x0C620000 = [V(0), V(5), V(10)];
+]
+"vfcmp.seq.s" = [
+    0x0C528000 = [V(0), V(5), V(10)];
+]
+"vfcmp.seq.d" = [
+    0x0C628000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cle.s" = [
+    0x0C530000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cle.d" = [
+    0x0C630000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sle.s" = [
+    0x0C538000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sle.d" = [
+    0x0C638000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cun.s" = [
+    0x0C540000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cun.d" = [
+    0x0C640000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sun.s" = [
+    0x0C548000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sun.d" = [
+    0x0C648000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cult.s" = [
+    0x0C550000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cult.d" = [
+    0x0C650000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sult.s" = [
+    0x0C558000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sult.d" = [
+    0x0C658000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cueq.s" = [
+    0x0C560000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cueq.d" = [
+    0x0C660000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sueq.s" = [
+    0x0C568000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sueq.d" = [
+    0x0C668000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cule.s" = [
+    0x0C570000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cule.d" = [
+    0x0C670000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sule.s" = [
+    0x0C578000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sule.d" = [
+    0x0C678000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cne.s" = [
+    0x0C580000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cne.d" = [
+    0x0C680000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sne.s" = [
+    0x0C588000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sne.d" = [
+    0x0C688000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cor.s" = [
+    0x0C5A0000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cor.d" = [
+    0x0C6A0000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sor.s" = [
+    0x0C5A8000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sor.d" = [
+    0x0C6A8000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cune.s" = [
+    0x0C5C0000 = [V(0), V(5), V(10)];
+]
+"vfcmp.cune.d" = [
+    0x0C6C0000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sune.s" = [
+    0x0C5C8000 = [V(0), V(5), V(10)];
+]
+"vfcmp.sune.d" = [
+    0x0C6C8000 = [V(0), V(5), V(10)];
+]
+"vbitsel.v" = [
+    0x0D100000 = [V(0), V(5), V(10), V(15)];
+]
+"vshuf.b" = [
+    0x0D500000 = [V(0), V(5), V(10), V(15)];
+]
+// LASX 256-bit vector instructions
+"xvld" = [
+    0x2C800000 = [X(0), R(5), Imm(SI12)];
+]
+"xvst" = [
+    0x2CC00000 = [X(0), R(5), Imm(SI12)];
+]
+"xvldx" = [
+    0x38480000 = [X(0), R(5), R(10)];
+]
+"xvstx" = [
+    0x384C0000 = [X(0), R(5), R(10)];
+]
+"xvseq.b" = [
+    0x74000000 = [X(0), X(5), X(10)];
+]
+"xvseq.h" = [
+    0x74008000 = [X(0), X(5), X(10)];
+]
+"xvseq.w" = [
+    0x74010000 = [X(0), X(5), X(10)];
+]
+"xvseq.d" = [
+    0x74018000 = [X(0), X(5), X(10)];
+]
+"xvsle.b" = [
+    0x74020000 = [X(0), X(5), X(10)];
+]
+"xvsle.h" = [
+    0x74028000 = [X(0), X(5), X(10)];
+]
+"xvsle.w" = [
+    0x74030000 = [X(0), X(5), X(10)];
+]
+"xvsle.d" = [
+    0x74038000 = [X(0), X(5), X(10)];
+]
+"xvslt.b" = [
+    0x74060000 = [X(0), X(5), X(10)];
+]
+"xvslt.h" = [
+    0x74068000 = [X(0), X(5), X(10)];
+]
+"xvslt.w" = [
+    0x74070000 = [X(0), X(5), X(10)];
+]
+"xvslt.d" = [
+    0x74078000 = [X(0), X(5), X(10)];
+]
+"xvadd.b" = [
+    0x740A0000 = [X(0), X(5), X(10)];
+]
+"xvadd.h" = [
+    0x740A8000 = [X(0), X(5), X(10)];
+]
+"xvadd.w" = [
+    0x740B0000 = [X(0), X(5), X(10)];
+]
+"xvadd.d" = [
+    0x740B8000 = [X(0), X(5), X(10)];
+]
+"xvsub.b" = [
+    0x740C0000 = [X(0), X(5), X(10)];
+]
+"xvsub.h" = [
+    0x740C8000 = [X(0), X(5), X(10)];
+]
+"xvsub.w" = [
+    0x740D0000 = [X(0), X(5), X(10)];
+]
+"xvsub.d" = [
+    0x740D8000 = [X(0), X(5), X(10)];
+]
+"xvsadd.b" = [
+    0x74460000 = [X(0), X(5), X(10)];
+]
+"xvsadd.h" = [
+    0x74468000 = [X(0), X(5), X(10)];
+]
+"xvsadd.w" = [
+    0x74470000 = [X(0), X(5), X(10)];
+]
+"xvsadd.d" = [
+    0x74478000 = [X(0), X(5), X(10)];
+]
+"xvssub.b" = [
+    0x74480000 = [X(0), X(5), X(10)];
+]
+"xvssub.h" = [
+    0x74488000 = [X(0), X(5), X(10)];
+]
+"xvssub.w" = [
+    0x74490000 = [X(0), X(5), X(10)];
+]
+"xvssub.d" = [
+    0x74498000 = [X(0), X(5), X(10)];
+]
+"xvmax.b" = [
+    0x74700000 = [X(0), X(5), X(10)];
+]
+"xvmax.h" = [
+    0x74708000 = [X(0), X(5), X(10)];
+]
+"xvmax.w" = [
+    0x74710000 = [X(0), X(5), X(10)];
+]
+"xvmax.d" = [
+    0x74718000 = [X(0), X(5), X(10)];
+]
+"xvmin.b" = [
+    0x74720000 = [X(0), X(5), X(10)];
+]
+"xvmin.h" = [
+    0x74728000 = [X(0), X(5), X(10)];
+]
+"xvmin.w" = [
+    0x74730000 = [X(0), X(5), X(10)];
+]
+"xvmin.d" = [
+    0x74738000 = [X(0), X(5), X(10)];
+]
+"xvmul.b" = [
+    0x74840000 = [X(0), X(5), X(10)];
+]
+"xvmul.h" = [
+    0x74848000 = [X(0), X(5), X(10)];
+]
+"xvmul.w" = [
+    0x74850000 = [X(0), X(5), X(10)];
+]
+"xvmul.d" = [
+    0x74858000 = [X(0), X(5), X(10)];
+]
+"xvmuh.b" = [
+    0x74860000 = [X(0), X(5), X(10)];
+]
+"xvmuh.h" = [
+    0x74868000 = [X(0), X(5), X(10)];
+]
+"xvmuh.w" = [
+    0x74870000 = [X(0), X(5), X(10)];
+]
+"xvmuh.d" = [
+    0x74878000 = [X(0), X(5), X(10)];
+]
+"xvmadd.b" = [
+    0x74A80000 = [X(0), X(5), X(10)];
+]
+"xvmadd.h" = [
+    0x74A88000 = [X(0), X(5), X(10)];
+]
+"xvmadd.w" = [
+    0x74A90000 = [X(0), X(5), X(10)];
+]
+"xvmadd.d" = [
+    0x74A98000 = [X(0), X(5), X(10)];
+]
+"xvmsub.b" = [
+    0x74AA0000 = [X(0), X(5), X(10)];
+]
+"xvmsub.h" = [
+    0x74AA8000 = [X(0), X(5), X(10)];
+]
+"xvmsub.w" = [
+    0x74AB0000 = [X(0), X(5), X(10)];
+]
+"xvmsub.d" = [
+    0x74AB8000 = [X(0), X(5), X(10)];
+]
+"xvdiv.b" = [
+    0x74E00000 = [X(0), X(5), X(10)];
+]
+"xvdiv.h" = [
+    0x74E08000 = [X(0), X(5), X(10)];
+]
+"xvdiv.w" = [
+    0x74E10000 = [X(0), X(5), X(10)];
+]
+"xvdiv.d" = [
+    0x74E18000 = [X(0), X(5), X(10)];
+]
+"xvmod.b" = [
+    0x74E20000 = [X(0), X(5), X(10)];
+]
+"xvmod.h" = [
+    0x74E28000 = [X(0), X(5), X(10)];
+]
+"xvmod.w" = [
+    0x74E30000 = [X(0), X(5), X(10)];
+]
+"xvmod.d" = [
+    0x74E38000 = [X(0), X(5), X(10)];
+]
+"xvsll.b" = [
+    0x74E80000 = [X(0), X(5), X(10)];
+]
+"xvsll.h" = [
+    0x74E88000 = [X(0), X(5), X(10)];
+]
+"xvsll.w" = [
+    0x74E90000 = [X(0), X(5), X(10)];
+]
+"xvsll.d" = [
+    0x74E98000 = [X(0), X(5), X(10)];
+]
+"xvsrl.b" = [
+    0x74EA0000 = [X(0), X(5), X(10)];
+]
+"xvsrl.h" = [
+    0x74EA8000 = [X(0), X(5), X(10)];
+]
+"xvsrl.w" = [
+    0x74EB0000 = [X(0), X(5), X(10)];
+]
+"xvsrl.d" = [
+    0x74EB8000 = [X(0), X(5), X(10)];
+]
+"xvsra.b" = [
+    0x74EC0000 = [X(0), X(5), X(10)];
+]
+"xvsra.h" = [
+    0x74EC8000 = [X(0), X(5), X(10)];
+]
+"xvsra.w" = [
+    0x74ED0000 = [X(0), X(5), X(10)];
+]
+"xvsra.d" = [
+    0x74ED8000 = [X(0), X(5), X(10)];
+]
+"xvrotr.b" = [
+    0x74EE0000 = [X(0), X(5), X(10)];
+]
+"xvrotr.h" = [
+    0x74EE8000 = [X(0), X(5), X(10)];
+]
+"xvrotr.w" = [
+    0x74EF0000 = [X(0), X(5), X(10)];
+]
+"xvrotr.d" = [
+    0x74EF8000 = [X(0), X(5), X(10)];
+]
+"xvsle.bu" = [
+    0x74040000 = [X(0), X(5), X(10)];
+]
+"xvsle.hu" = [
+    0x74048000 = [X(0), X(5), X(10)];
+]
+"xvsle.wu" = [
+    0x74050000 = [X(0), X(5), X(10)];
+]
+"xvsle.du" = [
+    0x74058000 = [X(0), X(5), X(10)];
+]
+"xvslt.bu" = [
+    0x74080000 = [X(0), X(5), X(10)];
+]
+"xvslt.hu" = [
+    0x74088000 = [X(0), X(5), X(10)];
+]
+"xvslt.wu" = [
+    0x74090000 = [X(0), X(5), X(10)];
+]
+"xvslt.du" = [
+    0x74098000 = [X(0), X(5), X(10)];
+]
+"xvsadd.bu" = [
+    0x744A0000 = [X(0), X(5), X(10)];
+]
+"xvsadd.hu" = [
+    0x744A8000 = [X(0), X(5), X(10)];
+]
+"xvsadd.wu" = [
+    0x744B0000 = [X(0), X(5), X(10)];
+]
+"xvsadd.du" = [
+    0x744B8000 = [X(0), X(5), X(10)];
+]
+"xvssub.bu" = [
+    0x744C0000 = [X(0), X(5), X(10)];
+]
+"xvssub.hu" = [
+    0x744C8000 = [X(0), X(5), X(10)];
+]
+"xvssub.wu" = [
+    0x744D0000 = [X(0), X(5), X(10)];
+]
+"xvssub.du" = [
+    0x744D8000 = [X(0), X(5), X(10)];
+]
+"xvmax.bu" = [
+    0x74740000 = [X(0), X(5), X(10)];
+]
+"xvmax.hu" = [
+    0x74748000 = [X(0), X(5), X(10)];
+]
+"xvmax.wu" = [
+    0x74750000 = [X(0), X(5), X(10)];
+]
+"xvmax.du" = [
+    0x74758000 = [X(0), X(5), X(10)];
+]
+"xvmin.bu" = [
+    0x74760000 = [X(0), X(5), X(10)];
+]
+"xvmin.hu" = [
+    0x74768000 = [X(0), X(5), X(10)];
+]
+"xvmin.wu" = [
+    0x74770000 = [X(0), X(5), X(10)];
+]
+"xvmin.du" = [
+    0x74778000 = [X(0), X(5), X(10)];
+]
+"xvmuh.bu" = [
+    0x74880000 = [X(0), X(5), X(10)];
+]
+"xvmuh.hu" = [
+    0x74888000 = [X(0), X(5), X(10)];
+]
+"xvmuh.wu" = [
+    0x74890000 = [X(0), X(5), X(10)];
+]
+"xvmuh.du" = [
+    0x74898000 = [X(0), X(5), X(10)];
+]
+"xvdiv.bu" = [
+    0x74E40000 = [X(0), X(5), X(10)];
+]
+"xvdiv.hu" = [
+    0x74E48000 = [X(0), X(5), X(10)];
+]
+"xvdiv.wu" = [
+    0x74E50000 = [X(0), X(5), X(10)];
+]
+"xvdiv.du" = [
+    0x74E58000 = [X(0), X(5), X(10)];
+]
+"xvmod.bu" = [
+    0x74E60000 = [X(0), X(5), X(10)];
+]
+"xvmod.hu" = [
+    0x74E68000 = [X(0), X(5), X(10)];
+]
+"xvmod.wu" = [
+    0x74E70000 = [X(0), X(5), X(10)];
+]
+"xvmod.du" = [
+    0x74E78000 = [X(0), X(5), X(10)];
+]
+"xvand.v" = [
+    0x75260000 = [X(0), X(5), X(10)];
+]
+"xvor.v" = [
+    0x75268000 = [X(0), X(5), X(10)];
+]
+"xvxor.v" = [
+    0x75270000 = [X(0), X(5), X(10)];
+]
+"xvnor.v" = [
+    0x75278000 = [X(0), X(5), X(10)];
+]
+"xvandn.v" = [
+    0x75280000 = [X(0), X(5), X(10)];
+]
+"xvorn.v" = [
+    0x75288000 = [X(0), X(5), X(10)];
+]
+"xvfadd.s" = [
+    0x75308000 = [X(0), X(5), X(10)];
+]
+"xvfadd.d" = [
+    0x75310000 = [X(0), X(5), X(10)];
+]
+"xvfsub.s" = [
+    0x75328000 = [X(0), X(5), X(10)];
+]
+"xvfsub.d" = [
+    0x75330000 = [X(0), X(5), X(10)];
+]
+"xvfmul.s" = [
+    0x75388000 = [X(0), X(5), X(10)];
+]
+"xvfmul.d" = [
+    0x75390000 = [X(0), X(5), X(10)];
+]
+"xvfdiv.s" = [
+    0x753A8000 = [X(0), X(5), X(10)];
+]
+"xvfdiv.d" = [
+    0x753B0000 = [X(0), X(5), X(10)];
+]
+"xvfmax.s" = [
+    0x753C8000 = [X(0), X(5), X(10)];
+]
+"xvfmax.d" = [
+    0x753D0000 = [X(0), X(5), X(10)];
+]
+"xvfmin.s" = [
+    0x753E8000 = [X(0), X(5), X(10)];
+]
+"xvfmin.d" = [
+    0x753F0000 = [X(0), X(5), X(10)];
+]
+"xvaddi.bu" = [
+    0x768A0000 = [X(0), X(5), Imm(UI5)];
+]
+"xvaddi.hu" = [
+    0x768A8000 = [X(0), X(5), Imm(UI5)];
+]
+"xvaddi.wu" = [
+    0x768B0000 = [X(0), X(5), Imm(UI5)];
+]
+"xvaddi.du" = [
+    0x768B8000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsubi.bu" = [
+    0x768C0000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsubi.hu" = [
+    0x768C8000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsubi.wu" = [
+    0x768D0000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsubi.du" = [
+    0x768D8000 = [X(0), X(5), Imm(UI5)];
+]
+"xvslli.b" = [
+    0x772C2000 = [X(0), X(5), Imm(UI3)];
+]
+"xvslli.h" = [
+    0x772C4000 = [X(0), X(5), Imm(UI4)];
+]
+"xvslli.w" = [
+    0x772C8000 = [X(0), X(5), Imm(UI5)];
+]
+"xvslli.d" = [
+    0x772D0000 = [X(0), X(5), Imm(UI6)];
+]
+"xvsrli.b" = [
+    0x77302000 = [X(0), X(5), Imm(UI3)];
+]
+"xvsrli.h" = [
+    0x77304000 = [X(0), X(5), Imm(UI4)];
+]
+"xvsrli.w" = [
+    0x77308000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsrli.d" = [
+    0x77310000 = [X(0), X(5), Imm(UI6)];
+]
+"xvsrai.b" = [
+    0x77342000 = [X(0), X(5), Imm(UI3)];
+]
+"xvsrai.h" = [
+    0x77344000 = [X(0), X(5), Imm(UI4)];
+]
+"xvsrai.w" = [
+    0x77348000 = [X(0), X(5), Imm(UI5)];
+]
+"xvsrai.d" = [
+    0x77350000 = [X(0), X(5), Imm(UI6)];
+]
+"xvandi.b" = [
+    0x77D00000 = [X(0), X(5), Imm(UI8)];
+]
+"xvori.b" = [
+    0x77D40000 = [X(0), X(5), Imm(UI8)];
+]
+"xvxori.b" = [
+    0x77D80000 = [X(0), X(5), Imm(UI8)];
+]
+"xvnori.b" = [
+    0x77DC0000 = [X(0), X(5), Imm(UI8)];
+]
+"xvfsqrt.s" = [
+    0x769CE400 = [X(0), X(5)];
+]
+"xvfsqrt.d" = [
+    0x769CE800 = [X(0), X(5)];
+]
+"xvreplgr2vr.b" = [
+    0x769F0000 = [X(0), R(5)];
+]
+"xvreplgr2vr.h" = [
+    0x769F0400 = [X(0), R(5)];
+]
+"xvreplgr2vr.w" = [
+    0x769F0800 = [X(0), R(5)];
+]
+"xvreplgr2vr.d" = [
+    0x769F0C00 = [X(0), R(5)];
+]
+"xvinsgr2vr.w" = [
+    0x76EBC000 = [X(0), R(5), Imm(UI3)];
+]
+"xvinsgr2vr.d" = [
+    0x76EBE000 = [X(0), R(5), Imm(UI2)];
+]
+"xvpickve2gr.w" = [
+    0x76EFC000 = [R(0), X(5), Imm(UI3)];
+]
+"xvpickve2gr.wu" = [
+    0x76F3C000 = [R(0), X(5), Imm(UI3)];
+]
+"xvpickve2gr.d" = [
+    0x76EFE000 = [R(0), X(5), Imm(UI2)];
+]
+"xvpickve2gr.du" = [
+    0x76F3E000 = [R(0), X(5), Imm(UI2)];
+]
+"xvpermi.w" = [
+    0x77E40000 = [X(0), X(5), Imm(UI8)];
+]
+"xvpermi.d" = [
+    0x77E80000 = [X(0), X(5), Imm(UI8)];
+]
+"xvpermi.q" = [
+    0x77EC0000 = [X(0), X(5), Imm(UI8)];
+]
+"xvfmadd.s" = [
+    0x0A100000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfmadd.d" = [
+    0x0A200000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfmsub.s" = [
+    0x0A500000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfmsub.d" = [
+    0x0A600000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfnmadd.s" = [
+    0x0A900000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfnmadd.d" = [
+    0x0AA00000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfnmsub.s" = [
+    0x0AD00000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfnmsub.d" = [
+    0x0AE00000 = [X(0), X(5), X(10), X(15)];
+]
+"xvfcmp.caf.s" = [
+    0x0C900000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.caf.d" = [
+    0x0CA00000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.saf.s" = [
+    0x0C908000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.saf.d" = [
+    0x0CA08000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.clt.s" = [
+    0x0C910000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.clt.d" = [
+    0x0CA10000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.slt.s" = [
+    0x0C918000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.slt.d" = [
+    0x0CA18000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.ceq.s" = [
+    0x0C920000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.ceq.d" = [
+    0x0CA20000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.seq.s" = [
+    0x0C928000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.seq.d" = [
+    0x0CA28000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cle.s" = [
+    0x0C930000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cle.d" = [
+    0x0CA30000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sle.s" = [
+    0x0C938000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sle.d" = [
+    0x0CA38000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cun.s" = [
+    0x0C940000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cun.d" = [
+    0x0CA40000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sun.s" = [
+    0x0C948000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sun.d" = [
+    0x0CA48000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cult.s" = [
+    0x0C950000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cult.d" = [
+    0x0CA50000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sult.s" = [
+    0x0C958000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sult.d" = [
+    0x0CA58000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cueq.s" = [
+    0x0C960000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cueq.d" = [
+    0x0CA60000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sueq.s" = [
+    0x0C968000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sueq.d" = [
+    0x0CA68000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cule.s" = [
+    0x0C970000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cule.d" = [
+    0x0CA70000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sule.s" = [
+    0x0C978000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sule.d" = [
+    0x0CA78000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cne.s" = [
+    0x0C980000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cne.d" = [
+    0x0CA80000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sne.s" = [
+    0x0C988000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sne.d" = [
+    0x0CA88000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cor.s" = [
+    0x0C9A0000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cor.d" = [
+    0x0CAA0000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sor.s" = [
+    0x0C9A8000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sor.d" = [
+    0x0CAA8000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cune.s" = [
+    0x0C9C0000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.cune.d" = [
+    0x0CAC0000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sune.s" = [
+    0x0C9C8000 = [X(0), X(5), X(10)];
+]
+"xvfcmp.sune.d" = [
+    0x0CAC8000 = [X(0), X(5), X(10)];
+]
+"xvbitsel.v" = [
+    0x0D200000 = [X(0), X(5), X(10), X(15)];
+]
+"xvshuf.b" = [
+    0x0D600000 = [X(0), X(5), X(10), X(15)];
+]
+// pseudo instructions
+"nop" = [
+    0x03400000 = [];
+]
+"move" = [
+    0x00150000 = [R(0), R(5)];
+]
+"not" = [
+    0x00140000 = [R(0), R(5)];
+]
+"ret" = [
+    0x4C000020 = [];
+]
+"jr" = [
+    0x4C000000 = [R(5)];
+]
+"bgt" = [
+    0x60000000 = [R(0), R(5), Target(OFFS16, B16)];
+]
+"ble" = [
+    0x64000000 = [R(0), R(5), Target(OFFS16, B16)];
+]
+"bgtu" = [
+    0x68000000 = [R(0), R(5), Target(OFFS16, B16)];
+]
+"bleu" = [
+    0x6C000000 = [R(0), R(5), Target(OFFS16, B16)];
+]
+"li.w" = [
+    0x00000000 = [R(0), LiW];
+]
+"li.d" = [
+    0x00000000 = [R(0), LiD];
+]
+"la.pcrel" = [
+    0x02C00000_1C000000 = [Rs(&[0, 32, 37]), Target(PAIR32, PCADDU12I)];
+]
+"call36" = [
+    0x4C000021_1E000001 = [Target(PAIR38, PCADDU18I)];
+]
+"tail36" = [
+    0x4C000000_1E000000 = [Rs(&[0, 37]), Target(PAIR38, PCADDU18I)];
+]
+)
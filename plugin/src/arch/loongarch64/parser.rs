@@ -0,0 +1,215 @@
+use syn::{parse, Token};
+use proc_macro2::Delimiter;
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt, dynamic_register};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegFamily};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op, like fcmp.clt.d or ammax_db.wu
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            name,
+            span
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // registers can be written with a leading $, in which case they have to be a register
+    if input.peek(Token![$]) {
+        let _: Token![$] = input.parse()?;
+
+        return match parse_reg(ctx, input)? {
+            Some(reg) => Ok(RawArg::Direct {
+                reg
+            }),
+            None => Err(input.error("expected a register"))
+        };
+    }
+
+    // register
+    if let Some(reg) = parse_reg(ctx, input)? {
+        return Ok(RawArg::Direct {
+            reg
+        });
+    }
+
+    // immediate
+    Ok(RawArg::Immediate {
+        value: input.parse()?
+    })
+}
+
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<Register>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // families are only recognized when followed by the dynamic register expression
+            if LOONGARCH64_FAMILIES.contains_key(&*ident) && rest.group(Delimiter::Parenthesis).is_some() {
+                return Ok((ident, rest));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if LOONGARCH64_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&(family, code)) = LOONGARCH64_REGISTERS.get(&*name) {
+        Ok(Some(Register::new_static(family, code)))
+
+    } else if let Some(&family) = LOONGARCH64_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(Register::new_dynamic(family, dynamic_register("loongarch64", typed_register(family), "u32", &expr))))
+    } else {
+        unreachable!();
+    }
+}
+
+// The typed register in `dynasmrt::loongarch64` that dynamic registers of `family` are checked against.
+fn typed_register(family: RegFamily) -> &'static str {
+    match family {
+        RegFamily::INTEGER => "R",
+        RegFamily::FLOAT   => "F",
+        RegFamily::LSX     => "VR",
+        RegFamily::LASX    => "XR",
+        RegFamily::FCC |
+        RegFamily::FCSR    => unreachable!("no dynamic register family"),
+    }
+}
+
+lazy_static!{
+    static ref LOONGARCH64_REGISTERS: HashMap<&'static str, (RegFamily, u8)> = {
+        use self::RegFamily::*;
+
+        static MAP: &[(&str, (RegFamily, u8))] = &[
+            ("r0", (INTEGER, 0)), ("r1", (INTEGER, 1)), ("r2", (INTEGER, 2)), ("r3", (INTEGER, 3)),
+            ("r4", (INTEGER, 4)), ("r5", (INTEGER, 5)), ("r6", (INTEGER, 6)), ("r7", (INTEGER, 7)),
+            ("r8", (INTEGER, 8)), ("r9", (INTEGER, 9)), ("r10", (INTEGER, 10)), ("r11", (INTEGER, 11)),
+            ("r12", (INTEGER, 12)), ("r13", (INTEGER, 13)), ("r14", (INTEGER, 14)), ("r15", (INTEGER, 15)),
+            ("r16", (INTEGER, 16)), ("r17", (INTEGER, 17)), ("r18", (INTEGER, 18)), ("r19", (INTEGER, 19)),
+            ("r20", (INTEGER, 20)), ("r21", (INTEGER, 21)), ("r22", (INTEGER, 22)), ("r23", (INTEGER, 23)),
+            ("r24", (INTEGER, 24)), ("r25", (INTEGER, 25)), ("r26", (INTEGER, 26)), ("r27", (INTEGER, 27)),
+            ("r28", (INTEGER, 28)), ("r29", (INTEGER, 29)), ("r30", (INTEGER, 30)), ("r31", (INTEGER, 31)),
+
+            ("zero", (INTEGER, 0)), ("ra", (INTEGER, 1)), ("tp", (INTEGER, 2)), ("sp", (INTEGER, 3)),
+            ("a0", (INTEGER, 4)), ("a1", (INTEGER, 5)), ("a2", (INTEGER, 6)), ("a3", (INTEGER, 7)),
+            ("a4", (INTEGER, 8)), ("a5", (INTEGER, 9)), ("a6", (INTEGER, 10)), ("a7", (INTEGER, 11)),
+            ("t0", (INTEGER, 12)), ("t1", (INTEGER, 13)), ("t2", (INTEGER, 14)), ("t3", (INTEGER, 15)),
+            ("t4", (INTEGER, 16)), ("t5", (INTEGER, 17)), ("t6", (INTEGER, 18)), ("t7", (INTEGER, 19)),
+            ("t8", (INTEGER, 20)), ("fp", (INTEGER, 22)), ("s9", (INTEGER, 22)), ("s0", (INTEGER, 23)),
+            ("s1", (INTEGER, 24)), ("s2", (INTEGER, 25)), ("s3", (INTEGER, 26)), ("s4", (INTEGER, 27)),
+            ("s5", (INTEGER, 28)), ("s6", (INTEGER, 29)), ("s7", (INTEGER, 30)), ("s8", (INTEGER, 31)),
+
+            ("f0", (FLOAT, 0)), ("f1", (FLOAT, 1)), ("f2", (FLOAT, 2)), ("f3", (FLOAT, 3)),
+            ("f4", (FLOAT, 4)), ("f5", (FLOAT, 5)), ("f6", (FLOAT, 6)), ("f7", (FLOAT, 7)),
+            ("f8", (FLOAT, 8)), ("f9", (FLOAT, 9)), ("f10", (FLOAT, 10)), ("f11", (FLOAT, 11)),
+            ("f12", (FLOAT, 12)), ("f13", (FLOAT, 13)), ("f14", (FLOAT, 14)), ("f15", (FLOAT, 15)),
+            ("f16", (FLOAT, 16)), ("f17", (FLOAT, 17)), ("f18", (FLOAT, 18)), ("f19", (FLOAT, 19)),
+            ("f20", (FLOAT, 20)), ("f21", (FLOAT, 21)), ("f22", (FLOAT, 22)), ("f23", (FLOAT, 23)),
+            ("f24", (FLOAT, 24)), ("f25", (FLOAT, 25)), ("f26", (FLOAT, 26)), ("f27", (FLOAT, 27)),
+            ("f28", (FLOAT, 28)), ("f29", (FLOAT, 29)), ("f30", (FLOAT, 30)), ("f31", (FLOAT, 31)),
+
+            ("fa0", (FLOAT, 0)), ("fa1", (FLOAT, 1)), ("fa2", (FLOAT, 2)), ("fa3", (FLOAT, 3)),
+            ("fa4", (FLOAT, 4)), ("fa5", (FLOAT, 5)), ("fa6", (FLOAT, 6)), ("fa7", (FLOAT, 7)),
+            ("ft0", (FLOAT, 8)), ("ft1", (FLOAT, 9)), ("ft2", (FLOAT, 10)), ("ft3", (FLOAT, 11)),
+            ("ft4", (FLOAT, 12)), ("ft5", (FLOAT, 13)), ("ft6", (FLOAT, 14)), ("ft7", (FLOAT, 15)),
+            ("ft8", (FLOAT, 16)), ("ft9", (FLOAT, 17)), ("ft10", (FLOAT, 18)), ("ft11", (FLOAT, 19)),
+            ("ft12", (FLOAT, 20)), ("ft13", (FLOAT, 21)), ("ft14", (FLOAT, 22)), ("ft15", (FLOAT, 23)),
+            ("fs0", (FLOAT, 24)), ("fs1", (FLOAT, 25)), ("fs2", (FLOAT, 26)), ("fs3", (FLOAT, 27)),
+            ("fs4", (FLOAT, 28)), ("fs5", (FLOAT, 29)), ("fs6", (FLOAT, 30)), ("fs7", (FLOAT, 31)),
+
+            ("fcc0", (FCC, 0)), ("fcc1", (FCC, 1)), ("fcc2", (FCC, 2)), ("fcc3", (FCC, 3)),
+            ("fcc4", (FCC, 4)), ("fcc5", (FCC, 5)), ("fcc6", (FCC, 6)), ("fcc7", (FCC, 7)),
+
+            ("fcsr0", (FCSR, 0)), ("fcsr1", (FCSR, 1)), ("fcsr2", (FCSR, 2)), ("fcsr3", (FCSR, 3)),
+
+            ("vr0", (LSX, 0)), ("vr1", (LSX, 1)), ("vr2", (LSX, 2)), ("vr3", (LSX, 3)),
+            ("vr4", (LSX, 4)), ("vr5", (LSX, 5)), ("vr6", (LSX, 6)), ("vr7", (LSX, 7)),
+            ("vr8", (LSX, 8)), ("vr9", (LSX, 9)), ("vr10", (LSX, 10)), ("vr11", (LSX, 11)),
+            ("vr12", (LSX, 12)), ("vr13", (LSX, 13)), ("vr14", (LSX, 14)), ("vr15", (LSX, 15)),
+            ("vr16", (LSX, 16)), ("vr17", (LSX, 17)), ("vr18", (LSX, 18)), ("vr19", (LSX, 19)),
+            ("vr20", (LSX, 20)), ("vr21", (LSX, 21)), ("vr22", (LSX, 22)), ("vr23", (LSX, 23)),
+            ("vr24", (LSX, 24)), ("vr25", (LSX, 25)), ("vr26", (LSX, 26)), ("vr27", (LSX, 27)),
+            ("vr28", (LSX, 28)), ("vr29", (LSX, 29)), ("vr30", (LSX, 30)), ("vr31", (LSX, 31)),
+
+            ("xr0", (LASX, 0)), ("xr1", (LASX, 1)), ("xr2", (LASX, 2)), ("xr3", (LASX, 3)),
+            ("xr4", (LASX, 4)), ("xr5", (LASX, 5)), ("xr6", (LASX, 6)), ("xr7", (LASX, 7)),
+            ("xr8", (LASX, 8)), ("xr9", (LASX, 9)), ("xr10", (LASX, 10)), ("xr11", (LASX, 11)),
+            ("xr12", (LASX, 12)), ("xr13", (LASX, 13)), ("xr14", (LASX, 14)), ("xr15", (LASX, 15)),
+            ("xr16", (LASX, 16)), ("xr17", (LASX, 17)), ("xr18", (LASX, 18)), ("xr19", (LASX, 19)),
+            ("xr20", (LASX, 20)), ("xr21", (LASX, 21)), ("xr22", (LASX, 22)), ("xr23", (LASX, 23)),
+            ("xr24", (LASX, 24)), ("xr25", (LASX, 25)), ("xr26", (LASX, 26)), ("xr27", (LASX, 27)),
+            ("xr28", (LASX, 28)), ("xr29", (LASX, 29)), ("xr30", (LASX, 30)), ("xr31", (LASX, 31)),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref LOONGARCH64_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("R", RegFamily::INTEGER),
+            ("F", RegFamily::FLOAT),
+            ("VR", RegFamily::LSX),
+            ("XR", RegFamily::LASX),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
@@ -8,6 +8,10 @@ use std::fmt::Debug;
 
 pub mod x64;
 pub mod aarch64;
+pub mod riscv64;
+pub mod loongarch64;
+pub mod wasm;
+pub mod arm;
 
 pub(crate) trait Arch : Debug + Send {
     fn name(&self) -> &str;
@@ -58,7 +62,13 @@ pub(crate) fn from_str(s: &str) -> Option<Box<dyn Arch>> {
     match s {
         "x64" => Some(Box::new(x64::Archx64::default())),
         "x86" => Some(Box::new(x64::Archx86::default())),
+        "x86_16" => Some(Box::new(x64::Archx86::new(x64::X86Mode::Real))),
         "aarch64" => Some(Box::new(aarch64::ArchAarch64::default())),
+        "riscv64" => Some(Box::new(riscv64::ArchRiscv64::default())),
+        "loongarch64" => Some(Box::new(loongarch64::ArchLoongarch64::default())),
+        "wasm" => Some(Box::new(wasm::ArchWasm::default())),
+        "arm" => Some(Box::new(arm::ArchArm::default())),
+        "thumb" => Some(Box::new(arm::ArchThumb::default())),
         "unknown" => Some(Box::new(DummyArch::new("unknown"))),
         _ => None
     }
@@ -70,5 +80,13 @@ pub const CURRENT_ARCH: &str = "x64";
 pub const CURRENT_ARCH: &str = "x86";
 #[cfg(target_arch="aarch64")]
 pub const CURRENT_ARCH: &str = "aarch64";
-#[cfg(not(any(target_arch="x86", target_arch="x86_64", target_arch="aarch64")))]
+#[cfg(target_arch="riscv64")]
+pub const CURRENT_ARCH: &str = "riscv64";
+#[cfg(target_arch="loongarch64")]
+pub const CURRENT_ARCH: &str = "loongarch64";
+#[cfg(all(target_arch="arm", not(target_feature="thumb-mode")))]
+pub const CURRENT_ARCH: &str = "arm";
+#[cfg(all(target_arch="arm", target_feature="thumb-mode"))]
+pub const CURRENT_ARCH: &str = "thumb";
+#[cfg(not(any(target_arch="x86", target_arch="x86_64", target_arch="aarch64", target_arch="riscv64", target_arch="loongarch64", target_arch="arm")))]
 pub const CURRENT_ARCH: &str = "unknown";
@@ -0,0 +1,83 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+
+/// A riscv64 register, either chosen statically or dynamically
+#[derive(Debug, Clone)]
+pub struct Register {
+    pub family: RegFamily,
+    pub kind: RegKind
+}
+
+// Register id without indication of its usage. Either a static register encoding or an expression to choose the register
+#[derive(Debug, Clone)]
+pub enum RegKind {
+    Static(u8),
+    Dynamic(syn::Expr)
+}
+
+// register family. INTEGER = xn and its ABI names. FLOAT = fn and its ABI names
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum RegFamily {
+    INTEGER = 0,
+    FLOAT   = 1,
+}
+
+impl Register {
+    pub fn new_static(family: RegFamily, code: u8) -> Register {
+        Register {
+            family,
+            kind: RegKind::Static(code)
+        }
+    }
+
+    pub fn new_dynamic(family: RegFamily, expr: syn::Expr) -> Register {
+        Register {
+            family,
+            kind: RegKind::Dynamic(expr)
+        }
+    }
+
+    /// The static encoding of this register, if it is known at compile time
+    pub fn static_code(&self) -> Option<u8> {
+        match self.kind {
+            RegKind::Static(code) => Some(code),
+            RegKind::Dynamic(_) => None
+        }
+    }
+}
+
+/**
+ * Parsed ast
+ */
+
+/// An instruction, with the full dot-separated mnemonic
+#[derive(Debug)]
+pub struct Instruction {
+    pub name: String,
+    pub span: Span
+}
+
+/// The possible arguments of a riscv64 instruction
+#[derive(Debug)]
+pub enum RawArg {
+    // A register
+    Direct {
+        reg: Register
+    },
+    // A memory reference: offset(base). The offset is optional.
+    Reference {
+        offset: Option<syn::Expr>,
+        base: Register
+    },
+    // A label or other relocation target
+    JumpTarget {
+        jump: Jump
+    },
+    // An immediate, or a literal like a rounding mode, fence set or CSR name
+    Immediate {
+        value: syn::Expr
+    }
+}
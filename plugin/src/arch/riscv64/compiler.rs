@@ -0,0 +1,335 @@
+use super::matching::MatchData;
+use super::riscv64data::{Operand, Imm, Relocation, ROUNDING_MODE_MAP, CSR_MAP};
+use super::Context;
+use super::ast::{RawArg, Register, RegKind};
+
+use crate::common::{Stmt, Size, delimited, bitmask64};
+use crate::parse_helpers::{as_ident, as_number, as_signed_number};
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+
+pub(super) fn compile_instruction(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    let opdata = data.data;
+
+    // the li pseudo instruction picks its expansion depending on its immediate
+    if opdata.operands.contains(&Operand::Li) {
+        return compile_li(ctx, data);
+    }
+
+    let mut statics: Vec<(u8, u64)> = Vec::new();
+    let mut dynamics: Vec<(u8, TokenStream)> = Vec::new();
+    let mut relocations = Vec::new();
+
+    let mut args = data.args.into_iter();
+
+    for operand in opdata.operands {
+        let arg = match args.next() {
+            Some(arg) => arg,
+            None => match *operand {
+                Operand::Rm(default) => {
+                    statics.push((12, u64::from(default)));
+                    continue;
+                },
+                _ => panic!("Not enough arguments matched")
+            }
+        };
+
+        match (*operand, arg) {
+            (Operand::X(offset), RawArg::Direct { reg, .. }) |
+            (Operand::XNz(offset), RawArg::Direct { reg, .. }) |
+            (Operand::XNoSp(offset), RawArg::Direct { reg, .. }) |
+            (Operand::F(offset), RawArg::Direct { reg, .. }) => encode_register(&reg, offset, &mut statics, &mut dynamics),
+            (Operand::Xs(offsets), RawArg::Direct { reg, .. }) |
+            (Operand::Fs(offsets), RawArg::Direct { reg, .. }) => for &offset in offsets {
+                encode_register(&reg, offset, &mut statics, &mut dynamics);
+            },
+            (Operand::Xc(offset), RawArg::Direct { reg, .. }) |
+            (Operand::Fc(offset), RawArg::Direct { reg, .. }) => encode_compressed_register(&reg, offset, &mut statics, &mut dynamics),
+            (Operand::Sp, _) => (),
+
+            (Operand::Imm(imm), RawArg::Immediate { value }) => encode_immediate(&value, imm, &mut statics, &mut dynamics)?,
+
+            (Operand::Mem(imm, offset), RawArg::Reference { offset: disp, base, .. }) => {
+                if let Some(disp) = disp {
+                    encode_immediate(&disp, imm, &mut statics, &mut dynamics)?;
+                }
+                encode_register(&base, offset, &mut statics, &mut dynamics);
+            },
+            (Operand::MemC(imm, offset), RawArg::Reference { offset: disp, base, .. }) => {
+                if let Some(disp) = disp {
+                    encode_immediate(&disp, imm, &mut statics, &mut dynamics)?;
+                }
+                encode_compressed_register(&base, offset, &mut statics, &mut dynamics);
+            },
+            (Operand::MemSp(imm), RawArg::Reference { offset: disp, .. }) => if let Some(disp) = disp {
+                encode_immediate(&disp, imm, &mut statics, &mut dynamics)?;
+            },
+            (Operand::Addr(offset), RawArg::Reference { base, .. }) => encode_register(&base, offset, &mut statics, &mut dynamics),
+
+            (Operand::Target(_, relocation), RawArg::JumpTarget { jump }) => {
+                // what kind of relocation is it
+                let data = [relocation.to_id()];
+
+                // encode the complete relocation
+                relocations.push(jump.encode(&data));
+            },
+            (Operand::Target(_, Relocation::AUIPC), RawArg::Immediate { value }) => encode_pair_immediate(&value, -0x8000_0800, 0x7FFF_F7FF, &mut statics, &mut dynamics)?,
+            (Operand::Target(imm, _), RawArg::Immediate { value }) => encode_immediate(&value, imm, &mut statics, &mut dynamics)?,
+
+            (Operand::Rm(_), RawArg::Immediate { value }) => {
+                if let Some(&mode) = as_ident(&value).and_then(|ident| ROUNDING_MODE_MAP.get(&*ident.to_string())) {
+                    statics.push((12, u64::from(mode)));
+                } else if let Some(mode) = as_number(&value) {
+                    if mode > 0b111 || mode == 0b101 || mode == 0b110 {
+                        emit_error!(value, "Invalid rounding mode");
+                        return Err(None);
+                    }
+                    statics.push((12, mode));
+                } else {
+                    emit_error!(value, "Unknown rounding mode");
+                    return Err(None);
+                }
+            },
+            (Operand::Fence(offset), RawArg::Immediate { value }) => {
+                let set = if let Some(ident) = as_ident(&value) {
+                    fence_set(&ident.to_string())
+                } else {
+                    as_number(&value).filter(|&set| set <= 0xF)
+                };
+
+                if let Some(set) = set {
+                    statics.push((offset, set));
+                } else {
+                    emit_error!(value, "Invalid fence set, expected a combination of i, o, r and w");
+                    return Err(None);
+                }
+            },
+            (Operand::Csr, RawArg::Immediate { value }) => {
+                if let Some(&csr) = as_ident(&value).and_then(|ident| CSR_MAP.get(&*ident.to_string())) {
+                    statics.push((20, u64::from(csr)));
+                } else if let Some(csr) = as_number(&value) {
+                    if csr > 0xFFF {
+                        emit_error!(value, "Value too large");
+                        return Err(None);
+                    }
+                    statics.push((20, csr));
+                } else {
+                    dynamics.push((20, quote_spanned!{ value.span()=>
+                        (#value) & 0xFFF
+                    }));
+                }
+            },
+
+            _ => panic!("Invalid argument processor")
+        }
+    }
+
+    // sanity
+    if args.next().is_some() {
+        panic!("Not enough operand processors");
+    }
+
+    emit_encoding(ctx, opdata.base, opdata.size(), statics, dynamics);
+
+    // generate code to be emitted for relocations
+    ctx.state.stmts.extend(relocations);
+
+    Ok(())
+}
+
+// li rd, imm expands to addi rd, zero, imm if the immediate fits in 12 bits, otherwise to lui rd, hi ; addiw rd, rd, lo
+fn compile_li(ctx: &mut Context, data: MatchData) -> Result<(), Option<String>> {
+    let mut args = data.args.into_iter();
+    let (reg, value) = match (args.next(), args.next()) {
+        (Some(RawArg::Direct { reg, .. }), Some(RawArg::Immediate { value })) => (reg, value),
+        _ => panic!("Invalid argument processor")
+    };
+
+    let mut statics = Vec::new();
+    let mut dynamics = Vec::new();
+
+    if let Some(number) = as_signed_number(&value) {
+        if number >= -0x800 && number < 0x800 {
+            // addi rd, zero, imm
+            encode_register(&reg, 7, &mut statics, &mut dynamics);
+            statics.push((20, (number as u64) & 0xFFF));
+            emit_encoding(ctx, 0x0000_0013, 4, statics, dynamics);
+            return Ok(());
+        }
+
+        if number & 0xFFF == 0 && number >= -0x8000_0000 && number < 0x8000_0000 {
+            // lui rd, hi
+            encode_register(&reg, 7, &mut statics, &mut dynamics);
+            statics.push((12, ((number >> 12) as u64) & 0xF_FFFF));
+            emit_encoding(ctx, 0x0000_0037, 4, statics, dynamics);
+            return Ok(());
+        }
+    }
+
+    for &offset in &[7, 39, 47] {
+        encode_register(&reg, offset, &mut statics, &mut dynamics);
+    }
+    encode_pair_immediate(&value, -0x8000_0000, 0x7FFF_FFFF, &mut statics, &mut dynamics)?;
+    emit_encoding(ctx, data.data.base, data.data.size(), statics, dynamics);
+
+    Ok(())
+}
+
+// emit the statement encoding an instruction of the given size
+fn emit_encoding(ctx: &mut Context, base: u64, size: u8, statics: Vec<(u8, u64)>, dynamics: Vec<(u8, TokenStream)>) {
+    // apply all statics to bits
+    let mut bits = base;
+    for (offset, value) in statics {
+        bits |= value << offset;
+    }
+
+    let (size, ty) = match size {
+        2 => (Size::WORD, quote!(u16)),
+        4 => (Size::DWORD, quote!(u32)),
+        8 => (Size::QWORD, quote!(u64)),
+        _ => unreachable!()
+    };
+
+    // generate code to be emitted for dynamics
+    if !dynamics.is_empty() {
+        let mut res = quote!{
+            (#bits as #ty)
+        };
+        for (offset, expr) in dynamics {
+            res = quote!{
+                #res | (((#expr) as #ty) << #offset)
+            };
+        }
+        ctx.state.stmts.push(Stmt::ExprUnsigned(delimited(res), size));
+    } else {
+        ctx.state.stmts.push(Stmt::Const(bits, size));
+    }
+}
+
+fn encode_register(reg: &Register, offset: u8, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) {
+    match reg.kind {
+        RegKind::Static(code) => statics.push((offset, u64::from(code))),
+        RegKind::Dynamic(ref expr) => dynamics.push((offset, quote_spanned!{ expr.span()=>
+            #expr & 0x1F
+        }))
+    }
+}
+
+// compressed instructions encode x8-x15 and f8-f15 in 3 bits
+fn encode_compressed_register(reg: &Register, offset: u8, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) {
+    match reg.kind {
+        RegKind::Static(code) => statics.push((offset, u64::from(code - 8))),
+        RegKind::Dynamic(ref expr) => dynamics.push((offset, quote_spanned!{ expr.span()=>
+            #expr & 0x7
+        }))
+    }
+}
+
+fn encode_immediate(expr: &syn::Expr, imm: Imm, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        let value = immediate_rangecheck(expr, value, imm)?;
+
+        for &(low, bits, offset) in imm.fields {
+            statics.push((offset, ((value >> low) as u64) & bitmask64(bits)));
+        }
+    } else {
+        // scatter the fields of the immediate at runtime
+        let span = expr.span();
+        let mut res = quote_spanned!{ span=> 0u32 };
+        for &(low, bits, offset) in imm.fields {
+            let mask = bitmask64(bits) as u32;
+            res = quote_spanned!{ span=>
+                #res | (((imm >> #low) & #mask) << #offset)
+            };
+        }
+
+        dynamics.push((0, quote_spanned!{ span=>
+            {
+                let imm = (#expr) as u32;
+                #res
+            }
+        }));
+    }
+
+    Ok(())
+}
+
+// encodes a 32-bit value split over an auipc or lui and an I-type instruction in the upper half of the encoding
+fn encode_pair_immediate(expr: &syn::Expr, min: i64, max: i64, statics: &mut Vec<(u8, u64)>, dynamics: &mut Vec<(u8, TokenStream)>) -> Result<(), Option<String>> {
+    if let Some(value) = as_signed_number(expr) {
+        if value > max {
+            emit_error!(expr, "Value too large");
+            return Err(None);
+        } else if value < min {
+            emit_error!(expr, "Value too small");
+            return Err(None);
+        }
+
+        statics.push((12, (((value + 0x800) >> 12) as u64) & 0xF_FFFF));
+        statics.push((52, (value as u64) & 0xFFF));
+    } else {
+        dynamics.push((0, quote_spanned!{ expr.span()=>
+            {
+                let imm = (#expr) as u32;
+                u64::from(imm.wrapping_add(0x800) & 0xFFFF_F000) | (u64::from(imm & 0xFFF) << 52)
+            }
+        }));
+    }
+
+    Ok(())
+}
+
+// checks if a static immediate can be encoded, returning the value to encode
+fn immediate_rangecheck(expr: &syn::Expr, mut value: i64, imm: Imm) -> Result<i64, Option<String>> {
+    // immediates that are sign-extended to a larger width may also be written in that width
+    if imm.unsigned_alias != 0 {
+        let alias = 1i64 << imm.unsigned_alias;
+        if value >= alias - (1 << (imm.bits - 1)) && value < alias {
+            value -= alias;
+        }
+    }
+
+    let (min, max) = if imm.signed {
+        (-(1i64 << (imm.bits - 1)), (1i64 << (imm.bits - 1)) - 1)
+    } else {
+        (0, (1i64 << imm.bits) - 1)
+    };
+
+    if value & ((1 << imm.scale) - 1) != 0 {
+        emit_error!(expr, "Unrepresentable value");
+        Err(None)
+    } else if value > max {
+        emit_error!(expr, "Value too large");
+        Err(None)
+    } else if value < min {
+        emit_error!(expr, "Value too small");
+        Err(None)
+    } else if imm.nonzero && value == 0 {
+        emit_error!(expr, "Value cannot be zero");
+        Err(None)
+    } else {
+        Ok(value)
+    }
+}
+
+// parses a fence predecessor or successor set like "iorw" or "rw"
+fn fence_set(name: &str) -> Option<u64> {
+    let mut set = 0;
+    let mut rest = name;
+
+    for (i, c) in "iorw".chars().enumerate() {
+        if rest.starts_with(c) {
+            set |= 8 >> i;
+            rest = &rest[1..];
+        }
+    }
+
+    if rest.is_empty() && set != 0 {
+        Some(set)
+    } else {
+        None
+    }
+}
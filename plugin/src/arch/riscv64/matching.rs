@@ -0,0 +1,149 @@
+use proc_macro_error::emit_error;
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegFamily};
+use super::riscv64data::{Opdata, Operand, get_mnemonic_data};
+
+use crate::common::JumpKind;
+use crate::parse_helpers::as_number;
+
+/// The result of matching an instruction: the encoding to use and the arguments to encode with it.
+#[derive(Debug)]
+pub struct MatchData {
+    pub data: &'static Opdata,
+    pub args: Vec<RawArg>
+}
+
+/// Try finding an appropriate definition that matches the given instruction / arguments.
+pub(super) fn match_instruction(_ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<MatchData, Option<String>> {
+    // validate that only relative jumps are allowed (no extern relocations)
+    for arg in &args {
+        if let RawArg::JumpTarget { ref jump } = *arg {
+            if let JumpKind::Bare(_) = jump.kind {
+                emit_error!(jump.span(), "Extern relocations are not allowed in riscv64");
+                return Err(None);
+            }
+        }
+    }
+
+    // get the possible encodings
+    let name = &instruction.name;
+    let opdata = if let Some(o) = get_mnemonic_data(name) {
+        o
+    } else {
+        return Err(Some(format!("Unknown instruction mnemonic '{}'", name)));
+    };
+
+    // matching loop
+    for data in opdata {
+        if match_args(&args, data) {
+            return Ok(MatchData {
+                data,
+                args
+            });
+        }
+    }
+
+    Err(Some(
+        format!("'{}': instruction format mismatch, expected one of the following forms:\n{}", name, format_opdata_list(name, opdata))
+    ))
+}
+
+fn match_args(args: &[RawArg], data: &Opdata) -> bool {
+    let mut args = args.iter();
+
+    for operand in data.operands {
+        let arg = match args.next() {
+            Some(arg) => arg,
+            // rounding modes can be omitted
+            None => if let Operand::Rm(_) = *operand {
+                continue;
+            } else {
+                return false;
+            }
+        };
+
+        if !match_operand(arg, operand) {
+            return false;
+        }
+    }
+
+    args.next().is_none()
+}
+
+fn match_operand(arg: &RawArg, operand: &Operand) -> bool {
+    match (arg, *operand) {
+        (RawArg::Direct { reg, .. }, Operand::X(_)) |
+        (RawArg::Direct { reg, .. }, Operand::Xs(_)) => reg.family == RegFamily::INTEGER,
+        (RawArg::Direct { reg, .. }, Operand::XNz(_)) => reg.family == RegFamily::INTEGER && reg.static_code() != Some(0),
+        (RawArg::Direct { reg, .. }, Operand::XNoSp(_)) => reg.family == RegFamily::INTEGER && reg.static_code() != Some(2),
+        (RawArg::Direct { reg, .. }, Operand::Xc(_)) => reg.family == RegFamily::INTEGER && is_compressible(reg),
+        (RawArg::Direct { reg, .. }, Operand::F(_)) |
+        (RawArg::Direct { reg, .. }, Operand::Fs(_)) => reg.family == RegFamily::FLOAT,
+        (RawArg::Direct { reg, .. }, Operand::Fc(_)) => reg.family == RegFamily::FLOAT && is_compressible(reg),
+        (RawArg::Direct { reg, .. }, Operand::Sp) => reg.family == RegFamily::INTEGER && reg.static_code() == Some(2),
+
+        (RawArg::Reference { base, .. }, Operand::Mem(_, _)) => base.family == RegFamily::INTEGER,
+        (RawArg::Reference { base, .. }, Operand::MemC(_, _)) => base.family == RegFamily::INTEGER && is_compressible(base),
+        (RawArg::Reference { base, .. }, Operand::MemSp(_)) => base.family == RegFamily::INTEGER && base.static_code() == Some(2),
+        (RawArg::Reference { base, offset, .. }, Operand::Addr(_)) => base.family == RegFamily::INTEGER && match offset {
+            None => true,
+            Some(offset) => as_number(offset) == Some(0)
+        },
+
+        (RawArg::JumpTarget { .. }, Operand::Target(_, _)) |
+        (RawArg::Immediate { .. }, Operand::Target(_, _)) |
+        (RawArg::Immediate { .. }, Operand::Imm(_)) |
+        (RawArg::Immediate { .. }, Operand::Rm(_)) |
+        (RawArg::Immediate { .. }, Operand::Fence(_)) |
+        (RawArg::Immediate { .. }, Operand::Csr) |
+        (RawArg::Immediate { .. }, Operand::Li) => true,
+
+        _ => false
+    }
+}
+
+// compressed instructions can only encode x8-x15 and f8-f15
+fn is_compressible(reg: &Register) -> bool {
+    match reg.static_code() {
+        Some(code) => code >= 8 && code < 16,
+        None => true
+    }
+}
+
+/// Formats the list of possible forms of an instruction for error messages
+fn format_opdata_list(name: &str, data: &[Opdata]) -> String {
+    let mut forms = Vec::new();
+
+    for data in data {
+        let operands: Vec<&str> = data.operands.iter().map(|operand| match *operand {
+            Operand::X(_) |
+            Operand::Xs(_) => "x",
+            Operand::XNz(_) => "x (not zero)",
+            Operand::XNoSp(_) => "x (not sp)",
+            Operand::Xc(_) => "x8-x15",
+            Operand::F(_) |
+            Operand::Fs(_) => "f",
+            Operand::Fc(_) => "f8-f15",
+            Operand::Sp => "sp",
+            Operand::Imm(_) |
+            Operand::Li => "imm",
+            Operand::Mem(_, _) => "imm(x)",
+            Operand::MemC(_, _) => "imm(x8-x15)",
+            Operand::MemSp(_) => "imm(sp)",
+            Operand::Addr(_) => "(x)",
+            Operand::Target(_, _) => "label",
+            Operand::Rm(_) => "[rm]",
+            Operand::Fence(_) => "iorw",
+            Operand::Csr => "csr",
+        }).collect();
+
+        if operands.is_empty() {
+            forms.push(format!(">>> {}", name));
+        } else {
+            forms.push(format!(">>> {} {}", name, operands.join(", ")));
+        }
+    }
+
+    forms.join("\n")
+}
@@ -0,0 +1,92 @@
+use syn::parse;
+use proc_macro_error::emit_error;
+
+mod ast;
+mod parser;
+mod matching;
+mod compiler;
+mod riscv64data;
+
+use crate::State;
+use crate::common::{Size, Stmt, Jump};
+use crate::arch::Arch;
+use self::riscv64data::Relocation;
+
+struct Context<'a, 'b: 'a> {
+    pub state: &'a mut State<'b>
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchRiscv64 {
+
+}
+
+impl Default for ArchRiscv64 {
+    fn default() -> ArchRiscv64 {
+        ArchRiscv64 { }
+    }
+}
+
+impl Arch for ArchRiscv64 {
+    fn name(&self) -> &str {
+        "riscv64"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Arch riscv64 has no known features");
+        }
+    }
+
+    fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        let span = reloc.span();
+
+        let relocation = match size {
+            Size::BYTE => Relocation::LITERAL8,
+            Size::WORD => Relocation::LITERAL16,
+            Size::DWORD => Relocation::LITERAL32,
+            Size::QWORD => Relocation::LITERAL64,
+            _ => {
+                emit_error!(span, "Relocation of unsupported size for the current target architecture");
+                return;
+            }
+        };
+        let data = [relocation.to_id()];
+
+        stmts.push(Stmt::Const(0, size));
+        stmts.push(reloc.encode(&data));
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state
+        };
+
+        let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
+        let span = instruction.span;
+
+        let match_data = match matching::match_instruction(&mut ctx, &instruction, args) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error!(span, e);
+                return Ok(())
+            }
+            Ok(m) => m
+        };
+
+        match compiler::compile_instruction(&mut ctx, match_data) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error!(span, e);
+                return Ok(())
+            }
+            Ok(()) => ()
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,972 @@
+Ops!(
+// RV64I
+"lui" = [
+    0x00000037 = [X(7), Imm(U20)];
+]
+"auipc" = [
+    0x00000017 = [X(7), Imm(U20)];
+]
+"jal" = [
+    0x0000006F = [X(7), Target(J21, JAL)];
+    0x000000EF = [Target(J21, JAL)];
+]
+"jalr" = [
+    0x00000067 = [X(7), Mem(I12, 15)];
+    0x00000067 = [X(7), X(15), Imm(I12)];
+    0x000000E7 = [Mem(I12, 15)];
+    0x000000E7 = [X(15)];
+]
+"beq" = [
+    0x00000063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"bne" = [
+    0x00001063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"blt" = [
+    0x00004063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"bge" = [
+    0x00005063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"bltu" = [
+    0x00006063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"bgeu" = [
+    0x00007063 = [X(15), X(20), Target(B13, BRANCH)];
+]
+"lb" = [
+    0x00000003 = [X(7), Mem(I12, 15)];
+]
+"lh" = [
+    0x00001003 = [X(7), Mem(I12, 15)];
+]
+"lw" = [
+    0x00002003 = [X(7), Mem(I12, 15)];
+]
+"ld" = [
+    0x00003003 = [X(7), Mem(I12, 15)];
+]
+"lbu" = [
+    0x00004003 = [X(7), Mem(I12, 15)];
+]
+"lhu" = [
+    0x00005003 = [X(7), Mem(I12, 15)];
+]
+"lwu" = [
+    0x00006003 = [X(7), Mem(I12, 15)];
+]
+"sb" = [
+    0x00000023 = [X(20), Mem(S12, 15)];
+]
+"sh" = [
+    0x00001023 = [X(20), Mem(S12, 15)];
+]
+"sw" = [
+    0x00002023 = [X(20), Mem(S12, 15)];
+]
+"sd" = [
+    0x00003023 = [X(20), Mem(S12, 15)];
+]
+"addi" = [
+    0x00000013 = [X(7), X(15), Imm(I12)];
+]
+"slti" = [
+    0x00002013 = [X(7), X(15), Imm(I12)];
+]
+"sltiu" = [
+    0x00003013 = [X(7), X(15), Imm(I12)];
+]
+"xori" = [
+    0x00004013 = [X(7), X(15), Imm(I12)];
+]
+"ori" = [
+    0x00006013 = [X(7), X(15), Imm(I12)];
+]
+"andi" = [
+    0x00007013 = [X(7), X(15), Imm(I12)];
+]
+"slli" = [
+    0x00001013 = [X(7), X(15), Imm(SHAMT6)];
+]
+"srli" = [
+    0x00005013 = [X(7), X(15), Imm(SHAMT6)];
+]
+"srai" = [
+    0x40005013 = [X(7), X(15), Imm(SHAMT6)];
+]
+"addiw" = [
+    0x0000001B = [X(7), X(15), Imm(I12)];
+]
+"slliw" = [
+    0x0000101B = [X(7), X(15), Imm(SHAMT5)];
+]
+"srliw" = [
+    0x0000501B = [X(7), X(15), Imm(SHAMT5)];
+]
+"sraiw" = [
+    0x4000501B = [X(7), X(15), Imm(SHAMT5)];
+]
+"add" = [
+    0x00000033 = [X(7), X(15), X(20)];
+]
+"sub" = [
+    0x40000033 = [X(7), X(15), X(20)];
+]
+"sll" = [
+    0x00001033 = [X(7), X(15), X(20)];
+]
+"slt" = [
+    0x00002033 = [X(7), X(15), X(20)];
+]
+"sltu" = [
+    0x00003033 = [X(7), X(15), X(20)];
+]
+"xor" = [
+    0x00004033 = [X(7), X(15), X(20)];
+]
+"srl" = [
+    0x00005033 = [X(7), X(15), X(20)];
+]
+"sra" = [
+    0x40005033 = [X(7), X(15), X(20)];
+]
+"or" = [
+    0x00006033 = [X(7), X(15), X(20)];
+]
+"and" = [
+    0x00007033 = [X(7), X(15), X(20)];
+]
+"addw" = [
+    0x0000003B = [X(7), X(15), X(20)];
+]
+"subw" = [
+    0x4000003B = [X(7), X(15), X(20)];
+]
+"sllw" = [
+    0x0000103B = [X(7), X(15), X(20)];
+]
+"srlw" = [
+    0x0000503B = [X(7), X(15), X(20)];
+]
+"sraw" = [
+    0x4000503B = [X(7), X(15), X(20)];
+]
+"fence" = [
+    0x0FF0000F = [];
+    0x0000000F = [Fence(24), Fence(20)];
+]
+"fence.tso" = [
+    0x8330000F = [];
+]
+"ecall" = [
+    0x00000073 = [];
+]
+"ebreak" = [
+    0x00100073 = [];
+]
+
+// Zifencei
+"fence.i" = [
+    0x0000100F = [];
+]
+
+// Zicsr
+"csrrw" = [
+    0x00001073 = [X(7), Csr, X(15)];
+]
+"csrrs" = [
+    0x00002073 = [X(7), Csr, X(15)];
+]
+"csrrc" = [
+    0x00003073 = [X(7), Csr, X(15)];
+]
+"csrrwi" = [
+    0x00005073 = [X(7), Csr, Imm(UIMM5)];
+]
+"csrrsi" = [
+    0x00006073 = [X(7), Csr, Imm(UIMM5)];
+]
+"csrrci" = [
+    0x00007073 = [X(7), Csr, Imm(UIMM5)];
+]
+
+// privileged
+"wfi" = [
+    0x10500073 = [];
+]
+"mret" = [
+    0x30200073 = [];
+]
+"sret" = [
+    0x10200073 = [];
+]
+"sfence.vma" = [
+    0x12000073 = [];
+    0x12000073 = [X(15)];
+    0x12000073 = [X(15), X(20)];
+]
+
+// M
+"mul" = [
+    0x02000033 = [X(7), X(15), X(20)];
+]
+"mulh" = [
+    0x02001033 = [X(7), X(15), X(20)];
+]
+"mulhsu" = [
+    0x02002033 = [X(7), X(15), X(20)];
+]
+"mulhu" = [
+    0x02003033 = [X(7), X(15), X(20)];
+]
+"div" = [
+    0x02004033 = [X(7), X(15), X(20)];
+]
+"divu" = [
+    0x02005033 = [X(7), X(15), X(20)];
+]
+"rem" = [
+    0x02006033 = [X(7), X(15), X(20)];
+]
+"remu" = [
+    0x02007033 = [X(7), X(15), X(20)];
+]
+"mulw" = [
+    0x0200003B = [X(7), X(15), X(20)];
+]
+"divw" = [
+    0x0200403B = [X(7), X(15), X(20)];
+]
+"divuw" = [
+    0x0200503B = [X(7), X(15), X(20)];
+]
+"remw" = [
+    0x0200603B = [X(7), X(15), X(20)];
+]
+"remuw" = [
+    0x0200703B = [X(7), X(15), X(20)];
+]
+
+// A
+"lr.w" = [
+    0x1000202F = [X(7), Addr(15)];
+]
+"lr.w.aq" = [
+    0x1400202F = [X(7), Addr(15)];
+]
+"lr.w.rl" = [
+    0x1200202F = [X(7), Addr(15)];
+]
+"lr.w.aqrl" = [
+    0x1600202F = [X(7), Addr(15)];
+]
+"sc.w" = [
+    0x1800202F = [X(7), X(20), Addr(15)];
+]
+"sc.w.aq" = [
+    0x1C00202F = [X(7), X(20), Addr(15)];
+]
+"sc.w.rl" = [
+    0x1A00202F = [X(7), X(20), Addr(15)];
+]
+"sc.w.aqrl" = [
+    0x1E00202F = [X(7), X(20), Addr(15)];
+]
+"amoswap.w" = [
+    0x0800202F = [X(7), X(20), Addr(15)];
+]
+"amoswap.w.aq" = [
+    0x0C00202F = [X(7), X(20), Addr(15)];
+]
+"amoswap.w.rl" = [
+    0x0A00202F = [X(7), X(20), Addr(15)];
+]
+"amoswap.w.aqrl" = [
+    0x0E00202F = [X(7), X(20), Addr(15)];
+]
+"amoadd.w" = [
+    0x0000202F = [X(7), X(20), Addr(15)];
+]
+"amoadd.w.aq" = [
+    0x0400202F = [X(7), X(20), Addr(15)];
+]
+"amoadd.w.rl" = [
+    0x0200202F = [X(7), X(20), Addr(15)];
+]
+"amoadd.w.aqrl" = [
+    0x0600202F = [X(7), X(20), Addr(15)];
+]
+"amoxor.w" = [
+    0x2000202F = [X(7), X(20), Addr(15)];
+]
+"amoxor.w.aq" = [
+    0x2400202F = [X(7), X(20), Addr(15)];
+]
+"amoxor.w.rl" = [
+    0x2200202F = [X(7), X(20), Addr(15)];
+]
+"amoxor.w.aqrl" = [
+    0x2600202F = [X(7), X(20), Addr(15)];
+]
+"amoand.w" = [
+    0x6000202F = [X(7), X(20), Addr(15)];
+]
+"amoand.w.aq" = [
+    0x6400202F = [X(7), X(20), Addr(15)];
+]
+"amoand.w.rl" = [
+    0x6200202F = [X(7), X(20), Addr(15)];
+]
+"amoand.w.aqrl" = [
+    0x6600202F = [X(7), X(20), Addr(15)];
+]
+"amoor.w" = [
+    0x4000202F = [X(7), X(20), Addr(15)];
+]
+"amoor.w.aq" = [
+    0x4400202F = [X(7), X(20), Addr(15)];
+]
+"amoor.w.rl" = [
+    0x4200202F = [X(7), X(20), Addr(15)];
+]
+"amoor.w.aqrl" = [
+    0x4600202F = [X(7), X(20), Addr(15)];
+]
+"amomin.w" = [
+    0x8000202F = [X(7), X(20), Addr(15)];
+]
+"amomin.w.aq" = [
+    0x8400202F = [X(7), X(20), Addr(15)];
+]
+"amomin.w.rl" = [
+    0x8200202F = [X(7), X(20), Addr(15)];
+]
+"amomin.w.aqrl" = [
+    0x8600202F = [X(7), X(20), Addr(15)];
+]
+"amomax.w" = [
+    0xA000202F = [X(7), X(20), Addr(15)];
+]
+"amomax.w.aq" = [
+    0xA400202F = [X(7), X(20), Addr(15)];
+]
+"amomax.w.rl" = [
+    0xA200202F = [X(7), X(20), Addr(15)];
+]
+"amomax.w.aqrl" = [
+    0xA600202F = [X(7), X(20), Addr(15)];
+]
+"amominu.w" = [
+    0xC000202F = [X(7), X(20), Addr(15)];
+]
+"amominu.w.aq" = [
+    0xC400202F = [X(7), X(20), Addr(15)];
+]
+"amominu.w.rl" = [
+    0xC200202F = [X(7), X(20), Addr(15)];
+]
+"amominu.w.aqrl" = [
+    0xC600202F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.w" = [
+    0xE000202F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.w.aq" = [
+    0xE400202F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.w.rl" = [
+    0xE200202F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.w.aqrl" = [
+    0xE600202F = [X(7), X(20), Addr(15)];
+]
+"lr.d" = [
+    0x1000302F = [X(7), Addr(15)];
+]
+"lr.d.aq" = [
+    0x1400302F = [X(7), Addr(15)];
+]
+"lr.d.rl" = [
+    0x1200302F = [X(7), Addr(15)];
+]
+"lr.d.aqrl" = [
+    0x1600302F = [X(7), Addr(15)];
+]
+"sc.d" = [
+    0x1800302F = [X(7), X(20), Addr(15)];
+]
+"sc.d.aq" = [
+    0x1C00302F = [X(7), X(20), Addr(15)];
+]
+"sc.d.rl" = [
+    0x1A00302F = [X(7), X(20), Addr(15)];
+]
+"sc.d.aqrl" = [
+    0x1E00302F = [X(7), X(20), Addr(15)];
+]
+"amoswap.d" = [
+    0x0800302F = [X(7), X(20), Addr(15)];
+]
+"amoswap.d.aq" = [
+    0x0C00302F = [X(7), X(20), Addr(15)];
+]
+"amoswap.d.rl" = [
+    0x0A00302F = [X(7), X(20), Addr(15)];
+]
+"amoswap.d.aqrl" = [
+    0x0E00302F = [X(7), X(20), Addr(15)];
+]
+"amoadd.d" = [
+    0x0000302F = [X(7), X(20), Addr(15)];
+]
+"amoadd.d.aq" = [
+    0x0400302F = [X(7), X(20), Addr(15)];
+]
+"amoadd.d.rl" = [
+    0x0200302F = [X(7), X(20), Addr(15)];
+]
+"amoadd.d.aqrl" = [
+    0x0600302F = [X(7), X(20), Addr(15)];
+]
+"amoxor.d" = [
+    0x2000302F = [X(7), X(20), Addr(15)];
+]
+"amoxor.d.aq" = [
+    0x2400302F = [X(7), X(20), Addr(15)];
+]
+"amoxor.d.rl" = [
+    0x2200302F = [X(7), X(20), Addr(15)];
+]
+"amoxor.d.aqrl" = [
+    0x2600302F = [X(7), X(20), Addr(15)];
+]
+"amoand.d" = [
+    0x6000302F = [X(7), X(20), Addr(15)];
+]
+"amoand.d.aq" = [
+    0x6400302F = [X(7), X(20), Addr(15)];
+]
+"amoand.d.rl" = [
+    0x6200302F = [X(7), X(20), Addr(15)];
+]
+"amoand.d.aqrl" = [
+    0x6600302F = [X(7), X(20), Addr(15)];
+]
+"amoor.d" = [
+    0x4000302F = [X(7), X(20), Addr(15)];
+]
+"amoor.d.aq" = [
+    0x4400302F = [X(7), X(20), Addr(15)];
+]
+"amoor.d.rl" = [
+    0x4200302F = [X(7), X(20), Addr(15)];
+]
+"amoor.d.aqrl" = [
+    0x4600302F = [X(7), X(20), Addr(15)];
+]
+"amomin.d" = [
+    0x8000302F = [X(7), X(20), Addr(15)];
+]
+"amomin.d.aq" = [
+    0x8400302F = [X(7), X(20), Addr(15)];
+]
+"amomin.d.rl" = [
+    0x8200302F = [X(7), X(20), Addr(15)];
+]
+"amomin.d.aqrl" = [
+    0x8600302F = [X(7), X(20), Addr(15)];
+]
+"amomax.d" = [
+    0xA000302F = [X(7), X(20), Addr(15)];
+]
+"amomax.d.aq" = [
+    0xA400302F = [X(7), X(20), Addr(15)];
+]
+"amomax.d.rl" = [
+    0xA200302F = [X(7), X(20), Addr(15)];
+]
+"amomax.d.aqrl" = [
+    0xA600302F = [X(7), X(20), Addr(15)];
+]
+"amominu.d" = [
+    0xC000302F = [X(7), X(20), Addr(15)];
+]
+"amominu.d.aq" = [
+    0xC400302F = [X(7), X(20), Addr(15)];
+]
+"amominu.d.rl" = [
+    0xC200302F = [X(7), X(20), Addr(15)];
+]
+"amominu.d.aqrl" = [
+    0xC600302F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.d" = [
+    0xE000302F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.d.aq" = [
+    0xE400302F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.d.rl" = [
+    0xE200302F = [X(7), X(20), Addr(15)];
+]
+"amomaxu.d.aqrl" = [
+    0xE600302F = [X(7), X(20), Addr(15)];
+]
+
+// F and D
+"flw" = [
+    0x00002007 = [F(7), Mem(I12, 15)];
+]
+"fld" = [
+    0x00003007 = [F(7), Mem(I12, 15)];
+]
+"fsw" = [
+    0x00002027 = [F(20), Mem(S12, 15)];
+]
+"fsd" = [
+    0x00003027 = [F(20), Mem(S12, 15)];
+]
+"fmadd.s" = [
+    0x00000043 = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fmsub.s" = [
+    0x00000047 = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fnmsub.s" = [
+    0x0000004B = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fnmadd.s" = [
+    0x0000004F = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fadd.s" = [
+    0x00000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fsub.s" = [
+    0x08000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fmul.s" = [
+    0x10000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fdiv.s" = [
+    0x18000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fsqrt.s" = [
+    0x58000053 = [F(7), F(15), Rm(0b111)];
+]
+"fsgnj.s" = [
+    0x20000053 = [F(7), F(15), F(20)];
+]
+"fsgnjn.s" = [
+    0x20001053 = [F(7), F(15), F(20)];
+]
+"fsgnjx.s" = [
+    0x20002053 = [F(7), F(15), F(20)];
+]
+"fmin.s" = [
+    0x28000053 = [F(7), F(15), F(20)];
+]
+"fmax.s" = [
+    0x28001053 = [F(7), F(15), F(20)];
+]
+"feq.s" = [
+    0xA0002053 = [X(7), F(15), F(20)];
+]
+"flt.s" = [
+    0xA0001053 = [X(7), F(15), F(20)];
+]
+"fle.s" = [
+    0xA0000053 = [X(7), F(15), F(20)];
+]
+"fclass.s" = [
+    0xE0001053 = [X(7), F(15)];
+]
+"fcvt.w.s" = [
+    0xC0000053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.wu.s" = [
+    0xC0100053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.l.s" = [
+    0xC0200053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.lu.s" = [
+    0xC0300053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.s.w" = [
+    0xD0000053 = [F(7), X(15), Rm(0b111)];
+]
+"fcvt.s.wu" = [
+    0xD0100053 = [F(7), X(15), Rm(0b111)];
+]
+"fcvt.s.l" = [
+    0xD0200053 = [F(7), X(15), Rm(0b111)];
+]
+"fcvt.s.lu" = [
+    0xD0300053 = [F(7), X(15), Rm(0b111)];
+]
+"fmadd.d" = [
+    0x02000043 = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fmsub.d" = [
+    0x02000047 = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fnmsub.d" = [
+    0x0200004B = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fnmadd.d" = [
+    0x0200004F = [F(7), F(15), F(20), F(27), Rm(0b111)];
+]
+"fadd.d" = [
+    0x02000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fsub.d" = [
+    0x0A000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fmul.d" = [
+    0x12000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fdiv.d" = [
+    0x1A000053 = [F(7), F(15), F(20), Rm(0b111)];
+]
+"fsqrt.d" = [
+    0x5A000053 = [F(7), F(15), Rm(0b111)];
+]
+"fsgnj.d" = [
+    0x22000053 = [F(7), F(15), F(20)];
+]
+"fsgnjn.d" = [
+    0x22001053 = [F(7), F(15), F(20)];
+]
+"fsgnjx.d" = [
+    0x22002053 = [F(7), F(15), F(20)];
+]
+"fmin.d" = [
+    0x2A000053 = [F(7), F(15), F(20)];
+]
+"fmax.d" = [
+    0x2A001053 = [F(7), F(15), F(20)];
+]
+"feq.d" = [
+    0xA2002053 = [X(7), F(15), F(20)];
+]
+"flt.d" = [
+    0xA2001053 = [X(7), F(15), F(20)];
+]
+"fle.d" = [
+    0xA2000053 = [X(7), F(15), F(20)];
+]
+"fclass.d" = [
+    0xE2001053 = [X(7), F(15)];
+]
+"fcvt.w.d" = [
+    0xC2000053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.wu.d" = [
+    0xC2100053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.l.d" = [
+    0xC2200053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.lu.d" = [
+    0xC2300053 = [X(7), F(15), Rm(0b111)];
+]
+"fcvt.d.w" = [
+    0xD2000053 = [F(7), X(15), Rm(0b000)];
+]
+"fcvt.d.wu" = [
+    0xD2100053 = [F(7), X(15), Rm(0b000)];
+]
+"fcvt.d.l" = [
+    0xD2200053 = [F(7), X(15), Rm(0b111)];
+]
+"fcvt.d.lu" = [
+    0xD2300053 = [F(7), X(15), Rm(0b111)];
+]
+"fcvt.s.d" = [
+    0x40100053 = [F(7), F(15), Rm(0b111)];
+]
+"fcvt.d.s" = [
+    0x42000053 = [F(7), F(15), Rm(0b000)];
+]
+"fmv.x.w" = [
+    0xE0000053 = [X(7), F(15)];
+]
+"fmv.w.x" = [
+    0xF0000053 = [F(7), X(15)];
+]
+"fmv.x.d" = [
+    0xE2000053 = [X(7), F(15)];
+]
+"fmv.d.x" = [
+    0xF2000053 = [F(7), X(15)];
+]
+
+// C
+"c.addi4spn" = [
+    0x0000 = [Xc(2), Sp, Imm(CADDI4SPN)];
+]
+"c.fld" = [
+    0x2000 = [Fc(2), MemC(CLD, 7)];
+]
+"c.lw" = [
+    0x4000 = [Xc(2), MemC(CLW, 7)];
+]
+"c.ld" = [
+    0x6000 = [Xc(2), MemC(CLD, 7)];
+]
+"c.fsd" = [
+    0xA000 = [Fc(2), MemC(CLD, 7)];
+]
+"c.sw" = [
+    0xC000 = [Xc(2), MemC(CLW, 7)];
+]
+"c.sd" = [
+    0xE000 = [Xc(2), MemC(CLD, 7)];
+]
+"c.nop" = [
+    0x0001 = [];
+]
+"c.addi" = [
+    0x0001 = [X(7), Imm(CI6)];
+]
+"c.addiw" = [
+    0x2001 = [XNz(7), Imm(CI6)];
+]
+"c.li" = [
+    0x4001 = [X(7), Imm(CI6)];
+]
+"c.addi16sp" = [
+    0x6101 = [Sp, Imm(CADDI16SP)];
+]
+"c.lui" = [
+    0x6001 = [XNoSp(7), Imm(CLUI)];
+]
+"c.srli" = [
+    0x8001 = [Xc(7), Imm(CSHAMT)];
+]
+"c.srai" = [
+    0x8401 = [Xc(7), Imm(CSHAMT)];
+]
+"c.andi" = [
+    0x8801 = [Xc(7), Imm(CI6)];
+]
+"c.sub" = [
+    0x8C01 = [Xc(7), Xc(2)];
+]
+"c.xor" = [
+    0x8C21 = [Xc(7), Xc(2)];
+]
+"c.or" = [
+    0x8C41 = [Xc(7), Xc(2)];
+]
+"c.and" = [
+    0x8C61 = [Xc(7), Xc(2)];
+]
+"c.subw" = [
+    0x9C01 = [Xc(7), Xc(2)];
+]
+"c.addw" = [
+    0x9C21 = [Xc(7), Xc(2)];
+]
+"c.j" = [
+    0xA001 = [Target(CJ12, CJ)];
+]
+"c.beqz" = [
+    0xC001 = [Xc(7), Target(CB9, CB)];
+]
+"c.bnez" = [
+    0xE001 = [Xc(7), Target(CB9, CB)];
+]
+"c.slli" = [
+    0x0002 = [X(7), Imm(CSHAMT)];
+]
+"c.fldsp" = [
+    0x2002 = [F(7), MemSp(CLDSP)];
+]
+"c.lwsp" = [
+    0x4002 = [XNz(7), MemSp(CLWSP)];
+]
+"c.ldsp" = [
+    0x6002 = [XNz(7), MemSp(CLDSP)];
+]
+"c.jr" = [
+    0x8002 = [XNz(7)];
+]
+"c.mv" = [
+    0x8002 = [X(7), XNz(2)];
+]
+"c.ebreak" = [
+    0x9002 = [];
+]
+"c.jalr" = [
+    0x9002 = [XNz(7)];
+]
+"c.add" = [
+    0x9002 = [X(7), XNz(2)];
+]
+"c.fsdsp" = [
+    0xA002 = [F(2), MemSp(CSDSP)];
+]
+"c.swsp" = [
+    0xC002 = [X(2), MemSp(CSWSP)];
+]
+"c.sdsp" = [
+    0xE002 = [X(2), MemSp(CSDSP)];
+]
+
+// pseudo instructions
+"nop" = [
+    0x00000013 = [];
+]
+"li" = [
+    0x0000001B_00000037 = [Xs(&[7, 39, 47]), Li];
+]
+"la" = [
+    0x00000013_00000017 = [Xs(&[7, 39, 47]), Target(PAIR, AUIPC)];
+]
+"lla" = [
+    0x00000013_00000017 = [Xs(&[7, 39, 47]), Target(PAIR, AUIPC)];
+]
+"mv" = [
+    0x00000013 = [X(7), X(15)];
+]
+"not" = [
+    0xFFF04013 = [X(7), X(15)];
+]
+"neg" = [
+    0x40000033 = [X(7), X(20)];
+]
+"negw" = [
+    0x4000003B = [X(7), X(20)];
+]
+"sext.w" = [
+    0x0000001B = [X(7), X(15)];
+]
+"seqz" = [
+    0x00103013 = [X(7), X(15)];
+]
+"snez" = [
+    0x00003033 = [X(7), X(20)];
+]
+"sltz" = [
+    0x00002033 = [X(7), X(15)];
+]
+"sgtz" = [
+    0x00002033 = [X(7), X(20)];
+]
+"beqz" = [
+    0x00000063 = [X(15), Target(B13, BRANCH)];
+]
+"bnez" = [
+    0x00001063 = [X(15), Target(B13, BRANCH)];
+]
+"blez" = [
+    0x00005063 = [X(20), Target(B13, BRANCH)];
+]
+"bgez" = [
+    0x00005063 = [X(15), Target(B13, BRANCH)];
+]
+"bltz" = [
+    0x00004063 = [X(15), Target(B13, BRANCH)];
+]
+"bgtz" = [
+    0x00004063 = [X(20), Target(B13, BRANCH)];
+]
+"bgt" = [
+    0x00004063 = [X(20), X(15), Target(B13, BRANCH)];
+]
+"ble" = [
+    0x00005063 = [X(20), X(15), Target(B13, BRANCH)];
+]
+"bgtu" = [
+    0x00006063 = [X(20), X(15), Target(B13, BRANCH)];
+]
+"bleu" = [
+    0x00007063 = [X(20), X(15), Target(B13, BRANCH)];
+]
+"j" = [
+    0x0000006F = [Target(J21, JAL)];
+]
+"jr" = [
+    0x00000067 = [X(15)];
+    0x00000067 = [Mem(I12, 15)];
+]
+"ret" = [
+    0x00008067 = [];
+]
+"call" = [
+    0x000080E7_00000097 = [Target(PAIR, AUIPC)];
+]
+"tail" = [
+    0x00030067_00000317 = [Target(PAIR, AUIPC)];
+]
+"csrr" = [
+    0x00002073 = [X(7), Csr];
+]
+"csrw" = [
+    0x00001073 = [Csr, X(15)];
+]
+"csrs" = [
+    0x00002073 = [Csr, X(15)];
+]
+"csrc" = [
+    0x00003073 = [Csr, X(15)];
+]
+"csrwi" = [
+    0x00005073 = [Csr, Imm(UIMM5)];
+]
+"csrsi" = [
+    0x00006073 = [Csr, Imm(UIMM5)];
+]
+"csrci" = [
+    0x00007073 = [Csr, Imm(UIMM5)];
+]
+"rdcycle" = [
+    0xC0002073 = [X(7)];
+]
+"rdtime" = [
+    0xC0102073 = [X(7)];
+]
+"rdinstret" = [
+    0xC0202073 = [X(7)];
+]
+"frcsr" = [
+    0x00302073 = [X(7)];
+]
+"fscsr" = [
+    0x00301073 = [X(7), X(15)];
+    0x00301073 = [X(15)];
+]
+"frrm" = [
+    0x00202073 = [X(7)];
+]
+"fsrm" = [
+    0x00201073 = [X(7), X(15)];
+    0x00201073 = [X(15)];
+]
+"frflags" = [
+    0x00102073 = [X(7)];
+]
+"fsflags" = [
+    0x00101073 = [X(7), X(15)];
+    0x00101073 = [X(15)];
+]
+"fmv.s" = [
+    0x20000053 = [F(7), Fs(&[15, 20])];
+]
+"fneg.s" = [
+    0x20001053 = [F(7), Fs(&[15, 20])];
+]
+"fabs.s" = [
+    0x20002053 = [F(7), Fs(&[15, 20])];
+]
+"fmv.d" = [
+    0x22000053 = [F(7), Fs(&[15, 20])];
+]
+"fneg.d" = [
+    0x22001053 = [F(7), Fs(&[15, 20])];
+]
+"fabs.d" = [
+    0x22002053 = [F(7), Fs(&[15, 20])];
+]
+)
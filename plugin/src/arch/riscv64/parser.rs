@@ -0,0 +1,221 @@
+use syn::{parse, Token};
+use syn::parse::Parser;
+use quote::{quote, ToTokens};
+use proc_macro2::Delimiter;
+
+use lazy_static::lazy_static;
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt, dynamic_register};
+
+use super::Context;
+use super::ast::{Instruction, RawArg, Register, RegFamily};
+
+use std::collections::HashMap;
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op, like fcvt.d.w or amoadd.w.aqrl
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(ctx, input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(ctx, input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            name,
+            span
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<RawArg> {
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // register
+    if let Some(reg) = parse_reg(ctx, input)? {
+        return Ok(RawArg::Direct {
+            reg
+        });
+    }
+
+    // memory references are written as offset(base), which parses as a call expression,
+    // or as just (base), which parses as a parenthesized expression.
+    let value: syn::Expr = input.parse()?;
+
+    match value {
+        syn::Expr::Paren(syn::ExprParen { ref expr, .. }) => {
+            if let Some(base) = reparse_reg(ctx, &**expr) {
+                return Ok(RawArg::Reference {
+                    offset: None,
+                    base
+                });
+            }
+        },
+        syn::Expr::Call(syn::ExprCall { ref func, ref args, .. }) if args.len() == 1 => {
+            if let Some(base) = reparse_reg(ctx, &args[0]) {
+                return Ok(RawArg::Reference {
+                    offset: Some((**func).clone()),
+                    base
+                });
+            }
+        },
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), ref expr, .. }) => {
+            if let syn::Expr::Call(syn::ExprCall { ref func, ref args, .. }) = **expr {
+                if args.len() == 1 {
+                    if let Some(base) = reparse_reg(ctx, &args[0]) {
+                        let offset = syn::parse2(quote!(-#func)).expect("negated offset");
+                        return Ok(RawArg::Reference {
+                            offset: Some(offset),
+                            base
+                        });
+                    }
+                }
+            }
+        },
+        _ => ()
+    }
+
+    // immediate
+    Ok(RawArg::Immediate {
+        value
+    })
+}
+
+// tries to interpret an already parsed expression as a register
+fn reparse_reg(ctx: &mut Context, expr: &syn::Expr) -> Option<Register> {
+    let parser = |input: parse::ParseStream| parse_reg(ctx, input);
+    parser.parse2(expr.into_token_stream()).ok().and_then(|reg| reg)
+}
+
+fn parse_reg(ctx: &mut Context, input: parse::ParseStream) -> parse::Result<Option<Register>> {
+    let name = match input.step(|cursor| {
+        if let Some((ident, rest)) = cursor.ident() {
+            let mut ident = ident.to_string();
+
+            // families are only recognized when followed by the dynamic register expression
+            if RISCV64_FAMILIES.contains_key(&*ident) && rest.group(Delimiter::Parenthesis).is_some() {
+                return Ok((ident, rest));
+            }
+
+            if let Some(repl) = ctx.state.file_data.aliases.get(&ident) {
+                ident = repl.clone();
+            }
+
+            if RISCV64_REGISTERS.contains_key(&*ident) {
+                return Ok((ident, rest));
+            }
+        }
+        Err(cursor.error("expected identifier"))
+    }) {
+        Ok(name) => name,
+        Err(_) => return Ok(None)
+    };
+
+    if let Some(&(family, code)) = RISCV64_REGISTERS.get(&*name) {
+        Ok(Some(Register::new_static(family, code)))
+
+    } else if let Some(&family) = RISCV64_FAMILIES.get(&*name) {
+
+        // parse the dynamic register expression
+        let inner;
+        let _ = syn::parenthesized!(inner in input);
+        let inner = &inner;
+
+        let expr: syn::Expr = inner.parse()?;
+
+        Ok(Some(Register::new_dynamic(family, dynamic_register("riscv64", typed_register(family), "u32", &expr))))
+    } else {
+        unreachable!();
+    }
+}
+
+// The typed register in `dynasmrt::riscv64` that dynamic registers of `family` are checked against.
+fn typed_register(family: RegFamily) -> &'static str {
+    match family {
+        RegFamily::INTEGER => "X",
+        RegFamily::FLOAT   => "F",
+    }
+}
+
+lazy_static!{
+    static ref RISCV64_REGISTERS: HashMap<&'static str, (RegFamily, u8)> = {
+        use self::RegFamily::*;
+
+        static MAP: &[(&str, (RegFamily, u8))] = &[
+            ("x0", (INTEGER, 0)), ("x1", (INTEGER, 1)), ("x2", (INTEGER, 2)), ("x3", (INTEGER, 3)),
+            ("x4", (INTEGER, 4)), ("x5", (INTEGER, 5)), ("x6", (INTEGER, 6)), ("x7", (INTEGER, 7)),
+            ("x8", (INTEGER, 8)), ("x9", (INTEGER, 9)), ("x10", (INTEGER, 10)), ("x11", (INTEGER, 11)),
+            ("x12", (INTEGER, 12)), ("x13", (INTEGER, 13)), ("x14", (INTEGER, 14)), ("x15", (INTEGER, 15)),
+            ("x16", (INTEGER, 16)), ("x17", (INTEGER, 17)), ("x18", (INTEGER, 18)), ("x19", (INTEGER, 19)),
+            ("x20", (INTEGER, 20)), ("x21", (INTEGER, 21)), ("x22", (INTEGER, 22)), ("x23", (INTEGER, 23)),
+            ("x24", (INTEGER, 24)), ("x25", (INTEGER, 25)), ("x26", (INTEGER, 26)), ("x27", (INTEGER, 27)),
+            ("x28", (INTEGER, 28)), ("x29", (INTEGER, 29)), ("x30", (INTEGER, 30)), ("x31", (INTEGER, 31)),
+
+            ("zero", (INTEGER, 0)), ("ra", (INTEGER, 1)), ("sp", (INTEGER, 2)), ("gp", (INTEGER, 3)),
+            ("tp", (INTEGER, 4)), ("t0", (INTEGER, 5)), ("t1", (INTEGER, 6)), ("t2", (INTEGER, 7)),
+            ("s0", (INTEGER, 8)), ("fp", (INTEGER, 8)), ("s1", (INTEGER, 9)),
+            ("a0", (INTEGER, 10)), ("a1", (INTEGER, 11)), ("a2", (INTEGER, 12)), ("a3", (INTEGER, 13)),
+            ("a4", (INTEGER, 14)), ("a5", (INTEGER, 15)), ("a6", (INTEGER, 16)), ("a7", (INTEGER, 17)),
+            ("s2", (INTEGER, 18)), ("s3", (INTEGER, 19)), ("s4", (INTEGER, 20)), ("s5", (INTEGER, 21)),
+            ("s6", (INTEGER, 22)), ("s7", (INTEGER, 23)), ("s8", (INTEGER, 24)), ("s9", (INTEGER, 25)),
+            ("s10", (INTEGER, 26)), ("s11", (INTEGER, 27)),
+            ("t3", (INTEGER, 28)), ("t4", (INTEGER, 29)), ("t5", (INTEGER, 30)), ("t6", (INTEGER, 31)),
+
+            ("f0", (FLOAT, 0)), ("f1", (FLOAT, 1)), ("f2", (FLOAT, 2)), ("f3", (FLOAT, 3)),
+            ("f4", (FLOAT, 4)), ("f5", (FLOAT, 5)), ("f6", (FLOAT, 6)), ("f7", (FLOAT, 7)),
+            ("f8", (FLOAT, 8)), ("f9", (FLOAT, 9)), ("f10", (FLOAT, 10)), ("f11", (FLOAT, 11)),
+            ("f12", (FLOAT, 12)), ("f13", (FLOAT, 13)), ("f14", (FLOAT, 14)), ("f15", (FLOAT, 15)),
+            ("f16", (FLOAT, 16)), ("f17", (FLOAT, 17)), ("f18", (FLOAT, 18)), ("f19", (FLOAT, 19)),
+            ("f20", (FLOAT, 20)), ("f21", (FLOAT, 21)), ("f22", (FLOAT, 22)), ("f23", (FLOAT, 23)),
+            ("f24", (FLOAT, 24)), ("f25", (FLOAT, 25)), ("f26", (FLOAT, 26)), ("f27", (FLOAT, 27)),
+            ("f28", (FLOAT, 28)), ("f29", (FLOAT, 29)), ("f30", (FLOAT, 30)), ("f31", (FLOAT, 31)),
+
+            ("ft0", (FLOAT, 0)), ("ft1", (FLOAT, 1)), ("ft2", (FLOAT, 2)), ("ft3", (FLOAT, 3)),
+            ("ft4", (FLOAT, 4)), ("ft5", (FLOAT, 5)), ("ft6", (FLOAT, 6)), ("ft7", (FLOAT, 7)),
+            ("fs0", (FLOAT, 8)), ("fs1", (FLOAT, 9)),
+            ("fa0", (FLOAT, 10)), ("fa1", (FLOAT, 11)), ("fa2", (FLOAT, 12)), ("fa3", (FLOAT, 13)),
+            ("fa4", (FLOAT, 14)), ("fa5", (FLOAT, 15)), ("fa6", (FLOAT, 16)), ("fa7", (FLOAT, 17)),
+            ("fs2", (FLOAT, 18)), ("fs3", (FLOAT, 19)), ("fs4", (FLOAT, 20)), ("fs5", (FLOAT, 21)),
+            ("fs6", (FLOAT, 22)), ("fs7", (FLOAT, 23)), ("fs8", (FLOAT, 24)), ("fs9", (FLOAT, 25)),
+            ("fs10", (FLOAT, 26)), ("fs11", (FLOAT, 27)),
+            ("ft8", (FLOAT, 28)), ("ft9", (FLOAT, 29)), ("ft10", (FLOAT, 30)), ("ft11", (FLOAT, 31)),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    static ref RISCV64_FAMILIES: HashMap<&'static str, RegFamily> = {
+        static MAP: &[(&str, RegFamily)] = &[
+            ("X", RegFamily::INTEGER),
+            ("F", RegFamily::FLOAT),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
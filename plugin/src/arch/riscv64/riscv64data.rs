@@ -0,0 +1,270 @@
+use lazy_static::lazy_static;
+use std::collections::{HashMap, hash_map};
+
+/// The operands an instruction encoding accepts, and how they are encoded.
+/// Offsets are bit offsets into the encoding template.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    // an integer register
+    X(u8),
+    // an integer register other than zero
+    XNz(u8),
+    // an integer register other than sp
+    XNoSp(u8),
+    // an integer register from x8 to x15, in the 3-bit encoding used by compressed instructions
+    Xc(u8),
+    // an integer register encoded in multiple fields
+    Xs(&'static [u8]),
+    // a floating point register
+    F(u8),
+    // a floating point register from f8 to f15, in the 3-bit encoding used by compressed instructions
+    Fc(u8),
+    // a floating point register encoded in multiple fields
+    Fs(&'static [u8]),
+    // the stack pointer, which is implied by the encoding
+    Sp,
+
+    // an immediate
+    Imm(Imm),
+    // a memory reference of the form offset(base), with the base register encoded at the offset
+    Mem(Imm, u8),
+    // a memory reference of the form offset(base), with a compressed base register encoded at the offset
+    MemC(Imm, u8),
+    // a memory reference of the form offset(sp)
+    MemSp(Imm),
+    // a memory reference without offset, (base), with the base register encoded at the offset
+    Addr(u8),
+    // a relocation target, or an immediate offset
+    Target(Imm, Relocation),
+
+    // an optional rounding mode, with the mode used when it is absent
+    Rm(u8),
+    // a fence predecessor or successor set
+    Fence(u8),
+    // a control and status register, by name or by number
+    Csr,
+    // the immediate of the li pseudo instruction, which is split over a lui and addiw
+    Li,
+}
+
+/// A description of an immediate operand: its range, and how its bits are scattered over the encoding.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Imm {
+    /// If the immediate is a signed value
+    pub signed: bool,
+    /// The width of the immediate, including the implied low bits
+    pub bits: u8,
+    /// The amount of low bits that have to be zero, and are not encoded
+    pub scale: u8,
+    /// If zero is an invalid value for this immediate
+    pub nonzero: bool,
+    /// If nonzero, a signed immediate may also be given as its zero-extended representation of this many bits
+    pub unsigned_alias: u8,
+    /// A list of fields of the immediate: (lowest bit in the immediate, amount of bits, offset in the encoding)
+    pub fields: &'static [(u8, u8, u8)],
+}
+
+macro_rules! imm {
+    ($signed:expr, $bits:expr, $scale:expr, $nonzero:expr, [ $( $field:expr ),* ]) => {
+        Imm {
+            signed: $signed,
+            bits: $bits,
+            scale: $scale,
+            nonzero: $nonzero,
+            unsigned_alias: 0,
+            fields: &[ $( $field ),* ]
+        }
+    }
+}
+
+// I-type immediates
+pub const I12: Imm = imm!(true, 12, 0, false, [(0, 12, 20)]);
+// S-type immediates
+pub const S12: Imm = imm!(true, 12, 0, false, [(5, 7, 25), (0, 5, 7)]);
+// B-type offsets
+pub const B13: Imm = imm!(true, 13, 1, false, [(12, 1, 31), (5, 6, 25), (1, 4, 8), (11, 1, 7)]);
+// J-type offsets
+pub const J21: Imm = imm!(true, 21, 1, false, [(20, 1, 31), (1, 10, 21), (11, 1, 20), (12, 8, 12)]);
+// U-type immediates
+pub const U20: Imm = imm!(false, 20, 0, false, [(0, 20, 12)]);
+// shift amounts
+pub const SHAMT6: Imm = imm!(false, 6, 0, false, [(0, 6, 20)]);
+pub const SHAMT5: Imm = imm!(false, 5, 0, false, [(0, 5, 20)]);
+// immediates of csrr*i instructions
+pub const UIMM5: Imm = imm!(false, 5, 0, false, [(0, 5, 15)]);
+// the offset of an auipc pair. This is not a simple bitfield, and handled by the compiler.
+pub const PAIR: Imm = imm!(true, 32, 0, false, []);
+
+// compressed instruction immediates
+pub const CI6: Imm = imm!(true, 6, 0, false, [(5, 1, 12), (0, 5, 2)]);
+pub const CSHAMT: Imm = imm!(false, 6, 0, true, [(5, 1, 12), (0, 5, 2)]);
+pub const CLUI: Imm = Imm { unsigned_alias: 20, ..imm!(true, 6, 0, true, [(5, 1, 12), (0, 5, 2)]) };
+pub const CADDI16SP: Imm = imm!(true, 10, 4, true, [(9, 1, 12), (4, 1, 6), (6, 1, 5), (7, 2, 3), (5, 1, 2)]);
+pub const CADDI4SPN: Imm = imm!(false, 10, 2, true, [(4, 2, 11), (6, 4, 7), (2, 1, 6), (3, 1, 5)]);
+pub const CLW: Imm = imm!(false, 7, 2, false, [(3, 3, 10), (2, 1, 6), (6, 1, 5)]);
+pub const CLD: Imm = imm!(false, 8, 3, false, [(3, 3, 10), (6, 2, 5)]);
+pub const CLWSP: Imm = imm!(false, 8, 2, false, [(5, 1, 12), (2, 3, 4), (6, 2, 2)]);
+pub const CLDSP: Imm = imm!(false, 9, 3, false, [(5, 1, 12), (3, 2, 5), (6, 3, 2)]);
+pub const CSWSP: Imm = imm!(false, 8, 2, false, [(2, 4, 9), (6, 2, 7)]);
+pub const CSDSP: Imm = imm!(false, 9, 3, false, [(3, 3, 10), (6, 3, 7)]);
+pub const CJ12: Imm = imm!(true, 12, 1, false, [(11, 1, 12), (4, 1, 11), (8, 2, 9), (10, 1, 8), (6, 1, 7), (7, 1, 6), (1, 3, 3), (5, 1, 2)]);
+pub const CB9: Imm = imm!(true, 9, 1, false, [(8, 1, 12), (3, 2, 10), (6, 2, 5), (1, 2, 3), (5, 1, 2)]);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relocation {
+    // jal: 21 bits, word aligned
+    JAL = 0,
+    // beq, bne, blt, bge, bltu, bgeu: 13 bits, word aligned
+    BRANCH = 1,
+    // auipc followed by an I-type instruction: split 32 bits, byte aligned
+    AUIPC = 2,
+    // c.j: 12 bits, word aligned
+    CJ = 3,
+    // c.beqz, c.bnez: 9 bits, word aligned
+    CB = 4,
+    // 8-bit literal
+    LITERAL8 = 5,
+    // 16-bit literal
+    LITERAL16 = 6,
+    // 32-bit literal
+    LITERAL32 = 8,
+    // 64-bit literal
+    LITERAL64 = 12,
+}
+
+impl Relocation {
+    pub fn to_id(self) -> u8 {
+        self as u8
+    }
+}
+
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The base template for the encoding. For pseudo instructions that expand to two instructions,
+    /// the second instruction is stored in the upper 32 bits.
+    pub base: u64,
+    /// The operands of this instruction, in order
+    pub operands: &'static [Operand],
+}
+
+impl Opdata {
+    /// The size of the encoding in bytes. Like the hardware, this is determined from the lowest bits
+    /// of the encoding: 32-bit instructions have their lowest two bits set, compressed instructions do not.
+    pub fn size(&self) -> u8 {
+        if self.base > 0xFFFF_FFFF {
+            8
+        } else if self.base & 3 == 3 {
+            4
+        } else {
+            2
+        }
+    }
+}
+
+macro_rules! SingleOp {
+    ( $base:expr, [ $( $operand:expr ),* ] ) => {
+        {
+            const OPERANDS: &'static [Operand] = {
+                #[allow(unused_imports)]
+                use self::Operand::*;
+                &[ $(
+                    $operand
+                ),* ]
+            };
+            Opdata {
+                base: $base,
+                operands: OPERANDS,
+            }
+        }
+    }
+}
+
+macro_rules! Ops {
+    ( $( $name:tt = [ $( $base:tt = [ $( $operand:expr ),* ] ; )+ ] )* ) => {
+        [ $(
+            (
+                $name,
+                &[ $(
+                    SingleOp!( $base, [ $( $operand ),* ] )
+                ),+ ] as &[_]
+            )
+        ),* ]
+    }
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static [Opdata]> {
+    OPMAP.get(&name).cloned()
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, &'static [Opdata]> {
+    OPMAP.keys()
+}
+
+lazy_static! {
+    static ref OPMAP: HashMap<&'static str, &'static [Opdata]> = {
+        use self::Relocation::*;
+
+        static MAP: &[(&str, &[Opdata])] = &include!("opmap.rs");
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of the rounding modes of floating point instructions
+    pub static ref ROUNDING_MODE_MAP: HashMap<&'static str, u8> = {
+        static MAP: &[(&str, u8)] = &[
+            ("rne", 0b000),
+            ("rtz", 0b001),
+            ("rdn", 0b010),
+            ("rup", 0b011),
+            ("rmm", 0b100),
+            ("dyn", 0b111),
+        ];
+        MAP.iter().cloned().collect()
+    };
+
+    /// A map of the names of common control and status registers
+    pub static ref CSR_MAP: HashMap<&'static str, u16> = {
+        static MAP: &[(&str, u16)] = &[
+            // floating point
+            ("fflags", 0x001),
+            ("frm", 0x002),
+            ("fcsr", 0x003),
+            // counters
+            ("cycle", 0xC00),
+            ("time", 0xC01),
+            ("instret", 0xC02),
+            // supervisor
+            ("sstatus", 0x100),
+            ("sie", 0x104),
+            ("stvec", 0x105),
+            ("scounteren", 0x106),
+            ("sscratch", 0x140),
+            ("sepc", 0x141),
+            ("scause", 0x142),
+            ("stval", 0x143),
+            ("sip", 0x144),
+            ("satp", 0x180),
+            // machine
+            ("mstatus", 0x300),
+            ("misa", 0x301),
+            ("medeleg", 0x302),
+            ("mideleg", 0x303),
+            ("mie", 0x304),
+            ("mtvec", 0x305),
+            ("mcounteren", 0x306),
+            ("mscratch", 0x340),
+            ("mepc", 0x341),
+            ("mcause", 0x342),
+            ("mtval", 0x343),
+            ("mip", 0x344),
+            ("mcycle", 0xB00),
+            ("minstret", 0xB02),
+            ("mvendorid", 0xF11),
+            ("marchid", 0xF12),
+            ("mimpid", 0xF13),
+            ("mhartid", 0xF14),
+        ];
+        MAP.iter().cloned().collect()
+    };
+}
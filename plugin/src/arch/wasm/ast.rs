@@ -0,0 +1,28 @@
+use syn;
+use proc_macro2::Span;
+
+use crate::common::Jump;
+
+/**
+ * Parsed ast
+ */
+
+/// An instruction, with the full dot-separated mnemonic
+#[derive(Debug)]
+pub struct Instruction {
+    pub name: String,
+    pub span: Span
+}
+
+/// The possible arguments of a wasm instruction
+#[derive(Debug)]
+pub enum RawArg {
+    // A label naming a structured control instruction
+    JumpTarget {
+        jump: Jump
+    },
+    // An immediate, or a value type
+    Immediate {
+        value: syn::Expr
+    }
+}
@@ -0,0 +1,321 @@
+use super::wasmdata::{get_mnemonic_data, Format};
+use super::Context;
+use super::ast::{Instruction, RawArg};
+
+use crate::common::{Stmt, Size, Jump, JumpKind, delimited};
+use crate::parse_helpers::{as_ident, as_number, as_signed_number, as_float};
+use crate::serialize::expr_string_from_ident;
+
+use syn::spanned::Spanned;
+use quote::{quote, quote_spanned};
+use proc_macro2::TokenStream;
+use proc_macro_error::emit_error;
+
+// the block type of a construct without parameters and results
+const EMPTY_BLOCKTYPE: u8 = 0x40;
+
+pub(super) fn compile_instruction(ctx: &mut Context, instruction: &Instruction, args: Vec<RawArg>) -> Result<(), Option<String>> {
+    let name = &instruction.name;
+    let data = if let Some(data) = get_mnemonic_data(name) {
+        data
+    } else {
+        return Err(Some(format!("Unknown instruction mnemonic '{}'", name)));
+    };
+
+    let target = ctx.state.target.clone();
+    let stmts = &mut *ctx.state.stmts;
+
+    stmts.push(Stmt::Extend(data.opcode.to_vec()));
+
+    match data.format {
+        Format::Plain => {
+            check_arg_count(name, &args, 0, 0)?;
+        },
+        Format::Block => {
+            check_arg_count(name, &args, 0, 2)?;
+            let mut args = args.into_iter().peekable();
+
+            // the optional label naming this construct
+            let label = if let Some(RawArg::JumpTarget { .. }) = args.peek() {
+                match args.next() {
+                    Some(RawArg::JumpTarget { jump }) => control_label(jump)?,
+                    _ => unreachable!()
+                }
+            } else {
+                quote!(dynasmrt::wasm::ControlLabel::Anonymous)
+            };
+
+            // the optional block type
+            match args.next() {
+                None => stmts.push(Stmt::u8(EMPTY_BLOCKTYPE)),
+                Some(arg) => {
+                    let value = immediate(arg)?;
+                    encode_blocktype(stmts, value)?;
+                }
+            }
+            if args.next().is_some() {
+                return Err(Some(format!("'{}': expected an optional label followed by an optional block type", name)));
+            }
+
+            stmts.push(Stmt::Stmt(delimited(quote!{ #target.enter_block(#label) })));
+        },
+        Format::End => {
+            check_arg_count(name, &args, 0, 0)?;
+            stmts.push(Stmt::Stmt(delimited(quote!{ #target.exit_block() })));
+        },
+        Format::Branch => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                encode_branch_target(stmts, &target, arg)?;
+            }
+        },
+        Format::BranchTable => {
+            if args.is_empty() {
+                return Err(Some(format!("'{}': expected at least a default target", name)));
+            }
+            stmts.push(Stmt::Extend(uleb128(args.len() as u64 - 1)));
+            for arg in args {
+                encode_branch_target(stmts, &target, arg)?;
+            }
+        },
+        Format::Index => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                encode_index(stmts, immediate(arg)?)?;
+            }
+        },
+        Format::CallIndirect => {
+            check_arg_count(name, &args, 1, 2)?;
+            let has_table = args.len() == 2;
+            for arg in args {
+                encode_index(stmts, immediate(arg)?)?;
+            }
+            if !has_table {
+                stmts.push(Stmt::u8(0));
+            }
+        },
+        Format::I32 => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                let value = immediate(arg)?;
+                if let Some(number) = as_signed_number(&value) {
+                    if number < i64::from(i32::min_value()) || number > i64::from(u32::max_value()) {
+                        emit_error!(value.span(), "Immediate too large for a 32-bit integer");
+                        return Err(None);
+                    }
+                    stmts.push(Stmt::Extend(sleb128(i64::from(number as i32))));
+                } else {
+                    stmts.push(Stmt::ExprExtend(delimited(quote_spanned!{ value.span()=>
+                        dynasmrt::wasm::sleb128(i64::from((#value) as i32))
+                    })));
+                }
+            }
+        },
+        Format::I64 => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                let value = immediate(arg)?;
+                if let Some(number) = as_signed_number(&value) {
+                    stmts.push(Stmt::Extend(sleb128(number)));
+                } else if let Some(number) = as_number(&value) {
+                    stmts.push(Stmt::Extend(sleb128(number as i64)));
+                } else {
+                    stmts.push(Stmt::ExprExtend(delimited(quote_spanned!{ value.span()=>
+                        dynasmrt::wasm::sleb128((#value) as i64)
+                    })));
+                }
+            }
+        },
+        Format::F32 => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                let value = immediate(arg)?;
+                if let Some(number) = as_float(&value) {
+                    stmts.push(Stmt::u32((number as f32).to_bits()));
+                } else {
+                    stmts.push(Stmt::ExprUnsigned(delimited(quote_spanned!{ value.span()=>
+                        f32::to_bits((#value) as f32)
+                    }), Size::DWORD));
+                }
+            }
+        },
+        Format::F64 => {
+            check_arg_count(name, &args, 1, 1)?;
+            for arg in args {
+                let value = immediate(arg)?;
+                if let Some(number) = as_float(&value) {
+                    stmts.push(Stmt::u64(number.to_bits()));
+                } else {
+                    stmts.push(Stmt::ExprUnsigned(delimited(quote_spanned!{ value.span()=>
+                        f64::to_bits((#value) as f64)
+                    }), Size::QWORD));
+                }
+            }
+        },
+        Format::Mem(natural) => {
+            check_arg_count(name, &args, 0, 2)?;
+            let mut args = args.into_iter();
+            let offset = args.next().map(immediate).transpose()?;
+            let align = args.next().map(immediate).transpose()?;
+
+            // the alignment is given in bytes, but encoded as its log2
+            let align = if let Some(align) = align {
+                match as_number(&align) {
+                    Some(value) if value.is_power_of_two() && value <= 1 << natural => value.trailing_zeros() as u8,
+                    Some(_) => {
+                        emit_error!(align.span(), "The alignment of this memory access has to be a power of two no larger than {}", 1 << natural);
+                        return Err(None);
+                    },
+                    None => {
+                        emit_error!(align.span(), "The alignment of a memory access has to be a constant");
+                        return Err(None);
+                    }
+                }
+            } else {
+                natural
+            };
+            stmts.push(Stmt::u8(align));
+
+            if let Some(offset) = offset {
+                encode_index(stmts, offset)?;
+            } else {
+                stmts.push(Stmt::u8(0));
+            }
+        },
+    }
+
+    Ok(())
+}
+
+fn check_arg_count(name: &str, args: &[RawArg], min: usize, max: usize) -> Result<(), Option<String>> {
+    if args.len() < min || args.len() > max {
+        Err(Some(if min == max {
+            format!("'{}': expected {} arguments, found {}", name, min, args.len())
+        } else {
+            format!("'{}': expected {} to {} arguments, found {}", name, min, max, args.len())
+        }))
+    } else {
+        Ok(())
+    }
+}
+
+/// Unwraps an immediate argument, erroring if a label was given
+fn immediate(arg: RawArg) -> Result<syn::Expr, Option<String>> {
+    match arg {
+        RawArg::Immediate { value } => Ok(value),
+        RawArg::JumpTarget { jump } => {
+            emit_error!(jump.span(), "Expected an immediate, found a label");
+            Err(None)
+        }
+    }
+}
+
+/// Converts a label naming a construct to a `dynasmrt::wasm::ControlLabel` expression
+fn control_label(jump: Jump) -> Result<TokenStream, Option<String>> {
+    let span = jump.span();
+
+    if let Some(offset) = jump.offset {
+        emit_error!(offset.span(), "Labels cannot have an offset in wasm");
+        return Err(None);
+    }
+
+    match jump.kind {
+        JumpKind::Global(ident) => {
+            let name = expr_string_from_ident(&ident);
+            Ok(quote_spanned!{ span=> dynasmrt::wasm::ControlLabel::Global(#name) })
+        },
+        JumpKind::Dynamic(expr) => Ok(quote_spanned!{ span=> dynasmrt::wasm::ControlLabel::Dynamic(#expr) }),
+        _ => {
+            emit_error!(span, "Only global and dynamic labels can name a construct in wasm");
+            Err(None)
+        }
+    }
+}
+
+/// Encodes a block type: empty, a value type or a type index
+fn encode_blocktype(stmts: &mut Vec<Stmt>, value: syn::Expr) -> Result<(), Option<String>> {
+    if let Some(ident) = as_ident(&value) {
+        let valtype = match ident.to_string().as_str() {
+            "i32" => Some(0x7F),
+            "i64" => Some(0x7E),
+            "f32" => Some(0x7D),
+            "f64" => Some(0x7C),
+            "v128" => Some(0x7B),
+            "funcref" => Some(0x70),
+            "externref" => Some(0x6F),
+            _ => None
+        };
+        if let Some(valtype) = valtype {
+            stmts.push(Stmt::u8(valtype));
+            return Ok(());
+        }
+    }
+
+    // a type index, encoded as a positive signed 33-bit integer
+    if let Some(number) = as_number(&value) {
+        if number > u64::from(u32::max_value()) {
+            emit_error!(value.span(), "Type index too large");
+            return Err(None);
+        }
+        stmts.push(Stmt::Extend(sleb128(number as i64)));
+    } else {
+        stmts.push(Stmt::ExprExtend(delimited(quote_spanned!{ value.span()=>
+            dynasmrt::wasm::sleb128(i64::from((#value) as u32))
+        })));
+    }
+    Ok(())
+}
+
+/// Encodes a branch target, either as an immediate depth or as the label of an enclosing construct
+fn encode_branch_target(stmts: &mut Vec<Stmt>, target: &proc_macro2::TokenTree, arg: RawArg) -> Result<(), Option<String>> {
+    match arg {
+        RawArg::JumpTarget { jump } => {
+            let label = control_label(jump)?;
+            stmts.push(Stmt::Stmt(delimited(quote!{ #target.push_depth(#label) })));
+            Ok(())
+        },
+        RawArg::Immediate { value } => encode_index(stmts, value)
+    }
+}
+
+/// Encodes an unsigned 32-bit immediate
+fn encode_index(stmts: &mut Vec<Stmt>, value: syn::Expr) -> Result<(), Option<String>> {
+    if let Some(number) = as_number(&value) {
+        if number > u64::from(u32::max_value()) {
+            emit_error!(value.span(), "Immediate too large for a 32-bit integer");
+            return Err(None);
+        }
+        stmts.push(Stmt::Extend(uleb128(number)));
+    } else {
+        stmts.push(Stmt::ExprExtend(delimited(quote_spanned!{ value.span()=>
+            dynasmrt::wasm::uleb128(u64::from((#value) as u32))
+        })));
+    }
+    Ok(())
+}
+
+fn uleb128(mut value: u64) -> Vec<u8> {
+    let mut res = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            res.push(byte);
+            return res;
+        }
+        res.push(byte | 0x80);
+    }
+}
+
+fn sleb128(mut value: i64) -> Vec<u8> {
+    let mut res = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            res.push(byte);
+            return res;
+        }
+        res.push(byte | 0x80);
+    }
+}
@@ -0,0 +1,67 @@
+use syn::parse;
+use proc_macro_error::emit_error;
+
+mod ast;
+mod parser;
+mod compiler;
+mod wasmdata;
+
+use crate::State;
+use crate::common::{Size, Stmt, Jump};
+use crate::arch::Arch;
+
+struct Context<'a, 'b: 'a> {
+    pub state: &'a mut State<'b>
+}
+
+#[derive(Clone, Debug)]
+pub struct ArchWasm {
+
+}
+
+impl Default for ArchWasm {
+    fn default() -> ArchWasm {
+        ArchWasm { }
+    }
+}
+
+impl Arch for ArchWasm {
+    fn name(&self) -> &str {
+        "wasm"
+    }
+
+    fn set_features(&mut self, features: &[syn::Ident]) {
+        if let Some(feature) = features.first() {
+            emit_error!(feature, "Arch wasm has no known features");
+        }
+    }
+
+    fn handle_static_reloc(&self, _stmts: &mut Vec<Stmt>, reloc: Jump, _size: Size) {
+        let span = reloc.span();
+        emit_error!(span, "Relocations are not supported in wasm, as code cannot refer to its own addresses");
+    }
+
+    fn default_align(&self) -> u8 {
+        0
+    }
+
+    fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
+        let mut ctx = Context {
+            state
+        };
+
+        let (instruction, args) = parser::parse_instruction(input)?;
+        let span = instruction.span;
+
+        match compiler::compile_instruction(&mut ctx, &instruction, args) {
+            Err(None) => return Ok(()),
+            Err(Some(e)) => {
+                emit_error!(span, e);
+                return Ok(())
+            }
+            Ok(()) => ()
+        }
+
+        Ok(())
+    }
+}
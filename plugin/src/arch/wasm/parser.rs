@@ -0,0 +1,58 @@
+use syn::{parse, Token};
+
+use crate::parse_helpers::{parse_ident_or_rust_keyword, ParseOptExt};
+
+use super::ast::{Instruction, RawArg};
+
+// parses a full instruction
+// syntax for a single op: ident ("." ident)* (arg ("," arg)*)? ";"
+pub(super) fn parse_instruction(input: parse::ParseStream) -> parse::Result<(Instruction, Vec<RawArg>)> {
+    let span = input.cursor().span();
+
+    // read the full dot-separated op, like i32.const or local.get
+    let mut name = parse_ident_or_rust_keyword(input)?.to_string();
+
+    while input.peek(Token![.]) {
+        let _: Token![.] = input.parse()?;
+        let part = parse_ident_or_rust_keyword(input)?;
+
+        name.push('.');
+        name.push_str(&part.to_string());
+    }
+
+    let mut args = Vec::new();
+
+    // parse 0 or more comma-separated args
+    if !(input.is_empty() || input.peek(Token![;])) {
+        args.push(parse_arg(input)?);
+
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+
+            args.push(parse_arg(input)?);
+        }
+    }
+
+    Ok((
+        Instruction {
+            name,
+            span
+        },
+        args
+    ))
+}
+
+/// tries to parse a full arg definition
+fn parse_arg(input: parse::ParseStream) -> parse::Result<RawArg> {
+    // a label
+    if let Some(jump) = input.parse_opt()? {
+        return Ok(RawArg::JumpTarget {
+            jump
+        });
+    }
+
+    // immediate
+    Ok(RawArg::Immediate {
+        value: input.parse()?
+    })
+}
@@ -0,0 +1,243 @@
+use lazy_static::lazy_static;
+use std::collections::{HashMap, hash_map};
+
+/// The immediates an instruction takes after its opcode, and how they are encoded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    // no immediates
+    Plain,
+    // a block type, optionally preceded by a label naming the construct
+    Block,
+    // closes the innermost construct
+    End,
+    // a branch depth, or the label of an enclosing construct
+    Branch,
+    // a list of branch depths or labels, the last one being the default target
+    BranchTable,
+    // an unsigned LEB128 index
+    Index,
+    // a type index followed by an optional table index
+    CallIndirect,
+    // a signed LEB128 32-bit integer
+    I32,
+    // a signed LEB128 64-bit integer
+    I64,
+    // a little-endian 32-bit float
+    F32,
+    // a little-endian 64-bit float
+    F64,
+    // a memory argument: an optional offset and an optional alignment. Contains the log2 of the natural alignment
+    Mem(u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Opdata {
+    /// The opcode, including the prefix and its subopcode for prefixed instructions
+    pub opcode: &'static [u8],
+    /// The immediates of the instruction
+    pub format: Format,
+}
+
+pub fn get_mnemonic_data(name: &str) -> Option<&'static Opdata> {
+    OPMAP.get(name)
+}
+
+#[allow(dead_code)]
+pub fn mnemnonics() -> hash_map::Keys<'static, &'static str, Opdata> {
+    OPMAP.keys()
+}
+
+lazy_static! {
+    static ref OPMAP: HashMap<&'static str, Opdata> = {
+        use self::Format::*;
+
+        static MAP: &[(&str, &[u8], Format)] = &[
+    ("unreachable",          &[0x00],       Plain),
+    ("nop",                  &[0x01],       Plain),
+    ("block",                &[0x02],       Block),
+    ("loop",                 &[0x03],       Block),
+    ("if",                   &[0x04],       Block),
+    ("else",                 &[0x05],       Plain),
+    ("end",                  &[0x0B],       End),
+    ("br",                   &[0x0C],       Branch),
+    ("br_if",                &[0x0D],       Branch),
+    ("br_table",             &[0x0E],       BranchTable),
+    ("return",               &[0x0F],       Plain),
+    ("call",                 &[0x10],       Index),
+    ("call_indirect",        &[0x11],       CallIndirect),
+    ("drop",                 &[0x1A],       Plain),
+    ("select",               &[0x1B],       Plain),
+    ("local.get",            &[0x20],       Index),
+    ("local.set",            &[0x21],       Index),
+    ("local.tee",            &[0x22],       Index),
+    ("global.get",           &[0x23],       Index),
+    ("global.set",           &[0x24],       Index),
+    ("i32.load",             &[0x28],       Mem(2)),
+    ("i64.load",             &[0x29],       Mem(3)),
+    ("f32.load",             &[0x2A],       Mem(2)),
+    ("f64.load",             &[0x2B],       Mem(3)),
+    ("i32.load8_s",          &[0x2C],       Mem(0)),
+    ("i32.load8_u",          &[0x2D],       Mem(0)),
+    ("i32.load16_s",         &[0x2E],       Mem(1)),
+    ("i32.load16_u",         &[0x2F],       Mem(1)),
+    ("i64.load8_s",          &[0x30],       Mem(0)),
+    ("i64.load8_u",          &[0x31],       Mem(0)),
+    ("i64.load16_s",         &[0x32],       Mem(1)),
+    ("i64.load16_u",         &[0x33],       Mem(1)),
+    ("i64.load32_s",         &[0x34],       Mem(2)),
+    ("i64.load32_u",         &[0x35],       Mem(2)),
+    ("i32.store",            &[0x36],       Mem(2)),
+    ("i64.store",            &[0x37],       Mem(3)),
+    ("f32.store",            &[0x38],       Mem(2)),
+    ("f64.store",            &[0x39],       Mem(3)),
+    ("i32.store8",           &[0x3A],       Mem(0)),
+    ("i32.store16",          &[0x3B],       Mem(1)),
+    ("i64.store8",           &[0x3C],       Mem(0)),
+    ("i64.store16",          &[0x3D],       Mem(1)),
+    ("i64.store32",          &[0x3E],       Mem(2)),
+    ("memory.size",          &[0x3F, 0x00], Plain),
+    ("memory.grow",          &[0x40, 0x00], Plain),
+    ("i32.const",            &[0x41],       I32),
+    ("i64.const",            &[0x42],       I64),
+    ("f32.const",            &[0x43],       F32),
+    ("f64.const",            &[0x44],       F64),
+    ("i32.eqz",              &[0x45],       Plain),
+    ("i32.eq",               &[0x46],       Plain),
+    ("i32.ne",               &[0x47],       Plain),
+    ("i32.lt_s",             &[0x48],       Plain),
+    ("i32.lt_u",             &[0x49],       Plain),
+    ("i32.gt_s",             &[0x4A],       Plain),
+    ("i32.gt_u",             &[0x4B],       Plain),
+    ("i32.le_s",             &[0x4C],       Plain),
+    ("i32.le_u",             &[0x4D],       Plain),
+    ("i32.ge_s",             &[0x4E],       Plain),
+    ("i32.ge_u",             &[0x4F],       Plain),
+    ("i64.eqz",              &[0x50],       Plain),
+    ("i64.eq",               &[0x51],       Plain),
+    ("i64.ne",               &[0x52],       Plain),
+    ("i64.lt_s",             &[0x53],       Plain),
+    ("i64.lt_u",             &[0x54],       Plain),
+    ("i64.gt_s",             &[0x55],       Plain),
+    ("i64.gt_u",             &[0x56],       Plain),
+    ("i64.le_s",             &[0x57],       Plain),
+    ("i64.le_u",             &[0x58],       Plain),
+    ("i64.ge_s",             &[0x59],       Plain),
+    ("i64.ge_u",             &[0x5A],       Plain),
+    ("f32.eq",               &[0x5B],       Plain),
+    ("f32.ne",               &[0x5C],       Plain),
+    ("f32.lt",               &[0x5D],       Plain),
+    ("f32.gt",               &[0x5E],       Plain),
+    ("f32.le",               &[0x5F],       Plain),
+    ("f32.ge",               &[0x60],       Plain),
+    ("f64.eq",               &[0x61],       Plain),
+    ("f64.ne",               &[0x62],       Plain),
+    ("f64.lt",               &[0x63],       Plain),
+    ("f64.gt",               &[0x64],       Plain),
+    ("f64.le",               &[0x65],       Plain),
+    ("f64.ge",               &[0x66],       Plain),
+    ("i32.clz",              &[0x67],       Plain),
+    ("i32.ctz",              &[0x68],       Plain),
+    ("i32.popcnt",           &[0x69],       Plain),
+    ("i32.add",              &[0x6A],       Plain),
+    ("i32.sub",              &[0x6B],       Plain),
+    ("i32.mul",              &[0x6C],       Plain),
+    ("i32.div_s",            &[0x6D],       Plain),
+    ("i32.div_u",            &[0x6E],       Plain),
+    ("i32.rem_s",            &[0x6F],       Plain),
+    ("i32.rem_u",            &[0x70],       Plain),
+    ("i32.and",              &[0x71],       Plain),
+    ("i32.or",               &[0x72],       Plain),
+    ("i32.xor",              &[0x73],       Plain),
+    ("i32.shl",              &[0x74],       Plain),
+    ("i32.shr_s",            &[0x75],       Plain),
+    ("i32.shr_u",            &[0x76],       Plain),
+    ("i32.rotl",             &[0x77],       Plain),
+    ("i32.rotr",             &[0x78],       Plain),
+    ("i64.clz",              &[0x79],       Plain),
+    ("i64.ctz",              &[0x7A],       Plain),
+    ("i64.popcnt",           &[0x7B],       Plain),
+    ("i64.add",              &[0x7C],       Plain),
+    ("i64.sub",              &[0x7D],       Plain),
+    ("i64.mul",              &[0x7E],       Plain),
+    ("i64.div_s",            &[0x7F],       Plain),
+    ("i64.div_u",            &[0x80],       Plain),
+    ("i64.rem_s",            &[0x81],       Plain),
+    ("i64.rem_u",            &[0x82],       Plain),
+    ("i64.and",              &[0x83],       Plain),
+    ("i64.or",               &[0x84],       Plain),
+    ("i64.xor",              &[0x85],       Plain),
+    ("i64.shl",              &[0x86],       Plain),
+    ("i64.shr_s",            &[0x87],       Plain),
+    ("i64.shr_u",            &[0x88],       Plain),
+    ("i64.rotl",             &[0x89],       Plain),
+    ("i64.rotr",             &[0x8A],       Plain),
+    ("f32.abs",              &[0x8B],       Plain),
+    ("f32.neg",              &[0x8C],       Plain),
+    ("f32.ceil",             &[0x8D],       Plain),
+    ("f32.floor",            &[0x8E],       Plain),
+    ("f32.trunc",            &[0x8F],       Plain),
+    ("f32.nearest",          &[0x90],       Plain),
+    ("f32.sqrt",             &[0x91],       Plain),
+    ("f32.add",              &[0x92],       Plain),
+    ("f32.sub",              &[0x93],       Plain),
+    ("f32.mul",              &[0x94],       Plain),
+    ("f32.div",              &[0x95],       Plain),
+    ("f32.min",              &[0x96],       Plain),
+    ("f32.max",              &[0x97],       Plain),
+    ("f32.copysign",         &[0x98],       Plain),
+    ("f64.abs",              &[0x99],       Plain),
+    ("f64.neg",              &[0x9A],       Plain),
+    ("f64.ceil",             &[0x9B],       Plain),
+    ("f64.floor",            &[0x9C],       Plain),
+    ("f64.trunc",            &[0x9D],       Plain),
+    ("f64.nearest",          &[0x9E],       Plain),
+    ("f64.sqrt",             &[0x9F],       Plain),
+    ("f64.add",              &[0xA0],       Plain),
+    ("f64.sub",              &[0xA1],       Plain),
+    ("f64.mul",              &[0xA2],       Plain),
+    ("f64.div",              &[0xA3],       Plain),
+    ("f64.min",              &[0xA4],       Plain),
+    ("f64.max",              &[0xA5],       Plain),
+    ("f64.copysign",         &[0xA6],       Plain),
+    ("i32.wrap_i64",         &[0xA7],       Plain),
+    ("i32.trunc_f32_s",      &[0xA8],       Plain),
+    ("i32.trunc_f32_u",      &[0xA9],       Plain),
+    ("i32.trunc_f64_s",      &[0xAA],       Plain),
+    ("i32.trunc_f64_u",      &[0xAB],       Plain),
+    ("i64.extend_i32_s",     &[0xAC],       Plain),
+    ("i64.extend_i32_u",     &[0xAD],       Plain),
+    ("i64.trunc_f32_s",      &[0xAE],       Plain),
+    ("i64.trunc_f32_u",      &[0xAF],       Plain),
+    ("i64.trunc_f64_s",      &[0xB0],       Plain),
+    ("i64.trunc_f64_u",      &[0xB1],       Plain),
+    ("f32.convert_i32_s",    &[0xB2],       Plain),
+    ("f32.convert_i32_u",    &[0xB3],       Plain),
+    ("f32.convert_i64_s",    &[0xB4],       Plain),
+    ("f32.convert_i64_u",    &[0xB5],       Plain),
+    ("f32.demote_f64",       &[0xB6],       Plain),
+    ("f64.convert_i32_s",    &[0xB7],       Plain),
+    ("f64.convert_i32_u",    &[0xB8],       Plain),
+    ("f64.convert_i64_s",    &[0xB9],       Plain),
+    ("f64.convert_i64_u",    &[0xBA],       Plain),
+    ("f64.promote_f32",      &[0xBB],       Plain),
+    ("i32.reinterpret_f32",  &[0xBC],       Plain),
+    ("i64.reinterpret_f64",  &[0xBD],       Plain),
+    ("f32.reinterpret_i32",  &[0xBE],       Plain),
+    ("f64.reinterpret_i64",  &[0xBF],       Plain),
+    ("i32.extend8_s",        &[0xC0],       Plain),
+    ("i32.extend16_s",       &[0xC1],       Plain),
+    ("i64.extend8_s",        &[0xC2],       Plain),
+    ("i64.extend16_s",       &[0xC3],       Plain),
+    ("i64.extend32_s",       &[0xC4],       Plain),
+    ("i32.trunc_sat_f32_s",  &[0xFC, 0x00], Plain),
+    ("i32.trunc_sat_f32_u",  &[0xFC, 0x01], Plain),
+    ("i32.trunc_sat_f64_s",  &[0xFC, 0x02], Plain),
+    ("i32.trunc_sat_f64_u",  &[0xFC, 0x03], Plain),
+    ("i64.trunc_sat_f32_s",  &[0xFC, 0x04], Plain),
+    ("i64.trunc_sat_f32_u",  &[0xFC, 0x05], Plain),
+    ("i64.trunc_sat_f64_s",  &[0xFC, 0x06], Plain),
+    ("i64.trunc_sat_f64_u",  &[0xFC, 0x07], Plain),
+        ];
+        MAP.iter().map(|&(name, opcode, format)| (name, Opdata { opcode, format })).collect()
+    };
+}
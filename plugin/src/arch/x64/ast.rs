@@ -31,49 +31,57 @@ pub enum RegKind {
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
 pub enum RegId {
     // size: 1, 2, 4 or 8 bytes
-    RAX = 0x00, RCX = 0x01, RDX = 0x02, RBX = 0x03,
-    RSP = 0x04, RBP = 0x05, RSI = 0x06, RDI = 0x07,
-    R8  = 0x08, R9  = 0x09, R10 = 0x0A, R11 = 0x0B,
-    R12 = 0x0C, R13 = 0x0D, R14 = 0x0E, R15 = 0x0F,
+    RAX = 0x000, RCX = 0x001, RDX = 0x002, RBX = 0x003,
+    RSP = 0x004, RBP = 0x005, RSI = 0x006, RDI = 0x007,
+    R8  = 0x008, R9  = 0x009, R10 = 0x00A, R11 = 0x00B,
+    R12 = 0x00C, R13 = 0x00D, R14 = 0x00E, R15 = 0x00F,
+    R16 = 0x010, R17 = 0x011, R18 = 0x012, R19 = 0x013,
+    R20 = 0x014, R21 = 0x015, R22 = 0x016, R23 = 0x017,
+    R24 = 0x018, R25 = 0x019, R26 = 0x01A, R27 = 0x01B,
+    R28 = 0x01C, R29 = 0x01D, R30 = 0x01E, R31 = 0x01F,
 
     // size: 4 or 8 bytes
-    RIP = 0x15,
+    RIP = 0x105,
 
     // size: 1 byte
-    AH = 0x24, CH = 0x25, DH = 0x26, BH = 0x27,
+    AH = 0x204, CH = 0x205, DH = 0x206, BH = 0x207,
 
     // size: 10 bytes
-    ST0 = 0x30, ST1 = 0x31, ST2 = 0x32, ST3 = 0x33,
-    ST4 = 0x34, ST5 = 0x35, ST6 = 0x36, ST7 = 0x37,
+    ST0 = 0x300, ST1 = 0x301, ST2 = 0x302, ST3 = 0x303,
+    ST4 = 0x304, ST5 = 0x305, ST6 = 0x306, ST7 = 0x307,
 
     // size: 8 bytes. alternative encoding exists
-    MMX0 = 0x40, MMX1 = 0x41, MMX2 = 0x42, MMX3 = 0x43,
-    MMX4 = 0x44, MMX5 = 0x45, MMX6 = 0x46, MMX7 = 0x47,
+    MMX0 = 0x400, MMX1 = 0x401, MMX2 = 0x402, MMX3 = 0x403,
+    MMX4 = 0x404, MMX5 = 0x405, MMX6 = 0x406, MMX7 = 0x407,
 
     // size: 16 bytes or 32 bytes
-    XMM0  = 0x50, XMM1  = 0x51, XMM2  = 0x52, XMM3  = 0x53,
-    XMM4  = 0x54, XMM5  = 0x55, XMM6  = 0x56, XMM7  = 0x57,
-    XMM8  = 0x58, XMM9  = 0x59, XMM10 = 0x5A, XMM11 = 0x5B,
-    XMM12 = 0x5C, XMM13 = 0x5D, XMM14 = 0x5E, XMM15 = 0x5F,
+    XMM0  = 0x500, XMM1  = 0x501, XMM2  = 0x502, XMM3  = 0x503,
+    XMM4  = 0x504, XMM5  = 0x505, XMM6  = 0x506, XMM7  = 0x507,
+    XMM8  = 0x508, XMM9  = 0x509, XMM10 = 0x50A, XMM11 = 0x50B,
+    XMM12 = 0x50C, XMM13 = 0x50D, XMM14 = 0x50E, XMM15 = 0x50F,
 
     // size: 2 bytes. alternative encoding exists
-    ES = 0x60, CS = 0x61, SS = 0x62, DS = 0x63,
-    FS = 0x64, GS = 0x65,
+    ES = 0x600, CS = 0x601, SS = 0x602, DS = 0x603,
+    FS = 0x604, GS = 0x605,
 
     // size: 4 bytes
-    CR0  = 0x70, CR1  = 0x71, CR2  = 0x72, CR3  = 0x73,
-    CR4  = 0x74, CR5  = 0x75, CR6  = 0x76, CR7  = 0x77,
-    CR8  = 0x78, CR9  = 0x79, CR10 = 0x7A, CR11 = 0x7B,
-    CR12 = 0x7C, CR13 = 0x7D, CR14 = 0x7E, CR15 = 0x7F,
+    CR0  = 0x700, CR1  = 0x701, CR2  = 0x702, CR3  = 0x703,
+    CR4  = 0x704, CR5  = 0x705, CR6  = 0x706, CR7  = 0x707,
+    CR8  = 0x708, CR9  = 0x709, CR10 = 0x70A, CR11 = 0x70B,
+    CR12 = 0x70C, CR13 = 0x70D, CR14 = 0x70E, CR15 = 0x70F,
 
     // size: 4 bytes
-    DR0  = 0x80, DR1  = 0x81, DR2  = 0x82, DR3  = 0x83,
-    DR4  = 0x84, DR5  = 0x85, DR6  = 0x86, DR7  = 0x87,
-    DR8  = 0x88, DR9  = 0x89, DR10 = 0x8A, DR11 = 0x8B,
-    DR12 = 0x8C, DR13 = 0x8D, DR14 = 0x8E, DR15 = 0x8F,
+    DR0  = 0x800, DR1  = 0x801, DR2  = 0x802, DR3  = 0x803,
+    DR4  = 0x804, DR5  = 0x805, DR6  = 0x806, DR7  = 0x807,
+    DR8  = 0x808, DR9  = 0x809, DR10 = 0x80A, DR11 = 0x80B,
+    DR12 = 0x80C, DR13 = 0x80D, DR14 = 0x80E, DR15 = 0x80F,
 
     // size: 16 bytes
-    BND0 = 0x90, BND1 = 0x91, BND2 = 0x92, BND3 = 0x93
+    BND0 = 0x900, BND1 = 0x901, BND2 = 0x902, BND3 = 0x903,
+
+    // size: none, the tiles are matched without checking their size
+    TMM0 = 0xA00, TMM1 = 0xA01, TMM2 = 0xA02, TMM3 = 0xA03,
+    TMM4 = 0xA04, TMM5 = 0xA05, TMM6 = 0xA06, TMM7 = 0xA07
 }
 
 #[derive(Debug, PartialOrd, PartialEq, Ord, Eq, Hash, Clone, Copy)]
@@ -87,7 +95,8 @@ pub enum RegFamily {
     SEGMENT = 6,
     CONTROL = 7,
     DEBUG = 8,
-    BOUND = 9
+    BOUND = 9,
+    TILE = 10
 }
 
 impl Register {
@@ -136,6 +145,11 @@ impl RegKind {
         }
     }
 
+    // the APX extended general purpose registers r16-r31. dynamic registers are not known to be extended.
+    pub fn is_apx_extended(&self) -> bool {
+        self.family() == RegFamily::LEGACY && self.code().unwrap_or(0) > 15
+    }
+
     pub fn encode(&self) -> u8 {
         self.code().unwrap_or(0)
     }
@@ -194,11 +208,11 @@ impl PartialEq<RegId> for Option<RegKind> {
 
 impl RegId {
     pub fn code(self) -> u8 {
-        self as u8 & 0xF
+        (self as u16 & 0xFF) as u8
     }
 
     pub fn family(self) -> RegFamily {
-        match self as u8 >> 4 {
+        match self as u16 >> 8 {
             0 => RegFamily::LEGACY,
             1 => RegFamily::RIP,
             2 => RegFamily::HIGHBYTE,
@@ -209,6 +223,7 @@ impl RegId {
             7 => RegFamily::CONTROL,
             8 => RegFamily::DEBUG,
             9 => RegFamily::BOUND,
+            10 => RegFamily::TILE,
             _ => unreachable!()
         }
     }
@@ -231,6 +246,22 @@ impl RegId {
             13 => RegId::R13,
             14 => RegId::R14,
             15 => RegId::R15,
+            16 => RegId::R16,
+            17 => RegId::R17,
+            18 => RegId::R18,
+            19 => RegId::R19,
+            20 => RegId::R20,
+            21 => RegId::R21,
+            22 => RegId::R22,
+            23 => RegId::R23,
+            24 => RegId::R24,
+            25 => RegId::R25,
+            26 => RegId::R26,
+            27 => RegId::R27,
+            28 => RegId::R28,
+            29 => RegId::R29,
+            30 => RegId::R30,
+            31 => RegId::R31,
             _ => panic!("invalid register code {:?}", id)
         }
     }
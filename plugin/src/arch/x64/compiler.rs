@@ -5,6 +5,7 @@ use proc_macro_error::emit_error;
 
 use crate::common::{Stmt, Size, Jump, JumpKind, delimited};
 use crate::serialize;
+use crate::parse_helpers::dynamic_register_bound;
 
 use super::{Context, X86Mode};
 use super::ast::{RawArg, CleanArg, SizedArg, Instruction, MemoryRefItem, Register, RegKind, RegFamily, RegId};
@@ -101,7 +102,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     let addr_size = sanitize_indirects_and_sizes(&ctx, &mut args)?;
     let addr_size = addr_size.unwrap_or(match ctx.mode {
         X86Mode::Long => Size::QWORD,
-        X86Mode::Protected => Size::DWORD
+        X86Mode::Protected => Size::DWORD,
+        X86Mode::Real => Size::WORD
     });
 
     // determine if we need an address size override prefix
@@ -110,6 +112,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         (X86Mode::Long, Size::DWORD) => true,
         (X86Mode::Protected, Size::DWORD) => false,
         (X86Mode::Protected, Size::WORD) => true,
+        (X86Mode::Real, Size::WORD) => false,
+        (X86Mode::Real, Size::DWORD) => true,
         _ => return Err(Some("Impossible address size".into()))
     };
 
@@ -127,10 +131,30 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     // determine legacy prefixes
     let (mut pref_mod, pref_seg) = get_legacy_prefixes(data, prefixes)?;
 
+    // in 16-bit mode, instruction offsets are 16 bits wide unless a DWORD offset was explicitly asked for
+    let wide_offset = ctx.mode == X86Mode::Real && args.iter()
+        .zip(FormatStringIterator::new(&data.args))
+        .any(|(arg, (code, _))| code == b'o' && match *arg {
+            CleanArg::Immediate {size, ..} |
+            CleanArg::JumpTarget {size, ..} => size == Some(Size::DWORD),
+            _ => false
+        });
+
     // fill in size info from the format string to create the final SizedArg vec
-    let (op_size, args) = size_operands(data, args)?;
+    let (op_size, mut args) = size_operands(data, args)?;
+
+    if ctx.mode == X86Mode::Real && !wide_offset {
+        for (arg, (code, _)) in args.iter_mut().zip(FormatStringIterator::new(&data.args)) {
+            match *arg {
+                SizedArg::Immediate {ref mut size, ..} |
+                SizedArg::JumpTarget {ref mut size, ..} if code == b'o' && *size == Size::DWORD => *size = Size::WORD,
+                _ => ()
+            }
+        }
+    }
 
     let mut pref_size = false;
+    let mut pref_dword = false;
     let mut rex_w = false;
     let mut vex_l = false;
 
@@ -143,6 +167,9 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             X86Mode::Protected => if op_size == Size::QWORD {
                 return Err(Some(format!("'{}': Does not support 64 bit operands in 32-bit mode", op.to_string())));
             },
+            X86Mode::Real => if op_size == Size::QWORD {
+                return Err(Some(format!("'{}': Does not support 64 bit operands in 16-bit mode", op.to_string())));
+            },
             X86Mode::Long => ()
         }
 
@@ -151,13 +178,16 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 (Size::WORD, _) => pref_size = true,
                 (Size::QWORD, X86Mode::Long) => (),
                 (Size::DWORD, X86Mode::Protected) => (),
+                (Size::DWORD, X86Mode::Real) => pref_dword = true,
                 (Size::DWORD, X86Mode::Long) => return Err(Some(format!("'{}': Does not support 32 bit operands in 64-bit mode", op.to_string()))),
                 (_, _) => panic!("bad formatting data"),
             }
         } else if data.flags.contains(Flags::AUTO_REXW) {
             if op_size == Size::QWORD {
                 rex_w = true;
-            } else if op_size != Size::DWORD {
+            } else if op_size == Size::DWORD {
+                pref_dword = true;
+            } else {
                 return Err(Some(format!("'{}': Does not support 16-bit operands", op.to_string())));
             }
         } else if data.flags.contains(Flags::AUTO_VEXL) {
@@ -170,13 +200,19 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             pref_size = true;
         } else if op_size == Size::QWORD {
             rex_w = true;
-        } else if op_size != Size::DWORD {
+        } else if op_size == Size::DWORD {
+            pref_dword = true;
+        } else {
             panic!("bad formatting data");
         }
     }
 
-    // mandatory prefixes
-    let pref_size = pref_size || data.flags.contains(Flags::WORD_SIZE);
+    // mandatory prefixes. In 16-bit mode, the meaning of the operand size prefix is inverted.
+    let pref_size = match ctx.mode {
+        X86Mode::Real => pref_dword || wide_offset || data.flags.contains(Flags::DWORD_SIZE),
+        _             => pref_size  || data.flags.contains(Flags::WORD_SIZE)
+    };
+    let pref_66   = pref_size || data.flags.contains(Flags::PREF_66);
     let rex_w     = rex_w     || data.flags.contains(Flags::WITH_REXW);
     let vex_l     = vex_l     || data.flags.contains(Flags::WITH_VEXL);
     let pref_addr = pref_addr || data.flags.contains(Flags::PREF_67);
@@ -192,6 +228,20 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
     // split args
     let (mut rm, reg, vvvv, ireg, mut args) = extract_args(data, args);
 
+    // some instructions require a SIB byte. If there's no index, this is done by encoding rsp as index (no index)
+    if data.flags.contains(Flags::ENC_SIB) {
+        if let Some(SizedArg::Indirect {ref base, ref mut index, ..}) = rm {
+            if base.as_ref().map_or(false, |b| b.kind.family() == RegFamily::RIP) {
+                return Err(Some(format!("'{}': Does not support RIP-relative addressing", op.to_string())));
+            }
+            if index.is_none() {
+                if let Some(ref base) = *base {
+                    *index = Some((Register::new_static(base.size(), RegId::RSP), 1, None));
+                }
+            }
+        }
+    }
+
     // we'll need this to keep track of where relocations need to be made
     // (target, offset, size, kind)
     let mut relocations = Vec::new();
@@ -207,6 +257,9 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         None
     };
 
+    // dynamic registers get masked to the amount of bits that the encoding has for them, so check that they fit
+    check_dynamic_registers(ctx, data, [&reg, &rm, &vvvv, &ireg]);
+
     // shorthand
     let buffer = &mut ctx.state.stmts;
 
@@ -218,9 +271,25 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         buffer.push(Stmt::u8(0x67));
     }
 
-    // VEX/XOP prefixes embed the operand size prefix / modification prefixes in them.
-    if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) {
-        let prefix = if pref_size        { 0b01
+    // VEX/XOP/EVEX prefixes embed the operand size prefix / modification prefixes in them.
+    if data.flags.contains(Flags::EVEX_OP) {
+        if ctx.mode != X86Mode::Long {
+            return Err(Some(format!("'{}': APX encodings are only available in 64-bit mode", op.to_string())));
+        }
+        let prefix = if pref_66          { 0b01
+        } else if pref_mod == Some(0xF3) { 0b10
+        } else if pref_mod == Some(0xF2) { 0b11
+        } else                           { 0
+        };
+        // map_sel is stored in the first byte of the opcode
+        let (&map_sel, tail) = ops.split_first().expect("bad formatting data");
+        ops = tail;
+        compile_evex(buffer, &reg, &rm, map_sel, rex_w, &vvvv, prefix);
+    } else if data.flags.intersects(Flags::VEX_OP | Flags::XOP_OP) {
+        if ctx.mode == X86Mode::Real {
+            return Err(Some(format!("'{}': VEX and XOP encodings are not available in 16-bit mode", op.to_string())));
+        }
+        let prefix = if pref_66          { 0b01
         } else if pref_mod == Some(0xF3) { 0b10
         } else if pref_mod == Some(0xF2) { 0b11
         } else                           { 0
@@ -234,16 +303,20 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         if let Some(pref) = pref_mod {
             buffer.push(Stmt::u8(pref));
         }
-        if pref_size {
+        if pref_66 {
             buffer.push(Stmt::u8(0x66));
         }
         if need_rex {
             // Certain SSE/AVX legacy encoded operations are not available in 32-bit mode
             // as they require a REX.W prefix to be encoded, which is impossible. We catch those cases here
-            if ctx.mode == X86Mode::Protected {
+            if ctx.mode != X86Mode::Long {
                 return Err(Some(format!("'{}': Does not support 64 bit operand size in 32-bit mode", op.to_string())))
             }
-            compile_rex(buffer, rex_w, &reg, &rm);
+            // with APX, a REX2 prefix can be used instead, which also encodes the 0x0F escape byte
+            let rex2_map = if ctx.features.contains(Features::APX) { rex2_map(data) } else { None };
+            if compile_rex(buffer, ctx.state.target, rex_w, &reg, &rm, rex2_map) {
+                ops = &ops[1..];
+            }
         }
     }
 
@@ -291,7 +364,7 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         let mode_vsib = index.as_ref().map_or(false, |&(ref i, _, _)| i.kind.family() == RegFamily::XMM);
         let mode_16bit = addr_size == Size::WORD;
         let mode_rip_relative = base.as_ref().map_or(false, |b| b.kind.family() == RegFamily::RIP);
-        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b.kind.is_dynamic());
+        let mode_rbp_base = base.as_ref().map_or(false, |b| b == &RegId::RBP || b == &RegId::R13 || b == &RegId::R21 || b == &RegId::R29 || b.kind.is_dynamic());
 
         if mode_vsib {
             let (index, scale, scale_expr) = index.unwrap();
@@ -329,14 +402,22 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
         } else if mode_16bit {
             // 16-bit mode: the index/base combination has been encoded in the base register.
-            // this register is guaranteed to be present.
-            let base_k = base.unwrap().kind;
-            let mode = match (&disp, disp_size) {
-                (&Some(_), Some(Size::BYTE)) => MOD_DISP8,
-                (&Some(_), _) => MOD_DISP32, // well, technically 16-bit.
-                (&None, _) => if mode_rbp_base {MOD_DISP8} else {MOD_NODISP}
+            // If no base is present, only a displacement is encoded using the slot of [bp].
+            let (base_k, mode) = if let Some(base) = base {
+                let base_k = base.kind;
+                let mode = match (&disp, disp_size) {
+                    (&Some(_), Some(Size::BYTE)) => MOD_DISP8,
+                    (&Some(_), _) => MOD_DISP32, // well, technically 16-bit.
+                    // [bp] can only be encoded with a displacement
+                    (&None, _) => if base_k.code() == Some(6) {MOD_DISP8} else {MOD_NODISP}
+                };
+                (base_k, mode)
+            } else {
+                (RegKind::from_number(6), MOD_NODISP)
             };
 
+            let no_base = mode == MOD_NODISP && base_k.code() == Some(6);
+
             // only need a mod.r/m byte for 16-bit addressing
             compile_modrm_sib(buffer, mode, reg_k, base_k);
 
@@ -344,6 +425,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 buffer.push(Stmt::ExprSigned(delimited(disp), if mode == MOD_DISP8 {Size::BYTE} else {Size::WORD}));
             } else if mode == MOD_DISP8 {
                 buffer.push(Stmt::u8(0));
+            } else if no_base {
+                buffer.push(Stmt::u16(0));
             }
 
         } else if mode_rip_relative {
@@ -356,7 +439,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 } else {
                     buffer.push(Stmt::u32(0))
                 },
-                X86Mode::Protected => {
+                X86Mode::Protected |
+                X86Mode::Real => {
                     // x86 doesn't actually allow RIP-relative addressing
                     // but we can work around it with relocations
                     buffer.push(Stmt::u32(0));
@@ -406,7 +490,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
             // no base, no index. only disp. Easy in x86, but in x64 escape, use RBP as base and RSP as index
             } else {
                 match ctx.mode {
-                    X86Mode::Protected => {
+                    X86Mode::Protected |
+                    X86Mode::Real => {
                         compile_modrm_sib(buffer, mode, reg_k, RegKind::Static(RegId::RBP));
                     },
                     X86Mode::Long => {
@@ -433,13 +518,28 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
         } else {
             RegKind::from_number(data.reg)
         };
-        compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
-
-        buffer.push(Stmt::u32(0));
         match ctx.mode {
-            X86Mode::Long      => relocations.push((jump, 0, Size::DWORD, RelocationKind::Relative)),
-            X86Mode::Protected => relocations.push((jump, 0, Size::DWORD, RelocationKind::Absolute))
+            X86Mode::Long => {
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
+                buffer.push(Stmt::u32(0));
+                relocations.push((jump, 0, Size::DWORD, RelocationKind::Relative));
+            },
+            X86Mode::Protected => {
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RBP));
+                buffer.push(Stmt::u32(0));
+                relocations.push((jump, 0, Size::DWORD, RelocationKind::Absolute));
+            },
+            X86Mode::Real => {
+                // 16-bit addressing encodes a bare displacement in the slot of [bp]
+                compile_modrm_sib(buffer, MOD_NODISP, reg_k, RegKind::Static(RegId::RSI));
+                buffer.push(Stmt::u16(0));
+                relocations.push((jump, 0, Size::WORD, RelocationKind::Absolute));
+            }
         }
+
+    // only a register in the reg field, the r/m field is left empty
+    } else if let Some(SizedArg::Direct {reg, ..}) = reg {
+        compile_modrm_sib(buffer, MOD_DIRECT, reg.kind, RegKind::from_number(0));
     }
 
     // opcode encoded after the displacement
@@ -493,7 +593,8 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
                 // add the new relocation
                 if let JumpKind::Bare(_) = &jump.kind {
                     match ctx.mode {
-                        X86Mode::Protected => relocations.push((jump, 0, size, RelocationKind::Extern)),
+                        X86Mode::Protected |
+                        X86Mode::Real => relocations.push((jump, 0, size, RelocationKind::Extern)),
                         X86Mode::Long => return Err(Some("Extern relocations are not supported in x64 mode".to_string()))
                     }
                 } else {
@@ -506,9 +607,10 @@ pub(super) fn compile_instruction(ctx: &mut Context, instruction: Instruction, a
 
     // push relocations
     for (target, offset, size, kind) in relocations {
-        let data = [offset, size.in_bytes(), kind.to_id()];
+        let data = [offset, size.in_bytes(), kind.to_id(), (ctx.mode == X86Mode::Real) as u8];
         let data = match ctx.mode {
-            X86Mode::Protected => &data,
+            X86Mode::Protected |
+            X86Mode::Real      => &data,
             X86Mode::Long      => &data[..2],
         };
 
@@ -972,8 +1074,9 @@ fn sanitize_indirect(ctx: &Context, span: Span, nosplit: bool, base: &mut Option
         }
     }
 
-    // RSP, R12 or a dynamic register as base without index (add an index so we escape into SIB)
-    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
+    // RSP, R12, R20, R28 or a dynamic register as base without index (add an index so we escape into SIB)
+    if index.is_none() && (*base == RegId::RSP || *base == RegId::R12 || *base == RegId::R20 || *base == RegId::R28 ||
+        base.as_ref().map_or(false, |r| r.kind.is_dynamic())) {
         *index = Some((Register::new_static(size, RegId::RSP), 1, None));
     }
 
@@ -1006,7 +1109,7 @@ fn match_op_format(ctx: &Context, ident: &syn::Ident, args: &[CleanArg]) -> Resu
 fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result<(), &'static str> {
     let fmtstr = &fmt.args;
 
-    if ctx.mode != X86Mode::Protected && fmt.flags.intersects(Flags::X86_ONLY) {
+    if ctx.mode == X86Mode::Long && fmt.flags.intersects(Flags::X86_ONLY) {
         return Err("Not available in 32-bit mode");
     }
 
@@ -1028,6 +1131,7 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
     // c : control reg
     // d : debug reg
     // b : bound reg
+    // t : tile reg
 
     // v : r and m
     // u : x and m
@@ -1097,6 +1201,8 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 reg.kind.family() == RegFamily::DEBUG => Some(reg.size()),
             (b'b', &CleanArg::Direct{ref reg, ..}) if
                 reg.kind.family() == RegFamily::BOUND => Some(reg.size()),
+            (b't', &CleanArg::Direct{ref reg, ..}) if
+                reg.kind.family() == RegFamily::TILE => Some(reg.size()),
 
             // memory offsets
             (b'm',          &CleanArg::Indirect {size, ref index, ..}) |
@@ -1124,6 +1230,8 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
                 (b'd', b'i') => size <= Size::DWORD,
                 (b'q', b'i') => size <= Size::QWORD,
                 (b'*', b'i') => size <= Size::DWORD,
+                // in 16-bit mode, instruction offsets can be either word or dword sized
+                (b'd', b'o') if ctx.mode == X86Mode::Real => size == Size::WORD || size == Size::DWORD,
                 // normal size matches
                 (b'b', _)    => size == Size::BYTE,
                 (b'w', _)    => size == Size::WORD,
@@ -1149,10 +1257,12 @@ fn match_format_string(ctx: &Context, fmt: &Opdata, args: &[CleanArg]) -> Result
             } {
                 return Err("argument size mismatch");
             }
-        } else if fsize != b'*' && fmt.flags.contains(Flags::EXACT_SIZE) {
+        } else if fsize != b'*' && fmt.flags.contains(Flags::EXACT_SIZE) &&
+            !(ctx.mode == X86Mode::Real && fmt.flags.contains(Flags::WORD_SIZE)) {
             // Basically, this format is a more specific version of an instruction
             // that also has more general versions. This should only be picked
-            // if the size constraints are met, not if the size is unspecified
+            // if the size constraints are met, not if the size is unspecified.
+            // In 16-bit mode, the word-sized variants are the default ones instead.
             return Err("alternate variant exists");
         }
     }
@@ -1250,7 +1360,8 @@ fn size_operands(fmt: &Opdata, args: Vec<CleanArg>) -> Result<(Option<Size>, Vec
             (b'h', _) => Size::HWORD,
             (b'*', b'i') => im_size.unwrap(),
             (b'*', _) => op_size.unwrap(),
-            (b'!', _) => Size::BYTE, // will never be used, placeholder
+            (b'!', _) |
+            (b'?', _) => Size::BYTE, // will never be used, placeholder
             _ => unreachable!()
         };
 
@@ -1325,7 +1436,7 @@ fn get_legacy_prefixes(fmt: &'static Opdata, idents: Vec<syn::Ident>) -> Result<
 fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool) -> Result<bool, Option<String>> {
     // performs checks for not encodable arg combinations
     // output arg indicates if a rex prefix can be encoded
-    if ctx.mode == X86Mode::Protected {
+    if ctx.mode != X86Mode::Long {
         if rex_w {
             return Err(Some("Does not support 64 bit operand size in 32-bit mode".to_string()));
         } else {
@@ -1333,8 +1444,10 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
         }
     }
 
-    let mut requires_rex    = rex_w;
+    // the APX encodings cannot encode high byte registers either
+    let mut requires_rex    = rex_w || fmt.flags.contains(Flags::EVEX_OP);
     let mut requires_no_rex = false;
+    let mut requires_apx    = false;
 
     for (arg, (c, _)) in args.iter().zip(FormatStringIterator::new(fmt.args)) {
         // only scan args that are actually encoded
@@ -1348,13 +1461,16 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
                         (reg.kind == RegId::RSP || reg.kind == RegId::RBP || reg.kind == RegId::RSI || reg.kind == RegId::RDI)) {
                         requires_rex = true;
                     }
+                    requires_apx = requires_apx || reg.kind.is_apx_extended();
                 },
                 SizedArg::Indirect {ref base, ref index, ..} => {
                     if let Some(ref reg) = *base {
                         requires_rex = requires_rex || reg.kind.is_extended();
+                        requires_apx = requires_apx || reg.kind.is_apx_extended();
                     }
                     if let Some((ref reg, _, _)) = *index {
                         requires_rex = requires_rex || reg.kind.is_extended();
+                        requires_apx = requires_apx || reg.kind.is_apx_extended();
                     }
                 },
                 _ => (),
@@ -1362,7 +1478,11 @@ fn check_rex(ctx: &Context, fmt: &'static Opdata, args: &[SizedArg], rex_w: bool
         }
     }
 
-    if requires_rex && requires_no_rex {
+    if requires_apx && !ctx.features.contains(Features::APX) {
+        Err(Some("The extended registers r16-r31 require the apx feature".to_string()))
+    } else if requires_apx && !fmt.flags.contains(Flags::EVEX_OP) && rex2_map(fmt).is_none() {
+        Err(Some("The extended registers r16-r31 cannot be used with this instruction".to_string()))
+    } else if requires_rex && requires_no_rex {
         Err(Some("High byte register combined with extended registers or 64-bit operand size".to_string()))
     } else {
         Ok(requires_rex)
@@ -1377,9 +1497,10 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
 
     // default argument encoding order is as follows:
     // no encoding flag: m, rm, rvm, rvim
-    // ENC_MR:              mr, rmv, rvmi
+    // ENC_MR:           r, mr, rmv, rvmi
     // ENC_VM:              vm, mvr
     // these can also be chosen based on the location of a memory argument (except for vm)
+    // EVEX_OP: the first argument goes into vvvv, the others are encoded as above
 
     let mut memarg = None;
     let mut regarg = None;
@@ -1394,7 +1515,7 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
                 memarg = Some(regs.len());
                 regs.push(arg)
             },
-            b'f' | b'x' | b'r' | b'y' | b'b' => regs.push(arg),
+            b'f' | b'x' | b'r' | b'y' | b'b' | b't' => regs.push(arg),
             b'c' | b'd' | b's'        => if regarg.is_some() {
                 panic!("multiple segment, debug or control registers in format string");
             } else {
@@ -1406,6 +1527,12 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
         }
     }
 
+    let mut v = None;
+    if fmt.flags.contains(Flags::EVEX_OP) {
+        v = Some(regs.remove(0));
+        memarg = memarg.map(|m| m - 1);
+    }
+
     let len = regs.len();
     if len > 4 {
         panic!("too many arguments");
@@ -1414,7 +1541,6 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
 
     let mut m = None;
     let mut r = None;
-    let mut v = None;
     let mut i = None;
 
     if let Some(i) = regarg {
@@ -1426,7 +1552,11 @@ fn extract_args(fmt: &'static Opdata, args: Vec<SizedArg>) -> (Option<SizedArg>,
             r = regs.next();
         }
     } else if len == 1 {
-        m = regs.next();
+        if fmt.flags.contains(Flags::ENC_MR) {
+            r = regs.next();
+        } else {
+            m = regs.next();
+        }
     } else if len == 2 {
         if fmt.flags.contains(Flags::ENC_MR) || memarg == Some(0) {
             m = regs.next();
@@ -1479,7 +1609,8 @@ fn encode_scale(scale: isize) -> Option<u8> {
     }
 }
 
-fn compile_rex(buffer: &mut Vec<Stmt>, rex_w: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>) {
+fn compile_rex(buffer: &mut Vec<Stmt>, target: &TokenTree, rex_w: bool, reg: &Option<SizedArg>, rm: &Option<SizedArg>, rex2_map: Option<bool>) -> bool {
+    // returns if the 0x0F escape byte of the opcode has been encoded in the prefix
     let mut reg_k   = RegKind::from_number(0);
     let mut index_k = RegKind::from_number(0);
     let mut base_k  = RegKind::from_number(0);
@@ -1503,23 +1634,171 @@ fn compile_rex(buffer: &mut Vec<Stmt>, rex_w: bool, reg: &Option<SizedArg>, rm:
                      (reg_k.encode()   & 8) >> 1 |
                      (index_k.encode() & 8) >> 2 |
                      (base_k.encode()  & 8) >> 3 ;
+    let rex2 =      (reg_k.encode()   & 16) << 2 |
+                    (index_k.encode() & 16) << 1 |
+                    (base_k.encode()  & 16)      ;
+
     if !reg_k.is_dynamic() && !index_k.is_dynamic() && !base_k.is_dynamic() {
-        buffer.push(Stmt::u8(rex));
-        return;
+        if rex2 == 0 {
+            buffer.push(Stmt::u8(rex));
+            return false;
+        }
+
+        // check_rex has verified that a REX2 prefix can be used
+        let map1 = rex2_map.expect("REX2 prefix for an incompatible instruction");
+        buffer.push(Stmt::u8(0xD5));
+        buffer.push(Stmt::u8((map1 as u8) << 7 | rex2 | (rex & 0xF)));
+        return map1;
     }
 
-    let mut rex: TokenTree = proc_macro2::Literal::u8_suffixed(rex).into();
+    // without APX, dynamic registers are always encoded using a REX prefix
+    let map1 = match rex2_map {
+        Some(map1) => map1,
+        None => {
+            let mut rex: TokenTree = proc_macro2::Literal::u8_suffixed(rex).into();
 
-    if let RegKind::Dynamic(_, expr) = reg_k {
-        rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -1);
+            if let RegKind::Dynamic(_, expr) = reg_k {
+                rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -1);
+            }
+            if let RegKind::Dynamic(_, expr) = index_k {
+                rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -2);
+            }
+            if let RegKind::Dynamic(_, expr) = base_k {
+                rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -3);
+            }
+            buffer.push(Stmt::ExprUnsigned(rex, Size::BYTE));
+            return false;
+        }
+    };
+
+    // with APX, the REX2 payload is calculated and a REX2 prefix is picked at runtime if any of the
+    // registers are r16 - r31. Otherwise, a REX prefix is used, followed by the 0x0F escape byte if required.
+    let payload = (map1 as u8) << 7 | rex2 | (rex & 0xF);
+    let mut payload: TokenTree = proc_macro2::Literal::u8_suffixed(payload).into();
+
+    // only general purpose registers have a fifth register bit
+    if let RegKind::Dynamic(family, expr) = reg_k {
+        let expr = delimited(expr);
+        payload = serialize::expr_mask_shift_or(&payload, &expr, 8, -1);
+        if family == RegFamily::LEGACY {
+            payload = serialize::expr_mask_shift_or(&payload, &expr, 16, 2);
+        }
     }
     if let RegKind::Dynamic(_, expr) = index_k {
-        rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -2);
+        let expr = delimited(expr);
+        payload = serialize::expr_mask_shift_or(&payload, &expr, 8, -2);
+        payload = serialize::expr_mask_shift_or(&payload, &expr, 16, 1);
     }
-    if let RegKind::Dynamic(_, expr) = base_k {
-        rex = serialize::expr_mask_shift_or(&rex, &delimited(expr), 8, -3);
+    if let RegKind::Dynamic(family, expr) = base_k {
+        let expr = delimited(expr);
+        payload = serialize::expr_mask_shift_or(&payload, &expr, 8, -3);
+        if family == RegFamily::LEGACY {
+            payload = serialize::expr_mask_shift_or(&payload, &expr, 16, 0);
+        }
+    }
+
+    let span = payload.span();
+    buffer.push(Stmt::Stmt(delimited(quote_spanned!{ span=> {
+        let rex_payload: u8 = #payload;
+        if rex_payload & 0x70 != 0 {
+            #target.push(0xD5);
+            #target.push(rex_payload);
+        } else {
+            #target.push(0x40 | (rex_payload & 0xF));
+            if rex_payload & 0x80 != 0 {
+                #target.push(0x0F);
+            }
+        }
+    }})));
+    map1
+}
+
+fn check_dynamic_registers(ctx: &mut Context, fmt: &'static Opdata, args: [&Option<SizedArg>; 4]) {
+    // emits a runtime check for every dynamic register that could be out of range for the encoding of this instruction.
+    // EVEX prefixes have five bits for every register. REX prefixes have four, or five for general purpose registers
+    // when a REX2 prefix can be picked at runtime. VEX/XOP prefixes have four, and outside of 64-bit mode only three
+    // bits can be encoded.
+    let long = ctx.mode == X86Mode::Long;
+    let evex = fmt.flags.contains(Flags::EVEX_OP);
+    let vex = fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP);
+    let rex2 = long && ctx.features.contains(Features::APX) && rex2_map(fmt).is_some();
+
+    let mut dynamics = Vec::new();
+    for arg in args.iter() {
+        match **arg {
+            Some(SizedArg::Direct {ref reg, ..}) => dynamics.push((&reg.kind, false)),
+            Some(SizedArg::Indirect {ref base, ref index, ..}) => {
+                if let Some(ref base) = *base {
+                    dynamics.push((&base.kind, false));
+                }
+                if let Some((ref index, _, _)) = *index {
+                    dynamics.push((&index.kind, true));
+                }
+            },
+            _ => ()
+        }
+    }
+
+    for (kind, is_index) in dynamics {
+        let (family, expr) = match *kind {
+            RegKind::Dynamic(family, ref expr) => (family, expr),
+            RegKind::Static(_) => continue
+        };
+
+        let (limit, msg): (u8, &str) = if evex {
+            (32, "Dynamic register out of range: the EVEX encoding only supports registers 0-31")
+        } else if !long {
+            (8, "Dynamic register out of range: only registers 0-7 can be encoded outside of 64-bit mode")
+        } else if vex {
+            (16, "Dynamic register out of range: VEX and XOP encodings only support registers 0-15")
+        } else if rex2 && (family == RegFamily::LEGACY || is_index) {
+            (32, "Dynamic register out of range: the REX2 encoding only supports registers 0-31")
+        } else if let RegFamily::LEGACY | RegFamily::XMM | RegFamily::CONTROL | RegFamily::DEBUG = family {
+            (16, if family == RegFamily::LEGACY && !ctx.features.contains(Features::APX) {
+                "Dynamic register out of range: registers 16-31 require the apx feature"
+            } else {
+                "Dynamic register out of range: this instruction only supports registers 0-15"
+            })
+        } else {
+            (8, "Dynamic register out of range: this register family only supports registers 0-7")
+        };
+
+        // typed registers are bounded by their type, which allows the compiler to remove the check when they always fit
+        let target = ctx.state.target;
+        let span = expr.span();
+        let code = delimited(expr);
+        let check = match dynamic_register_bound(expr) {
+            Some(bound) => quote_spanned!{ span=> #bound >= #limit && #code >= #limit },
+            None => quote_spanned!{ span=> #code >= #limit }
+        };
+        ctx.state.stmts.push(Stmt::Stmt(delimited(quote_spanned!{ span=>
+            if #check {
+                #target.runtime_error(#msg);
+            }
+        })));
+    }
+}
+
+fn rex2_map(fmt: &'static Opdata) -> Option<bool> {
+    // determines if an instruction can be encoded with a REX2 prefix, and if so, whether it is part of
+    // opcode map 1 (the 0x0F escape byte gets encoded in the prefix). The rows of the opcode maps that
+    // contain the jcc, string and I/O instructions are not available with REX2.
+    if fmt.flags.intersects(Flags::VEX_OP | Flags::XOP_OP | Flags::EVEX_OP | Flags::IMM_OP) {
+        return None;
+    }
+
+    match *fmt.ops {
+        [0x0F, 0x38, ..] | [0x0F, 0x3A, ..] => None,
+        [0x0F, op, ..] => match op & 0xF0 {
+            0x30 | 0x80 => None,
+            _ => Some(true)
+        },
+        [op, ..] => match op & 0xF0 {
+            0x70 | 0xA0 | 0xE0 => None,
+            _ => Some(false)
+        },
+        [] => None
     }
-    buffer.push(Stmt::ExprUnsigned(rex, Size::BYTE));
 }
 
 fn compile_vex_xop(mode: X86Mode, buffer: &mut Vec<Stmt>, data: &'static Opdata, reg: &Option<SizedArg>,
@@ -1551,7 +1830,8 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
             (!index_k.encode() & 8) << 3 |
             (!base_k.encode()  & 8) << 2
         },
-        X86Mode::Protected => {
+        X86Mode::Protected |
+        X86Mode::Real => {
             (map_sel & 0x1f) | 0xE0
         }
     };
@@ -1566,7 +1846,7 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
                 (vex_l            as u8) << 2 ;
 
     if data.flags.contains(Flags::VEX_OP) && (byte1 & 0x7F) == 0x61 && (byte2 & 0x80) == 0 &&
-    ((!index_k.is_dynamic() && !base_k.is_dynamic()) || mode == X86Mode::Protected) {
+    ((!index_k.is_dynamic() && !base_k.is_dynamic()) || mode != X86Mode::Long) {
         // 2-byte vex
         buffer.push(Stmt::u8(0xC5));
 
@@ -1618,6 +1898,94 @@ rm: &Option<SizedArg>, map_sel: u8, rex_w: bool, vvvv: &Option<SizedArg>, vex_l:
     }
 }
 
+fn compile_evex(buffer: &mut Vec<Stmt>, reg: &Option<SizedArg>, rm: &Option<SizedArg>, map_sel: u8, rex_w: bool,
+vvvv: &Option<SizedArg>, prefix: u8) {
+    // the APX extended EVEX prefix. It's used here to encode the new data destination forms of legacy instructions.
+    let mut reg_k   = RegKind::from_number(0);
+    let mut index_k = RegKind::from_number(0);
+    let mut base_k  = RegKind::from_number(0);
+    let mut vvvv_k  = RegKind::from_number(0);
+
+    if let Some(SizedArg::Direct {ref reg, ..}) = *reg {
+        reg_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *rm {
+        base_k = reg.kind.clone();
+    }
+    if let Some(SizedArg::Indirect {ref base, ref index, ..}) = *rm {
+        if let Some(ref base) = *base {
+            base_k = base.kind.clone();
+        }
+        if let Some((ref index, _, _)) = *index {
+            index_k = index.kind.clone();
+        }
+    }
+    if let Some(SizedArg::Direct {ref reg, ..}) = *vvvv {
+        vvvv_k = reg.kind.clone();
+    }
+
+    // ~R3 ~X3 ~B3 ~R4 B4 mmm
+    let byte1 = (map_sel            & 0x7)      |
+                (!reg_k.encode()    & 8)  << 4  |
+                (!index_k.encode()  & 8)  << 3  |
+                (!base_k.encode()   & 8)  << 2  |
+                (!reg_k.encode()    & 16)       |
+                (base_k.encode()    & 16) >> 1  ;
+    // W ~vvvv ~X4 pp
+    let byte2 = (prefix             & 0x3)      |
+                (rex_w              as u8) << 7 |
+                (!vvvv_k.encode()   & 0xF) << 3 |
+                (!index_k.encode()  & 16)  >> 2 ;
+    // z L'L ND ~V4 NF 00
+    let byte3 = 0x10                            |
+                (!vvvv_k.encode()   & 16)  >> 1 ;
+
+    buffer.push(Stmt::u8(0x62));
+
+    if reg_k.is_dynamic() || index_k.is_dynamic() || base_k.is_dynamic() {
+        let mut byte1: TokenTree = proc_macro2::Literal::u8_suffixed(byte1).into();
+
+        if let RegKind::Dynamic(_, expr) = reg_k {
+            let expr = delimited(expr);
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &expr, 8, 4);
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &expr, 16, 0);
+        }
+        if let RegKind::Dynamic(_, expr) = index_k.clone() {
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &delimited(expr), 8, 3);
+        }
+        if let RegKind::Dynamic(_, expr) = base_k {
+            let expr = delimited(expr);
+            byte1 = serialize::expr_mask_shift_inverted_and(&byte1, &expr, 8, 2);
+            byte1 = serialize::expr_mask_shift_or(&byte1, &expr, 16, -1);
+        }
+        buffer.push(Stmt::ExprUnsigned(byte1, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte1));
+    }
+
+    if vvvv_k.is_dynamic() || index_k.is_dynamic() {
+        let mut byte2: TokenTree = proc_macro2::Literal::u8_suffixed(byte2).into();
+
+        if let RegKind::Dynamic(_, expr) = vvvv_k.clone() {
+            byte2 = serialize::expr_mask_shift_inverted_and(&byte2, &delimited(expr), 0xF, 3);
+        }
+        if let RegKind::Dynamic(_, expr) = index_k {
+            byte2 = serialize::expr_mask_shift_inverted_and(&byte2, &delimited(expr), 16, -2);
+        }
+        buffer.push(Stmt::ExprUnsigned(byte2, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte2));
+    }
+
+    if let RegKind::Dynamic(_, expr) = vvvv_k {
+        let byte3: TokenTree = proc_macro2::Literal::u8_suffixed(byte3).into();
+        let byte3 = serialize::expr_mask_shift_inverted_and(&byte3, &delimited(expr), 16, -1);
+        buffer.push(Stmt::ExprUnsigned(byte3, Size::BYTE));
+    } else {
+        buffer.push(Stmt::u8(byte3));
+    }
+}
+
 fn compile_modrm_sib(buffer: &mut Vec<Stmt>, mode: u8, reg1: RegKind, reg2: RegKind) {
     let byte = mode                << 6 |
               (reg1.encode()  & 7) << 3 |
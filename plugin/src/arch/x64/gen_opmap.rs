@@ -27,6 +27,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 2, AUTO_SIZE ;
     b"r*r*"       , [0x11              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x13              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 2, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x10        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x12        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 2, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 2, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x11        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x13        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "adcx" = [
     b"rqvq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | PREF_66;
@@ -46,6 +53,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 0, AUTO_SIZE ;
     b"r*r*"       , [0x01              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x03              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 0, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x00        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x02        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 0, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 0, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x01        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x03        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "addpd" = [
     b"yowo"       , [0x0F, 0x58        ], X, PREF_66, SSE2;
@@ -103,6 +117,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 4, AUTO_SIZE ;
     b"r*r*"       , [0x21              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x23              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 4, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x20        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x22        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 4, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 4, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x21        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x23        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "andn" = [
     b"r*r*v*"     , [0x02, 0xF2        ], X, VEX_OP | AUTO_REXW, BMI1;
@@ -240,7 +261,7 @@ Ops!(
     b""           , [0x98              ], X, WORD_SIZE;
 ]
 "cdq" = [
-    b""           , [0x99              ], X;
+    b""           , [0x99              ], X, DWORD_SIZE;
 ]
 "cdqe" = [
     b""           , [0x98              ], X, WITH_REXW;
@@ -254,18 +275,30 @@ Ops!(
 "cld" = [
     b""           , [0xFC              ], X;
 ]
+"cldemote" = [
+    b"mb"         , [0x0F, 0x1C        ], 0, DEFAULT, CLDEMOTE;
+]
 "clflush" = [
     b"mb"         , [0x0F, 0xAE        ], 7, DEFAULT, SSE2;
 ]
+"clflushopt" = [
+    b"mb"         , [0x0F, 0xAE        ], 7, PREF_66, CLFLUSHOPT;
+]
 "clgi" = [
     b""           , [0x0F, 0x01, 0xDD  ], X, DEFAULT, VMX | AMD;
 ]
 "cli" = [
     b""           , [0xFA              ], X;
 ]
+"clrssbsy" = [
+    b"mq"         , [0x0F, 0xAE        ], 6, PREF_F3, CET;
+]
 "clts" = [
     b""           , [0x0F, 0x06        ], X;
 ]
+"clwb" = [
+    b"mb"         , [0x0F, 0xAE        ], 6, PREF_66, CLWB;
+]
 "clzero" = [
     b""           , [0x0F, 0x01, 0xFC  ], X, DEFAULT, AMD;
 ]
@@ -392,7 +425,7 @@ Ops!(
     b""           , [0xA6              ], X, REPE;
 ]
 "cmpsd" = [
-    b""           , [0xA7              ], X, REPE;
+    b""           , [0xA7              ], X, REPE | DWORD_SIZE;
     b"yowoib"     , [0x0F, 0xC2        ], X, PREF_F2, SSE2;
 ]
 "cmpsq" = [
@@ -541,7 +574,7 @@ Ops!(
     b""           , [0x99              ], X, WORD_SIZE;
 ]
 "cwde" = [
-    b""           , [0x98              ], X;
+    b""           , [0x98              ], X, DWORD_SIZE;
 ]
 "daa" = [
     b""           , [0x27              ], X, X86_ONLY;
@@ -553,8 +586,10 @@ Ops!(
     b"mb"         , [0xFE              ], 1, LOCK;
     b"rb"         , [0xFE              ], 1;
     b"m*"         , [0xFF              ], 1, AUTO_SIZE | LOCK;
-    b"r*"         , [0x48              ], 0, X86_ONLY | SHORT_ARG;
+    b"r*"         , [0x48              ], 0, AUTO_SIZE | X86_ONLY | SHORT_ARG;
     b"r*"         , [0xFF              ], 1, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 1, EVEX_OP, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 1, AUTO_SIZE | EVEX_OP, APX;
 ]
 "div" = [
     b"vb"         , [0xF6              ], 6;
@@ -588,6 +623,12 @@ Ops!(
 "emms" = [
     b""           , [0x0F, 0x77        ], X, DEFAULT, MMX;
 ]
+"endbr32" = [
+    b""           , [0x0F, 0x1E, 0xFB  ], X, PREF_F3;
+]
+"endbr64" = [
+    b""           , [0x0F, 0x1E, 0xFA  ], X, PREF_F3;
+]
 "enter" = [
     b"iwib"       , [0xC8              ], X;
 ]
@@ -1027,6 +1068,15 @@ Ops!(
 "getsec" = [
     b""           , [0x0F, 0x37        ], X;
 ]
+"gf2p8affineinvqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCF  ], X, PREF_66, GFNI;
+]
+"gf2p8affineqb" = [
+    b"yowoib"     , [0x0F, 0x3A, 0xCE  ], X, PREF_66, GFNI;
+]
+"gf2p8mulb" = [
+    b"yowo"       , [0x0F, 0x38, 0xCF  ], X, PREF_66, GFNI;
+]
 "haddpd" = [
     b"yowo"       , [0x0F, 0x7C        ], X, PREF_66, SSE3;
 ]
@@ -1053,14 +1103,22 @@ Ops!(
     b"mb"         , [0xFE              ], 0, LOCK;
     b"rb"         , [0xFE              ], 0;
     b"m*"         , [0xFF              ], 0, AUTO_SIZE | LOCK;
-    b"r*"         , [0x40              ], 0, X86_ONLY | SHORT_ARG;
+    b"r*"         , [0x40              ], 0, AUTO_SIZE | X86_ONLY | SHORT_ARG;
     b"r*"         , [0xFF              ], 0, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xFE        ], 0, EVEX_OP, APX;
+    b"r*v*"       , [0x04, 0xFF        ], 0, AUTO_SIZE | EVEX_OP, APX;
+]
+"incsspd" = [
+    b"rd"         , [0x0F, 0xAE        ], 5, PREF_F3, CET;
+]
+"incsspq" = [
+    b"rq"         , [0x0F, 0xAE        ], 5, WITH_REXW | PREF_F3, CET;
 ]
 "insb" = [
     b""           , [0x6C              ], X, REP;
 ]
 "insd" = [
-    b""           , [0x6D              ], X, REP;
+    b""           , [0x6D              ], X, REP | DWORD_SIZE;
 ]
 "insertps" = [
     b"yomdib"     , [0x0F, 0x3A, 0x21  ], X, PREF_66, SSE41;
@@ -1114,7 +1172,7 @@ Ops!(
     b""           , [0xCF              ], X;
 ]
 "iretd" = [
-    b""           , [0xCF              ], X;
+    b""           , [0xCF              ], X, DWORD_SIZE;
 ]
 "iretq" = [
     b""           , [0xCF              ], X, WITH_REXW;
@@ -1144,6 +1202,9 @@ Ops!(
 "lds" = [
     b"r*m!"       , [0xC5              ], X, AUTO_SIZE | X86_ONLY;
 ]
+"ldtilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP, AMX_TILE;
+]
 "lea" = [
     b"r*m!"       , [0x8D              ], X, AUTO_SIZE;
 ]
@@ -1183,7 +1244,7 @@ Ops!(
     b""           , [0xAC              ], X, REP;
 ]
 "lodsd" = [
-    b""           , [0xAD              ], X, REP;
+    b""           , [0xAD              ], X, REP | DWORD_SIZE;
 ]
 "lodsq" = [
     b""           , [0xAD              ], X, WITH_REXW | REP;
@@ -1302,6 +1363,14 @@ Ops!(
     b"yomq"       , [0x0F, 0x12        ], X, PREF_F2, SSE3;
     b"yoyo"       , [0x0F, 0x12        ], X, PREF_F2, SSE3;
 ]
+"movdir64b" = [
+    b"rqm!"       , [0x0F, 0x38, 0xF8  ], X, PREF_66, MOVDIR64B;
+    b"rdm!"       , [0x0F, 0x38, 0xF8  ], X, PREF_66 | X86_ONLY, MOVDIR64B;
+]
+"movdiri" = [
+    b"mdrd"       , [0x0F, 0x38, 0xF9  ], X, ENC_MR | DWORD_SIZE, MOVDIRI;
+    b"mqrq"       , [0x0F, 0x38, 0xF9  ], X, WITH_REXW | ENC_MR, MOVDIRI;
+]
 "movdq2q" = [
     b"xqyo"       , [0x0F, 0xD6        ], X, PREF_F2, SSE2;
 ]
@@ -1354,7 +1423,7 @@ Ops!(
     b"yomo"       , [0x0F, 0x38, 0x2A  ], X, PREF_66, SSE41;
 ]
 "movnti" = [
-    b"mdrd"       , [0x0F, 0xC3        ], X, ENC_MR;
+    b"mdrd"       , [0x0F, 0xC3        ], X, ENC_MR | DWORD_SIZE;
     b"mqrq"       , [0x0F, 0xC3        ], X, WITH_REXW | ENC_MR;
 ]
 "movntpd" = [
@@ -1391,7 +1460,7 @@ Ops!(
     b""           , [0xA4              ], X, REP;
 ]
 "movsd" = [
-    b""           , [0xA5              ], X, REP;
+    b""           , [0xA5              ], X, REP | DWORD_SIZE;
     b"mqyo"       , [0x0F, 0x11        ], X, ENC_MR | PREF_F2, SSE2;
     b"yomq"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
     b"yoyo"       , [0x0F, 0x10        ], X, PREF_F2, SSE2;
@@ -1478,6 +1547,8 @@ Ops!(
     b"rb"         , [0xF6              ], 3;
     b"m*"         , [0xF7              ], 3, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 3, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 3, EVEX_OP, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 3, AUTO_SIZE | EVEX_OP, APX;
 ]
 "nop" = [
     b""           , [0x90              ], X;
@@ -1488,6 +1559,8 @@ Ops!(
     b"rb"         , [0xF6              ], 2;
     b"m*"         , [0xF7              ], 2, AUTO_SIZE | LOCK;
     b"r*"         , [0xF7              ], 2, AUTO_SIZE ;
+    b"rbvb"       , [0x04, 0xF6        ], 2, EVEX_OP, APX;
+    b"r*v*"       , [0x04, 0xF7        ], 2, AUTO_SIZE | EVEX_OP, APX;
 ]
 "or" = [
     b"Abib"       , [0x0C              ], X;
@@ -1504,6 +1577,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 1, AUTO_SIZE ;
     b"r*r*"       , [0x09              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x0B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 1, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x08        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x0A        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 1, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 1, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x09        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x0B        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "orpd" = [
     b"yowo"       , [0x0F, 0x56        ], X, PREF_66, SSE2;
@@ -1515,7 +1595,7 @@ Ops!(
     b""           , [0x6E              ], X, REP;
 ]
 "outsd" = [
-    b""           , [0x6F              ], X, REP;
+    b""           , [0x6F              ], X, REP | DWORD_SIZE;
 ]
 "outsw" = [
     b""           , [0x6F              ], X, WORD_SIZE | REP;
@@ -2017,7 +2097,7 @@ Ops!(
     b""           , [0x61              ], X, X86_ONLY | WORD_SIZE;
 ]
 "popad" = [
-    b""           , [0x61              ], X, X86_ONLY;
+    b""           , [0x61              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "popcnt" = [
     b"r*v*"       , [0x0F, 0xB8        ], X, AUTO_SIZE | PREF_F3;
@@ -2188,6 +2268,10 @@ Ops!(
     b"yomq"       , [0x0F, 0x38, 0x17  ], X, PREF_66, SSE41;
     b"yoyo"       , [0x0F, 0x38, 0x17  ], X, PREF_66, SSE41;
 ]
+"ptwrite" = [
+    b"vd"         , [0x0F, 0xAE        ], 4, PREF_F3, PTWRITE;
+    b"vq"         , [0x0F, 0xAE        ], 4, WITH_REXW | PREF_F3, PTWRITE;
+]
 "punpckhbw" = [
     b"xquq"       , [0x0F, 0x68        ], X, DEFAULT, MMX;
     b"yowo"       , [0x0F, 0x68        ], X, PREF_66, SSE2;
@@ -2227,7 +2311,7 @@ Ops!(
     b"Vw"         , [0x0F, 0xA8        ], X;
     b"ib"         , [0x6A              ], X, EXACT_SIZE;
     b"iw"         , [0x68              ], X, EXACT_SIZE | WORD_SIZE;
-    b"id"         , [0x68              ], X;
+    b"id"         , [0x68              ], X, DWORD_SIZE;
     b"r*"         , [0x50              ], X, AUTO_NO32 | SHORT_ARG;
     b"v*"         , [0xFF              ], 6, AUTO_NO32;
 ]
@@ -2235,7 +2319,7 @@ Ops!(
     b""           , [0x60              ], X, X86_ONLY | WORD_SIZE;
 ]
 "pushad" = [
-    b""           , [0x60              ], X, X86_ONLY;
+    b""           , [0x60              ], X, X86_ONLY | DWORD_SIZE;
 ]
 "pushf" = [
     b""           , [0x9C              ], X;
@@ -2284,7 +2368,8 @@ Ops!(
     b""           , [0x0F, 0x32        ], X;
 ]
 "rdpid" = [
-    b"rq"         , [0x0F, 0xC7        ], 7, PREF_F3;
+    b"rq"         , [0x0F, 0xC7        ], 7, PREF_F3, RDPID;
+    b"rd"         , [0x0F, 0xC7        ], 7, PREF_F3 | X86_ONLY, RDPID;
 ]
 "rdpkru" = [
     b""           , [0x0F, 0x01, 0xEE  ], X;
@@ -2299,7 +2384,13 @@ Ops!(
     b"rq"         , [0x0F, 0xC7        ], 7, WITH_REXW;
 ]
 "rdshr" = [
-    b"vd"         , [0x0F, 0x36        ], 0, DEFAULT, CYRIX;
+    b"vd"         , [0x0F, 0x36        ], 0, DWORD_SIZE, CYRIX;
+]
+"rdsspd" = [
+    b"rd"         , [0x0F, 0x1E        ], 1, PREF_F3, CET;
+]
+"rdsspq" = [
+    b"rq"         , [0x0F, 0x1E        ], 1, WITH_REXW | PREF_F3, CET;
 ]
 "rdtsc" = [
     b""           , [0x0F, 0x31        ], X;
@@ -2366,6 +2457,9 @@ Ops!(
     b"yomd"       , [0x0F, 0x52        ], X, PREF_F3, SSE;
     b"yoyo"       , [0x0F, 0x52        ], X, PREF_F3, SSE;
 ]
+"rstorssp" = [
+    b"mq"         , [0x0F, 0x01        ], 5, PREF_F3, CET;
+]
 "rsts" = [
     b"mp"         , [0x0F, 0x7D        ], 0, EXACT_SIZE, CYRIX;
 ]
@@ -2387,6 +2481,9 @@ Ops!(
 "sarx" = [
     b"r*v*r*"     , [0x02, 0xF7        ], X, VEX_OP | AUTO_REXW | ENC_MR | PREF_F3, BMI2;
 ]
+"saveprevssp" = [
+    b""           , [0x0F, 0x01, 0xEA  ], X, PREF_F3, CET;
+]
 "sbb" = [
     b"Abib"       , [0x1C              ], X;
     b"mbib"       , [0x80              ], 3, LOCK;
@@ -2402,12 +2499,19 @@ Ops!(
     b"r*i*"       , [0x81              ], 3, AUTO_SIZE ;
     b"r*r*"       , [0x19              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x1B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 3, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x18        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x1A        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 3, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 3, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x19        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x1B        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "scasb" = [
     b""           , [0xAE              ], X, REPE;
 ]
 "scasd" = [
-    b""           , [0xAF              ], X, REPE;
+    b""           , [0xAF              ], X, REPE | DWORD_SIZE;
 ]
 "scasq" = [
     b""           , [0xAF              ], X, REPE | WITH_REXW;
@@ -2415,6 +2519,12 @@ Ops!(
 "scasw" = [
     b""           , [0xAF              ], X, REPE | WORD_SIZE;
 ]
+"serialize" = [
+    b""           , [0x0F, 0x01, 0xE8  ], X, DEFAULT, SERIALIZE;
+]
+"setssbsy" = [
+    b""           , [0x0F, 0x01, 0xE8  ], X, PREF_F3, CET;
+]
 "sfence" = [
     b""           , [0x0F, 0xAE, 0xF8  ], X, DEFAULT, AMD;
 ]
@@ -2530,7 +2640,7 @@ Ops!(
     b""           , [0xAA              ], X, REP;
 ]
 "stosd" = [
-    b""           , [0xAB              ], X, REP;
+    b""           , [0xAB              ], X, REP | DWORD_SIZE;
 ]
 "stosq" = [
     b""           , [0xAB              ], X, WITH_REXW | REP;
@@ -2542,6 +2652,9 @@ Ops!(
     b"m!"         , [0x0F, 0x00        ], 1;
     b"r*"         , [0x0F, 0x00        ], 1, AUTO_SIZE;
 ]
+"sttilecfg" = [
+    b"m!"         , [0x02, 0x49        ], 0, VEX_OP | PREF_66, AMX_TILE;
+]
 "sub" = [
     b"Abib"       , [0x2C              ], X;
     b"mbib"       , [0x80              ], 5, LOCK;
@@ -2557,6 +2670,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 5, AUTO_SIZE ;
     b"r*r*"       , [0x29              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x2B              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 5, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x28        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x2A        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 5, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 5, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x29        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x2B        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "subpd" = [
     b"yowo"       , [0x0F, 0x5C        ], X, PREF_66, SSE2;
@@ -2599,6 +2719,21 @@ Ops!(
 "t1mskc" = [
     b"r*v*"       , [0x09, 0x01        ], 7, XOP_OP | AUTO_REXW | ENC_VM, TBM;
 ]
+"tdpbf16ps" = [
+    b"t?t?t?"     , [0x02, 0x5C        ], X, VEX_OP | ENC_MR | PREF_F3, AMX_BF16;
+]
+"tdpbssd" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_F2, AMX_INT8;
+]
+"tdpbsud" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_F3, AMX_INT8;
+]
+"tdpbusd" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR | PREF_66, AMX_INT8;
+]
+"tdpbuud" = [
+    b"t?t?t?"     , [0x02, 0x5E        ], X, VEX_OP | ENC_MR, AMX_INT8;
+]
 "test" = [
     b"Abib"       , [0xA8              ], X;
     b"rbmb"       , [0x84              ], X;
@@ -2609,6 +2744,24 @@ Ops!(
     b"v*i*"       , [0xF7              ], 0, AUTO_SIZE;
     b"v*r*"       , [0x85              ], X, AUTO_SIZE | ENC_MR;
 ]
+"tileloadd" = [
+    b"t?m!"       , [0x02, 0x4B        ], X, VEX_OP | ENC_SIB | PREF_F2, AMX_TILE;
+]
+"tileloaddt1" = [
+    b"t?m!"       , [0x02, 0x4B        ], X, VEX_OP | ENC_SIB | PREF_66, AMX_TILE;
+]
+"tilerelease" = [
+    b""           , [0x02, 0x49, 0xC0  ], X, VEX_OP, AMX_TILE;
+]
+"tilestored" = [
+    b"m!t?"       , [0x02, 0x4B        ], X, VEX_OP | ENC_SIB | PREF_F3, AMX_TILE;
+]
+"tilezero" = [
+    b"t?"         , [0x02, 0x49        ], X, VEX_OP | ENC_MR | PREF_F2, AMX_TILE;
+]
+"tpause" = [
+    b"rd"         , [0x0F, 0xAE        ], 6, PREF_66, WAITPKG;
+]
 "tzcnt" = [
     b"r*v*"       , [0x0F, 0xBC        ], X, AUTO_SIZE | PREF_F3, BMI1;
 ]
@@ -2629,6 +2782,13 @@ Ops!(
 "ud2a" = [
     b""           , [0x0F, 0x0B        ], X;
 ]
+"umonitor" = [
+    b"rq"         , [0x0F, 0xAE        ], 6, PREF_F3, WAITPKG;
+    b"rd"         , [0x0F, 0xAE        ], 6, PREF_F3 | X86_ONLY, WAITPKG;
+]
+"umwait" = [
+    b"rd"         , [0x0F, 0xAE        ], 6, PREF_F2, WAITPKG;
+]
 "unpckhpd" = [
     b"yowo"       , [0x0F, 0x15        ], X, PREF_66, SSE2;
 ]
@@ -2663,15 +2823,19 @@ Ops!(
 ]
 "vaesdec" = [
     b"yoyowo"     , [0x02, 0xDE        ], X, VEX_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x02, 0xDE        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
 ]
 "vaesdeclast" = [
     b"yoyowo"     , [0x02, 0xDF        ], X, VEX_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x02, 0xDF        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
 ]
 "vaesenc" = [
     b"yoyowo"     , [0x02, 0xDC        ], X, VEX_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x02, 0xDC        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
 ]
 "vaesenclast" = [
     b"yoyowo"     , [0x02, 0xDD        ], X, VEX_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x02, 0xDD        ], X, VEX_OP | WITH_VEXL | PREF_66, VAES;
 ]
 "vaesimc" = [
     b"yowo"       , [0x02, 0xDB        ], X, VEX_OP | PREF_66, AVX;
@@ -4018,6 +4182,15 @@ Ops!(
 "vgatherqps" = [
     b"yok*yo"     , [0x02, 0x93        ], X, VEX_OP | AUTO_VEXL | ENC_MR | PREF_66, AVX2;
 ]
+"vgf2p8affineinvqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCF        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, AVX | GFNI;
+]
+"vgf2p8affineqb" = [
+    b"y*y*w*ib"   , [0x03, 0xCE        ], X, VEX_OP | AUTO_VEXL | WITH_REXW | PREF_66, AVX | GFNI;
+]
+"vgf2p8mulb" = [
+    b"y*y*w*"     , [0x02, 0xCF        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX | GFNI;
+]
 "vhaddpd" = [
     b"y*y*w*"     , [0x01, 0x7C        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX;
 ]
@@ -4359,18 +4532,23 @@ Ops!(
 ]
 "vpclmulhqhqdq" = [
     b"yoyowo"     , [0x03, 0x44, 0x11  ], X, VEX_OP | PREF_66 | IMM_OP, AVX;
+    b"yhyhwh"     , [0x03, 0x44, 0x11  ], X, VEX_OP | IMM_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
 ]
 "vpclmulhqlqdq" = [
     b"yoyowo"     , [0x03, 0x44, 0x01  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x03, 0x44, 0x01  ], X, VEX_OP | IMM_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
 ]
 "vpclmullqhqdq" = [
     b"yoyowo"     , [0x03, 0x44, 0x10  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x03, 0x44, 0x10  ], X, VEX_OP | IMM_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
 ]
 "vpclmullqlqdq" = [
     b"yoyowo"     , [0x03, 0x44, 0x00  ], X, VEX_OP | IMM_OP | PREF_66, AVX;
+    b"yhyhwh"     , [0x03, 0x44, 0x00  ], X, VEX_OP | IMM_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
 ]
 "vpclmulqdq" = [
     b"yoyowoib"   , [0x03, 0x44        ], X, VEX_OP | PREF_66, AVX;
+    b"yhyhwhib"   , [0x03, 0x44        ], X, VEX_OP | WITH_VEXL | PREF_66, VPCLMULQDQ;
 ]
 "vpcmov" = [
     b"y*y*w*y*"   , [0x08, 0xA2        ], X, XOP_OP | AUTO_VEXL, SSE5 | AMD;
@@ -4436,6 +4614,18 @@ Ops!(
 "vpcomw" = [
     b"yoyowoib"   , [0x08, 0xCD        ], X, XOP_OP, AMD | SSE5;
 ]
+"vpdpbusd" = [
+    b"y*y*w*"     , [0x02, 0x50        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX_VNNI;
+]
+"vpdpbusds" = [
+    b"y*y*w*"     , [0x02, 0x51        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX_VNNI;
+]
+"vpdpwssd" = [
+    b"y*y*w*"     , [0x02, 0x52        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX_VNNI;
+]
+"vpdpwssds" = [
+    b"y*y*w*"     , [0x02, 0x53        ], X, VEX_OP | AUTO_VEXL | PREF_66, AVX_VNNI;
+]
 "vperm2f128" = [
     b"yhyhwhib"   , [0x03, 0x06        ], X, WITH_VEXL | VEX_OP | PREF_66, AVX;
 ]
@@ -5027,6 +5217,9 @@ Ops!(
 "wbinvd" = [
     b""           , [0x0F, 0x09        ], X;
 ]
+"wbnoinvd" = [
+    b""           , [0x0F, 0x09        ], X, PREF_F3, WBNOINVD;
+]
 "wrfsbase" = [
     b"rd"         , [0x0F, 0xAE        ], 2, PREF_F3;
     b"rq"         , [0x0F, 0xAE        ], 2, WITH_REXW | PREF_F3;
@@ -5042,7 +5235,19 @@ Ops!(
     b""           , [0x0F, 0x01, 0xEF  ], X;
 ]
 "wrshr" = [
-    b"vd"         , [0x0F, 0x37        ], 0, DEFAULT, CYRIX;
+    b"vd"         , [0x0F, 0x37        ], 0, DWORD_SIZE, CYRIX;
+]
+"wrssd" = [
+    b"mdrd"       , [0x0F, 0x38, 0xF6  ], X, ENC_MR, CET;
+]
+"wrssq" = [
+    b"mqrq"       , [0x0F, 0x38, 0xF6  ], X, WITH_REXW | ENC_MR, CET;
+]
+"wrussd" = [
+    b"mdrd"       , [0x0F, 0x38, 0xF5  ], X, ENC_MR | PREF_66, CET;
+]
+"wrussq" = [
+    b"mqrq"       , [0x0F, 0x38, 0xF5  ], X, WITH_REXW | ENC_MR | PREF_66, CET;
 ]
 "xabort" = [
     b"ib"         , [0xC6, 0xF8        ], X, DEFAULT, RTM;
@@ -5110,6 +5315,13 @@ Ops!(
     b"r*i*"       , [0x81              ], 6, AUTO_SIZE ;
     b"r*r*"       , [0x31              ], X, AUTO_SIZE | ENC_MR;
     b"r*v*"       , [0x33              ], X, AUTO_SIZE;
+    b"rbvbib"     , [0x04, 0x80        ], 6, EVEX_OP, APX;
+    b"rbvbrb"     , [0x04, 0x30        ], X, EVEX_OP | ENC_MR, APX;
+    b"rbrbvb"     , [0x04, 0x32        ], X, EVEX_OP, APX;
+    b"r*v*ib"     , [0x04, 0x83        ], 6, AUTO_SIZE | EVEX_OP | EXACT_SIZE, APX;
+    b"r*v*i*"     , [0x04, 0x81        ], 6, AUTO_SIZE | EVEX_OP, APX;
+    b"r*v*r*"     , [0x04, 0x31        ], X, AUTO_SIZE | EVEX_OP | ENC_MR, APX;
+    b"r*r*v*"     , [0x04, 0x33        ], X, AUTO_SIZE | EVEX_OP, APX;
 ]
 "xorpd" = [
     b"yowo"       , [0x0F, 0x57        ], X, PREF_66, SSE2;
@@ -5159,30 +5371,34 @@ Ops!(
 
 "call"  = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
+    b"if"         , [0x9A              ], X, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"od"         , [0xE8              ], X;
     b"v*"         , [0xFF              ], 2, AUTO_NO32;
 ]
 "callf" = [
     b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0x9A              ], X, X86_ONLY;
+    b"idiw"       , [0x9A              ], X, X86_ONLY | DWORD_SIZE;
+    b"if"         , [0x9A              ], X, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"md"         , [0xFF              ], 3, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 3, X86_ONLY;
+    b"mf"         , [0xFF              ], 3, X86_ONLY | DWORD_SIZE;
 ]
 "jmp"   = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
-    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
+    b"if"         , [0xEA              ], X, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"ob"         , [0xEB              ], X, EXACT_SIZE;
     b"od"         , [0xE9              ], X;
     b"v*"         , [0xFF              ], 4, AUTO_NO32 ;
 ]
 "jmpf" = [
-    b"iwiw"       , [0x9A              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"idiw"       , [0xEA              ], X, X86_ONLY;
+    b"iwiw"       , [0xEA              ], X, X86_ONLY | WORD_SIZE | EXACT_SIZE;
+    b"idiw"       , [0xEA              ], X, X86_ONLY | DWORD_SIZE;
+    b"if"         , [0xEA              ], X, X86_ONLY | EXACT_SIZE | DWORD_SIZE;
     b"md"         , [0xFF              ], 5, X86_ONLY | WORD_SIZE | EXACT_SIZE;
-    b"mf"         , [0xFF              ], 5, X86_ONLY;
+    b"mf"         , [0xFF              ], 5, X86_ONLY | DWORD_SIZE;
 ]
 "mov"   = [
     b"v*r*"       , [0x89              ], X, AUTO_SIZE;
@@ -5195,7 +5411,7 @@ Ops!(
     b"swrw"       , [0x8C              ], X;
     b"rbib"       , [0xB0              ], X,             SHORT_ARG;
     b"rwiw"       , [0xB8              ], X, WORD_SIZE | SHORT_ARG;
-    b"rdid"       , [0xB8              ], X,             SHORT_ARG;
+    b"rdid"       , [0xB8              ], X, DWORD_SIZE | SHORT_ARG;
     b"v*i*"       , [0xC7              ], 0, AUTO_SIZE;
     b"vbib"       , [0xC6              ], 0;
     b"rqiq"       , [0xB8              ], X, WITH_REXW | SHORT_ARG;
@@ -5529,19 +5745,19 @@ Ops!(
 "in"    = [
     b"Abib"       , [0xE4            ], X;
     b"Awib"       , [0xE5            ], X, WORD_SIZE;
-    b"Adib"       , [0xE5            ], X;
+    b"Adib"       , [0xE5            ], X, DWORD_SIZE;
     b"AbCw"       , [0xEC            ], X;
     b"AwCw"       , [0xED            ], X, WORD_SIZE;
-    b"AdCw"       , [0xED            ], X;
+    b"AdCw"       , [0xED            ], X, DWORD_SIZE;
 ]
 
 "out"   = [
     b"ibAb"       , [0xE6            ], X;
     b"ibAw"       , [0xE7            ], X;
-    b"ibAd"       , [0xE7            ], X;
+    b"ibAd"       , [0xE7            ], X, DWORD_SIZE;
     b"CwAb"       , [0xEE            ], X;
     b"CwAw"       , [0xEF            ], X, WORD_SIZE;
-    b"CwAd"       , [0xEF            ], X;
+    b"CwAd"       , [0xEF            ], X, DWORD_SIZE;
 ]
 
 "crc32"  = [
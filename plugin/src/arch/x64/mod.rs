@@ -17,7 +17,19 @@ pub use debug::create_opmap;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum X86Mode {
     Long,
-    Protected
+    Protected,
+    Real
+}
+
+/// Relocations can only be a byte, word, doubleword or quadword in size
+fn check_reloc_size(reloc: &Jump, size: Size) -> bool {
+    match size {
+        Size::BYTE | Size::WORD | Size::DWORD | Size::QWORD => true,
+        _ => {
+            emit_error!(reloc.span(), "Relocation of unsupported size for the current target architecture");
+            false
+        }
+    }
 }
 
 struct Context<'a, 'b: 'a> {
@@ -57,6 +69,9 @@ impl Arch for Archx64 {
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
+        if !check_reloc_size(&reloc, size) {
+            return;
+        }
         let data = [0, size.in_bytes()]; // no offset, specified size, relative implicit
 
         stmts.push(Stmt::Const(0, size));
@@ -85,18 +100,30 @@ impl Arch for Archx64 {
 
 #[derive(Clone, Debug)]
 pub struct Archx86 {
-    features: x64data::Features
+    features: x64data::Features,
+    mode: X86Mode
+}
+
+impl Archx86 {
+    /// Creates a 32-bit target (protected mode) or a 16-bit target (real mode).
+    pub fn new(mode: X86Mode) -> Archx86 {
+        debug_assert!(mode != X86Mode::Long, "use Archx64 for 64-bit code");
+        Archx86 { features: x64data::Features::all(), mode }
+    }
 }
 
 impl Default for Archx86 {
     fn default() -> Archx86 {
-        Archx86 { features: x64data::Features::all() }
+        Archx86::new(X86Mode::Protected)
     }
 }
 
 impl Arch for Archx86 {
     fn name(&self) -> &str {
-        "x86"
+        match self.mode {
+            X86Mode::Real => "x86_16",
+            _ => "x86"
+        }
     }
 
     fn set_features(&mut self, features: &[syn::Ident]) {
@@ -105,7 +132,7 @@ impl Arch for Archx86 {
             new_features |= match x64data::Features::from_str(&ident.to_string()) {
                 Some(feature) => feature,
                 None => {
-                    emit_error!(ident, "Architecture x86 does not support feature '{}'", ident);
+                    emit_error!(ident, "Architecture {} does not support feature '{}'", self.name(), ident);
                     continue;
                 }
             }
@@ -114,7 +141,12 @@ impl Arch for Archx86 {
     }
 
     fn handle_static_reloc(&self, stmts: &mut Vec<Stmt>, reloc: Jump, size: Size) {
-        let data = [0, size.in_bytes(), 0]; // no offset, specified size, relative
+        if !check_reloc_size(&reloc, size) {
+            return;
+        }
+        // no offset, specified size, relative, real mode flag
+        let real_mode = (self.mode == X86Mode::Real) as u8;
+        let data = [0, size.in_bytes(), 0, real_mode];
 
         stmts.push(Stmt::Const(0, size));
         stmts.push(reloc.encode(&data));
@@ -127,7 +159,7 @@ impl Arch for Archx86 {
     fn compile_instruction(&self, state: &mut State, input: parse::ParseStream) -> parse::Result<()> {
         let mut ctx = Context {
             state,
-            mode: X86Mode::Protected,
+            mode: self.mode,
             features: self.features
         };
         let (instruction, args) = parser::parse_instruction(&mut ctx, input)?;
@@ -6,7 +6,7 @@ use proc_macro_error::emit_error;
 use lazy_static::lazy_static;
 
 use crate::common::Size;
-use crate::parse_helpers::{eat_pseudo_keyword, parse_ident_or_rust_keyword, as_ident, ParseOptExt};
+use crate::parse_helpers::{eat_pseudo_keyword, parse_ident_or_rust_keyword, as_ident, ParseOptExt, dynamic_register};
 
 use super::{Context, X86Mode};
 use super::ast::{Instruction, RawArg, Register, RegId, RegFamily, MemoryRefItem};
@@ -70,6 +70,17 @@ fn is_prefix(ident: &syn::Ident) -> bool {
 
 /// if a size hint is present in the parse stream, returning the indicated size
 fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
+    const X86_16_SIZES: [(&str, Size); 9] = [
+        ("BYTE", Size::BYTE),
+        ("WORD", Size::WORD),
+        ("DWORD", Size::DWORD),
+        ("AWORD", Size::WORD),
+        ("FWORD", Size::FWORD),
+        ("QWORD", Size::QWORD),
+        ("TWORD", Size::PWORD),
+        ("OWORD", Size::OWORD),
+        ("YWORD", Size::HWORD)
+    ];
     const X86_SIZES: [(&str, Size); 9] = [
         ("BYTE", Size::BYTE),
         ("WORD", Size::WORD),
@@ -94,6 +105,7 @@ fn eat_size_hint(ctx: &Context, input: parse::ParseStream) -> Option<Size> {
     ];
 
     let sizes = match ctx.mode {
+        X86Mode::Real      => &X86_16_SIZES,
         X86Mode::Protected => &X86_SIZES,
         X86Mode::Long      => &X64_SIZES
     };
@@ -237,7 +249,8 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
 
         let (reg, size) = match ctx.mode {
             X86Mode::Long      => X64_REGISTERS.get(&name).cloned(),
-            X86Mode::Protected => X86_REGISTERS.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_REGISTERS.get(&name).cloned()
         }?;
 
         Some((
@@ -261,18 +274,36 @@ fn parse_reg(ctx: &Context, expr: &syn::Expr) -> Option<(Span, Register)> {
         let name = name.as_str();
         let (size, family) = match ctx.mode {
             X86Mode::Long      => X64_FAMILIES.get(&name).cloned(),
-            X86Mode::Protected => X86_FAMILIES.get(&name).cloned()
+            X86Mode::Protected |
+            X86Mode::Real      => X86_FAMILIES.get(&name).cloned()
         }?;
 
         Some((
             expr.span(), // FIXME:can't join spans atm
-            Register::new_dynamic(size, family, args[0].clone())
+            Register::new_dynamic(size, family, dynamic_register("x64", typed_register(family), "u8", &args[0]))
         ))
     } else {
         None
     }
 }
 
+// The typed register in `dynasmrt::x64` that dynamic registers of `family` are checked against.
+fn typed_register(family: RegFamily) -> &'static str {
+    match family {
+        RegFamily::LEGACY   => "Rq",
+        RegFamily::HIGHBYTE => "Rh",
+        RegFamily::FP       => "Rf",
+        RegFamily::MMX      => "Rm",
+        RegFamily::XMM      => "Rx",
+        RegFamily::SEGMENT  => "Rs",
+        RegFamily::CONTROL  => "RC",
+        RegFamily::DEBUG    => "RD",
+        RegFamily::BOUND    => "RB",
+        RegFamily::TILE     => "Rt",
+        RegFamily::RIP      => unreachable!("rip cannot be used dynamically")
+    }
+}
+
 /// splits an expression into different components of a memory reference.
 fn parse_adds(ctx: &Context, expr: syn::Expr) -> Vec<MemoryRefItem> {
     let mut adds = Vec::new();
@@ -360,6 +391,22 @@ lazy_static!{
             ("r13", (R13, QWORD)),
             ("r14", (R14, QWORD)),
             ("r15", (R15, QWORD)),
+            ("r16", (R16, QWORD)),
+            ("r17", (R17, QWORD)),
+            ("r18", (R18, QWORD)),
+            ("r19", (R19, QWORD)),
+            ("r20", (R20, QWORD)),
+            ("r21", (R21, QWORD)),
+            ("r22", (R22, QWORD)),
+            ("r23", (R23, QWORD)),
+            ("r24", (R24, QWORD)),
+            ("r25", (R25, QWORD)),
+            ("r26", (R26, QWORD)),
+            ("r27", (R27, QWORD)),
+            ("r28", (R28, QWORD)),
+            ("r29", (R29, QWORD)),
+            ("r30", (R30, QWORD)),
+            ("r31", (R31, QWORD)),
 
             ("eax" , (RAX, DWORD)),
             ("r0d" , (RAX, DWORD)),
@@ -385,6 +432,22 @@ lazy_static!{
             ("r13d", (R13, DWORD)),
             ("r14d", (R14, DWORD)),
             ("r15d", (R15, DWORD)),
+            ("r16d", (R16, DWORD)),
+            ("r17d", (R17, DWORD)),
+            ("r18d", (R18, DWORD)),
+            ("r19d", (R19, DWORD)),
+            ("r20d", (R20, DWORD)),
+            ("r21d", (R21, DWORD)),
+            ("r22d", (R22, DWORD)),
+            ("r23d", (R23, DWORD)),
+            ("r24d", (R24, DWORD)),
+            ("r25d", (R25, DWORD)),
+            ("r26d", (R26, DWORD)),
+            ("r27d", (R27, DWORD)),
+            ("r28d", (R28, DWORD)),
+            ("r29d", (R29, DWORD)),
+            ("r30d", (R30, DWORD)),
+            ("r31d", (R31, DWORD)),
 
             ("ax"  , (RAX, WORD)),
             ("r0w" , (RAX, WORD)),
@@ -410,6 +473,22 @@ lazy_static!{
             ("r13w", (R13, WORD)),
             ("r14w", (R14, WORD)),
             ("r15w", (R15, WORD)),
+            ("r16w", (R16, WORD)),
+            ("r17w", (R17, WORD)),
+            ("r18w", (R18, WORD)),
+            ("r19w", (R19, WORD)),
+            ("r20w", (R20, WORD)),
+            ("r21w", (R21, WORD)),
+            ("r22w", (R22, WORD)),
+            ("r23w", (R23, WORD)),
+            ("r24w", (R24, WORD)),
+            ("r25w", (R25, WORD)),
+            ("r26w", (R26, WORD)),
+            ("r27w", (R27, WORD)),
+            ("r28w", (R28, WORD)),
+            ("r29w", (R29, WORD)),
+            ("r30w", (R30, WORD)),
+            ("r31w", (R31, WORD)),
 
             ("al"  , (RAX, BYTE)),
             ("r0b" , (RAX, BYTE)),
@@ -435,6 +514,22 @@ lazy_static!{
             ("r13b", (R13, BYTE)),
             ("r14b", (R14, BYTE)),
             ("r15b", (R15, BYTE)),
+            ("r16b", (R16, BYTE)),
+            ("r17b", (R17, BYTE)),
+            ("r18b", (R18, BYTE)),
+            ("r19b", (R19, BYTE)),
+            ("r20b", (R20, BYTE)),
+            ("r21b", (R21, BYTE)),
+            ("r22b", (R22, BYTE)),
+            ("r23b", (R23, BYTE)),
+            ("r24b", (R24, BYTE)),
+            ("r25b", (R25, BYTE)),
+            ("r26b", (R26, BYTE)),
+            ("r27b", (R27, BYTE)),
+            ("r28b", (R28, BYTE)),
+            ("r29b", (R29, BYTE)),
+            ("r30b", (R30, BYTE)),
+            ("r31b", (R31, BYTE)),
 
             ("rip", (RIP, QWORD)),
             ("eip", (RIP, DWORD)),
@@ -541,6 +636,15 @@ lazy_static!{
             ("bnd1", (BND1, OWORD)),
             ("bnd2", (BND2, OWORD)),
             ("bnd3", (BND3, OWORD)),
+
+            ("tmm0", (TMM0, BYTE)),
+            ("tmm1", (TMM1, BYTE)),
+            ("tmm2", (TMM2, BYTE)),
+            ("tmm3", (TMM3, BYTE)),
+            ("tmm4", (TMM4, BYTE)),
+            ("tmm5", (TMM5, BYTE)),
+            ("tmm6", (TMM6, BYTE)),
+            ("tmm7", (TMM7, BYTE)),
         ];
         MAP.iter().cloned().collect()
     };
@@ -663,6 +767,7 @@ lazy_static!{
             ("RC", (Size::QWORD, RegFamily::CONTROL)),
             ("RD", (Size::QWORD, RegFamily::DEBUG)),
             ("RB", (Size::OWORD, RegFamily::BOUND)),
+            ("Rt", (Size::BYTE,  RegFamily::TILE)),
         ];
         MAP.iter().cloned().collect()
     };
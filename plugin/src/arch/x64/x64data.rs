@@ -52,7 +52,7 @@ bitflags! {
         const WITH_VEXL = 0x0000_0200; // implies VEX.L/XOP.L
         const EXACT_SIZE= 0x0000_0400; // operands with unknown sizes cannot be assumed to match
 
-        const PREF_66   = WORD_SIZE;   // mandatory prefix (same as WORD_SIZE)
+        const PREF_66   = 0x0400_0000; // mandatory prefix (same encoding as WORD_SIZE, but not inverted in 16-bit mode)
         const PREF_67   = 0x0000_0800; // mandatory prefix (same as SMALL_ADDRESS)
         const PREF_F0   = 0x0000_1000; // mandatory prefix (same as LOCK)
         const PREF_F2   = 0x0000_2000; // mandatory prefix (REPNE)
@@ -67,6 +67,9 @@ bitflags! {
         const ENC_VM    = 0x0010_0000; // select alternate arg encoding
         const ENC_MIB   = 0x0020_0000; // A special encoding using the SIB to specify an immediate and two registers
         const X86_ONLY  = 0x0040_0000; // instructions available in protected mode, but not long mode
+        const ENC_SIB   = 0x0080_0000; // the memory operand has to be encoded using a SIB byte
+        const EVEX_OP   = 0x0100_0000; // this instruction requires an APX extended EVEX prefix, the first operand is the new data destination
+        const DWORD_SIZE= 0x0200_0000; // implies opsize prefix in 16-bit mode
     }
 }
 
@@ -77,10 +80,10 @@ impl Flags {
 }
 
 bitflags! {
-    pub struct Features: u32 {
+    pub struct Features: u64 {
         const X64_IMPLICIT = 0x0000_0000;
         const FPU          = 0x0000_0001;
-          const MMX          = 0x0000_0002;
+        const MMX          = 0x0000_0002;
         const TDNOW        = 0x0000_0004;
         const SSE          = 0x0000_0008;
         const SSE2         = 0x0000_0010;
@@ -104,11 +107,30 @@ bitflags! {
         const PREFETCHWT1  = 0x0040_0000;
         const CYRIX        = 0x0080_0000;
         const AMD          = 0x0100_0000;
+        const GFNI         = 0x0200_0000;
+        const VAES         = 0x0400_0000;
+        const VPCLMULQDQ   = 0x0800_0000;
+        const AVX_VNNI     = 0x1000_0000;
+        const CET          = 0x2000_0000;
+        const CLFLUSHOPT   = 0x4000_0000;
+        const CLWB         = 0x8000_0000;
+        const CLDEMOTE     = 0x0001_0000_0000;
+        const RDPID        = 0x0002_0000_0000;
+        const SERIALIZE    = 0x0004_0000_0000;
+        const MOVDIRI      = 0x0008_0000_0000;
+        const MOVDIR64B    = 0x0010_0000_0000;
+        const WAITPKG      = 0x0020_0000_0000;
+        const WBNOINVD     = 0x0040_0000_0000;
+        const PTWRITE      = 0x0080_0000_0000;
+        const AMX_TILE     = 0x0100_0000_0000;
+        const AMX_INT8     = 0x0200_0000_0000;
+        const AMX_BF16     = 0x0400_0000_0000;
+        const APX          = 0x0800_0000_0000;
     }
 }
 
 impl Features {
-    const fn make(bits: u32) -> Features {
+    const fn make(bits: u64) -> Features {
         Features { bits }
     }
 
@@ -139,6 +161,25 @@ impl Features {
             "prefetchwt1" => Some(Features::PREFETCHWT1),
             "cyrix" => Some(Features::CYRIX),
             "amd"   => Some(Features::AMD),
+            "gfni"  => Some(Features::GFNI),
+            "vaes"  => Some(Features::VAES),
+            "vpclmulqdq" => Some(Features::VPCLMULQDQ),
+            "avxvnni" => Some(Features::AVX_VNNI),
+            "cet"   => Some(Features::CET),
+            "clflushopt" => Some(Features::CLFLUSHOPT),
+            "clwb"  => Some(Features::CLWB),
+            "cldemote" => Some(Features::CLDEMOTE),
+            "rdpid" => Some(Features::RDPID),
+            "serialize" => Some(Features::SERIALIZE),
+            "movdiri" => Some(Features::MOVDIRI),
+            "movdir64b" => Some(Features::MOVDIR64B),
+            "waitpkg" => Some(Features::WAITPKG),
+            "wbnoinvd" => Some(Features::WBNOINVD),
+            "ptwrite" => Some(Features::PTWRITE),
+            "amxtile" => Some(Features::AMX_TILE),
+            "amxint8" => Some(Features::AMX_INT8),
+            "amxbf16" => Some(Features::AMX_BF16),
+            "apx" => Some(Features::APX),
             _ => None
         }
     }
@@ -172,6 +213,25 @@ impl Display for Features {
         if self.contains(Features::PREFETCHWT1) { keys.push("prefetchwt1"); }
         if self.contains(Features::CYRIX) { keys.push("cyrix"); }
         if self.contains(Features::AMD)   { keys.push("amd"); }
+        if self.contains(Features::GFNI)  { keys.push("gfni"); }
+        if self.contains(Features::VAES)  { keys.push("vaes"); }
+        if self.contains(Features::VPCLMULQDQ) { keys.push("vpclmulqdq"); }
+        if self.contains(Features::AVX_VNNI) { keys.push("avxvnni"); }
+        if self.contains(Features::CET)   { keys.push("cet"); }
+        if self.contains(Features::CLFLUSHOPT) { keys.push("clflushopt"); }
+        if self.contains(Features::CLWB)  { keys.push("clwb"); }
+        if self.contains(Features::CLDEMOTE) { keys.push("cldemote"); }
+        if self.contains(Features::RDPID) { keys.push("rdpid"); }
+        if self.contains(Features::SERIALIZE) { keys.push("serialize"); }
+        if self.contains(Features::MOVDIRI) { keys.push("movdiri"); }
+        if self.contains(Features::MOVDIR64B) { keys.push("movdir64b"); }
+        if self.contains(Features::WAITPKG) { keys.push("waitpkg"); }
+        if self.contains(Features::WBNOINVD) { keys.push("wbnoinvd"); }
+        if self.contains(Features::PTWRITE) { keys.push("ptwrite"); }
+        if self.contains(Features::AMX_TILE) { keys.push("amxtile"); }
+        if self.contains(Features::AMX_INT8) { keys.push("amxint8"); }
+        if self.contains(Features::AMX_BF16) { keys.push("amxbf16"); }
+        if self.contains(Features::APX) { keys.push("apx"); }
         for (i, k) in keys.into_iter().enumerate() {
             if i != 0 {
                 f.write_str(", ")?;
@@ -198,6 +258,7 @@ const AUTO_NO32  : u32 = Flags::AUTO_NO32.bits;
 const AUTO_REXW  : u32 = Flags::AUTO_REXW.bits;
 const AUTO_VEXL  : u32 = Flags::AUTO_VEXL.bits;
 const WORD_SIZE  : u32 = Flags::WORD_SIZE.bits;
+const DWORD_SIZE : u32 = Flags::DWORD_SIZE.bits;
 const WITH_REXW  : u32 = Flags::WITH_REXW.bits;
 const WITH_VEXL  : u32 = Flags::WITH_VEXL.bits;
 const EXACT_SIZE : u32 = Flags::EXACT_SIZE.bits;
@@ -213,34 +274,55 @@ const ENC_MR     : u32 = Flags::ENC_MR.bits;
 const ENC_VM     : u32 = Flags::ENC_VM.bits;
 const ENC_MIB    : u32 = Flags::ENC_MIB.bits;
 const X86_ONLY   : u32 = Flags::X86_ONLY.bits;
+const ENC_SIB    : u32 = Flags::ENC_SIB.bits;
+const EVEX_OP    : u32 = Flags::EVEX_OP.bits;
 
 #[allow(dead_code)]
-const X64_IMPLICIT : u32 = Features::X64_IMPLICIT.bits;
-const FPU          : u32 = Features::FPU.bits;
-const MMX          : u32 = Features::MMX.bits;
-const TDNOW        : u32 = Features::TDNOW.bits;
-const SSE          : u32 = Features::SSE.bits;
-const SSE2         : u32 = Features::SSE2.bits;
-const SSE3         : u32 = Features::SSE3.bits;
-const VMX          : u32 = Features::VMX.bits;
-const SSSE3        : u32 = Features::SSSE3.bits;
-const SSE4A        : u32 = Features::SSE4A.bits;
-const SSE41        : u32 = Features::SSE41.bits;
-const SSE42        : u32 = Features::SSE42.bits;
-const SSE5         : u32 = Features::SSE5.bits;
-const AVX          : u32 = Features::AVX.bits;
-const AVX2         : u32 = Features::AVX2.bits;
-const FMA          : u32 = Features::FMA.bits;
-const BMI1         : u32 = Features::BMI1.bits;
-const BMI2         : u32 = Features::BMI2.bits;
-const TBM          : u32 = Features::TBM.bits;
-const RTM          : u32 = Features::RTM.bits;
-const INVPCID      : u32 = Features::INVPCID.bits;
-const MPX          : u32 = Features::MPX.bits;
-const SHA          : u32 = Features::SHA.bits;
-const PREFETCHWT1  : u32 = Features::PREFETCHWT1.bits;
-const CYRIX        : u32 = Features::CYRIX.bits;
-const AMD          : u32 = Features::AMD.bits;
+const X64_IMPLICIT : u64 = Features::X64_IMPLICIT.bits;
+const FPU          : u64 = Features::FPU.bits;
+const MMX          : u64 = Features::MMX.bits;
+const TDNOW        : u64 = Features::TDNOW.bits;
+const SSE          : u64 = Features::SSE.bits;
+const SSE2         : u64 = Features::SSE2.bits;
+const SSE3         : u64 = Features::SSE3.bits;
+const VMX          : u64 = Features::VMX.bits;
+const SSSE3        : u64 = Features::SSSE3.bits;
+const SSE4A        : u64 = Features::SSE4A.bits;
+const SSE41        : u64 = Features::SSE41.bits;
+const SSE42        : u64 = Features::SSE42.bits;
+const SSE5         : u64 = Features::SSE5.bits;
+const AVX          : u64 = Features::AVX.bits;
+const AVX2         : u64 = Features::AVX2.bits;
+const FMA          : u64 = Features::FMA.bits;
+const BMI1         : u64 = Features::BMI1.bits;
+const BMI2         : u64 = Features::BMI2.bits;
+const TBM          : u64 = Features::TBM.bits;
+const RTM          : u64 = Features::RTM.bits;
+const INVPCID      : u64 = Features::INVPCID.bits;
+const MPX          : u64 = Features::MPX.bits;
+const SHA          : u64 = Features::SHA.bits;
+const PREFETCHWT1  : u64 = Features::PREFETCHWT1.bits;
+const CYRIX        : u64 = Features::CYRIX.bits;
+const AMD          : u64 = Features::AMD.bits;
+const GFNI         : u64 = Features::GFNI.bits;
+const VAES         : u64 = Features::VAES.bits;
+const VPCLMULQDQ   : u64 = Features::VPCLMULQDQ.bits;
+const AVX_VNNI     : u64 = Features::AVX_VNNI.bits;
+const CET          : u64 = Features::CET.bits;
+const CLFLUSHOPT   : u64 = Features::CLFLUSHOPT.bits;
+const CLWB         : u64 = Features::CLWB.bits;
+const CLDEMOTE     : u64 = Features::CLDEMOTE.bits;
+const RDPID        : u64 = Features::RDPID.bits;
+const SERIALIZE    : u64 = Features::SERIALIZE.bits;
+const MOVDIRI      : u64 = Features::MOVDIRI.bits;
+const MOVDIR64B    : u64 = Features::MOVDIR64B.bits;
+const WAITPKG      : u64 = Features::WAITPKG.bits;
+const WBNOINVD     : u64 = Features::WBNOINVD.bits;
+const PTWRITE      : u64 = Features::PTWRITE.bits;
+const AMX_TILE     : u64 = Features::AMX_TILE.bits;
+const AMX_INT8     : u64 = Features::AMX_INT8.bits;
+const AMX_BF16     : u64 = Features::AMX_BF16.bits;
+const APX          : u64 = Features::APX.bits;
 
 
 lazy_static! {
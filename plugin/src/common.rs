@@ -58,6 +58,7 @@ pub enum JumpKind {
     // in order to allow the full range of expressions to be used. the only currently existing ambiguity is
     // with the symbol <, as this symbol is also the starting symbol for the universal calling syntax <Type as Trait>.method(args)
     Global(syn::Ident),   // -> label (["+" "-"] offset)?
+    Named(syn::Expr),     // -> (expr) (["+" "-"] offset)?
     Backward(syn::Ident), //  > label (["+" "-"] offset)?
     Forward(syn::Ident),  //  < label (["+" "-"] offset)?
     Dynamic(syn::Expr),   // =>expr | => (expr) (["+" "-"] offset)?
@@ -74,11 +75,21 @@ impl ParseOpt for Jump {
         }
 
         // -> global_label
+        // -> (named_label)
         let kind = if input.peek(Token![->]) {
             let _: Token![->] = input.parse()?;
-            let name: syn::Ident = input.parse()?;
 
-            JumpKind::Global(name)
+            if input.peek(syn::token::Paren) {
+                let inner;
+                let _ = syn::parenthesized!(inner in input);
+                let inner = &inner;
+
+                JumpKind::Named(inner.parse()?)
+            } else {
+                let name: syn::Ident = input.parse()?;
+
+                JumpKind::Global(name)
+            }
 
         // > forward_label
         } else if input.peek(Token![>]) {
@@ -152,6 +163,7 @@ impl Jump {
         let data = serialize::expr_tuple_of_u8s(span, data);
         match self.kind {
             JumpKind::Global(ident) => Stmt::GlobalJumpTarget(ident, offset, data),
+            JumpKind::Named(expr) => Stmt::NamedJumpTarget(delimited(expr), offset, data),
             JumpKind::Backward(ident) => Stmt::BackwardJumpTarget(ident, offset, data),
             JumpKind::Forward(ident) => Stmt::ForwardJumpTarget(ident, offset, data),
             JumpKind::Dynamic(expr) => Stmt::DynamicJumpTarget(delimited(expr), offset, data),
@@ -162,6 +174,7 @@ impl Jump {
     pub fn span(&self) -> Span {
         match &self.kind {
             JumpKind::Global(ident) => ident.span(),
+            JumpKind::Named(expr) => expr.span(),
             JumpKind::Backward(ident) => ident.span(),
             JumpKind::Forward(ident) => ident.span(),
             JumpKind::Dynamic(expr) => expr.span(),
@@ -192,12 +205,18 @@ pub enum Stmt {
     GlobalLabel(syn::Ident),
     LocalLabel(syn::Ident),
     DynamicLabel(TokenTree),
+    NamedLabel(TokenTree),
+
+    // label scope changes
+    BeginScope,
+    EndScope,
 
     // and their respective relocations (as expressions as they differ per assembler)
     GlobalJumpTarget(  syn::Ident, TokenTree, TokenTree),
     ForwardJumpTarget( syn::Ident, TokenTree, TokenTree),
     BackwardJumpTarget(syn::Ident, TokenTree, TokenTree),
     DynamicJumpTarget(TokenTree, TokenTree, TokenTree),
+    NamedJumpTarget(  TokenTree, TokenTree, TokenTree),
     BareJumpTarget(   TokenTree, TokenTree),
 
     // a random statement that has to be inserted between assembly hunks
@@ -223,6 +242,19 @@ impl Stmt {
     pub fn u64(value: u64) -> Stmt {
         Stmt::Const(value, Size::QWORD)
     }
+
+    /// Returns if this statement emits a relocation
+    pub fn is_relocation(&self) -> bool {
+        match self {
+            Stmt::GlobalJumpTarget(..)
+            | Stmt::ForwardJumpTarget(..)
+            | Stmt::BackwardJumpTarget(..)
+            | Stmt::DynamicJumpTarget(..)
+            | Stmt::NamedJumpTarget(..)
+            | Stmt::BareJumpTarget(..) => true,
+            _ => false
+        }
+    }
 }
 
 
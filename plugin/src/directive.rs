@@ -8,13 +8,13 @@ use proc_macro_error::emit_error;
 use crate::common::{Stmt, Size, delimited};
 use crate::arch;
 use crate::DynasmData;
-use crate::parse_helpers::ParseOptExt;
+use crate::parse_helpers::{ParseOptExt, eat_pseudo_keyword};
 
 pub(crate) fn evaluate_directive(file_data: &mut DynasmData, stmts: &mut Vec<Stmt>, input: parse::ParseStream) -> parse::Result<()> {
     let directive: syn::Ident = input.parse()?;
 
     match directive.to_string().as_str() {
-        // TODO: oword, qword, float, double, long double
+        // TODO: float, double, long double
 
         "arch" => {
             // ; .arch ident
@@ -48,6 +48,9 @@ pub(crate) fn evaluate_directive(file_data: &mut DynasmData, stmts: &mut Vec<Stm
         "word"  => directive_const(file_data, stmts, input, Size::WORD)?,
         "dword" => directive_const(file_data, stmts, input, Size::DWORD)?,
         "qword" => directive_const(file_data, stmts, input, Size::QWORD)?,
+        "fword" => directive_const(file_data, stmts, input, Size::FWORD)?,
+        "pword" => directive_const(file_data, stmts, input, Size::PWORD)?,
+        "oword" => directive_const(file_data, stmts, input, Size::OWORD)?,
         "bytes" => {
             // ; .bytes expr
             let iterator: syn::Expr = input.parse()?;
@@ -69,6 +72,14 @@ pub(crate) fn evaluate_directive(file_data: &mut DynasmData, stmts: &mut Vec<Stm
 
             stmts.push(Stmt::Align(delimited(value), with));
         },
+        "scope" => {
+            // ; .scope ("end")?
+            if eat_pseudo_keyword(input, "end") {
+                stmts.push(Stmt::EndScope);
+            } else {
+                stmts.push(Stmt::BeginScope);
+            }
+        },
         "alias" => {
             // ; .alias ident, ident
             // consider changing this to ; .alias ident = ident next breaking change
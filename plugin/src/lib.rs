@@ -99,9 +99,20 @@ impl parse::Parse for Dynasm {
             }
 
             // ; -> label :
+            // ; -> (expr) :
             if input.peek(Token![->]) {
                 let _: Token![->] = input.parse()?;
 
+                if input.peek(syn::token::Paren) {
+                    let inner;
+                    let _ = syn::parenthesized!(inner in input);
+                    let expr: syn::Expr = inner.parse()?;
+                    let _: Token![:] = input.parse()?;
+
+                    stmts.push(common::Stmt::NamedLabel(common::delimited(expr)));
+                    continue;
+                }
+
                 let name: syn::Ident = input.parse()?;
                 let _: Token![:] = input.parse()?;
 
@@ -1,5 +1,7 @@
 //! This file contains parsing helpers used by multiple parsing backends
 use syn::parse;
+use syn::spanned::Spanned;
+use quote::quote_spanned;
 use std::convert::TryInto;
 
 /**
@@ -119,6 +121,81 @@ pub fn as_signed_number(expr: &syn::Expr) -> Option<i64> {
     }
 }
 
+/// checks if an expression can only evaluate to an integer, like a literal, a cast or arithmetic.
+/// Other expressions, like variables, could also evaluate to a typed register.
+pub fn is_integer_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(_), .. }) => true,
+        syn::Expr::Cast(_) |
+        syn::Expr::Binary(_) => true,
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), .. }) |
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Not(_), .. }) => true,
+        syn::Expr::Paren(syn::ExprParen { expr, .. }) |
+        syn::Expr::Group(syn::ExprGroup { expr, .. }) => is_integer_expr(expr),
+        _ => false
+    }
+}
+
+/// Wraps a dynamic register expression in a conversion through the typed register API of the runtime,
+/// which accepts both typed registers `arch::ty` and plain encodings. The result is an expression of type `int`.
+/// Expressions that can only be integers are cast directly, so they don't require dynasmrt to be in scope.
+pub fn dynamic_register(arch: &str, ty: &str, int: &str, expr: &syn::Expr) -> syn::Expr {
+    let span = expr.span();
+    let int = syn::Ident::new(int, span);
+    if is_integer_expr(expr) {
+        return syn::parse2(quote_spanned! { span=>
+            ((#expr) as #int)
+        }).expect("dynamic register conversion");
+    }
+    let arch = syn::Ident::new(arch, span);
+    let ty = syn::Ident::new(ty, span);
+
+    // the runtime returns encodings as a u8
+    let code = quote_spanned! { span=>
+        dynasmrt::#arch::DynamicRegister::<dynasmrt::#arch::#ty>::code(#expr)
+    };
+    if int == "u8" {
+        syn::parse2(code)
+    } else {
+        syn::parse2(quote_spanned! { span=> (#code as #int) })
+    }.expect("dynamic register conversion")
+}
+
+/// Returns an expression for the highest encoding the register converted by `dynamic_register` into `expr` can have,
+/// if it was converted through the typed register API of the runtime. Casted integers can have any encoding.
+pub fn dynamic_register_bound(expr: &syn::Expr) -> Option<syn::Expr> {
+    let mut expr = expr;
+    loop {
+        match expr {
+            syn::Expr::Paren(syn::ExprParen { expr: inner, .. }) |
+            syn::Expr::Group(syn::ExprGroup { expr: inner, .. }) |
+            syn::Expr::Cast(syn::ExprCast { expr: inner, .. }) => expr = inner,
+            _ => break
+        }
+    }
+
+    let (func, args) = match expr {
+        syn::Expr::Call(syn::ExprCall { func, args, .. }) if args.len() == 1 => (func, args),
+        _ => return None
+    };
+    let mut path = match &**func {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path.clone(),
+        _ => return None
+    };
+    let len = path.segments.len();
+    if len < 2 || path.segments[len - 2].ident != "DynamicRegister" || path.segments[len - 1].ident != "code" {
+        return None;
+    }
+    let last = &mut path.segments[len - 1];
+    last.ident = syn::Ident::new("max_code", last.ident.span());
+
+    let arg = &args[0];
+    let span = arg.span();
+    syn::parse2(quote_spanned! { span=>
+        #path(&#arg)
+    }).ok()
+}
+
 /// checks if an expression is a constant float literal
 pub fn as_float(expr: &syn::Expr) -> Option<f64> {
     let (expr, negated) = as_lit_with_negation(expr)?;
@@ -15,27 +15,20 @@ pub fn serialize(name: &TokenTree, stmts: Vec<Stmt>) -> TokenStream {
     let mut folded_stmts = Vec::new();
     let mut const_buffer = Vec::new();
     for stmt in stmts {
+        // constants are unsigned. Ones wider than a QWORD with the top bit set are widened at runtime by the
+        // typed push methods, so only constants for which sign and zero extension agree get folded
+        let stmt = match stmt {
+            Stmt::Const(value, size) if size.in_bytes() > 8 && (value as i64) < 0 => {
+                Stmt::ExprUnsigned(proc_macro2::Literal::u64_unsuffixed(value).into(), size)
+            },
+            stmt => stmt
+        };
+
         match stmt {
             Stmt::Const(value, size) => {
-                match size {
-                    Size::BYTE => const_buffer.push(value as u8),
-                    Size::WORD => {
-                        let mut buffer = [0u8; 2];
-                        LittleEndian::write_u16(&mut buffer, value as u16);
-                        const_buffer.extend(&buffer);
-                    },
-                    Size::DWORD => {
-                        let mut buffer = [0u8; 4];
-                        LittleEndian::write_u32(&mut buffer, value as u32);
-                        const_buffer.extend(&buffer);
-                    },
-                    Size::QWORD => {
-                        let mut buffer = [0u8; 8];
-                        LittleEndian::write_u64(&mut buffer, value as u64);
-                        const_buffer.extend(&buffer);
-                    },
-                    _ => unimplemented!()
-                }
+                let mut buffer = [0u8; 32];
+                LittleEndian::write_u64(&mut buffer, value);
+                const_buffer.extend(&buffer[..usize::from(size.in_bytes())]);
             },
             Stmt::Extend(data) => {
                 const_buffer.extend(data);
@@ -63,28 +56,45 @@ pub fn serialize(name: &TokenTree, stmts: Vec<Stmt>) -> TokenStream {
     let mut output = TokenStream::new();
 
     for stmt in folded_stmts {
+        // before every relocation, tell the assembler where it came from for error reporting
+        if stmt.is_relocation() {
+            output.extend(quote! {
+                #name . source_location ( file!(), line!() ) ;
+            });
+        }
+
         let (method, args) = match stmt {
             Stmt::Const(_, _) => unreachable!(),
             Stmt::ExprUnsigned(expr, Size::BYTE)  => ("push",     vec![expr]),
             Stmt::ExprUnsigned(expr, Size::WORD)  => ("push_u16", vec![expr]),
             Stmt::ExprUnsigned(expr, Size::DWORD) => ("push_u32", vec![expr]),
+            Stmt::ExprUnsigned(expr, Size::FWORD) => ("push_u48", vec![expr]),
             Stmt::ExprUnsigned(expr, Size::QWORD) => ("push_u64", vec![expr]),
-            Stmt::ExprUnsigned(_, _) => unimplemented!(),
+            Stmt::ExprUnsigned(expr, Size::PWORD) => ("push_u80", vec![expr]),
+            Stmt::ExprUnsigned(expr, Size::OWORD) => ("push_u128", vec![expr]),
+            Stmt::ExprUnsigned(expr, Size::HWORD) => ("push_u256", vec![expr]),
             Stmt::ExprSigned(  expr, Size::BYTE)  => ("push_i8",  vec![expr]),
             Stmt::ExprSigned(  expr, Size::WORD)  => ("push_i16", vec![expr]),
             Stmt::ExprSigned(  expr, Size::DWORD) => ("push_i32", vec![expr]),
+            Stmt::ExprSigned(  expr, Size::FWORD) => ("push_i48", vec![expr]),
             Stmt::ExprSigned(  expr, Size::QWORD) => ("push_i64", vec![expr]),
-            Stmt::ExprSigned(_, _) => unimplemented!(),
+            Stmt::ExprSigned(  expr, Size::PWORD) => ("push_i80", vec![expr]),
+            Stmt::ExprSigned(  expr, Size::OWORD) => ("push_i128", vec![expr]),
+            Stmt::ExprSigned(  expr, Size::HWORD) => ("push_i256", vec![expr]),
             Stmt::Extend(data)     => ("extend", vec![proc_macro2::Literal::byte_string(&data).into()]),
             Stmt::ExprExtend(expr) => ("extend", vec![expr]),
             Stmt::Align(expr, with)      => ("align", vec![expr, with]),
             Stmt::GlobalLabel(n) => ("global_label", vec![expr_string_from_ident(&n)]),
             Stmt::LocalLabel(n)  => ("local_label", vec![expr_string_from_ident(&n)]),
             Stmt::DynamicLabel(expr) => ("dynamic_label", vec![expr]),
+            Stmt::NamedLabel(expr) => ("named_label", vec![expr]),
+            Stmt::BeginScope => ("begin_scope", vec![]),
+            Stmt::EndScope   => ("end_scope", vec![]),
             Stmt::GlobalJumpTarget(n,     offset, reloc) => ("global_reloc"  , vec![expr_string_from_ident(&n), offset, reloc]),
             Stmt::ForwardJumpTarget(n,    offset, reloc) => ("forward_reloc" , vec![expr_string_from_ident(&n), offset, reloc]),
             Stmt::BackwardJumpTarget(n,   offset, reloc) => ("backward_reloc", vec![expr_string_from_ident(&n), offset, reloc]),
             Stmt::DynamicJumpTarget(expr, offset, reloc) => ("dynamic_reloc" , vec![expr, offset, reloc]),
+            Stmt::NamedJumpTarget(expr,   offset, reloc) => ("named_reloc"   , vec![expr, offset, reloc]),
             Stmt::BareJumpTarget(expr, reloc)    => ("bare_reloc"    , vec![expr, reloc]),
             Stmt::Stmt(s) => {
                 output.extend(quote! {
@@ -2,7 +2,12 @@
 
 use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
 use byteorder::{ByteOrder, LittleEndian};
-use std::convert::TryFrom;
+use crate::DynasmApi;
+use crate::blob::{BlobRelocation, size_from_byte};
+use core::convert::TryFrom;
+use alloc::vec::Vec;
+
+pub use crate::registers::{DynamicRegister, InvalidRegister};
 
 /// Relocation implementation for the aarch64 architecture.
 #[derive(Debug, Clone)]
@@ -34,26 +39,26 @@ impl Aarch64Relocation {
         }
     }
 
-    fn encode(&self, value: isize) -> Result<u32, ImpossibleRelocation> {
-        let value = i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?;
+    fn encode(&self, orig: isize) -> Result<u32, ImpossibleRelocation> {
+        let value = i64::try_from(orig).map_err(|_| ImpossibleRelocation::signed_field(orig, 64, 1))?;
         Ok(match self {
             Self::B => {
                 if value & 3 != 0 || !fits_signed_bitfield(value >> 2, 26) {
-                    return Err(ImpossibleRelocation { } );
+                    return Err(ImpossibleRelocation::signed_field(orig, 26, 4));
                 }
                 let value = (value >> 2) as u32;
                 value & 0x3FF_FFFF
             },
             Self::BCOND => {
                 if value & 3 != 0 || !fits_signed_bitfield(value >> 2, 19) {
-                    return Err(ImpossibleRelocation { } );
+                    return Err(ImpossibleRelocation::signed_field(orig, 19, 4));
                 }
                 let value = (value >> 2) as u32;
                 (value & 0x7FFFF) << 5
             },
             Self::ADR => {
                 if !fits_signed_bitfield(value, 21) {
-                    return Err(ImpossibleRelocation { } );
+                    return Err(ImpossibleRelocation::signed_field(orig, 21, 1));
                 }
                 let low = (value) as u32;
                 let high = (value >> 2) as u32;
@@ -62,7 +67,7 @@ impl Aarch64Relocation {
             Self::ADRP => {
                 let value = value + 0xFFF;
                 if !fits_signed_bitfield(value >> 12, 21) {
-                    return Err(ImpossibleRelocation { } );
+                    return Err(ImpossibleRelocation::signed_field(orig, 21, 4096));
                 }
                 let low = (value >> 12) as u32;
                 let high = (value >> 14) as u32;
@@ -70,12 +75,13 @@ impl Aarch64Relocation {
             },
             Self::TBZ => {
                 if value & 3 != 0 || !fits_signed_bitfield(value >> 2, 14) {
-                    return Err(ImpossibleRelocation { } );
+                    return Err(ImpossibleRelocation::signed_field(orig, 14, 4));
                 }
                 let value = (value >> 2) as u32;
                 (value & 0x3FFF) << 5
             },
-            Self::Plain(_) => return Err(ImpossibleRelocation { } )
+            // plain relocations are not packed into an instruction, so there is no field to encode
+            Self::Plain(s) => return Err(ImpossibleRelocation::signed_field(orig, (s.size() * 8) as u8, 1))
         })
     }
 }
@@ -160,13 +166,167 @@ impl Relocation for Aarch64Relocation {
         RelocationKind::Relative
     }
     fn page_size() -> usize {
-        4096
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for Aarch64Relocation {
+    fn to_bytes(&self) -> [u8; 4] {
+        match self {
+            Self::B => [0, 0, 0, 0],
+            Self::BCOND => [1, 0, 0, 0],
+            Self::ADR => [2, 0, 0, 0],
+            Self::ADRP => [3, 0, 0, 0],
+            Self::TBZ => [4, 0, 0, 0],
+            Self::Plain(size) => [5, *size as u8, 0, 0],
+        }
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(match bytes[0] {
+            0 => Self::B,
+            1 => Self::BCOND,
+            2 => Self::ADR,
+            3 => Self::ADRP,
+            4 => Self::TBZ,
+            5 => Self::Plain(size_from_byte(bytes[1])?),
+            _ => return None
+        })
+    }
+}
+
+registers!(
+    /// A general purpose register where encoding 31 is the zero register, as used by the `X` and `W` families.
+    X {
+        X0 = 0, X1 = 1, X2 = 2, X3 = 3, X4 = 4, X5 = 5, X6 = 6, X7 = 7,
+        X8 = 8, X9 = 9, X10 = 10, X11 = 11, X12 = 12, X13 = 13, X14 = 14, X15 = 15,
+        X16 = 16, X17 = 17, X18 = 18, X19 = 19, X20 = 20, X21 = 21, X22 = 22, X23 = 23,
+        X24 = 24, X25 = 25, X26 = 26, X27 = 27, X28 = 28, X29 = 29, X30 = 30, XZR = 31,
+    }
+);
+
+registers!(
+    /// A general purpose register where encoding 31 is the stack pointer, as used by the `XSP` and `WSP` families.
+    XSP {
+        X0 = 0, X1 = 1, X2 = 2, X3 = 3, X4 = 4, X5 = 5, X6 = 6, X7 = 7,
+        X8 = 8, X9 = 9, X10 = 10, X11 = 11, X12 = 12, X13 = 13, X14 = 14, X15 = 15,
+        X16 = 16, X17 = 17, X18 = 18, X19 = 19, X20 = 20, X21 = 21, X22 = 22, X23 = 23,
+        X24 = 24, X25 = 25, X26 = 26, X27 = 27, X28 = 28, X29 = 29, X30 = 30, SP = 31,
     }
+);
+
+registers!(
+    /// A SIMD and floating point register, as used by the `V`, `B`, `H`, `S`, `D` and `Q` families.
+    V {
+        V0 = 0, V1 = 1, V2 = 2, V3 = 3, V4 = 4, V5 = 5, V6 = 6, V7 = 7,
+        V8 = 8, V9 = 9, V10 = 10, V11 = 11, V12 = 12, V13 = 13, V14 = 14, V15 = 15,
+        V16 = 16, V17 = 17, V18 = 18, V19 = 19, V20 = 20, V21 = 21, V22 = 22, V23 = 23,
+        V24 = 24, V25 = 25, V26 = 26, V27 = 27, V28 = 28, V29 = 29, V30 = 30, V31 = 31,
+    }
+);
+
+/// A description of the stack frame of an AAPCS64 function, which can emit a matching prologue and epilogue.
+/// The prologue saves the frame pointer and link register, points `x29` at them, pushes the callee-saved
+/// registers the function clobbers and allocates `locals` bytes of local variables, rounded up to keep
+/// `sp` 16-byte aligned. The local variables live at `[sp]`.
+/// Callee-saved SIMD registers are not saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    saved: Vec<X>,
+    allocated: u32,
 }
 
+impl Frame {
+    /// Describe a frame. `clobbered` lists all general purpose registers the function
+    /// overwrites, of which only the callee-saved ones (`x19` to `x28`) are saved.
+    ///
+    /// # Panics
+    /// Panics if the local variables take up 16MiB or more, which can't be allocated with a single `sub`.
+    pub fn new(clobbered: &[X], locals: usize) -> Frame {
+        let mut saved: Vec<X> = clobbered.iter().cloned().filter(|&reg| reg >= X::X19 && reg <= X::X28).collect();
+        saved.sort();
+        saved.dedup();
+
+        let allocated = locals.saturating_add(15) & !15;
+        assert!(allocated < 1 << 24, "stack frame too large");
+
+        Frame {
+            saved,
+            allocated: allocated as u32,
+        }
+    }
+
+    /// The callee-saved registers this frame saves, in the order they are pushed.
+    pub fn saved_registers(&self) -> &[X] {
+        &self.saved
+    }
+
+    /// The amount of stack space the prologue allocates for local variables.
+    pub fn allocated(&self) -> usize {
+        self.allocated as usize
+    }
+
+    /// Emit the function prologue, which saves registers and allocates the frame.
+    pub fn prologue<D: DynasmApi>(&self, ops: &mut D) {
+        // stp x29, x30, [sp, #-16]! ; mov x29, sp
+        ops.push_u32(0xA9BF_7BFD);
+        ops.push_u32(0x9100_03FD);
+
+        for pair in self.saved.chunks(2) {
+            ops.push_u32(match *pair {
+                // stp a, b, [sp, #-16]!
+                [a, b] => 0xA9BF_03E0 | u32::from(b.code()) << 10 | u32::from(a.code()),
+                // str a, [sp, #-16]!
+                [a] => 0xF81F_0FE0 | u32::from(a.code()),
+                _ => unreachable!()
+            });
+        }
+
+        // sub sp, sp, #allocated
+        self.adjust_sp(ops, 0xD100_03FF);
+    }
+
+    /// Emit the function epilogue, which deallocates the frame, restores registers and returns.
+    pub fn epilogue<D: DynasmApi>(&self, ops: &mut D) {
+        // add sp, sp, #allocated
+        self.adjust_sp(ops, 0x9100_03FF);
+
+        for pair in self.saved.chunks(2).rev() {
+            ops.push_u32(match *pair {
+                // ldp a, b, [sp], #16
+                [a, b] => 0xA8C1_03E0 | u32::from(b.code()) << 10 | u32::from(a.code()),
+                // ldr a, [sp], #16
+                [a] => 0xF841_07E0 | u32::from(a.code()),
+                _ => unreachable!()
+            });
+        }
+
+        // ldp x29, x30, [sp], #16 ; ret
+        ops.push_u32(0xA8C1_7BFD);
+        ops.push_u32(0xD65F_03C0);
+    }
+
+    // emits an add or sub of sp with an immediate, split into a shifted and unshifted part
+    fn adjust_sp<D: DynasmApi>(&self, ops: &mut D, op: u32) {
+        let high = self.allocated >> 12;
+        let low = self.allocated & 0xFFF;
+        if high != 0 {
+            ops.push_u32(op | 1 << 22 | high << 10);
+        }
+        if low != 0 {
+            ops.push_u32(op | low << 10);
+        }
+    }
+}
+
+/// The encoding of `brk #0`, which traps when executed. Suitable as `Config::poison` pattern.
+pub const BRK: &[u8] = &[0x00, 0x00, 0x20, 0xD4];
+
+
 /// An aarch64 Assembler. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type Assembler = crate::Assembler<Aarch64Relocation>;
 /// An aarch64 AssemblyModifier. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type AssemblyModifier<'a> = crate::Modifier<'a, Aarch64Relocation>;
 /// An aarch64 UncommittedModifier. This is aliased here for backwards compatability.
 pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
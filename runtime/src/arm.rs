@@ -0,0 +1,423 @@
+//! This module implements the relocation model for the 32-bit arm architecture, in both the arm and thumb
+//! instruction sets, as well as aliases for arm Assemblers.
+//!
+//! Relocations in arm code are relative to the start of the instruction plus 8 bytes, and in thumb code to the start
+//! of the instruction plus 4 bytes. PC-relative loads and `adr` in thumb code are relative to this address
+//! rounded down to a multiple of 4. As this depends on the alignment of the instruction itself, which the relocation
+//! cannot know, the targets of these instructions have to be aligned to 4 bytes.
+//!
+//! Thumb code is entered by branching to its address with the lowest bit set, so remember to set it when
+//! calling assembled thumb code from rust.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use crate::blob::{BlobRelocation, size_from_byte};
+use core::convert::TryFrom;
+
+pub use crate::registers::{DynamicRegister, InvalidRegister};
+
+/// Relocation implementation for the arm architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum ArmRelocation {
+    // b, bl: 26 bits, word aligned
+    BRANCH,
+    // ldr, ldrb (literal): 12 bits with a sign bit
+    LDR,
+    // adr: a rotated immediate with a sign bit
+    ADR,
+    // vldr (literal): 10 bits with a sign bit, word aligned
+    VLDR,
+    // thumb b.w, bl: 25 bits, halfword aligned
+    TBRANCH,
+    // thumb conditional b.w: 21 bits, halfword aligned
+    TBCOND,
+    // thumb cbz, cbnz: unsigned 7 bits, halfword aligned
+    TCBZ,
+    // thumb ldr, ldrb (literal): 12 bits with a sign bit, relative to the aligned pc
+    TLDR,
+    // thumb adr: 12 bits with a sign bit, relative to the aligned pc
+    TADR,
+    // thumb vldr (literal): 10 bits with a sign bit, word aligned, relative to the aligned pc
+    TVLDR,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl ArmRelocation {
+    fn op_mask(&self) -> u32 {
+        match self {
+            Self::BRANCH => 0xFF00_0000,
+            Self::LDR => 0xFF7F_F000,
+            Self::ADR => 0xFF3F_F000,
+            Self::VLDR => 0xFF7F_FF00,
+            Self::TBRANCH => 0xF800_D000,
+            Self::TBCOND => 0xFBC0_D000,
+            Self::TCBZ => 0xFD07,
+            Self::TLDR => 0xFF7F_F000,
+            Self::TADR => 0xFB5F_8F00,
+            Self::TVLDR => 0xFF7F_FF00,
+            Self::Plain(_) => 0
+        }
+    }
+
+    fn is_thumb32(&self) -> bool {
+        matches!(self,
+            Self::TBRANCH |
+            Self::TBCOND |
+            Self::TLDR |
+            Self::TADR |
+            Self::TVLDR
+        )
+    }
+
+    // the distance between the start of the instruction and the address the encoded offset is relative to
+    fn pc_bias(&self) -> i64 {
+        match self {
+            Self::BRANCH |
+            Self::LDR |
+            Self::ADR |
+            Self::VLDR => 8,
+            _ => 4
+        }
+    }
+
+    fn encode(&self, orig: isize) -> Result<u32, ImpossibleRelocation> {
+        let value = i64::try_from(orig).map_err(|_| ImpossibleRelocation::signed_field(orig, 64, 1))?;
+        let value = match self {
+            // these are relative to the pc rounded down to a multiple of 4. As the target is aligned,
+            // this is the same as rounding the offset up to a multiple of 4.
+            Self::TLDR |
+            Self::TADR |
+            Self::TVLDR => ((value + 3) & !3) - self.pc_bias(),
+            _ => value - self.pc_bias()
+        };
+
+        Ok(match self {
+            Self::BRANCH => {
+                if value & 3 != 0 || !fits_signed_bitfield(value, 26) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 24, 4));
+                }
+                ((value >> 2) as u32) & 0xFF_FFFF
+            },
+            Self::LDR |
+            Self::TLDR => {
+                if value.unsigned_abs() > 0xFFF {
+                    return Err(ImpossibleRelocation::signed_field(orig, 13, 1));
+                }
+                (if value < 0 { 0 } else { 1 << 23 }) | (value.unsigned_abs() as u32)
+            },
+            Self::ADR => {
+                let encoded = u32::try_from(value.unsigned_abs()).ok()
+                    .and_then(encode_modified_immediate)
+                    .ok_or_else(|| ImpossibleRelocation::signed_field(orig, 9, 4))?;
+                (if value < 0 { 1 << 22 } else { 1 << 23 }) | u32::from(encoded)
+            },
+            Self::VLDR |
+            Self::TVLDR => {
+                if value & 3 != 0 || value.unsigned_abs() > 0x3FC {
+                    return Err(ImpossibleRelocation::signed_field(orig, 9, 4));
+                }
+                (if value < 0 { 0 } else { 1 << 23 }) | ((value.unsigned_abs() >> 2) as u32)
+            },
+            Self::TBRANCH => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 25) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 24, 2));
+                }
+                let value = value as u32;
+                let s = (value >> 24) & 1;
+                let j1 = !((value >> 23) ^ s) & 1;
+                let j2 = !((value >> 22) ^ s) & 1;
+                s << 26 |
+                ((value >> 12) & 0x3FF) << 16 |
+                j1 << 13 |
+                j2 << 11 |
+                ((value >> 1) & 0x7FF)
+            },
+            Self::TBCOND => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 21) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 20, 2));
+                }
+                let value = value as u32;
+                ((value >> 20) & 1) << 26 |
+                ((value >> 12) & 0x3F) << 16 |
+                ((value >> 18) & 1) << 13 |
+                ((value >> 19) & 1) << 11 |
+                ((value >> 1) & 0x7FF)
+            },
+            Self::TCBZ => {
+                if value & 1 != 0 || !(0..=126).contains(&value) {
+                    return Err(ImpossibleRelocation {
+                        value: orig,
+                        min: 4,
+                        max: 130,
+                        alignment: 2
+                    });
+                }
+                let value = value as u32;
+                ((value >> 6) & 1) << 9 |
+                ((value >> 1) & 0x1F) << 3
+            },
+            Self::TADR => {
+                if value.unsigned_abs() > 0xFFF {
+                    return Err(ImpossibleRelocation::signed_field(orig, 13, 1));
+                }
+                let imm = value.unsigned_abs() as u32;
+                (if value < 0 { 0x00A0_0000 } else { 0 }) |
+                ((imm >> 11) & 1) << 26 |
+                ((imm >> 8) & 7) << 12 |
+                (imm & 0xFF)
+            },
+            Self::Plain(_) => unreachable!()
+        })
+    }
+}
+
+impl Relocation for ArmRelocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::BRANCH,
+            1 => Self::LDR,
+            2 => Self::ADR,
+            3 => Self::VLDR,
+            4 => Self::TBRANCH,
+            5 => Self::TBCOND,
+            6 => Self::TCBZ,
+            7 => Self::TLDR,
+            8 => Self::TADR,
+            9 => Self::TVLDR,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 10))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::Plain(s) => s.size(),
+            Self::TCBZ => RelocationSize::Word.size(),
+            _ => RelocationSize::DWord.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let mask = self.op_mask();
+        let packed = self.encode(value)?;
+
+        if let Self::TCBZ = self {
+            let template = u32::from(LittleEndian::read_u16(buf)) & mask;
+            LittleEndian::write_u16(buf, (template | packed) as u16);
+        } else if self.is_thumb32() {
+            // 32-bit thumb instructions are stored as two halfwords, most significant halfword first
+            let template = read_thumb32(buf) & mask;
+            write_thumb32(buf, template | packed);
+        } else {
+            let template = LittleEndian::read_u32(buf) & mask;
+            LittleEndian::write_u32(buf, template | packed);
+        }
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        if let Self::Plain(s) = self {
+            return s.read_value(buf);
+        };
+
+        let value = if let Self::TCBZ = self {
+            u32::from(LittleEndian::read_u16(buf))
+        } else if self.is_thumb32() {
+            read_thumb32(buf)
+        } else {
+            LittleEndian::read_u32(buf)
+        };
+
+        let offset: i64 = match self {
+            Self::BRANCH => i64::from(((value << 8) as i32) >> 6),
+            Self::LDR |
+            Self::TLDR => {
+                let imm = i64::from(value & 0xFFF);
+                if value & (1 << 23) != 0 { imm } else { -imm }
+            },
+            Self::ADR => {
+                let imm = i64::from((value & 0xFF).rotate_right(((value >> 8) & 0xF) * 2));
+                if value & (1 << 23) != 0 { imm } else { -imm }
+            },
+            Self::VLDR |
+            Self::TVLDR => {
+                let imm = i64::from(value & 0xFF) << 2;
+                if value & (1 << 23) != 0 { imm } else { -imm }
+            },
+            Self::TBRANCH => {
+                let s = (value >> 26) & 1;
+                let i1 = !((value >> 13) ^ s) & 1;
+                let i2 = !((value >> 11) ^ s) & 1;
+                let unpacked = s << 24 | i1 << 23 | i2 << 22 | ((value >> 16) & 0x3FF) << 12 | (value & 0x7FF) << 1;
+                i64::from(((unpacked << 7) as i32) >> 7)
+            },
+            Self::TBCOND => {
+                let unpacked = ((value >> 26) & 1) << 20 |
+                    ((value >> 11) & 1) << 19 |
+                    ((value >> 13) & 1) << 18 |
+                    ((value >> 16) & 0x3F) << 12 |
+                    (value & 0x7FF) << 1;
+                i64::from(((unpacked << 11) as i32) >> 11)
+            },
+            Self::TCBZ => i64::from(((value >> 9) & 1) << 6 | ((value >> 3) & 0x1F) << 1),
+            Self::TADR => {
+                let imm = i64::from(((value >> 26) & 1) << 11 | ((value >> 12) & 7) << 8 | (value & 0xFF));
+                if value & 0x00A0_0000 != 0 { -imm } else { imm }
+            },
+            Self::Plain(_) => unreachable!()
+        };
+
+        (offset + self.pc_bias()) as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for ArmRelocation {
+    fn to_bytes(&self) -> [u8; 4] {
+        match self {
+            Self::BRANCH => [0, 0, 0, 0],
+            Self::LDR => [1, 0, 0, 0],
+            Self::ADR => [2, 0, 0, 0],
+            Self::VLDR => [3, 0, 0, 0],
+            Self::TBRANCH => [4, 0, 0, 0],
+            Self::TBCOND => [5, 0, 0, 0],
+            Self::TCBZ => [6, 0, 0, 0],
+            Self::TLDR => [7, 0, 0, 0],
+            Self::TADR => [8, 0, 0, 0],
+            Self::TVLDR => [9, 0, 0, 0],
+            Self::Plain(size) => [10, *size as u8, 0, 0],
+        }
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(match bytes[0] {
+            0 => Self::BRANCH,
+            1 => Self::LDR,
+            2 => Self::ADR,
+            3 => Self::VLDR,
+            4 => Self::TBRANCH,
+            5 => Self::TBCOND,
+            6 => Self::TCBZ,
+            7 => Self::TLDR,
+            8 => Self::TADR,
+            9 => Self::TVLDR,
+            10 => Self::Plain(size_from_byte(bytes[1])?),
+            _ => return None
+        })
+    }
+}
+
+fn read_thumb32(buf: &[u8]) -> u32 {
+    u32::from(LittleEndian::read_u16(buf)) << 16 | u32::from(LittleEndian::read_u16(&buf[2..]))
+}
+
+fn write_thumb32(buf: &mut [u8], value: u32) {
+    LittleEndian::write_u16(buf, (value >> 16) as u16);
+    LittleEndian::write_u16(&mut buf[2..], value as u16);
+}
+
+/// Helper function for validating that a given value can be encoded as an arm modified immediate:
+/// an 8-bit value rotated right by an even amount of bits. Returns the 12-bit encoding.
+pub fn encode_modified_immediate(value: u32) -> Option<u16> {
+    for rotation in 0 .. 16 {
+        let unrotated = value.rotate_left(rotation * 2);
+        if unrotated <= 0xFF {
+            return Some((rotation << 8 | unrotated) as u16);
+        }
+    }
+    None
+}
+
+/// Helper function for validating that a given value can be encoded as a thumb modified immediate:
+/// an 8-bit value that is either replicated over the word in one of several patterns, or has its
+/// most significant bit set and is rotated right. Returns the 12-bit `i:imm3:imm8` encoding.
+pub fn encode_thumb_modified_immediate(value: u32) -> Option<u16> {
+    let low = value & 0xFF;
+    let high = (value >> 8) & 0xFF;
+
+    if value == low {
+        return Some(low as u16);
+    } else if value == low * 0x0001_0001 {
+        return Some((0x100 | low) as u16);
+    } else if value == high * 0x0100_0100 {
+        return Some((0x200 | high) as u16);
+    } else if value == low * 0x0101_0101 {
+        return Some((0x300 | low) as u16);
+    }
+
+    // a rotated value 1bcdefgh
+    let rotation = value.leading_zeros();
+    let unrotated = value.rotate_left(rotation + 8);
+    if (0x80..=0xFF).contains(&unrotated) {
+        let amount = rotation + 8;
+        Some((amount << 7 | (unrotated & 0x7F)) as u16)
+    } else {
+        None
+    }
+}
+
+registers!(
+    /// A general purpose register, as used by the `R` family. The special purpose names are available as associated constants.
+    R {
+        R0 = 0, R1 = 1, R2 = 2, R3 = 3, R4 = 4, R5 = 5, R6 = 6, R7 = 7,
+        R8 = 8, R9 = 9, R10 = 10, R11 = 11, R12 = 12, R13 = 13, R14 = 14, R15 = 15,
+    }
+);
+
+#[allow(missing_docs)]
+impl R {
+    pub const SB: R = R::R9;
+    pub const SL: R = R::R10;
+    pub const FP: R = R::R11;
+    pub const IP: R = R::R12;
+    pub const SP: R = R::R13;
+    pub const LR: R = R::R14;
+    pub const PC: R = R::R15;
+}
+
+registers!(
+    /// A single precision floating point register, as used by the `S` family.
+    S {
+        S0 = 0, S1 = 1, S2 = 2, S3 = 3, S4 = 4, S5 = 5, S6 = 6, S7 = 7,
+        S8 = 8, S9 = 9, S10 = 10, S11 = 11, S12 = 12, S13 = 13, S14 = 14, S15 = 15,
+        S16 = 16, S17 = 17, S18 = 18, S19 = 19, S20 = 20, S21 = 21, S22 = 22, S23 = 23,
+        S24 = 24, S25 = 25, S26 = 26, S27 = 27, S28 = 28, S29 = 29, S30 = 30, S31 = 31,
+    }
+);
+
+registers!(
+    /// A double precision floating point register, as used by the `D` family.
+    D {
+        D0 = 0, D1 = 1, D2 = 2, D3 = 3, D4 = 4, D5 = 5, D6 = 6, D7 = 7,
+        D8 = 8, D9 = 9, D10 = 10, D11 = 11, D12 = 12, D13 = 13, D14 = 14, D15 = 15,
+        D16 = 16, D17 = 17, D18 = 18, D19 = 19, D20 = 20, D21 = 21, D22 = 22, D23 = 23,
+        D24 = 24, D25 = 25, D26 = 26, D27 = 27, D28 = 28, D29 = 29, D30 = 30, D31 = 31,
+    }
+);
+
+/// The encoding of the arm `udf #0` instruction, which traps when executed. Suitable as `Config::poison` pattern.
+pub const UDF: &[u8] = &[0xF0, 0x00, 0xF0, 0xE7];
+
+/// The encoding of the thumb `udf #0` instruction, which traps when executed. Suitable as `Config::poison` pattern.
+pub const THUMB_UDF: &[u8] = &[0x00, 0xDE];
+
+
+/// An arm Assembler.
+#[cfg(feature = "std")]
+pub type Assembler = crate::Assembler<ArmRelocation>;
+/// An arm AssemblyModifier.
+#[cfg(feature = "std")]
+pub type AssemblyModifier<'a> = crate::Modifier<'a, ArmRelocation>;
+/// An arm UncommittedModifier.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
+
@@ -0,0 +1,278 @@
+//! This module implements relocatable blobs: assembled code together with all relocations in it that depend on
+//! the address the code is located at. These can be stored, i.e. in an on-disk cache, and later be loaded at any address.
+
+use byteorder::{ByteOrder, LittleEndian};
+
+#[cfg(feature = "std")]
+use std::io;
+use alloc::vec::Vec;
+use core::fmt;
+use core::error;
+use core::convert::TryFrom;
+
+use crate::{AssemblyOffset, DynasmError, TargetKind};
+use crate::components::PatchLoc;
+#[cfg(feature = "std")]
+use crate::mmap::{ExecutableBuffer, MutableBuffer};
+use crate::relocations::{Relocation, RelocationKind, RelocationSize};
+
+
+/// A relocation that can be stored in a serialized `RelocatableBlob`.
+pub trait BlobRelocation: Relocation + Sized {
+    /// Serialize this relocation.
+    fn to_bytes(&self) -> [u8; 4];
+    /// Deserialize a relocation serialized by `to_bytes`. Returns `None` if the data does not describe a valid relocation.
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self>;
+}
+
+impl BlobRelocation for RelocationSize {
+    fn to_bytes(&self) -> [u8; 4] {
+        [*self as u8, 0, 0, 0]
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        size_from_byte(bytes[0])
+    }
+}
+
+/// Decode a `RelocationSize` serialized as its size in bytes.
+pub(crate) fn size_from_byte(byte: u8) -> Option<RelocationSize> {
+    Some(match byte {
+        1 => RelocationSize::Byte,
+        2 => RelocationSize::Word,
+        4 => RelocationSize::DWord,
+        8 => RelocationSize::QWord,
+        _ => return None
+    })
+}
+
+/// Decode a `RelocationKind` serialized as its numeric value.
+pub(crate) fn kind_from_byte(byte: u8) -> Option<RelocationKind> {
+    Some(match byte {
+        0 => RelocationKind::Relative,
+        1 => RelocationKind::AbsToRel,
+        2 => RelocationKind::RelToAbs,
+        _ => return None
+    })
+}
+
+
+/// Error returned when loading a `RelocatableBlob` failed.
+#[derive(Debug)]
+pub enum BlobError {
+    /// The serialized data is not a valid blob.
+    Malformed,
+    /// Memory for the blob could not be allocated.
+    #[cfg(feature = "std")]
+    Io(io::Error),
+    /// Relocations could not be encoded at the address the blob was loaded at.
+    Relocation(Vec<DynasmError>),
+}
+
+impl fmt::Display for BlobError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlobError::Malformed => write!(f, "malformed relocatable blob"),
+            #[cfg(feature = "std")]
+            BlobError::Io(e) => write!(f, "could not allocate memory for relocatable blob: {}", e),
+            BlobError::Relocation(errors) => {
+                write!(f, "could not relocate blob:")?;
+                for e in errors {
+                    write!(f, "\n{}", e)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl error::Error for BlobError {
+    fn description(&self) -> &str {
+        match self {
+            BlobError::Malformed => "malformed relocatable blob",
+            #[cfg(feature = "std")]
+            BlobError::Io(_) => "could not allocate memory for relocatable blob",
+            BlobError::Relocation(_) => "could not relocate blob",
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<io::Error> for BlobError {
+    fn from(e: io::Error) -> BlobError {
+        BlobError::Io(e)
+    }
+}
+
+
+/// Assembled code together with the relocations in it that depend on its base address, as created by
+/// `VecAssembler::finalize_relocatable`. It can be loaded at any address, at which point these relocations are re-encoded.
+///
+/// Relocations to extern targets are re-encoded by asking a resolver function for the address of the target.
+/// The resolver receives the extern target that was used when the code was assembled. As these addresses
+/// generally change between runs of a program, code meant to be cached should be assembled using stable
+/// identifiers as extern targets (like indices into a table of functions), with a base address of 0.
+/// Only relocations emitted through `dynasm!` are tracked, addresses embedded as immediates are not.
+#[derive(Debug)]
+pub struct RelocatableBlob<R: Relocation> {
+    code: Vec<u8>,
+    baseaddr: usize,
+    relocs: Vec<PatchLoc<R>>,
+}
+
+// identifies serialized blobs, including the format version in the last byte
+const MAGIC: &[u8; 8] = b"DYNASMB\x01";
+
+impl<R: Relocation> RelocatableBlob<R> {
+    /// Create a new blob from `code`, which was assembled at `baseaddr`, with `relocs` being the relocations in it
+    /// that depend on its base address.
+    pub fn new(code: Vec<u8>, baseaddr: usize, relocs: Vec<PatchLoc<R>>) -> RelocatableBlob<R> {
+        RelocatableBlob {
+            code,
+            baseaddr,
+            relocs
+        }
+    }
+
+    /// The assembled code, encoded for `baseaddr()`.
+    pub fn code(&self) -> &[u8] {
+        &self.code
+    }
+
+    /// The address the code was assembled at.
+    pub fn baseaddr(&self) -> usize {
+        self.baseaddr
+    }
+
+    /// The relocations that depend on the base address of the code.
+    pub fn relocations(&self) -> &[PatchLoc<R>] {
+        &self.relocs
+    }
+
+    /// Write the code relocated to `addr` into `buffer`, which has to be as long as the code.
+    /// `resolve` is called with the original target of every relocation to an extern address, and should return
+    /// the address that it should point to now.
+    pub fn relocate<F>(&self, addr: usize, buffer: &mut [u8], mut resolve: F) -> Result<(), Vec<DynasmError>>
+    where F: FnMut(usize) -> usize {
+        buffer.copy_from_slice(&self.code);
+
+        let mut errors = Vec::new();
+        for loc in &self.relocs {
+            let target = loc.read_target(0, self.baseaddr, &self.code);
+            let (target, kind) = match loc.relocation.kind() {
+                RelocationKind::RelToAbs => {
+                    let target = resolve(target);
+                    (target, TargetKind::Extern(target))
+                },
+                _ => (target, TargetKind::Managed)
+            };
+
+            if let Err(e) = loc.patch(0, addr, buffer, target) {
+                errors.push(DynasmError::ImpossibleRelocation(loc.site(kind), e));
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Load the blob into a newly allocated `ExecutableBuffer`, resolving extern targets using `resolve`.
+    /// See `relocate` for details.
+    #[cfg(feature = "std")]
+    pub fn load<F>(&self, resolve: F) -> Result<ExecutableBuffer, BlobError>
+    where F: FnMut(usize) -> usize {
+        let mut buffer = MutableBuffer::new(self.code.len())?;
+        buffer.set_len(self.code.len());
+
+        let addr = buffer.as_ptr() as usize;
+        self.relocate(addr, &mut buffer, resolve).map_err(BlobError::Relocation)?;
+
+        Ok(buffer.make_exec()?)
+    }
+}
+
+impl<R: BlobRelocation> RelocatableBlob<R> {
+    /// Serialize this blob. Source locations of relocations are not preserved.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(32 + self.code.len() + self.relocs.len() * 20);
+        let mut word = [0u8; 8];
+
+        data.extend(MAGIC);
+        LittleEndian::write_u64(&mut word, self.baseaddr as u64);
+        data.extend(&word);
+        LittleEndian::write_u64(&mut word, self.code.len() as u64);
+        data.extend(&word);
+        data.extend(&self.code);
+        LittleEndian::write_u64(&mut word, self.relocs.len() as u64);
+        data.extend(&word);
+
+        for loc in &self.relocs {
+            LittleEndian::write_u64(&mut word, loc.location.0 as u64);
+            data.extend(&word);
+            LittleEndian::write_i64(&mut word, loc.offset as i64);
+            data.extend(&word);
+            data.extend(&loc.relocation.to_bytes());
+        }
+
+        data
+    }
+
+    /// Deserialize a blob serialized by `to_bytes`.
+    pub fn from_bytes(data: &[u8]) -> Result<RelocatableBlob<R>, BlobError> {
+        let mut reader = Reader { data };
+
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err(BlobError::Malformed);
+        }
+        let baseaddr = reader.usize()?;
+        let len = reader.usize()?;
+        let code = reader.take(len)?.to_vec();
+
+        let count = reader.usize()?;
+        let mut relocs = Vec::new();
+        for _ in 0 .. count {
+            let location = reader.usize()?;
+            let offset = isize::try_from(LittleEndian::read_i64(reader.take(8)?)).map_err(|_| BlobError::Malformed)?;
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(reader.take(4)?);
+            let relocation = R::from_bytes(bytes).ok_or(BlobError::Malformed)?;
+
+            // the relocation has to lie within the code
+            let field_offset = location.checked_sub(relocation.field_offset()).ok_or(BlobError::Malformed)?;
+            field_offset.checked_add(relocation.size()).filter(|&end| end <= code.len()).ok_or(BlobError::Malformed)?;
+            if relocation.start_offset() > location {
+                return Err(BlobError::Malformed);
+            }
+
+            relocs.push(PatchLoc::new(AssemblyOffset(location), offset, relocation));
+        }
+
+        if !reader.data.is_empty() {
+            return Err(BlobError::Malformed);
+        }
+
+        Ok(RelocatableBlob::new(code, baseaddr, relocs))
+    }
+}
+
+// reads the fields of a serialized blob
+struct Reader<'a> {
+    data: &'a [u8]
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], BlobError> {
+        if len > self.data.len() {
+            return Err(BlobError::Malformed);
+        }
+        let (head, tail) = self.data.split_at(len);
+        self.data = tail;
+        Ok(head)
+    }
+
+    fn usize(&mut self) -> Result<usize, BlobError> {
+        usize::try_from(LittleEndian::read_u64(self.take(8)?)).map_err(|_| BlobError::Malformed)
+    }
+}
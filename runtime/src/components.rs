@@ -1,21 +1,65 @@
 //! This module provides several reusable compoments for implementing assemblers
 
+#[cfg(feature = "std")]
 use std::io;
+#[cfg(feature = "std")]
 use std::collections::hash_map::{HashMap, Entry};
-use std::collections::BTreeMap;
+#[cfg(feature = "std")]
 use std::sync::{Arc, RwLock, RwLockWriteGuard};
-use std::mem;
-
-use crate::{DynamicLabel, AssemblyOffset, DynasmError, LabelKind, DynasmLabelApi};
-use crate::mmap::{ExecutableBuffer, MutableBuffer};
+// without std, fall back to ordered maps for the label and relocation registries
+#[cfg(not(feature = "std"))]
+use alloc::collections::btree_map::{BTreeMap as HashMap, Entry};
+use alloc::collections::BTreeMap;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+#[cfg(feature = "std")]
+use core::mem;
+use core::fmt;
+
+use crate::{DynamicLabel, LabelScope, AssemblyOffset, DynasmError, LabelKind, TargetKind, DynasmLabelApi, SourceLocation, RelocationSite};
+#[cfg(feature = "std")]
+use crate::mmap::{ExecutableBuffer, Config};
+#[cfg(feature = "std")]
+use crate::memory::{CodeMemory, WritableMemory};
 use crate::relocations::{Relocation, RelocationKind, RelocationSize, ImpossibleRelocation};
 
 
-/// This struct implements a protection-swapping assembling buffer
+/// The error returned when finalizing a `MemoryManager` or an `Assembler` failed.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub enum FinalizeError<T> {
+    /// The memory is still shared with an `Executor`. The manager or assembler is returned, so it can be finalized later.
+    InUse(T),
+    /// The memory could not be sealed, as requested by `Config::seal`.
+    Seal(io::Error),
+}
+
+#[cfg(feature = "std")]
+impl<T> fmt::Display for FinalizeError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FinalizeError::InUse(_) => write!(f, "The memory is still in use by an executor"),
+            FinalizeError::Seal(e) => write!(f, "The memory could not be sealed: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for FinalizeError<T> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FinalizeError::InUse(_) => None,
+            FinalizeError::Seal(e) => Some(e),
+        }
+    }
+}
+
+/// This struct implements a protection-swapping assembling buffer, backed by any `CodeMemory`.
+#[cfg(feature = "std")]
 #[derive(Debug)]
-pub struct MemoryManager {
+pub struct MemoryManager<M: CodeMemory = ExecutableBuffer> {
     // buffer where the end result is copied into
-    execbuffer: Arc<RwLock<ExecutableBuffer>>,
+    execbuffer: Arc<RwLock<M>>,
 
     // size of the allocated mmap (so we don't have to go through RwLock to get it)
     execbuffer_size: usize,
@@ -23,23 +67,39 @@ pub struct MemoryManager {
     asmoffset: usize,
 
     // the address that the current execbuffer starts at
-    execbuffer_addr: usize
+    execbuffer_addr: usize,
+
+    // how memory is allocated and protected
+    config: Config
 }
 
-impl MemoryManager {
+#[cfg(feature = "std")]
+impl<M: CodeMemory> MemoryManager<M> {
     /// Create a new memory manager, with `initial_mmap_size` data allocated
     pub fn new(initial_mmap_size: usize) -> io::Result<Self> {
-        let execbuffer = ExecutableBuffer::new(initial_mmap_size)?;
-        let execbuffer_addr = execbuffer.as_ptr() as usize;
+        MemoryManager::with_config(initial_mmap_size, Config::default())
+    }
+
+    /// Create a new memory manager, with `initial_mmap_size` data allocated according to `config`
+    pub fn with_config(initial_mmap_size: usize, config: Config) -> io::Result<Self> {
+        config.validate()?;
+        let execbuffer = M::allocate(initial_mmap_size, &config)?.make_executable()?;
+        let execbuffer_addr = execbuffer.address();
 
         Ok(MemoryManager {
+            execbuffer_size: execbuffer.size(),
             execbuffer: Arc::new(RwLock::new(execbuffer)),
-            execbuffer_size: initial_mmap_size,
             asmoffset: 0,
-            execbuffer_addr
+            execbuffer_addr,
+            config
         })
     }
 
+    /// Returns the configuration used to allocate and protect memory
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
     /// Returns the amount of bytes already committed to the manager
     pub fn committed(&self) -> usize {
         self.asmoffset
@@ -51,7 +111,9 @@ impl MemoryManager {
     }
 
     /// Commits the data from `new` into the managed memory, calling `f` when the buffer is moved to fix anything
-    /// that relies on the address of the buffer
+    /// that relies on the address of the buffer. In strict mode, committed code is never made writable again,
+    /// so the data is appended through `CodeMemory::append`, or copied into a newly allocated buffer if the
+    /// memory does not support that.
     pub fn commit<F>(&mut self, new: &mut Vec<u8>, f: F) where F: FnOnce(&mut [u8], usize, usize) {
         let old_asmoffset = self.asmoffset;
         let new_asmoffset = self.asmoffset + new.len();
@@ -62,39 +124,32 @@ impl MemoryManager {
 
         // see if we need to request a new buffer
         if new_asmoffset > self.execbuffer_size {
-            while self.execbuffer_size <= new_asmoffset {
-                self.execbuffer_size *= 2;
-            }
-
-            // create a larger writable buffer
-            let mut new_buffer = MutableBuffer::new(self.execbuffer_size).expect("Could not allocate a larger buffer");
-            new_buffer.set_len(new_asmoffset);
-
-            // copy over the data
-            new_buffer[.. old_asmoffset].copy_from_slice(&self.execbuffer.read().unwrap());
-            new_buffer[old_asmoffset..].copy_from_slice(&new);
-            let new_buffer_addr = new_buffer.as_ptr() as usize;
+            self.execbuffer_size = self.config.growth.grow(self.execbuffer_size, new_asmoffset);
+            self.move_and_commit(new, f);
 
-            // allow modifications to be made
-            f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
+        } else if self.config.strict {
 
-            // swap the buffers
-            self.execbuffer_addr = new_buffer_addr;
-            *self.execbuffer.write().unwrap() = new_buffer.make_exec().expect("Could not swap buffer protection modes")
+            // only the pages after the committed code are replaced
+            let appended = self.write().append(new);
+            match appended {
+                Ok(()) => (),
+                Err(ref e) if e.kind() == io::ErrorKind::Unsupported => self.move_and_commit(new, f),
+                Err(e) => panic!("Could not append to the buffer: {}", e)
+            }
 
         } else {
 
             // temporarily change the buffer protection modes and copy in new data
             let mut lock = self.write();
-            let buffer = mem::replace(&mut *lock, ExecutableBuffer::default());
-            let mut buffer = buffer.make_mut().expect("Could not swap buffer protection modes");
+            let buffer = mem::replace(&mut *lock, M::default());
+            let mut buffer = buffer.make_writable().expect("Could not swap buffer protection modes");
 
             // update buffer and length
             buffer.set_len(new_asmoffset);
             buffer[old_asmoffset..].copy_from_slice(&new);
 
             // repack the buffer
-            let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
+            let buffer = buffer.make_executable().expect("Could not swap buffer protection modes");
             mem::replace(&mut *lock, buffer);
         }
 
@@ -102,24 +157,55 @@ impl MemoryManager {
         self.asmoffset = new_asmoffset;
     }
 
+    // move the committed data into a new writable buffer of `execbuffer_size` bytes and append `new` to it
+    fn move_and_commit<F>(&mut self, new: &[u8], f: F) where F: FnOnce(&mut [u8], usize, usize) {
+        let old_asmoffset = self.asmoffset;
+        let new_asmoffset = self.asmoffset + new.len();
+
+        let mut lock = self.execbuffer.write().unwrap();
+        let buffer = mem::take(&mut *lock);
+        let mut new_buffer = buffer.grow(self.execbuffer_size, &self.config).expect("Could not allocate a larger buffer");
+
+        // the buffer can end up larger than requested, i.e. when it is rounded up to the page size
+        self.execbuffer_size = new_buffer.size();
+
+        // copy over the new data
+        new_buffer.set_len(new_asmoffset);
+        new_buffer[old_asmoffset..].copy_from_slice(new);
+        let new_buffer_addr = new_buffer.address();
+
+        // allow modifications to be made
+        f(&mut new_buffer, self.execbuffer_addr, new_buffer_addr);
+
+        // swap the buffers
+        self.execbuffer_addr = new_buffer_addr;
+        *lock = new_buffer.make_executable().expect("Could not swap buffer protection modes")
+    }
+
     /// Borrow the internal memory buffer mutably
-    pub fn write(&self) -> RwLockWriteGuard<ExecutableBuffer> {
+    pub fn write(&self) -> RwLockWriteGuard<M> {
         self.execbuffer.write().unwrap()
     }
 
-    /// finalizes the currently committed part of the buffer.
-    pub fn finalize(self) -> Result<ExecutableBuffer, Self> {
+    /// finalizes the currently committed part of the buffer. If the configuration asks for it, the buffer is sealed.
+    pub fn finalize(self) -> Result<M, FinalizeError<Self>> {
         match Arc::try_unwrap(self.execbuffer) {
-            Ok(execbuffer) => Ok(execbuffer.into_inner().unwrap()),
-            Err(arc) => Err(Self {
+            Ok(execbuffer) => {
+                let mut execbuffer = execbuffer.into_inner().unwrap();
+                if self.config.seal {
+                    execbuffer.seal().map_err(FinalizeError::Seal)?;
+                }
+                Ok(execbuffer)
+            },
+            Err(arc) => Err(FinalizeError::InUse(Self {
                 execbuffer: arc,
                 ..self
-            })
+            }))
         }
     }
 
     /// Create an atomically refcounted reference to the internal executable buffer
-    pub fn reader(&self) -> Arc<RwLock<ExecutableBuffer>> {
+    pub fn reader(&self) -> Arc<RwLock<M>> {
         self.execbuffer.clone()
     }
 }
@@ -128,14 +214,28 @@ impl MemoryManager {
 /// A registry of labels. Contains all necessessities for keeping track of dynasm labels.
 /// This is useful when implementing your own assembler and can also be used to query
 /// assemblers for the offsets of labels.
+///
+/// Global labels are defined in the currently active `LabelScope`. Scopes allow the same global label
+/// names to be reused by different pieces of code (i.e. functions) assembled into the same buffer.
+/// Global labels are first resolved in the scope they were referenced from, and then in the root scope.
 #[derive(Debug, Clone, Default)]
 pub struct LabelRegistry {
     // mapping of global labels to offsets
-    global_labels: HashMap<&'static str, AssemblyOffset>,
+    global_labels: HashMap<(LabelScope, &'static str), AssemblyOffset>,
     // mapping of local labels to offsets
     local_labels: HashMap<&'static str, AssemblyOffset>,
+    // every local label definition that was made, in order
+    local_definitions: Vec<(&'static str, AssemblyOffset)>,
     // mapping of dynamic label ids to offsets
     dynamic_labels: Vec<Option<AssemblyOffset>>,
+    // mapping of runtime label names to the dynamic labels backing them
+    named_labels: HashMap<String, DynamicLabel>,
+    // and the reverse mapping
+    label_names: HashMap<DynamicLabel, String>,
+    // the currently active scope
+    scope: LabelScope,
+    // amount of scopes created so far
+    scope_count: usize,
 }
 
 impl LabelRegistry {
@@ -144,7 +244,12 @@ impl LabelRegistry {
         LabelRegistry {
             global_labels: HashMap::new(),
             local_labels: HashMap::new(),
+            local_definitions: Vec::new(),
             dynamic_labels: Vec::new(),
+            named_labels: HashMap::new(),
+            label_names: HashMap::new(),
+            scope: LabelScope::ROOT,
+            scope_count: 0,
         }
     }
 
@@ -155,20 +260,75 @@ impl LabelRegistry {
         DynamicLabel(id)
     }
 
+    /// Returns the dynamic label backing the global label `name`, whose name was determined at runtime.
+    /// The label is created if it did not exist yet. Named labels ignore label scopes, so the same
+    /// label is returned no matter which scope is active.
+    pub fn named_label(&mut self, name: &str) -> DynamicLabel {
+        if let Some(&id) = self.named_labels.get(name) {
+            return id;
+        }
+
+        let id = self.new_dynamic_label();
+        self.named_labels.insert(name.to_string(), id);
+        self.label_names.insert(id, name.to_string());
+        id
+    }
+
+    /// Returns the runtime name of the dynamic label `id`, if it backs a named label.
+    pub fn label_name(&self, id: DynamicLabel) -> Option<&str> {
+        self.label_names.get(&id).map(|s| s.as_str())
+    }
+
+    /// Describe the dynamic label `id` as a relocation target, for error reporting.
+    pub fn dynamic_target(&self, id: DynamicLabel) -> TargetKind {
+        match self.label_name(id) {
+            Some(name) => TargetKind::Named(name.to_string()),
+            None => TargetKind::Dynamic(id)
+        }
+    }
+
+    // describe the dynamic label `id`, for error reporting.
+    fn dynamic_kind(&self, id: DynamicLabel) -> LabelKind {
+        match self.label_name(id) {
+            Some(name) => LabelKind::Named(name.to_string()),
+            None => LabelKind::Dynamic(id)
+        }
+    }
+
+    /// Create a new scope and make it the active scope. Any local labels defined before are forgotten.
+    /// Scopes do not nest: starting a scope while another scope is active simply replaces it.
+    pub fn begin_scope(&mut self) -> LabelScope {
+        self.scope_count += 1;
+        self.scope = LabelScope(self.scope_count);
+        self.local_labels.clear();
+        self.scope
+    }
+
+    /// Return to the root scope. Any local labels defined before are forgotten.
+    pub fn end_scope(&mut self) {
+        self.scope = LabelScope::ROOT;
+        self.local_labels.clear();
+    }
+
+    /// Returns the currently active scope.
+    pub fn current_scope(&self) -> LabelScope {
+        self.scope
+    }
+
     /// Define a the dynamic label `id` to be located at `offset`.
     pub fn define_dynamic(&mut self, id: DynamicLabel, offset: AssemblyOffset) -> Result<(), DynasmError> {
         let entry = &mut self.dynamic_labels[id.0];
         if entry.is_some() {
-            return Err(DynasmError::DuplicateLabel(LabelKind::Dynamic(id)));
+            return Err(DynasmError::DuplicateLabel(self.dynamic_kind(id)));
         }
 
         *entry = Some(offset);
         Ok(())
     }
 
-    /// Define a the global label `name` to be located at `offset`.
+    /// Define a the global label `name` to be located at `offset` in the current scope.
     pub fn define_global(&mut self, name: &'static str, offset: AssemblyOffset) -> Result<(), DynasmError> {
-        match self.global_labels.entry(name) {
+        match self.global_labels.entry((self.scope, name)) {
             Entry::Occupied(_) => Err(DynasmError::DuplicateLabel(LabelKind::Global(name))),
             Entry::Vacant(v) => {
                 v.insert(offset);
@@ -177,25 +337,109 @@ impl LabelRegistry {
         }
     }
 
-    /// Define a the local label `name` to be located at `offset`.
+    /// Define a the local label `name` to be located at `offset`. This shadows any earlier definition
+    /// of `name` in the current scope. Definitions made in an earlier scope are never visible here,
+    /// as they are forgotten when the scope changes.
     pub fn define_local(&mut self, name: &'static str, offset: AssemblyOffset) {
         self.local_labels.insert(name, offset);
+        self.local_definitions.push((name, offset));
     }
 
     /// Returns the offset at which the dynamic label `id` was defined, if one was defined.
     pub fn resolve_dynamic(&self, id: DynamicLabel) -> Result<AssemblyOffset, DynasmError> {
-        self.dynamic_labels.get(id.0).and_then(|&e| e).ok_or_else(|| DynasmError::UnknownLabel(LabelKind::Dynamic(id)))
+        self.dynamic_labels.get(id.0).and_then(|&e| e).ok_or_else(|| DynasmError::UnknownLabel(self.dynamic_kind(id)))
+    }
+
+    /// Returns the offset at which the global label `name`, whose name was determined at runtime, was defined, if one was defined.
+    pub fn resolve_named(&self, name: &str) -> Result<AssemblyOffset, DynasmError> {
+        match self.named_labels.get(name) {
+            Some(&id) => self.resolve_dynamic(id),
+            None => Err(DynasmError::UnknownLabel(LabelKind::Named(name.to_string())))
+        }
     }
 
     /// Returns the offset at which the global label `name` was defined, if one was defined.
+    /// The label is looked up as if it was referenced from the current scope.
     pub fn resolve_global(&self, name: &'static str) -> Result<AssemblyOffset, DynasmError> {
-        self.global_labels.get(&name).cloned().ok_or_else(|| DynasmError::UnknownLabel(LabelKind::Global(name)))
+        self.resolve_global_in(self.scope, name)
+    }
+
+    /// Returns the offset at which the global label `name` was defined, if one was defined.
+    /// The label is looked up as if it was referenced from `scope`.
+    pub fn resolve_global_in(&self, scope: LabelScope, name: &'static str) -> Result<AssemblyOffset, DynasmError> {
+        self.global_labels.get(&(scope, name))
+            .or_else(|| self.global_labels.get(&(LabelScope::ROOT, name)))
+            .cloned()
+            .ok_or_else(|| DynasmError::UnknownLabel(LabelKind::Global(name)))
     }
 
     /// Returns the offset at which the last local label named `id` was defined, if one was defined.
     pub fn resolve_local(&self, name: &'static str) -> Result<AssemblyOffset, DynasmError> {
         self.local_labels.get(&name).cloned().ok_or_else(|| DynasmError::UnknownLabel(LabelKind::Local(name)))
     }
+
+    /// Iterate through all labels that have been defined, and the offsets they were defined at, in no particular order.
+    /// Every definition of a local label is included, even if the label was redefined later on.
+    /// Use `finalize_with_labels` to keep the labels of an assembler around after finalizing it.
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item=(DefinedLabel<'a>, AssemblyOffset)> + 'a {
+        let globals = self.global_labels.iter()
+            .map(|(&(scope, name), &offset)| (DefinedLabel::Global(scope, name), offset));
+        let locals = self.local_definitions.iter()
+            .map(|&(name, offset)| (DefinedLabel::Local(name), offset));
+        let dynamics = self.dynamic_labels.iter().enumerate().filter_map(move |(id, &offset)| {
+            let id = DynamicLabel(id);
+            let label = match self.label_name(id) {
+                Some(name) => DefinedLabel::Named(name),
+                None => DefinedLabel::Dynamic(id)
+            };
+            offset.map(|offset| (label, offset))
+        });
+
+        globals.chain(locals).chain(dynamics)
+    }
+
+    /// Write a symbol map of all defined labels to `out`, sorted by offset. Each line contains the address of a label
+    /// in hexadecimal, the distance to the next label (or to the end of the code) in hexadecimal and the name of the label.
+    /// `base_addr` is the address the code was assembled at, and `code_size` the total size of the code.
+    /// This is the format `perf` reads from `/tmp/perf-<pid>.map` to name JIT-compiled code.
+    #[cfg(feature = "std")]
+    pub fn write_symbol_map<W: io::Write>(&self, mut out: W, base_addr: usize, code_size: usize) -> io::Result<()> {
+        let mut labels: Vec<_> = self.iter().collect();
+        labels.sort_by_key(|&(label, offset)| (offset, label));
+
+        for (i, &(label, offset)) in labels.iter().enumerate() {
+            let end = labels.get(i + 1).map_or(code_size, |&(_, next)| next.0).max(offset.0);
+            writeln!(out, "{:x} {:x} {}", base_addr + offset.0, end - offset.0, label)?;
+        }
+        Ok(())
+    }
+}
+
+
+/// A label definition, as enumerated by `LabelRegistry::iter`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DefinedLabel<'a> {
+    /// A local label, like `label:`
+    Local(&'static str),
+    /// A global label, like `->label:`, and the scope it was defined in
+    Global(LabelScope, &'static str),
+    /// A dynamic label, like `=>value:`
+    Dynamic(DynamicLabel),
+    /// A global label with a name determined at runtime, like `->(name):`
+    Named(&'a str),
+}
+
+/// Formats the label as a symbol name. Global labels defined in a scope are prefixed with the scope.
+impl<'a> fmt::Display for DefinedLabel<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DefinedLabel::Local(name) => write!(f, "{}", name),
+            DefinedLabel::Global(scope, name) if *scope == LabelScope::ROOT => write!(f, "{}", name),
+            DefinedLabel::Global(scope, name) => write!(f, "scope{}::{}", scope.get_id(), name),
+            DefinedLabel::Dynamic(id) => write!(f, "dynamic{}", id.get_id()),
+            DefinedLabel::Named(name) => write!(f, "{}", name),
+        }
+    }
 }
 
 
@@ -208,6 +452,8 @@ pub struct PatchLoc<R: Relocation> {
     pub relocation: R,
     /// A constant offset to be applied to this relocation when emitting it.
     pub offset: isize,
+    /// The source location of the `dynasm!` invocation that emitted this relocation, if known.
+    pub source: Option<SourceLocation>,
 }
 
 impl<R: Relocation> PatchLoc<R> {
@@ -216,7 +462,23 @@ impl<R: Relocation> PatchLoc<R> {
         PatchLoc {
             location,
             relocation,
-            offset
+            offset,
+            source: None
+        }
+    }
+
+    /// Attach the source location of the `dynasm!` invocation that emitted this relocation.
+    pub fn with_source(mut self, source: Option<SourceLocation>) -> PatchLoc<R> {
+        self.source = source;
+        self
+    }
+
+    /// Describe this relocation as targeting `target`, for error reporting.
+    pub fn site(&self, target: TargetKind) -> RelocationSite {
+        RelocationSite {
+            target,
+            location: self.location,
+            source: self.source
         }
     }
 
@@ -257,6 +519,19 @@ impl<R: Relocation> PatchLoc<R> {
         self.relocation.write_value(buf, value)
     }
 
+    /// Read back the target this relocation patch currently points to from `buffer`. This is the inverse of `patch`,
+    /// with `buf_offset` and `buf_addr` having the same meaning.
+    pub fn read_target(&self, buf_offset: usize, buf_addr: usize, buffer: &[u8]) -> usize {
+        let field_offset = self.location.0 - buf_offset - self.relocation.field_offset();
+        let buf = &buffer[field_offset .. field_offset + self.relocation.size()];
+        let value = self.relocation.read_value(buf).wrapping_sub(self.offset) as usize;
+        match self.relocation.kind() {
+            RelocationKind::Relative => value.wrapping_add(self.location.0 - self.relocation.start_offset()),
+            RelocationKind::RelToAbs => value.wrapping_add(self.location.0 - self.relocation.start_offset() + buf_addr),
+            RelocationKind::AbsToRel => value.wrapping_sub(buf_addr)
+        }
+    }
+
     /// Returns if this patch requires adjustment when the address of the buffer it resides in is altered.
     pub fn needs_adjustment(&self) -> bool {
         match self.relocation.kind() {
@@ -271,7 +546,7 @@ impl<R: Relocation> PatchLoc<R> {
 /// A registry of relocations and the respective labels they point towards.
 #[derive(Debug, Default)]
 pub struct RelocRegistry<R: Relocation> {
-    global: Vec<(PatchLoc<R>, &'static str)>,
+    global: Vec<(PatchLoc<R>, LabelScope, &'static str)>,
     dynamic: Vec<(PatchLoc<R>, DynamicLabel)>,
     local: HashMap<&'static str, Vec<PatchLoc<R>>>
 }
@@ -286,9 +561,9 @@ impl<R: Relocation> RelocRegistry<R> {
         }
     }
 
-    /// Add a new patch targetting the global label `name`.
-    pub fn add_global(&mut self, name: &'static str, patchloc: PatchLoc<R>) {
-        self.global.push((patchloc, name));
+    /// Add a new patch targetting the global label `name`, as referenced from `scope`.
+    pub fn add_global(&mut self, scope: LabelScope, name: &'static str, patchloc: PatchLoc<R>) {
+        self.global.push((patchloc, scope, name));
     }
 
     /// Add a new patch targetting the dynamic label `id`.
@@ -313,9 +588,9 @@ impl<R: Relocation> RelocRegistry<R> {
         self.local.get_mut(&name).into_iter().flat_map(|v| v.drain(..))
     }
 
-    /// Return an iterator through all defined relocations targeting global labels and the labels they target.
-    /// These relocations are removed from the registry.
-    pub fn take_globals<'a>(&'a mut self) -> impl Iterator<Item=(PatchLoc<R>, &'static str)> + 'a {
+    /// Return an iterator through all defined relocations targeting global labels, the scopes they were referenced from
+    /// and the labels they target. These relocations are removed from the registry.
+    pub fn take_globals<'a>(&'a mut self) -> impl Iterator<Item=(PatchLoc<R>, LabelScope, &'static str)> + 'a {
         self.global.drain(..)
     }
 
@@ -377,6 +652,11 @@ impl<R: Relocation> ManagedRelocs<R> {
     pub fn iter<'a>(&'a self) -> impl Iterator<Item=&'a PatchLoc<R>> + 'a {
         self.managed.values()
     } 
+
+    /// Consume this registry, returning all managed relocations ordered by their location.
+    pub fn into_vec(self) -> Vec<PatchLoc<R>> {
+        self.managed.into_values().collect()
+    }
 }
 
 
@@ -526,10 +806,10 @@ impl LitPool {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests {
     use crate::*;
-    use std::fmt::Debug;
+    use core::fmt::Debug;
     use relocations::{Relocation, RelocationSize};
 
     #[test]
@@ -552,6 +832,21 @@ mod tests {
         test_litpool::<aarch64::Aarch64Relocation>();
     }
 
+    #[test]
+    fn test_litpool_riscv64() {
+        test_litpool::<riscv64::Riscv64Relocation>();
+    }
+
+    #[test]
+    fn test_litpool_loongarch64() {
+        test_litpool::<loongarch64::Loongarch64Relocation>();
+    }
+
+    #[test]
+    fn test_litpool_arm() {
+        test_litpool::<arm::ArmRelocation>();
+    }
+
     fn test_litpool<R: Relocation + Debug>() {
         let mut ops = Assembler::<R>::new().unwrap();
         let dynamic1 = ops.new_dynamic_label();
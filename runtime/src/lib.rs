@@ -1,29 +1,63 @@
 #![warn(missing_docs)]
+#![cfg_attr(not(feature = "std"), no_std)]
 
 //! This crate provides runtime support for dynasm-rs. It contains traits that document the interface used by the dynasm proc_macro to generate code,
 //! Assemblers that implement these traits, and relocation models for the various supported architectures. Additionally, it also provides the tools
 //! to write your own Assemblers using these components.
+//!
+//! By default this crate depends on `std`. Without the default `std` feature it only requires `alloc`,
+//! in which case `VecAssembler`, `SimpleAssembler`, the label and relocation components and the relocation
+//! models are available, but the mmap-backed `Assembler` and `Executor` are not.
 
+#[cfg(feature = "std")]
 extern crate memmap;
 extern crate byteorder;
-
+#[cfg(all(unix, feature = "std"))]
+extern crate libc;
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+
+#[macro_use]
+pub mod registers;
+#[cfg(feature = "std")]
 pub mod mmap;
+#[cfg(feature = "std")]
+pub mod memory;
 pub mod components;
+pub mod blob;
 pub mod relocations;
 pub mod x64;
 pub mod x86;
 pub mod aarch64;
-
-pub use crate::mmap::ExecutableBuffer;
-use crate::components::{MemoryManager, LabelRegistry, RelocRegistry, ManagedRelocs, PatchLoc};
-use crate::relocations::Relocation;
-
-use std::iter::Extend;
+pub mod riscv64;
+pub mod loongarch64;
+pub mod wasm;
+pub mod arm;
+
+#[cfg(feature = "std")]
+pub use crate::mmap::{ExecutableBuffer, Func, FuncPtr, AtomicPatch, Config, GrowthPolicy};
+pub use crate::blob::RelocatableBlob;
+#[cfg(feature = "std")]
+pub use crate::memory::{CodeMemory, WritableMemory};
+#[cfg(feature = "std")]
+pub use crate::components::FinalizeError;
+#[cfg(feature = "std")]
+use crate::components::MemoryManager;
+use crate::components::{LabelRegistry, RelocRegistry, ManagedRelocs, PatchLoc};
+use crate::relocations::{Relocation, ImpossibleRelocation};
+
+use alloc::vec::Vec;
+use alloc::string::String;
+use core::iter::Extend;
+#[cfg(feature = "std")]
 use std::sync::{Arc, RwLock, RwLockReadGuard};
+#[cfg(feature = "std")]
 use std::io;
-use std::error;
-use std::fmt;
-use std::mem;
+use core::error;
+use core::fmt;
+use core::mem;
 
 /// This macro takes a *const pointer from the source operand, and then casts it to the desired return type.
 /// this allows it to be used as an easy shorthand for passing pointers as dynasm immediate arguments.
@@ -45,7 +79,7 @@ macro_rules! MutPointer {
 pub struct AssemblyOffset(pub usize);
 
 /// A dynamic label
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DynamicLabel(usize);
 
 
@@ -57,36 +91,65 @@ impl DynamicLabel {
 }
 
 
+/// A namespace for global labels. See `LabelRegistry` for how labels are resolved in scopes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct LabelScope(usize);
+
+
+impl LabelScope {
+    /// The root scope. It is active when no other scope is.
+    pub const ROOT: LabelScope = LabelScope(0);
+
+    /// Get the internal ID of this scope. This is only useful for debugging purposes.
+    pub fn get_id(self) -> usize {
+        self.0
+    }
+}
+
+
 /// A read-only shared reference to the executable buffer inside an Assembler. By
 /// locking it the internal `ExecutableBuffer` can be accessed and executed.
-#[derive(Debug, Clone)]
-pub struct Executor {
-    execbuffer: Arc<RwLock<ExecutableBuffer>>
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct Executor<M: CodeMemory = ExecutableBuffer> {
+    execbuffer: Arc<RwLock<M>>
+}
+
+#[cfg(feature = "std")]
+impl<M: CodeMemory> Clone for Executor<M> {
+    fn clone(&self) -> Self {
+        Executor {
+            execbuffer: self.execbuffer.clone()
+        }
+    }
 }
 
 /// A read-only lockable reference to the internal `ExecutableBuffer` of an Assembler.
 /// To gain access to this buffer, it must be locked.
-impl Executor {
+#[cfg(feature = "std")]
+impl<M: CodeMemory> Executor<M> {
     /// Gain read-access to the internal `ExecutableBuffer`. While the returned guard
     /// is alive, it can be used to read and execute from the `ExecutableBuffer`.
     /// Any pointers created to the `Executablebuffer` should no longer be used when
     /// the guard is dropped.
     #[inline]
-    pub fn lock(&self) -> RwLockReadGuard<ExecutableBuffer> {
+    pub fn lock(&self) -> RwLockReadGuard<M> {
         self.execbuffer.read().unwrap()
     }
 }
 
 
 /// A description of a label. Used for error reporting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum LabelKind {
     /// A local label, like `label:`
     Local(&'static str),
     /// A global label, like `->label:`
     Global(&'static str),
     /// A dynamic label, like `=>value:`
-    Dynamic(DynamicLabel)
+    Dynamic(DynamicLabel),
+    /// A global label with a name determined at runtime, like `->(name):`
+    Named(String),
 }
 
 impl fmt::Display for LabelKind {
@@ -94,14 +157,15 @@ impl fmt::Display for LabelKind {
         match self {
             Self::Local(s) => write!(f, "label {}", s),
             Self::Global(s) => write!(f, "label ->{}", s),
-            Self::Dynamic(id) => write!(f, "label =>{}", id.get_id())
+            Self::Dynamic(id) => write!(f, "label =>{}", id.get_id()),
+            Self::Named(s) => write!(f, "label ->({:?})", s),
         }
     }
 }
 
 
 /// A description of a relocation target. Used for error reporting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum TargetKind {
     /// This targets a local label with the specified name that still has to be defined.
     Forward(&'static str),
@@ -111,6 +175,8 @@ pub enum TargetKind {
     Global(&'static str),
     /// This targets the specified dynamic label.
     Dynamic(DynamicLabel),
+    /// This targets a global label with the specified name determined at runtime.
+    Named(String),
     /// This targets the specified address.
     Extern(usize),
     /// An already resolved relocation that needs to be adjusted when the buffer moves in memory.
@@ -120,19 +186,59 @@ pub enum TargetKind {
 impl fmt::Display for TargetKind {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::Forward(s) => write!(f, "target >{}", s),
-            Self::Backward(s) => write!(f, "target <{}", s),
-            Self::Global(s) => write!(f, "target ->{}", s),
-            Self::Dynamic(id) => write!(f, "target =>{}", id.get_id()),
-            Self::Extern(value) => write!(f, "target extern {}", value),
-            Self::Managed => write!(f, "while adjusting managed relocation"),
+            Self::Forward(s) => write!(f, "forward label `{}`", s),
+            Self::Backward(s) => write!(f, "backward label `{}`", s),
+            Self::Global(s) => write!(f, "global label `{}`", s),
+            Self::Dynamic(id) => write!(f, "dynamic label {}", id.get_id()),
+            Self::Named(s) => write!(f, "named label `{}`", s),
+            Self::Extern(value) => write!(f, "extern address {:#x}", value),
+            Self::Managed => write!(f, "previously resolved target"),
         }
     }
 }
 
 
+/// A location in rust source code of a `dynasm!` invocation. Used for error reporting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SourceLocation {
+    /// The file the invocation is in, as reported by `file!()`
+    pub file: &'static str,
+    /// The line the invocation is at, as reported by `line!()`
+    pub line: u32,
+}
+
+impl fmt::Display for SourceLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.file, self.line)
+    }
+}
+
+
+/// A description of the relocation that caused an error. Used for error reporting.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelocationSite {
+    /// The target of the relocation.
+    pub target: TargetKind,
+    /// The offset at which the relocation was emitted.
+    pub location: AssemblyOffset,
+    /// The source location of the `dynasm!` invocation that emitted the relocation, if known.
+    pub source: Option<SourceLocation>,
+}
+
+impl fmt::Display for RelocationSite {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "relocation at offset {:#x}", self.location.0)?;
+        if let Some(source) = &self.source {
+            write!(f, " ({})", source)?;
+        }
+        write!(f, " to {}", self.target)
+    }
+}
+
+
 /// The various error types generated by dynasm functions.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
 pub enum DynasmError {
     /// A check (like `Modifier::check` or `Modifier::check_exact`) that failed
     CheckFailed,
@@ -141,7 +247,15 @@ pub enum DynasmError {
     /// An unknown label
     UnknownLabel(LabelKind),
     /// The user tried to declare a relocation too far away from the label it targets
-    ImpossibleRelocation(TargetKind),
+    ImpossibleRelocation(RelocationSite, ImpossibleRelocation),
+    /// A relocation targets a label that was not defined at the time it had to be resolved
+    UnresolvedRelocation(RelocationSite),
+    /// Committed code was about to be altered, but the assembler was created with `Config::strict` set
+    StrictMode,
+    /// The code was rejected by a check specific to the assembling backend
+    Backend(BackendError),
+    /// The assembler does not support the requested operation
+    Unsupported(&'static str),
 }
 
 impl fmt::Display for DynasmError {
@@ -150,7 +264,11 @@ impl fmt::Display for DynasmError {
             DynasmError::CheckFailed => write!(f, "An assembly modification check failed"),
             DynasmError::DuplicateLabel(l) => write!(f, "Duplicate label defined: '{}'", l),
             DynasmError::UnknownLabel(l) => write!(f, "Unknown label: '{}'", l),
-            DynasmError::ImpossibleRelocation(s) => write!(f, "Impossible relocation: '{}'", s),
+            DynasmError::ImpossibleRelocation(site, e) => write!(f, "{} {}", site, e),
+            DynasmError::UnresolvedRelocation(site) => write!(f, "{} could not be resolved as the label is not defined", site),
+            DynasmError::StrictMode => write!(f, "Committed code cannot be altered in strict mode"),
+            DynasmError::Backend(e) => write!(f, "{}", e),
+            DynasmError::Unsupported(msg) => write!(f, "Unsupported operation: {}", msg),
         }
     }
 }
@@ -161,7 +279,27 @@ impl error::Error for DynasmError {
             DynasmError::CheckFailed => "An assembly modification offset check failed",
             DynasmError::DuplicateLabel(_) => "Duplicate label defined",
             DynasmError::UnknownLabel(_) => "Unknown label",
-            DynasmError::ImpossibleRelocation(_) => "Impossible relocation",
+            DynasmError::ImpossibleRelocation(_, _) => "Impossible relocation",
+            DynasmError::UnresolvedRelocation(_) => "Unresolved relocation",
+            DynasmError::StrictMode => "Committed code cannot be altered in strict mode",
+            DynasmError::Backend(_) => "Backend error",
+            DynasmError::Unsupported(_) => "Unsupported operation",
+        }
+    }
+}
+
+/// Errors that are only generated by a single assembling backend.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BackendError {
+    /// The structured control instructions of a WebAssembly function body were not properly nested
+    Wasm(wasm::StructureError),
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BackendError::Wasm(e) => write!(f, "Invalid structure: {}", e),
         }
     }
 }
@@ -212,6 +350,66 @@ pub trait DynasmApi: Extend<u8> + for<'a> Extend<&'a u8> {
     fn push_u64(&mut self, value: u64) {
         self.extend(&value.to_le_bytes());
     }
+    /// Push a signed 48-bit value into the assembling target. `value` must fit in 48 bits, either as a signed
+    /// or as an unsigned value, otherwise `runtime_error` is called.
+    #[inline]
+    fn push_i48(&mut self, value: i64) {
+        if !(-(1 << 47)..(1 << 48)).contains(&value) {
+            self.runtime_error("value does not fit in 48 bits");
+        }
+        self.extend(&value.to_le_bytes()[..6]);
+    }
+    /// Push an unsigned 48-bit value into the assembling target. `value` must fit in 48 bits, otherwise
+    /// `runtime_error` is called.
+    #[inline]
+    fn push_u48(&mut self, value: u64) {
+        if value >= (1 << 48) {
+            self.runtime_error("value does not fit in 48 bits");
+        }
+        self.extend(&value.to_le_bytes()[..6]);
+    }
+    /// Push a signed 80-bit value into the assembling target. `value` must fit in 80 bits, either as a signed
+    /// or as an unsigned value, otherwise `runtime_error` is called.
+    #[inline]
+    fn push_i80(&mut self, value: i128) {
+        if !(-(1 << 79)..(1 << 80)).contains(&value) {
+            self.runtime_error("value does not fit in 80 bits");
+        }
+        self.extend(&value.to_le_bytes()[..10]);
+    }
+    /// Push an unsigned 80-bit value into the assembling target. `value` must fit in 80 bits, otherwise
+    /// `runtime_error` is called.
+    #[inline]
+    fn push_u80(&mut self, value: u128) {
+        if value >= (1 << 80) {
+            self.runtime_error("value does not fit in 80 bits");
+        }
+        self.extend(&value.to_le_bytes()[..10]);
+    }
+    /// Push a signed octword into the assembling target
+    #[inline]
+    fn push_i128(&mut self, value: i128) {
+        self.extend(&value.to_le_bytes());
+    }
+    /// Push an unsigned octword into the assembling target
+    #[inline]
+    fn push_u128(&mut self, value: u128) {
+        self.extend(&value.to_le_bytes());
+    }
+    /// Push a signed hexadecword into the assembling target, sign-extending `value`. As `value` is only 128 bits
+    /// wide, the upper 128 bits are always a copy of its sign bit and cannot be set independently.
+    #[inline]
+    fn push_i256(&mut self, value: i128) {
+        self.extend(&value.to_le_bytes());
+        self.extend(&(value >> 127).to_le_bytes());
+    }
+    /// Push an unsigned hexadecword into the assembling target, zero-extending `value`. As `value` is only 128 bits
+    /// wide, the upper 128 bits are always zero. Use `extend` to emit arbitrary 256-bit data.
+    #[inline]
+    fn push_u256(&mut self, value: u128) {
+        self.extend(&value.to_le_bytes());
+        self.extend(&[0u8; 16]);
+    }
     /// This function is called in when a runtime error has to be generated. It panics.
     #[inline]
     fn runtime_error(&self, msg: &'static str) -> ! {
@@ -231,6 +429,31 @@ pub trait DynasmLabelApi : DynasmApi {
     /// Record the definition of a dynamic label
     fn dynamic_label(&mut self, id: DynamicLabel);
 
+    /// Record the definition of a global label with a name determined at runtime.
+    /// These labels are not affected by label scopes: a named label is visible from every scope,
+    /// and defining the same name in two scopes is a duplicate definition. By default, named labels
+    /// are not supported and this generates a runtime error.
+    fn named_label(&mut self, _name: &str) {
+        self.runtime_error("This assembler does not support named labels")
+    }
+
+    /// Start a new label scope. Global labels defined after this are only visible to code in the same scope,
+    /// and local labels defined before this can no longer be referenced. By default, label scopes are not
+    /// supported and this generates a runtime error.
+    fn begin_scope(&mut self) {
+        self.runtime_error("This assembler does not support label scopes")
+    }
+    /// Return to the root label scope. Local labels defined before this can no longer be referenced.
+    /// By default, label scopes are not supported and this generates a runtime error.
+    fn end_scope(&mut self) {
+        self.runtime_error("This assembler does not support label scopes")
+    }
+
+    /// Record the source location of the `dynasm!` invocation that emits the relocation following this call.
+    /// The location only applies to that single relocation. This is only used to improve error reporting,
+    /// so by default it is ignored.
+    fn source_location(&mut self, _file: &'static str, _line: u32) { }
+
     /// Record a relocation spot for a forward reference to a local label
     fn forward_reloc( &mut self, name: &'static str, offset: isize, kind: <Self::Relocation as Relocation>::Encoding) {
         self.forward_relocation(name, offset, Self::Relocation::from_encoding(kind))
@@ -247,6 +470,10 @@ pub trait DynasmLabelApi : DynasmApi {
     fn dynamic_reloc( &mut self, id: DynamicLabel,   offset: isize, kind: <Self::Relocation as Relocation>::Encoding) {
         self.dynamic_relocation(id, offset, Self::Relocation::from_encoding(kind))
     }
+    /// Record a relocation spot for a reference to a global label with a name determined at runtime
+    fn named_reloc(   &mut self, name: &str,         offset: isize, kind: <Self::Relocation as Relocation>::Encoding) {
+        self.named_relocation(name, offset, Self::Relocation::from_encoding(kind))
+    }
     /// Record a relocation spot to an arbitrary target.
     fn bare_reloc(&mut self, target: usize, kind: <Self::Relocation as Relocation>::Encoding) {
         self.bare_relocation(target, Self::Relocation::from_encoding(kind))
@@ -260,6 +487,11 @@ pub trait DynasmLabelApi : DynasmApi {
     fn global_relocation(  &mut self, name: &'static str, offset: isize, kind: Self::Relocation);
     /// Equivalent of dynamic_reloc, but takes a non-encoded relocation
     fn dynamic_relocation( &mut self, id: DynamicLabel,   offset: isize, kind: Self::Relocation);
+    /// Equivalent of named_reloc, but takes a non-encoded relocation. By default, named labels
+    /// are not supported and this generates a runtime error.
+    fn named_relocation(   &mut self, _name: &str,        _offset: isize, _kind: Self::Relocation) {
+        self.runtime_error("This assembler does not support named labels")
+    }
     /// Equivalent of bare_reloc, but takes a non-encoded relocation
     fn bare_relocation(&mut self, target: usize, kind: Self::Relocation);
 }
@@ -330,7 +562,9 @@ pub struct VecAssembler<R: Relocation> {
     baseaddr: usize,
     labels: LabelRegistry,
     relocs: RelocRegistry<R>,
-    error: Option<DynasmError>,
+    managed: ManagedRelocs<R>,
+    source: Option<SourceLocation>,
+    errors: Vec<DynasmError>,
 }
 
 impl<R: Relocation> VecAssembler<R> {
@@ -341,40 +575,58 @@ impl<R: Relocation> VecAssembler<R> {
             baseaddr,
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
-            error: None
+            managed: ManagedRelocs::new(),
+            source: None,
+            errors: Vec::new()
         }
     }
 
     /// Resolves any relocations emitted to the assembler before this point.
-    /// If an impossible relocation was specified before this point, returns them here.
-    pub fn commit(&mut self) -> Result<(), DynasmError> {
-        // If we accrued any errors while assembling before, emit them now.
-        if let Some(e) = self.error.take() {
-            return Err(e);
-        }
-
+    /// If any errors were encountered while assembling or resolving, all of them are returned here.
+    pub fn commit(&mut self) -> Result<(), Vec<DynasmError>> {
         // Resolve globals
-        for (loc, name) in self.relocs.take_globals() {
-            let target = self.labels.resolve_global(name)?;
-            if loc.patch(0, self.baseaddr, &mut self.ops, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Global(name)));
+        for (loc, scope, name) in self.relocs.take_globals() {
+            let target = match self.labels.resolve_global_in(scope, name) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Global(name))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(0, self.baseaddr, &mut self.ops, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Global(name)), e));
+            } else if loc.needs_adjustment() {
+                self.managed.add(loc);
             }
         }
 
         // Resolve dynamics
         for (loc, id) in self.relocs.take_dynamics() {
-            let target = self.labels.resolve_dynamic(id)?;
-            if loc.patch(0, self.baseaddr, &mut self.ops, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Dynamic(id)));
+            let target = match self.labels.resolve_dynamic(id) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(self.labels.dynamic_target(id))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(0, self.baseaddr, &mut self.ops, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(self.labels.dynamic_target(id)), e));
+            } else if loc.needs_adjustment() {
+                self.managed.add(loc);
             }
         }
 
         // Check that there are no unknown local labels
-        for (_, name) in self.relocs.take_locals() {
-            return Err(DynasmError::UnknownLabel(LabelKind::Local(name)));
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
         }
 
-        Ok(())
+        // Emit any errors we accrued while assembling or resolving
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(mem::take(&mut self.errors))
+        }
     }
 
     /// Use an `UncommittedModifier` to alter uncommitted code.
@@ -383,12 +635,39 @@ impl<R: Relocation> VecAssembler<R> {
         UncommittedModifier::new(&mut self.ops, AssemblyOffset(0))
     }
 
+    /// Provides access to the assemblers internal labels registry
+    pub fn labels(&self) -> &LabelRegistry {
+        &self.labels
+    }
+
     /// Finalizes the `VecAssembler`, returning the resulting `Vec<u8>` containing all assembled data.
-    /// this implicitly commits any relocations beforehand and returns an error if required.
-    pub fn finalize(mut self) -> Result<Vec<u8>, DynasmError> {
+    /// this implicitly commits any relocations beforehand and returns the errors if required.
+    pub fn finalize(mut self) -> Result<Vec<u8>, Vec<DynasmError>> {
         self.commit()?;
         Ok(self.ops)
     }
+
+    /// Equivalent of `finalize`, but also returns the labels that were defined, so they can still
+    /// be inspected afterwards.
+    pub fn finalize_with_labels(mut self) -> Result<(Vec<u8>, LabelRegistry), Vec<DynasmError>> {
+        self.commit()?;
+        Ok((self.ops, self.labels))
+    }
+
+    /// Finalizes the `VecAssembler` into a `RelocatableBlob`, which contains the assembled data as well as
+    /// all relocations that depend on the base address of the code, so it can be loaded at any other address.
+    /// This implicitly commits any relocations beforehand and returns the errors if required.
+    pub fn finalize_relocatable(mut self) -> Result<RelocatableBlob<R>, Vec<DynasmError>> {
+        self.commit()?;
+        Ok(RelocatableBlob::new(self.ops, self.baseaddr, self.managed.into_vec()))
+    }
+
+    // report any relocations still waiting for a local label, as they can no longer be resolved
+    fn close_local_scope(&mut self) {
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
+        }
+    }
 }
 
 impl<R: Relocation> Extend<u8> for VecAssembler<R> {
@@ -426,8 +705,10 @@ impl<R: Relocation> DynasmLabelApi for VecAssembler<R> {
     fn local_label(&mut self, name: &'static str) {
         let offset = self.offset();
         for loc in self.relocs.take_locals_named(name) {
-            if loc.patch(0, self.baseaddr, &mut self.ops, offset.0).is_err() {
-                self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Forward(name)))
+            if let Err(e) = loc.patch(0, self.baseaddr, &mut self.ops, offset.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Forward(name)), e))
+            } else if loc.needs_adjustment() {
+                self.managed.add(loc)
             }
         }
         self.labels.define_local(name, offset);
@@ -435,46 +716,69 @@ impl<R: Relocation> DynasmLabelApi for VecAssembler<R> {
     fn global_label( &mut self, name: &'static str) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
-            self.error = Some(e)
+            self.errors.push(e)
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
-            self.error = Some(e)
+            self.errors.push(e)
         }
     }
+    fn named_label(&mut self, name: &str) {
+        let id = self.labels.named_label(name);
+        self.dynamic_label(id);
+    }
+    fn source_location(&mut self, file: &'static str, line: u32) {
+        self.source = Some(SourceLocation { file, line });
+    }
+    fn begin_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.begin_scope();
+    }
+    fn end_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.end_scope();
+    }
     fn global_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_global(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_global(self.labels.current_scope(), name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn dynamic_relocation(&mut self, id: DynamicLabel, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind));
+        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
+    }
+    fn named_relocation(&mut self, name: &str, offset: isize, kind: R) {
+        let id = self.labels.named_label(name);
+        self.dynamic_relocation(id, offset, kind);
     }
     fn forward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_local(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_local(name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn backward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
+        let location = self.offset();
+        let loc = PatchLoc::new(location, offset, kind).with_source(self.source.take());
         let target = match self.labels.resolve_local(name) {
             Ok(target) => target.0,
-            Err(e) => {
-                self.error = Some(e);
+            Err(_) => {
+                self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Backward(name))));
                 return;
             }
         };
-        let location = self.offset();
-        let loc = PatchLoc::new(location, offset, kind);
-        if loc.patch(0, self.baseaddr, &mut self.ops, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Backward(name)))
+        if let Err(e) = loc.patch(0, self.baseaddr, &mut self.ops, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Backward(name)), e))
+        } else if loc.needs_adjustment() {
+            self.managed.add(loc)
         }
     }
     fn bare_relocation(&mut self, target: usize, kind: R) {
         let location = self.offset();
-        let loc = PatchLoc::new(location, 0, kind);
-        if loc.patch(0, self.baseaddr, &mut self.ops, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)))
+        let loc = PatchLoc::new(location, 0, kind).with_source(self.source.take());
+        if let Err(e) = loc.patch(0, self.baseaddr, &mut self.ops, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Extern(target)), e))
+        } else if loc.needs_adjustment() {
+            self.managed.add(loc)
         }
     }
 }
@@ -483,26 +787,50 @@ impl<R: Relocation> DynasmLabelApi for VecAssembler<R> {
 /// A full assembler implementation. Supports labels, all types of relocations,
 /// incremental compilation and multithreaded execution with simultaneous compiltion.
 /// Its implementation guarantees no memory is executable and writable at the same time.
+///
+/// By default, memory is allocated using `mmap`. A different memory backend can be used by
+/// specifying a `CodeMemory` implementation as `M`.
+#[cfg(feature = "std")]
 #[derive(Debug)]
-pub struct Assembler<R: Relocation> {
+pub struct Assembler<R: Relocation, M: CodeMemory = ExecutableBuffer> {
     ops: Vec<u8>,
-    memory: MemoryManager,
+    memory: MemoryManager<M>,
     labels: LabelRegistry,
     relocs: RelocRegistry<R>,
     managed: ManagedRelocs<R>,
-    error: Option<DynasmError>,
+    source: Option<SourceLocation>,
+    errors: Vec<DynasmError>,
 }
 
-impl<R: Relocation> Assembler<R> {
+#[cfg(feature = "std")]
+impl<R: Relocation, M: CodeMemory> Assembler<R, M> {
     /// Create a new, empty assembler, with initial allocation size `page_size`.
     pub fn new() -> io::Result<Self> {
+        Self::with_config(Config::default())
+    }
+
+    /// Create a new, empty assembler, with initial allocation size `page_size`, that allocates and protects
+    /// its memory according to `config`.
+    pub fn with_config(config: Config) -> io::Result<Self> {
+        Self::with_capacity_and_config(R::page_size(), config)
+    }
+
+    /// Create a new, empty assembler, with room for at least `capacity` bytes of code before it has to grow.
+    pub fn with_capacity(capacity: usize) -> io::Result<Self> {
+        Self::with_capacity_and_config(capacity, Config::default())
+    }
+
+    /// Create a new, empty assembler, with room for at least `capacity` bytes of code before it has to grow,
+    /// that allocates and protects its memory according to `config`.
+    pub fn with_capacity_and_config(capacity: usize, config: Config) -> io::Result<Self> {
         Ok(Self {
             ops: Vec::new(),
-            memory: MemoryManager::new(R::page_size())?,
+            memory: MemoryManager::with_config(capacity.max(1), config)?,
             labels: LabelRegistry::new(),
             relocs: RelocRegistry::new(),
             managed: ManagedRelocs::new(),
-            error: None
+            source: None,
+            errors: Vec::new()
         })
     }
 
@@ -521,82 +849,108 @@ impl<R: Relocation> Assembler<R> {
     /// Use a `Modifier` to alter committed code directly. While this is happening
     /// no code can be executed as the relevant pages are remapped as writable.
     /// This API supports defining new labels/relocations, and overwriting previously defined relocations.
-    pub fn alter<F, O>(&mut self, f: F) -> Result<O, DynasmError>
+    /// This fails with `DynasmError::StrictMode` if the assembler was created with `Config::strict` set.
+    pub fn alter<F, O>(&mut self, f: F) -> Result<O, Vec<DynasmError>>
     where F: FnOnce(&mut Modifier<R>) -> O {
+        if self.memory.config().strict {
+            return Err(vec![DynasmError::StrictMode]);
+        }
         self.commit()?;
 
         // swap out a buffer from base
         let mut lock = self.memory.write();
-        let buffer = mem::replace(&mut *lock, ExecutableBuffer::default());
-        let mut buffer = buffer.make_mut().expect("Could not swap buffer protection modes");
+        let buffer = mem::replace(&mut *lock, M::default());
+        let mut buffer = buffer.make_writable().expect("Could not swap buffer protection modes");
+        let buffer_addr = buffer.address();
 
         // construct the modifier
         let mut modifier = Modifier {
             asmoffset: 0,
             previous_asmoffset: 0,
             buffer: &mut *buffer,
+            buffer_addr,
 
             labels: &mut self.labels,
             relocs: &mut self.relocs,
             old_managed: &mut self.managed,
             new_managed: ManagedRelocs::new(),
 
-            error: None
+            source: None,
+            errors: Vec::new()
         };
 
         // execute the user code
         let output = f(&mut modifier);
 
         // flush any changes made by the user code to the buffer
-        modifier.encode_relocs()?;
+        let result = modifier.encode_relocs();
 
         // repack the buffer
-        let buffer = buffer.make_exec().expect("Could not swap buffer protection modes");
+        let buffer = buffer.make_executable().expect("Could not swap buffer protection modes");
         mem::replace(&mut *lock, buffer);
 
         // call it a day
-        Ok(output)
+        result.map(|_| output)
     }
 
     /// Commit code, flushing the temporary internal assembling buffer to the mapped executable memory.
     /// This makes assembled code available for execution.
-    pub fn commit(&mut self) -> Result<(), DynasmError> {
+    /// If any errors were encountered while assembling or resolving, all of them are returned here.
+    pub fn commit(&mut self) -> Result<(), Vec<DynasmError>> {
         self.encode_relocs()?;
 
         let managed = &self.managed;
-        let error = &mut self.error;
+        let errors = &mut self.errors;
 
         self.memory.commit(&mut self.ops, |buffer, old_addr, new_addr| {
             let change = new_addr.wrapping_sub(old_addr) as isize;
 
             for reloc in managed.iter() {
-                if reloc.adjust(0, buffer, change).is_err() {
-                    *error = Some(DynasmError::ImpossibleRelocation(TargetKind::Managed))
+                if let Err(e) = reloc.adjust(0, buffer, change) {
+                    errors.push(DynasmError::ImpossibleRelocation(reloc.site(TargetKind::Managed), e))
                 }
             }
         });
 
-        if let Some(e) = self.error.take() {
-            return Err(e);
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(mem::take(&mut self.errors))
         }
-        Ok(())
     }
 
     /// Finalize this assembler, returning the internal executablebuffer if no Executor instances exist.
+    /// Otherwise, the assembler is returned in `FinalizeError::InUse`. If sealing the buffer as requested
+    /// by `Config::seal` failed, `FinalizeError::Seal` is returned.
     /// This panics if any uncommitted changes caused errors near the end. To handle these, call `commit()` explicitly beforehand.
-    pub fn finalize(mut self) -> Result<ExecutableBuffer, Self> {
+    pub fn finalize(mut self) -> Result<M, FinalizeError<Self>> {
         self.commit().expect("Errors were encountered when committing before finalization");
         match self.memory.finalize() {
             Ok(execbuffer) => Ok(execbuffer),
-            Err(memory) => Err(Self {
+            Err(FinalizeError::InUse(memory)) => Err(FinalizeError::InUse(Self {
                 memory,
                 ..self
-            })
+            })),
+            Err(FinalizeError::Seal(e)) => Err(FinalizeError::Seal(e))
+        }
+    }
+
+    /// Equivalent of `finalize`, but also returns the labels that were defined, so they can still
+    /// be inspected afterwards.
+    pub fn finalize_with_labels(mut self) -> Result<(M, LabelRegistry), FinalizeError<Self>> {
+        self.commit().expect("Errors were encountered when committing before finalization");
+        match self.memory.finalize() {
+            Ok(execbuffer) => Ok((execbuffer, self.labels)),
+            Err(FinalizeError::InUse(memory)) => Err(FinalizeError::InUse(Self {
+                memory,
+                ..self
+            })),
+            Err(FinalizeError::Seal(e)) => Err(FinalizeError::Seal(e))
         }
     }
 
     /// Create an executor which can be used to execute code while still assembling code
-    pub fn reader(&self) -> Executor {
+    pub fn reader(&self) -> Executor<M> {
         Executor {
             execbuffer: self.memory.reader()
         }
@@ -613,60 +967,97 @@ impl<R: Relocation> Assembler<R> {
     }
 
     // encode uncommited relocations
-    fn encode_relocs(&mut self) -> Result<(), DynasmError> {
+    fn encode_relocs(&mut self) -> Result<(), Vec<DynasmError>> {
         let buf_offset = self.memory.committed();
         let buf_addr = self.memory.execbuffer_addr();
         let buf = &mut self.ops;
 
-        // If we accrued any errors while assembling before, emit them now.
-        if let Some(e) = self.error.take() {
-            return Err(e);
-        }
-
         // Resolve globals
-        for (loc, name) in self.relocs.take_globals() {
-            let target = self.labels.resolve_global(name)?;
-            if loc.patch(buf_offset, buf_addr, buf, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Global(name)));
-            }
-            if loc.needs_adjustment() {
-                self.managed.add(loc)
+        for (loc, scope, name) in self.relocs.take_globals() {
+            let target = match self.labels.resolve_global_in(scope, name) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Global(name))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(buf_offset, buf_addr, buf, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Global(name)), e));
+            } else if loc.needs_adjustment() {
+                self.managed.add(loc);
             }
         }
 
         // Resolve dynamics
         for (loc, id) in self.relocs.take_dynamics() {
-            let target = self.labels.resolve_dynamic(id)?;
-            if loc.patch(buf_offset, buf_addr, buf, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Dynamic(id)));
-            }
-            if loc.needs_adjustment() {
-                self.managed.add(loc)
+            let target = match self.labels.resolve_dynamic(id) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(self.labels.dynamic_target(id))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(buf_offset, buf_addr, buf, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(self.labels.dynamic_target(id)), e));
+            } else if loc.needs_adjustment() {
+                self.managed.add(loc);
             }
         }
 
         // Check that there are no unknown local labels
-        for (_, name) in self.relocs.take_locals() {
-            return Err(DynasmError::UnknownLabel(LabelKind::Local(name)));
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
         }
 
-        Ok(())
+        // Emit any errors we accrued while assembling or resolving
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(mem::take(&mut self.errors))
+        }
+    }
+
+    // report any relocations still waiting for a local label, as they can no longer be resolved
+    fn close_local_scope(&mut self) {
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<R: Relocation> Assembler<R, ExecutableBuffer> {
+    /// Atomically overwrite a naturally aligned piece of committed code at `offset` with `value`,
+    /// without preventing other threads from executing code through an `Executor`.
+    /// See `ExecutableBuffer::patch_atomic` for details. This fails if the assembler was created with `Config::strict` set.
+    ///
+    /// # Safety
+    /// No references into the executable buffer may be live while this is called, on any thread. In particular, this
+    /// includes the `&[u8]` the buffer behind an `Executor` lock derefs to.
+    pub unsafe fn patch_atomic<T: AtomicPatch>(&self, offset: AssemblyOffset, value: T) -> io::Result<()> {
+        if self.memory.config().strict {
+            return Err(io::Error::new(io::ErrorKind::PermissionDenied, "committed code cannot be patched in strict mode"));
+        }
+        self.memory.reader().read().unwrap().patch_atomic(offset, value)
     }
 }
 
-impl<R: Relocation> Extend<u8> for Assembler<R> {
+#[cfg(feature = "std")]
+impl<R: Relocation, M: CodeMemory> Extend<u8> for Assembler<R, M> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=u8> {
         self.ops.extend(iter)
     }
 }
 
-impl<'a, R: Relocation> Extend<&'a u8> for Assembler<R> {
+#[cfg(feature = "std")]
+impl<'a, R: Relocation, M: CodeMemory> Extend<&'a u8> for Assembler<R, M> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=&'a u8> {
         self.ops.extend(iter)
     }
 }
 
-impl<R: Relocation> DynasmApi for Assembler<R> {
+#[cfg(feature = "std")]
+impl<R: Relocation, M: CodeMemory> DynasmApi for Assembler<R, M> {
     fn offset(&self) -> AssemblyOffset {
         AssemblyOffset(self.memory.committed() + self.ops.len())
     }
@@ -685,14 +1076,15 @@ impl<R: Relocation> DynasmApi for Assembler<R> {
     }
 }
 
-impl<R: Relocation> DynasmLabelApi for Assembler<R> {
+#[cfg(feature = "std")]
+impl<R: Relocation, M: CodeMemory> DynasmLabelApi for Assembler<R, M> {
     type Relocation = R;
 
     fn local_label(&mut self, name: &'static str) {
         let offset = self.offset();
         for loc in self.relocs.take_locals_named(name) {
-            if loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, offset.0).is_err() {
-                self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Forward(name)))
+            if let Err(e) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, offset.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Forward(name)), e))
             } else if loc.needs_adjustment() {
                 self.managed.add(loc)
             }
@@ -702,48 +1094,67 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
     fn global_label( &mut self, name: &'static str) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
-            self.error = Some(e)
+            self.errors.push(e)
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
-            self.error = Some(e)
+            self.errors.push(e)
         }
     }
+    fn named_label(&mut self, name: &str) {
+        let id = self.labels.named_label(name);
+        self.dynamic_label(id);
+    }
+    fn source_location(&mut self, file: &'static str, line: u32) {
+        self.source = Some(SourceLocation { file, line });
+    }
+    fn begin_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.begin_scope();
+    }
+    fn end_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.end_scope();
+    }
     fn global_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_global(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_global(self.labels.current_scope(), name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn dynamic_relocation(&mut self, id: DynamicLabel, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind));
+        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
+    }
+    fn named_relocation(&mut self, name: &str, offset: isize, kind: R) {
+        let id = self.labels.named_label(name);
+        self.dynamic_relocation(id, offset, kind);
     }
     fn forward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_local(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_local(name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn backward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
+        let location = self.offset();
+        let loc = PatchLoc::new(location, offset, kind).with_source(self.source.take());
         let target = match self.labels.resolve_local(name) {
             Ok(target) => target.0,
-            Err(e) => {
-                self.error = Some(e);
+            Err(_) => {
+                self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Backward(name))));
                 return;
             }
         };
-        let location = self.offset();
-        let loc = PatchLoc::new(location, offset, kind);
-        if loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Backward(name)))
+        if let Err(e) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Backward(name)), e))
         } else if loc.needs_adjustment() {
             self.managed.add(loc)
         }
     }
     fn bare_relocation(&mut self, target: usize, kind: R) {
         let location = self.offset();
-        let loc = PatchLoc::new(location, 0, kind);
-        if loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)))
+        let loc = PatchLoc::new(location, 0, kind).with_source(self.source.take());
+        if let Err(e) = loc.patch(self.memory.committed(), self.memory.execbuffer_addr(), &mut self.ops, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Extern(target)), e))
         } else if loc.needs_adjustment() {
             self.managed.add(loc)
         }
@@ -754,20 +1165,24 @@ impl<R: Relocation> DynasmLabelApi for Assembler<R> {
 /// Allows modification of already committed assembly code. Contains an internal cursor
 /// into the emitted assembly, initialized to the start, that can be moved around either with the
 /// `goto` function, or just by assembling new code into this `Modifier`.
+#[cfg(feature = "std")]
 #[derive(Debug)]
 pub struct Modifier<'a, R: Relocation> {
     asmoffset: usize,
     previous_asmoffset: usize,
     buffer: &'a mut [u8],
+    buffer_addr: usize,
 
     labels: &'a mut LabelRegistry,
     relocs: &'a mut RelocRegistry<R>,
     old_managed: &'a mut ManagedRelocs<R>,
     new_managed: ManagedRelocs<R>,
 
-    error: Option<DynasmError>
+    source: Option<SourceLocation>,
+    errors: Vec<DynasmError>
 }
 
+#[cfg(feature = "std")]
 impl<'a, R: Relocation> Modifier<'a, R> {
     /// Move the modifier cursor to the selected location.
     pub fn goto(&mut self, offset: AssemblyOffset) {
@@ -795,39 +1210,45 @@ impl<'a, R: Relocation> Modifier<'a, R> {
     }
 
     // encode uncommited relocations
-    fn encode_relocs(&mut self) -> Result<(), DynasmError> {
-        let buf_addr = self.buffer.as_ptr() as usize;
-
-        // If we accrued any errors while assembling before, emit them now.
-        if let Some(e) = self.error.take() {
-            return Err(e);
-        }
+    fn encode_relocs(&mut self) -> Result<(), Vec<DynasmError>> {
+        let buf_addr = self.buffer_addr;
+        let buf = &mut *self.buffer;
 
         // Resolve globals
-        for (loc, name) in self.relocs.take_globals() {
-            let target = self.labels.resolve_global(name)?;
-            if loc.patch(0, buf_addr, self.buffer, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Global(name)));
-            }
-            if loc.needs_adjustment() {
+        for (loc, scope, name) in self.relocs.take_globals() {
+            let target = match self.labels.resolve_global_in(scope, name) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Global(name))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(0, buf_addr, buf, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Global(name)), e));
+            } else if loc.needs_adjustment() {
                 self.new_managed.add(loc);
             }
         }
 
         // Resolve dynamics
         for (loc, id) in self.relocs.take_dynamics() {
-            let target = self.labels.resolve_dynamic(id)?;
-            if loc.patch(0, buf_addr, self.buffer, target.0).is_err() {
-                return Err(DynasmError::ImpossibleRelocation(TargetKind::Dynamic(id)));
-            }
-            if loc.needs_adjustment() {
+            let target = match self.labels.resolve_dynamic(id) {
+                Ok(target) => target,
+                Err(_) => {
+                    self.errors.push(DynasmError::UnresolvedRelocation(loc.site(self.labels.dynamic_target(id))));
+                    continue;
+                }
+            };
+            if let Err(e) = loc.patch(0, buf_addr, buf, target.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(self.labels.dynamic_target(id)), e));
+            } else if loc.needs_adjustment() {
                 self.new_managed.add(loc);
             }
         }
 
         // Check for unknown locals
-        for (_, name) in self.relocs.take_locals() {
-            return Err(DynasmError::UnknownLabel(LabelKind::Local(name)));
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
         }
 
         self.old_managed.remove_between(self.previous_asmoffset, self.asmoffset);
@@ -835,26 +1256,41 @@ impl<'a, R: Relocation> Modifier<'a, R> {
 
         self.old_managed.append(&mut self.new_managed);
 
-        Ok(())
+        // Emit any errors we accrued while assembling or resolving
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(mem::take(&mut self.errors))
+        }
+    }
+
+    // report any relocations still waiting for a local label, as they can no longer be resolved
+    fn close_local_scope(&mut self) {
+        for (loc, name) in self.relocs.take_locals() {
+            self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Forward(name))));
+        }
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a, R: Relocation> Extend<u8> for Modifier<'a,R> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=u8> {
-        for (src, dst) in iter.into_iter().zip(self.buffer[self.asmoffset ..].iter_mut()) {
-            *dst = src;
+        for value in iter {
+            self.push(value);
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a, 'b, R: Relocation> Extend<&'b u8> for Modifier<'a, R> {
     fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=&'b u8> {
-        for (src, dst) in iter.into_iter().zip(self.buffer[self.asmoffset ..].iter_mut()) {
-            *dst = *src;
+        for value in iter {
+            self.push(*value);
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a, R: Relocation> DynasmApi for Modifier<'a, R> {
     fn offset(&self) -> AssemblyOffset {
         AssemblyOffset(self.asmoffset)
@@ -875,14 +1311,15 @@ impl<'a, R: Relocation> DynasmApi for Modifier<'a, R> {
     }
 }
 
+#[cfg(feature = "std")]
 impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
     type Relocation = R;
 
     fn local_label(&mut self, name: &'static str) {
         let offset = self.offset();
         for loc in self.relocs.take_locals_named(name) {
-            if loc.patch(0, self.buffer.as_ptr() as usize, self.buffer, offset.0).is_err()  {
-                self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Forward(name)));
+            if let Err(e) = loc.patch(0, self.buffer_addr, self.buffer, offset.0) {
+                self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Forward(name)), e));
             } else if loc.needs_adjustment() {
                 self.new_managed.add(loc);
             }
@@ -892,48 +1329,67 @@ impl<'a, R: Relocation> DynasmLabelApi for Modifier<'a, R> {
     fn global_label( &mut self, name: &'static str) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_global(name, offset) {
-            self.error = Some(e);
+            self.errors.push(e);
         }
     }
     fn dynamic_label(&mut self, id: DynamicLabel) {
         let offset = self.offset();
         if let Err(e) = self.labels.define_dynamic(id, offset) {
-            self.error = Some(e);
+            self.errors.push(e);
         }
     }
+    fn named_label(&mut self, name: &str) {
+        let id = self.labels.named_label(name);
+        self.dynamic_label(id);
+    }
+    fn source_location(&mut self, file: &'static str, line: u32) {
+        self.source = Some(SourceLocation { file, line });
+    }
+    fn begin_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.begin_scope();
+    }
+    fn end_scope(&mut self) {
+        self.close_local_scope();
+        self.labels.end_scope();
+    }
     fn global_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_global(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_global(self.labels.current_scope(), name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn dynamic_relocation(&mut self, id: DynamicLabel, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind));
+        self.relocs.add_dynamic(id, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
+    }
+    fn named_relocation(&mut self, name: &str, offset: isize, kind: R) {
+        let id = self.labels.named_label(name);
+        self.dynamic_relocation(id, offset, kind);
     }
     fn forward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
         let location = self.offset();
-        self.relocs.add_local(name, PatchLoc::new(location, offset, kind));
+        self.relocs.add_local(name, PatchLoc::new(location, offset, kind).with_source(self.source.take()));
     }
     fn backward_relocation(&mut self, name: &'static str, offset: isize, kind: R) {
+        let location = self.offset();
+        let loc = PatchLoc::new(location, offset, kind).with_source(self.source.take());
         let target = match self.labels.resolve_local(name) {
             Ok(target) => target.0,
-            Err(e) => {
-                self.error = Some(e);
+            Err(_) => {
+                self.errors.push(DynasmError::UnresolvedRelocation(loc.site(TargetKind::Backward(name))));
                 return;
             }
         };
-        let location = self.offset();
-        let loc = PatchLoc::new(location, offset, kind);
-        if loc.patch(0, self.buffer.as_ptr() as usize, self.buffer, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Backward(name)));
+        if let Err(e) = loc.patch(0, self.buffer_addr, self.buffer, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Backward(name)), e));
         } else if loc.needs_adjustment() {
             self.new_managed.add(loc)
         }
     }
     fn bare_relocation(&mut self, target: usize, kind: R) {
         let location = self.offset();
-        let loc = PatchLoc::new(location, 0, kind);
-        if loc.patch(0, self.buffer.as_ptr() as usize, self.buffer, target).is_err() {
-            self.error = Some(DynasmError::ImpossibleRelocation(TargetKind::Extern(target)));
+        let loc = PatchLoc::new(location, 0, kind).with_source(self.source.take());
+        if let Err(e) = loc.patch(0, self.buffer_addr, self.buffer, target) {
+            self.errors.push(DynasmError::ImpossibleRelocation(loc.site(TargetKind::Extern(target)), e));
         } else if loc.needs_adjustment() {
             self.new_managed.add(loc)
         }
@@ -0,0 +1,356 @@
+//! This module implements the relocation model for the loongarch64 architecture, as well as aliases for loongarch64 Assemblers.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use crate::blob::{BlobRelocation, size_from_byte};
+use core::convert::TryFrom;
+
+pub use crate::registers::{DynamicRegister, InvalidRegister};
+
+/// Relocation implementation for the loongarch64 architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum Loongarch64Relocation {
+    // beq, bne, blt, bge, bltu, bgeu: 16 bits, word aligned
+    B16,
+    // beqz, bnez, bceqz, bcnez: 21 bits split over two fields, word aligned
+    B21,
+    // b, bl: 26 bits split over two fields, word aligned
+    B26,
+    // pcaddi: 20 bits, word aligned
+    PCADDI,
+    // pcaddu12i followed by an instruction with a 12-bit immediate: split 32 bits, byte aligned
+    PCADDU12I,
+    // pcaddu18i followed by jirl: split 38 bits, word aligned
+    PCADDU18I,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl Loongarch64Relocation {
+    fn op_mask(&self) -> u32 {
+        match self {
+            Self::B16 => 0xFC00_03FF,
+            Self::B21 => 0xFC00_03E0,
+            Self::B26 => 0xFC00_0000,
+            Self::PCADDI |
+            Self::PCADDU12I |
+            Self::PCADDU18I => 0xFE00_001F,
+            Self::Plain(_) => 0
+        }
+    }
+
+    fn encode(&self, orig: isize) -> Result<u32, ImpossibleRelocation> {
+        let value = i64::try_from(orig).map_err(|_| ImpossibleRelocation::signed_field(orig, 64, 1))?;
+        Ok(match self {
+            Self::B16 => {
+                if value & 3 != 0 || !fits_signed_bitfield(value, 18) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 16, 4));
+                }
+                let value = value as u32;
+                ((value >> 2) & 0xFFFF) << 10
+            },
+            Self::B21 => {
+                if value & 3 != 0 || !fits_signed_bitfield(value, 23) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 21, 4));
+                }
+                let value = value as u32;
+                ((value >> 2) & 0xFFFF) << 10 |
+                ((value >> 18) & 0x1F)
+            },
+            Self::B26 => {
+                if value & 3 != 0 || !fits_signed_bitfield(value, 28) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 26, 4));
+                }
+                let value = value as u32;
+                ((value >> 2) & 0xFFFF) << 10 |
+                ((value >> 18) & 0x3FF)
+            },
+            Self::PCADDI => {
+                if value & 3 != 0 || !fits_signed_bitfield(value, 22) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 20, 4));
+                }
+                let value = value as u32;
+                ((value >> 2) & 0xF_FFFF) << 5
+            },
+            Self::PCADDU12I => {
+                // the pcaddu12i part, the low part is handled in write_value
+                let high = (value + 0x800) >> 12;
+                if !fits_signed_bitfield(high, 20) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 32, 1));
+                }
+                ((high as u32) & 0xF_FFFF) << 5
+            },
+            Self::PCADDU18I => {
+                // the pcaddu18i part, the low part is handled in write_value
+                let high = (value + 0x2_0000) >> 18;
+                if value & 3 != 0 || !fits_signed_bitfield(high, 20) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 36, 4));
+                }
+                ((high as u32) & 0xF_FFFF) << 5
+            },
+            Self::Plain(_) => unreachable!()
+        })
+    }
+}
+
+impl Relocation for Loongarch64Relocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::B16,
+            1 => Self::B21,
+            2 => Self::B26,
+            3 => Self::PCADDI,
+            4 => Self::PCADDU12I,
+            5 => Self::PCADDU18I,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 6))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::Plain(s) => s.size(),
+            Self::PCADDU12I |
+            Self::PCADDU18I => RelocationSize::QWord.size(),
+            _ => RelocationSize::DWord.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let mask = self.op_mask();
+        let packed = self.encode(value)?;
+
+        match self {
+            Self::PCADDU12I => {
+                // the second instruction receives the sign-extended remainder in its 12-bit immediate
+                let low = (value as u32) & 0xFFF;
+                let template = LittleEndian::read_u32(&buf[4..]) & 0xFFC0_03FF;
+                LittleEndian::write_u32(&mut buf[4..], template | low << 10);
+            },
+            Self::PCADDU18I => {
+                // the jirl receives the sign-extended remainder in its 16-bit immediate
+                let low = ((value >> 2) as u32) & 0xFFFF;
+                let template = LittleEndian::read_u32(&buf[4..]) & 0xFC00_03FF;
+                LittleEndian::write_u32(&mut buf[4..], template | low << 10);
+            },
+            _ => ()
+        }
+
+        let template = LittleEndian::read_u32(buf) & mask;
+        LittleEndian::write_u32(buf, template | packed);
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        if let Self::Plain(s) = self {
+            return s.read_value(buf);
+        };
+
+        let value = LittleEndian::read_u32(buf);
+
+        let unpacked = match self {
+            Self::B16 => u64::from(
+                ((value >> 10) & 0xFFFF) << 2
+            ),
+            Self::B21 => u64::from(
+                ((value >> 10) & 0xFFFF) << 2 |
+                (value & 0x1F) << 18
+            ),
+            Self::B26 => u64::from(
+                ((value >> 10) & 0xFFFF) << 2 |
+                (value & 0x3FF) << 18
+            ),
+            Self::PCADDI => u64::from(
+                ((value >> 5) & 0xF_FFFF) << 2
+            ),
+            Self::PCADDU12I => {
+                let high = i64::from(((value << 7) as i32) >> 12 << 12);
+                let low = i64::from(((LittleEndian::read_u32(&buf[4..]) << 10) as i32) >> 20);
+                return (high + low) as isize;
+            },
+            Self::PCADDU18I => {
+                let high = i64::from(((value << 7) as i32) >> 12) << 18;
+                let low = i64::from(((LittleEndian::read_u32(&buf[4..]) << 6) as i32) >> 16) << 2;
+                return (high + low) as isize;
+            },
+            Self::Plain(_) => unreachable!()
+        };
+
+        // Sign extend.
+        let bits = match self {
+            Self::B16 => 18,
+            Self::B21 => 23,
+            Self::B26 => 28,
+            Self::PCADDI => 22,
+            Self::PCADDU12I |
+            Self::PCADDU18I |
+            Self::Plain(_) => unreachable!()
+        };
+        let offset = 1u64 << (bits - 1);
+        let value: u64 = (unpacked ^ offset).wrapping_sub(offset);
+
+        value as i64 as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for Loongarch64Relocation {
+    fn to_bytes(&self) -> [u8; 4] {
+        match self {
+            Self::B16 => [0, 0, 0, 0],
+            Self::B21 => [1, 0, 0, 0],
+            Self::B26 => [2, 0, 0, 0],
+            Self::PCADDI => [3, 0, 0, 0],
+            Self::PCADDU12I => [4, 0, 0, 0],
+            Self::PCADDU18I => [5, 0, 0, 0],
+            Self::Plain(size) => [6, *size as u8, 0, 0],
+        }
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(match bytes[0] {
+            0 => Self::B16,
+            1 => Self::B21,
+            2 => Self::B26,
+            3 => Self::PCADDI,
+            4 => Self::PCADDU12I,
+            5 => Self::PCADDU18I,
+            6 => Self::Plain(size_from_byte(bytes[1])?),
+            _ => return None
+        })
+    }
+}
+
+registers!(
+    /// A general purpose register, as used by the `R` family. The ABI names are available as associated constants.
+    R {
+        R0 = 0, R1 = 1, R2 = 2, R3 = 3, R4 = 4, R5 = 5, R6 = 6, R7 = 7,
+        R8 = 8, R9 = 9, R10 = 10, R11 = 11, R12 = 12, R13 = 13, R14 = 14, R15 = 15,
+        R16 = 16, R17 = 17, R18 = 18, R19 = 19, R20 = 20, R21 = 21, R22 = 22, R23 = 23,
+        R24 = 24, R25 = 25, R26 = 26, R27 = 27, R28 = 28, R29 = 29, R30 = 30, R31 = 31,
+    }
+);
+
+#[allow(missing_docs)]
+impl R {
+    pub const ZERO: R = R::R0;
+    pub const RA: R = R::R1;
+    pub const TP: R = R::R2;
+    pub const SP: R = R::R3;
+    pub const A0: R = R::R4;
+    pub const A1: R = R::R5;
+    pub const A2: R = R::R6;
+    pub const A3: R = R::R7;
+    pub const A4: R = R::R8;
+    pub const A5: R = R::R9;
+    pub const A6: R = R::R10;
+    pub const A7: R = R::R11;
+    pub const T0: R = R::R12;
+    pub const T1: R = R::R13;
+    pub const T2: R = R::R14;
+    pub const T3: R = R::R15;
+    pub const T4: R = R::R16;
+    pub const T5: R = R::R17;
+    pub const T6: R = R::R18;
+    pub const T7: R = R::R19;
+    pub const T8: R = R::R20;
+    pub const FP: R = R::R22;
+    pub const S9: R = R::R22;
+    pub const S0: R = R::R23;
+    pub const S1: R = R::R24;
+    pub const S2: R = R::R25;
+    pub const S3: R = R::R26;
+    pub const S4: R = R::R27;
+    pub const S5: R = R::R28;
+    pub const S6: R = R::R29;
+    pub const S7: R = R::R30;
+    pub const S8: R = R::R31;
+}
+
+registers!(
+    /// A floating point register, as used by the `F` family. The ABI names are available as associated constants.
+    F {
+        F0 = 0, F1 = 1, F2 = 2, F3 = 3, F4 = 4, F5 = 5, F6 = 6, F7 = 7,
+        F8 = 8, F9 = 9, F10 = 10, F11 = 11, F12 = 12, F13 = 13, F14 = 14, F15 = 15,
+        F16 = 16, F17 = 17, F18 = 18, F19 = 19, F20 = 20, F21 = 21, F22 = 22, F23 = 23,
+        F24 = 24, F25 = 25, F26 = 26, F27 = 27, F28 = 28, F29 = 29, F30 = 30, F31 = 31,
+    }
+);
+
+#[allow(missing_docs)]
+impl F {
+    pub const FA0: F = F::F0;
+    pub const FA1: F = F::F1;
+    pub const FA2: F = F::F2;
+    pub const FA3: F = F::F3;
+    pub const FA4: F = F::F4;
+    pub const FA5: F = F::F5;
+    pub const FA6: F = F::F6;
+    pub const FA7: F = F::F7;
+    pub const FT0: F = F::F8;
+    pub const FT1: F = F::F9;
+    pub const FT2: F = F::F10;
+    pub const FT3: F = F::F11;
+    pub const FT4: F = F::F12;
+    pub const FT5: F = F::F13;
+    pub const FT6: F = F::F14;
+    pub const FT7: F = F::F15;
+    pub const FT8: F = F::F16;
+    pub const FT9: F = F::F17;
+    pub const FT10: F = F::F18;
+    pub const FT11: F = F::F19;
+    pub const FT12: F = F::F20;
+    pub const FT13: F = F::F21;
+    pub const FT14: F = F::F22;
+    pub const FT15: F = F::F23;
+    pub const FS0: F = F::F24;
+    pub const FS1: F = F::F25;
+    pub const FS2: F = F::F26;
+    pub const FS3: F = F::F27;
+    pub const FS4: F = F::F28;
+    pub const FS5: F = F::F29;
+    pub const FS6: F = F::F30;
+    pub const FS7: F = F::F31;
+}
+
+registers!(
+    /// A 128-bit LSX vector register, as used by the `VR` family.
+    VR {
+        VR0 = 0, VR1 = 1, VR2 = 2, VR3 = 3, VR4 = 4, VR5 = 5, VR6 = 6, VR7 = 7,
+        VR8 = 8, VR9 = 9, VR10 = 10, VR11 = 11, VR12 = 12, VR13 = 13, VR14 = 14, VR15 = 15,
+        VR16 = 16, VR17 = 17, VR18 = 18, VR19 = 19, VR20 = 20, VR21 = 21, VR22 = 22, VR23 = 23,
+        VR24 = 24, VR25 = 25, VR26 = 26, VR27 = 27, VR28 = 28, VR29 = 29, VR30 = 30, VR31 = 31,
+    }
+);
+
+registers!(
+    /// A 256-bit LASX vector register, as used by the `XR` family.
+    XR {
+        XR0 = 0, XR1 = 1, XR2 = 2, XR3 = 3, XR4 = 4, XR5 = 5, XR6 = 6, XR7 = 7,
+        XR8 = 8, XR9 = 9, XR10 = 10, XR11 = 11, XR12 = 12, XR13 = 13, XR14 = 14, XR15 = 15,
+        XR16 = 16, XR17 = 17, XR18 = 18, XR19 = 19, XR20 = 20, XR21 = 21, XR22 = 22, XR23 = 23,
+        XR24 = 24, XR25 = 25, XR26 = 26, XR27 = 27, XR28 = 28, XR29 = 29, XR30 = 30, XR31 = 31,
+    }
+);
+
+/// The encoding of `break 0`, which traps when executed. Suitable as `Config::poison` pattern.
+pub const BREAK: &[u8] = &[0x00, 0x00, 0x2A, 0x00];
+
+
+/// A loongarch64 Assembler.
+#[cfg(feature = "std")]
+pub type Assembler = crate::Assembler<Loongarch64Relocation>;
+/// A loongarch64 AssemblyModifier.
+#[cfg(feature = "std")]
+pub type AssemblyModifier<'a> = crate::Modifier<'a, Loongarch64Relocation>;
+/// A loongarch64 UncommittedModifier.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
@@ -0,0 +1,80 @@
+//! This module defines the interface between `Assembler` and the memory it assembles code into. By default,
+//! memory is allocated using `mmap` as `ExecutableBuffer` / `MutableBuffer` pairs, but any other backend,
+//! like pre-allocated executable regions handed out by a sandbox, or plain heap memory for testing, can be used
+//! by implementing `CodeMemory` and `WritableMemory` for it.
+//!
+//! A region of code memory is always in one of two states: executable, represented by a `CodeMemory`, or
+//! writable, represented by its `CodeMemory::Writable` type. A backend should never allow both at the same time.
+//! Both states track the length of the region that has been written to, which is what they deref to.
+
+use std::io;
+use std::ops::{Deref, DerefMut};
+
+use crate::mmap::Config;
+
+
+/// A region of executable memory that an `Assembler` can assemble into.
+pub trait CodeMemory: Deref<Target = [u8]> + Default + Sized {
+    /// The writable state of this memory.
+    type Writable: WritableMemory<Executable = Self>;
+
+    /// Allocate a new, writable region with room for at least `size` bytes and a length of 0.
+    /// `config` describes how the assembler would like its memory to be allocated and protected,
+    /// backends should ignore any options they do not support.
+    fn allocate(size: usize, config: &Config) -> io::Result<Self::Writable>;
+
+    /// Make this region writable.
+    fn make_writable(self) -> io::Result<Self::Writable>;
+
+    /// Replace this region with a writable region with room for at least `size` bytes, containing the data
+    /// of this region. The new region may be located at a different address. By default, this allocates
+    /// a new region and copies the data over.
+    fn grow(self, size: usize, config: &Config) -> io::Result<Self::Writable> {
+        let mut new = Self::allocate(size, config)?;
+        new.set_len(self.len());
+        new.copy_from_slice(&self);
+        Ok(new)
+    }
+
+    /// The address at which the code in this region is executed. By default, this is the address of the data itself.
+    fn address(&self) -> usize {
+        self.as_ptr() as usize
+    }
+
+    /// The amount of bytes this region can hold.
+    fn size(&self) -> usize;
+
+    /// Write `data` directly after the code in this region and extend its length to include it, without making the
+    /// code already in it writable again. This is used by assemblers created with `Config::strict` set. `data` always
+    /// fits in the region. By default, this returns an error of kind `Unsupported`, in which case the assembler copies
+    /// its code into a newly allocated region instead.
+    fn append(&mut self, _data: &[u8]) -> io::Result<()> {
+        Err(io::Error::new(io::ErrorKind::Unsupported, "appending is not supported by this memory"))
+    }
+
+    /// Prevent this region from being made writable again. By default, this is unsupported and returns an error.
+    fn seal(&mut self) -> io::Result<()> {
+        Err(io::Error::other("sealing is not supported by this memory"))
+    }
+}
+
+/// A region of writable memory, which can be made executable again.
+pub trait WritableMemory: DerefMut<Target = [u8]> + Sized {
+    /// The executable state of this memory.
+    type Executable: CodeMemory<Writable = Self>;
+
+    /// Make this region executable.
+    fn make_executable(self) -> io::Result<Self::Executable>;
+
+    /// Set the length of the part of this region that has been written to. It should never be set larger
+    /// than the size of the region.
+    fn set_len(&mut self, length: usize);
+
+    /// The address at which the code in this region will be executed. By default, this is the address of the data itself.
+    fn address(&self) -> usize {
+        self.as_ptr() as usize
+    }
+
+    /// The amount of bytes this region can hold.
+    fn size(&self) -> usize;
+}
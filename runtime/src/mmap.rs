@@ -2,11 +2,91 @@
 // Unfortunately Memmap itself doesn't support a cheap zero-length variant
 
 use std::ops::{Deref, DerefMut};
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::mem;
+use std::ptr;
 use std::io;
 
 use memmap::{Mmap, MmapMut};
 
 use crate::AssemblyOffset;
+use crate::memory::{CodeMemory, WritableMemory};
+
+/// Options controlling how executable memory is allocated and protected.
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    /// Surround every mapping with inaccessible guard pages, so running off either end of the code faults.
+    /// This is only supported on unix platforms.
+    pub guard_pages: bool,
+    /// Seal the buffer returned when finalizing an assembler. See `ExecutableBuffer::seal`.
+    pub seal: bool,
+    /// Never make committed code writable again, by disallowing `Assembler::alter` and `Assembler::patch_atomic`,
+    /// and by writing newly committed code to fresh pages that are mapped over the end of the buffer, instead of
+    /// making the buffer writable. Where that isn't supported, like on platforms other than Linux or with memory
+    /// backends that don't implement `CodeMemory::append`, code is copied into a new buffer on every commit instead.
+    pub strict: bool,
+    /// Fill unused memory with this byte pattern instead of zeroes, like `x64::INT3`, `aarch64::BRK`, `riscv64::EBREAK`, `loongarch64::BREAK` or `arm::UDF`,
+    /// so any stray jumps into it trap. The pattern cannot be empty.
+    pub poison: Option<&'static [u8]>,
+    /// Back memory with transparent huge pages, to reduce instruction TLB misses for large amounts of code.
+    /// Allocations are rounded up to and aligned on 2MB boundaries, and the kernel is advised to use huge pages for them.
+    /// This is only supported on Linux, and silently falls back to normal pages if huge pages are unavailable.
+    pub huge_pages: bool,
+    /// How the memory of an assembler grows when more space is needed.
+    pub growth: GrowthPolicy,
+}
+
+impl Config {
+    // checks that the options can be used to allocate memory
+    pub(crate) fn validate(&self) -> io::Result<()> {
+        if self.poison == Some(&[]) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the poison pattern cannot be empty"));
+        }
+        Ok(())
+    }
+}
+
+/// Determines how much memory a `MemoryManager` allocates when its code no longer fits in its current buffer.
+/// Every time the buffer grows, the code is copied into a new buffer and relocated, so growing in larger steps
+/// is cheaper when large amounts of code are assembled.
+#[derive(Debug, Clone, Copy)]
+pub enum GrowthPolicy {
+    /// Double the size of the buffer until the code fits.
+    Double,
+    /// Grow the buffer by this many bytes at a time until the code fits.
+    Linear(usize),
+    /// Call this function with the current size of the buffer and the amount of bytes required,
+    /// returning the new size of the buffer.
+    Custom(fn(usize, usize) -> usize),
+}
+
+impl GrowthPolicy {
+    /// Returns the size a buffer of size `current` should grow to to be able to hold more than `required` bytes.
+    pub fn grow(self, current: usize, required: usize) -> usize {
+        let mut size = current;
+        match self {
+            GrowthPolicy::Double => while size <= required {
+                size = (size * 2).max(1);
+            },
+            GrowthPolicy::Linear(step) => while size <= required {
+                size += step.max(1);
+            },
+            GrowthPolicy::Custom(f) => size = f(current, required)
+        }
+        size.max(required + 1)
+    }
+}
+
+impl Default for GrowthPolicy {
+    fn default() -> GrowthPolicy {
+        GrowthPolicy::Double
+    }
+}
+
+/// The size of the huge pages used when `Config::huge_pages` is set.
+pub const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
 
 /// A structure holding a buffer of executable memory. It also derefs to a `&[u8]`.
 /// This structure does not allocate when its size is 0.
@@ -15,7 +95,18 @@ pub struct ExecutableBuffer {
     // length of the buffer that has actually been written to
     length: usize,
     // backing buffer
-    buffer: Option<Mmap>
+    buffer: Option<Mmap>,
+    // pointer to the start of the backing buffer, obtained while it was still writable. Atomic patches are
+    // written through this pointer, as writing through the shared slice the buffer derefs to is not allowed.
+    base: *mut u8,
+    // offset of the usable region in the backing buffer
+    start: usize,
+    // size of the usable region in the backing buffer
+    size: usize,
+    // if this buffer may no longer be made writable
+    sealed: bool,
+    // if the backing buffer was sealed by the kernel, which means it can never be unmapped
+    kernel_sealed: bool,
 }
 
 /// ExecutableBuffer equivalent that holds a buffer of mutable memory instead of executable memory. It also derefs to a `&mut [u8]`.
@@ -25,9 +116,19 @@ pub struct MutableBuffer {
     // length of the buffer that has actually been written to
     length: usize,
     // backing buffer
-    buffer: Option<MmapMut>
+    buffer: Option<MmapMut>,
+    // offset of the usable region in the backing buffer
+    start: usize,
+    // size of the usable region in the backing buffer
+    size: usize,
 }
 
+// Safety: the base pointer is only written through by `patch_atomic`, which synchronizes its writes. As these
+// writes alias the shared slices the buffer derefs to, `patch_atomic` is unsafe and requires that no such slices
+// are live while it is called, on any thread.
+unsafe impl Send for ExecutableBuffer {}
+unsafe impl Sync for ExecutableBuffer {}
+
 impl ExecutableBuffer {
     /// Obtain a pointer into the executable memory from an offset into it.
     /// When an offset returned from `DynasmLabelApi::offset` is used, the resulting pointer
@@ -38,63 +139,229 @@ impl ExecutableBuffer {
     /// The assembler is free to relocate the executable buffer when it requires
     /// more memory than available.
     pub fn ptr(&self, offset: AssemblyOffset) -> *const u8 {
-        &self[offset.0] as *const u8
+        // this doesn't go through `Deref`, so pointers can be obtained while `patch_atomic` is writing
+        assert!(offset.0 < self.length, "offset {:#x} lies outside of the buffer", offset.0);
+        // Safety: the offset lies within the mapping, as checked above.
+        unsafe { self.base.add(self.start + offset.0) }
+    }
+
+    /// Obtain a typed function handle to the code at `offset`. `F` has to be a function pointer type
+    /// like `extern "C" fn(i32) -> i32`. The returned handle borrows this buffer, so it cannot outlive it.
+    /// When the buffer is accessed through an `Executor`, this means the handle can only be used while
+    /// the lock is held, which prevents calling into a buffer after the assembler moved it.
+    ///
+    /// # Safety
+    /// The code at `offset` has to be a function with the signature and calling convention described by `F`.
+    pub unsafe fn func<F: Copy>(&self, offset: AssemblyOffset) -> Func<'_, F> {
+        #[allow(clippy::let_unit_value)]
+        let () = PointerSized::<F>::CHECK;
+        let ptr = self.ptr(offset);
+
+        Func {
+            func: mem::transmute_copy(&ptr),
+            buffer: PhantomData
+        }
+    }
+
+    /// Atomically overwrite the `[u8; 4]` or `[u8; 8]` at `offset` with `value` while other threads may be
+    /// executing code in this buffer, i.e. through an `Executor`. This is intended for patching inline caches and
+    /// call targets. `offset` has to be aligned to the size of the patch, and the patch has to lie within the buffer.
+    ///
+    /// The page containing the patch is temporarily mapped readable, writable and executable, after which `value` is
+    /// written with a single atomic store. Any instruction cache maintenance the architecture requires is performed
+    /// afterwards, and on Linux all other threads are serialized through `membarrier` so they can't continue executing
+    /// stale instructions. Other threads will either execute the old or the new code, never a mixture of both.
+    /// On x64 an aligned 8-byte patch can replace any instruction contained within it, on aarch64 a patch should
+    /// consist of whole 4-byte instructions, and only a single instruction is replaced atomically.
+    ///
+    /// This returns an error if the buffer has been sealed, or if the protection of the page could not be changed,
+    /// which can happen on systems that do not allow memory to be writable and executable at the same time.
+    /// On Linux, an error is also returned if the kernel does not support serializing other threads through `membarrier`.
+    /// If this happens before the patch was written, the buffer is left untouched. If the patch was written but
+    /// the page could not be made read-only again afterwards, the error is returned and the page is left readable,
+    /// writable and executable. Other threads are still serialized in that case.
+    ///
+    /// # Safety
+    /// No references into this buffer may be live while this is called, on any thread. In particular, this includes
+    /// the `&[u8]` this buffer derefs to. Pointers obtained through `ptr` and function handles obtained through `func`
+    /// can still be used to execute the code.
+    pub unsafe fn patch_atomic<T: AtomicPatch>(&self, offset: AssemblyOffset, value: T) -> io::Result<()> {
+        if self.sealed {
+            return Err(sealed_error());
+        }
+
+        let size = mem::size_of::<T>();
+        assert!(offset.0 & (size - 1) == 0, "atomic patch at offset {:#x} is not aligned to {} bytes", offset.0, size);
+        assert!(offset.0 + size <= self.length, "atomic patch at offset {:#x} lies outside of the buffer", offset.0);
+
+        // patches to the same page from different threads would race on the page protection
+        let _guard = PATCH_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        prepare_serialize_threads()?;
+
+        // Safety: the offset lies within the mapping, as checked above.
+        let ptr = self.base.add(self.start + offset.0);
+        let addr = ptr as usize;
+        let page = addr & !(page_size() - 1);
+
+        protect(page, true)?;
+        // Safety: the pointer is aligned and within the mapping, which is now writable. The caller guarantees
+        // that no references to it are live.
+        value.store(ptr);
+        flush_icache(addr, size);
+
+        // the patch is visible now, so other threads have to be serialized even if the page stays writable
+        let restored = protect(page, false);
+        let serialized = serialize_threads();
+        restored.and(serialized)
     }
 
     /// Create a new executable buffer, backed by a buffer of size `size`.
     /// It will start with an initialized length of 0.
     pub fn new(size: usize) -> io::Result<ExecutableBuffer> {
-        let buffer = if size == 0 {
-            None
-        } else {
-            Some(MmapMut::map_anon(size)?.make_exec()?)
-        };
+        ExecutableBuffer::with_config(size, &Config::default())
+    }
 
-        Ok(ExecutableBuffer {
-            length: 0,
-            buffer
-        })
+    /// Create a new executable buffer, backed by a buffer of size `size` allocated according to `config`.
+    /// It will start with an initialized length of 0.
+    pub fn with_config(size: usize, config: &Config) -> io::Result<ExecutableBuffer> {
+        MutableBuffer::with_config(size, config)?.make_exec()
     }
 
     /// Query the backing size of this executable buffer
     pub fn size(&self) -> usize {
-        self.buffer.as_ref().map(|b| b.len()).unwrap_or(0) as usize
+        self.size
+    }
+
+    /// Seal this buffer, which prevents it from being made writable again through `make_mut` or `patch_atomic`.
+    /// On Linux kernels that support `mseal`, the mapping is also sealed by the kernel, so its protection can
+    /// not be changed anymore at all. As the kernel will then refuse to unmap it, the mapping is leaked when
+    /// the buffer is dropped. If sealing fails, the buffer is left unsealed.
+    pub fn seal(&mut self) -> io::Result<()> {
+        if let Some(map) = &self.buffer {
+            if kernel_seal(map.as_ptr() as usize, map.len())? {
+                self.kernel_sealed = true;
+            }
+        }
+        self.sealed = true;
+        Ok(())
+    }
+
+    /// Write `data` directly after the code in this buffer, without making that code writable again. The pages the
+    /// new code ends up in are prepared in a fresh mapping, which is made executable and then moved over the old pages
+    /// at once, so code sharing a page with the new code never changes while it could be executing.
+    /// This is only supported on Linux, and fails if the buffer has been sealed or if `data` does not fit.
+    pub fn append(&mut self, data: &[u8]) -> io::Result<()> {
+        if self.sealed {
+            return Err(sealed_error());
+        }
+        if data.len() > self.size - self.length {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "the data does not fit in the buffer"));
+        }
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        // the usable region starts on a page boundary and is a whole number of pages long
+        let from = self.length & !(page_size() - 1);
+        let to = round_up(self.length + data.len(), page_size());
+        let written = self.length - from;
+        // Safety: the pages lie within the usable region.
+        let addr = unsafe { self.base.add(self.start + from) } as usize;
+
+        replace_pages(addr, to - from, |pages| {
+            pages[written .. written + data.len()].copy_from_slice(data);
+        })?;
+
+        self.length += data.len();
+        Ok(())
+    }
+
+    /// Returns if this buffer has been sealed.
+    pub fn is_sealed(&self) -> bool {
+        self.sealed
     }
 
     /// Change this executable buffer into a mutable buffer.
-    pub fn make_mut(self) -> io::Result<MutableBuffer> {
-        let buffer = if let Some(map) = self.buffer {
-            Some(map.make_mut()?)
+    /// This fails if the buffer has been sealed.
+    pub fn make_mut(mut self) -> io::Result<MutableBuffer> {
+        if self.sealed {
+            return Err(sealed_error());
+        }
+
+        let buffer = if let Some(map) = self.buffer.take() {
+            let map = map.make_mut()?;
+            protect_unused(map.as_ptr() as usize, map.len(), self.start, self.size)?;
+            Some(map)
         } else {
             None
         };
 
         Ok(MutableBuffer {
             length: self.length,
-            buffer
+            buffer,
+            start: self.start,
+            size: self.size
         })
     }
 }
 
+impl Drop for ExecutableBuffer {
+    fn drop(&mut self) {
+        // the kernel refuses to unmap sealed mappings
+        if self.kernel_sealed {
+            mem::forget(self.buffer.take());
+        }
+    }
+}
+
 impl MutableBuffer {
     /// Create a new mutable buffer, backed by a buffer of size `size`.
     /// It will start with an initialized length of 0.
     pub fn new(size: usize) -> io::Result<MutableBuffer> {
-        let buffer = if size == 0 {
-            None
+        MutableBuffer::with_config(size, &Config::default())
+    }
+
+    /// Create a new mutable buffer, backed by a buffer of size `size` allocated according to `config`.
+    /// It will start with an initialized length of 0.
+    pub fn with_config(size: usize, config: &Config) -> io::Result<MutableBuffer> {
+        config.validate()?;
+        if size == 0 {
+            return Ok(MutableBuffer::default());
+        }
+
+        let guard = if config.guard_pages && cfg!(unix) {
+            page_size()
         } else {
-            Some(MmapMut::map_anon(size)?)
+            0
         };
 
+        let (mut map, start, size) = match allocate_huge(size, guard, config) {
+            Some(allocation) => allocation,
+            None => {
+                let size = round_up(size, page_size());
+                (MmapMut::map_anon(size + 2 * guard)?, guard, size)
+            }
+        };
+        protect_unused(map.as_ptr() as usize, map.len(), start, size)?;
+
+        if let Some(pattern) = config.poison {
+            for (i, byte) in map[start .. start + size].iter_mut().enumerate() {
+                *byte = pattern[i % pattern.len()];
+            }
+        }
+
         Ok(MutableBuffer {
             length: 0,
-            buffer
+            buffer: Some(map),
+            start,
+            size
         })
     }
 
     /// Query the backing size of this mutable buffer
     pub fn size(&self) -> usize {
-        self.buffer.as_ref().map(|b| b.len()).unwrap_or(0) as usize
+        self.size
     }
 
     /// Set the length of the usable part of this mutable buffer. The length
@@ -105,24 +372,456 @@ impl MutableBuffer {
 
     /// Change this mutable buffer into an executable buffer.
     pub fn make_exec(self) -> io::Result<ExecutableBuffer> {
-        let buffer = if let Some(map) = self.buffer {
-            Some(map.make_exec()?)
+        let mut base = ptr::null_mut();
+        let buffer = if let Some(mut map) = self.buffer {
+            base = map.as_mut_ptr();
+            let map = map.make_exec()?;
+            protect_unused(map.as_ptr() as usize, map.len(), self.start, self.size)?;
+            Some(map)
         } else {
             None
         };
 
         Ok(ExecutableBuffer {
             length: self.length,
-            buffer
+            buffer,
+            base,
+            start: self.start,
+            size: self.size,
+            sealed: false,
+            kernel_sealed: false
         })
     }
 }
 
+impl CodeMemory for ExecutableBuffer {
+    type Writable = MutableBuffer;
+
+    fn allocate(size: usize, config: &Config) -> io::Result<MutableBuffer> {
+        MutableBuffer::with_config(size, config)
+    }
+
+    fn make_writable(self) -> io::Result<MutableBuffer> {
+        self.make_mut()
+    }
+
+    fn size(&self) -> usize {
+        ExecutableBuffer::size(self)
+    }
+
+    fn seal(&mut self) -> io::Result<()> {
+        ExecutableBuffer::seal(self)
+    }
+
+    fn append(&mut self, data: &[u8]) -> io::Result<()> {
+        ExecutableBuffer::append(self, data)
+    }
+}
+
+impl WritableMemory for MutableBuffer {
+    type Executable = ExecutableBuffer;
+
+    fn make_executable(self) -> io::Result<ExecutableBuffer> {
+        self.make_exec()
+    }
+
+    fn set_len(&mut self, length: usize) {
+        MutableBuffer::set_len(self, length)
+    }
+
+    fn size(&self) -> usize {
+        MutableBuffer::size(self)
+    }
+}
+
+// Checks at compile time that the type of a `Func` can hold a pointer, as a function pointer does.
+struct PointerSized<F>(PhantomData<F>);
+
+impl<F> PointerSized<F> {
+    const CHECK: () = assert!(mem::size_of::<F>() == mem::size_of::<*const u8>(), "Func types have to be function pointers");
+}
+
+/// A typed function pointer into an `ExecutableBuffer`, as returned by `ExecutableBuffer::func`.
+/// It can only be called through `call` while the buffer it points into is borrowed.
+#[derive(Debug, Clone, Copy)]
+pub struct Func<'a, F: Copy> {
+    func: F,
+    buffer: PhantomData<&'a ExecutableBuffer>
+}
+
+impl<'a, F: FuncPtr> Func<'a, F> {
+    /// Call the function, with its arguments given as a tuple.
+    pub fn call(&self, args: F::Args) -> F::Output {
+        self.func.call_with(args)
+    }
+}
+
+impl<'a, F: Copy> Func<'a, F> {
+    /// Returns the bare function pointer. This can be used to call functions whose type does not
+    /// implement `FuncPtr`, like function pointers with higher-ranked lifetimes.
+    ///
+    /// # Safety
+    /// The returned pointer is not tied to the lifetime of the buffer. It must not be called after the buffer
+    /// has been dropped, or after the assembler owning the buffer moved it.
+    pub unsafe fn as_ptr(&self) -> F {
+        self.func
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// A function pointer type that a `Func` can be called as. It is implemented for `extern "C"` and
+/// `extern "system"` function pointers with up to eight arguments, and on x64 for `extern "sysv64"` and
+/// `extern "win64"` function pointers as well.
+pub trait FuncPtr: Copy + private::Sealed {
+    /// The arguments of the function, as a tuple.
+    type Args;
+    /// The return type of the function.
+    type Output;
+
+    #[doc(hidden)]
+    fn call_with(self, args: Self::Args) -> Self::Output;
+}
+
+macro_rules! impl_func_ptr {
+    ($abi:literal; $($arg:ident),*) => {
+        impl<R, $($arg),*> private::Sealed for extern $abi fn($($arg),*) -> R {}
+
+        impl<R, $($arg),*> FuncPtr for extern $abi fn($($arg),*) -> R {
+            type Args = ($($arg,)*);
+            type Output = R;
+
+            #[allow(non_snake_case)]
+            fn call_with(self, ($($arg,)*): Self::Args) -> R {
+                self($($arg),*)
+            }
+        }
+    }
+}
+
+macro_rules! impl_func_ptrs {
+    ($abi:literal) => {
+        impl_func_ptr!($abi;);
+        impl_func_ptr!($abi; A);
+        impl_func_ptr!($abi; A, B);
+        impl_func_ptr!($abi; A, B, C);
+        impl_func_ptr!($abi; A, B, C, D);
+        impl_func_ptr!($abi; A, B, C, D, E);
+        impl_func_ptr!($abi; A, B, C, D, E, F);
+        impl_func_ptr!($abi; A, B, C, D, E, F, G);
+        impl_func_ptr!($abi; A, B, C, D, E, F, G, H);
+    }
+}
+
+impl_func_ptrs!("C");
+impl_func_ptrs!("system");
+#[cfg(target_arch = "x86_64")]
+impl_func_ptrs!("sysv64");
+#[cfg(target_arch = "x86_64")]
+impl_func_ptrs!("win64");
+
+/// A value that can be written atomically into executable memory by `ExecutableBuffer::patch_atomic`.
+pub trait AtomicPatch: Copy {
+    /// Atomically store this value at `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` has to be valid for writes and aligned to the size of `Self`.
+    unsafe fn store(self, ptr: *mut u8);
+}
+
+impl AtomicPatch for [u8; 4] {
+    unsafe fn store(self, ptr: *mut u8) {
+        (*(ptr as *const AtomicU32)).store(u32::from_ne_bytes(self), Ordering::SeqCst);
+    }
+}
+
+impl AtomicPatch for [u8; 8] {
+    unsafe fn store(self, ptr: *mut u8) {
+        (*(ptr as *const AtomicU64)).store(u64::from_ne_bytes(self), Ordering::SeqCst);
+    }
+}
+
+// serializes the page protection changes made by atomic patches
+static PATCH_LOCK: Mutex<()> = Mutex::new(());
+
+/// Returns the size of the memory pages of the system.
+#[cfg(unix)]
+pub fn page_size() -> usize {
+    use std::sync::atomic::AtomicUsize;
+
+    // sysconf isn't free, so cache the result
+    static PAGE_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+    let mut size = PAGE_SIZE.load(Ordering::Relaxed);
+    if size == 0 {
+        size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) as usize };
+        PAGE_SIZE.store(size, Ordering::Relaxed);
+    }
+    size
+}
+
+/// Returns the size of the memory pages of the system.
+#[cfg(not(unix))]
+pub fn page_size() -> usize {
+    4096
+}
+
+#[cfg(unix)]
+fn protect(page: usize, writable: bool) -> io::Result<()> {
+    let mut prot = libc::PROT_READ | libc::PROT_EXEC;
+    if writable {
+        prot |= libc::PROT_WRITE;
+    }
+
+    if unsafe { libc::mprotect(page as *mut libc::c_void, page_size(), prot) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(not(unix))]
+fn protect(_page: usize, _writable: bool) -> io::Result<()> {
+    Err(io::Error::other("atomic patching is not supported on this platform"))
+}
+
+fn sealed_error() -> io::Error {
+    io::Error::new(io::ErrorKind::PermissionDenied, "the executable buffer has been sealed")
+}
+
+fn round_up(size: usize, align: usize) -> usize {
+    (size + align - 1) & !(align - 1)
+}
+
+// make the parts of a mapping outside of its usable region inaccessible, like guard pages
+#[cfg(unix)]
+fn protect_unused(addr: usize, len: usize, start: usize, size: usize) -> io::Result<()> {
+    for &(from, to) in &[(0, start), (start + size, len)] {
+        if from == to {
+            continue;
+        }
+
+        if unsafe { libc::mprotect((addr + from) as *mut libc::c_void, to - from, libc::PROT_NONE) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn protect_unused(_addr: usize, _len: usize, _start: usize, _size: usize) -> io::Result<()> {
+    Ok(())
+}
+
+// Allocate a mapping with a usable region of at least `size` bytes aligned to a huge page, surrounded by `guard` bytes
+// of guard pages. Returns the mapping and the offset and size of the usable region in it, or None if huge pages
+// were not requested or could not be allocated.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn allocate_huge(size: usize, guard: usize, config: &Config) -> Option<(MmapMut, usize, usize)> {
+    if !config.huge_pages {
+        return None;
+    }
+
+    // over-allocate so there is a huge page boundary to align to
+    let size = round_up(size, HUGE_PAGE_SIZE);
+    let map = MmapMut::map_anon(size + HUGE_PAGE_SIZE + 2 * guard).ok()?;
+    let addr = map.as_ptr() as usize;
+    let start = round_up(addr + guard, HUGE_PAGE_SIZE) - addr;
+
+    // the kernel ignores this if transparent huge pages are disabled, in which case normal pages are used
+    unsafe {
+        libc::madvise((addr + start) as *mut libc::c_void, size, libc::MADV_HUGEPAGE);
+    }
+
+    Some((map, start, size))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn allocate_huge(_size: usize, _guard: usize, _config: &Config) -> Option<(MmapMut, usize, usize)> {
+    None
+}
+
+// Replace the `len` bytes of executable pages at `addr` by a fresh mapping holding the same data, after `fill` made
+// changes to it. The new pages are only made executable and moved into place once they are complete.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn replace_pages<F: FnOnce(&mut [u8])>(addr: usize, len: usize, fill: F) -> io::Result<()> {
+    use std::slice;
+
+    unsafe {
+        let pages = libc::mmap(ptr::null_mut(), len, libc::PROT_READ | libc::PROT_WRITE, libc::MAP_PRIVATE | libc::MAP_ANONYMOUS, -1, 0);
+        if pages == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+
+        let data = slice::from_raw_parts_mut(pages as *mut u8, len);
+        data.copy_from_slice(slice::from_raw_parts(addr as *const u8, len));
+        fill(data);
+
+        if libc::mprotect(pages, len, libc::PROT_READ | libc::PROT_EXEC) != 0
+            || libc::mremap(pages, len, len, libc::MREMAP_MAYMOVE | libc::MREMAP_FIXED, addr) == libc::MAP_FAILED {
+            let error = io::Error::last_os_error();
+            libc::munmap(pages, len);
+            return Err(error);
+        }
+    }
+
+    flush_icache(addr, len);
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn replace_pages<F: FnOnce(&mut [u8])>(_addr: usize, _len: usize, _fill: F) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "replacing executable pages is not supported on this platform"))
+}
+
+// Seal a mapping using mseal. Returns false if the kernel doesn't support it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn kernel_seal(addr: usize, len: usize) -> io::Result<bool> {
+    const SYS_MSEAL: libc::c_long = 462;
+
+    if unsafe { libc::syscall(SYS_MSEAL, addr, len, 0) } == 0 {
+        return Ok(true);
+    }
+
+    let error = io::Error::last_os_error();
+    match error.raw_os_error() {
+        Some(libc::ENOSYS) | Some(libc::EPERM) => Ok(false),
+        _ => Err(error)
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn kernel_seal(_addr: usize, _len: usize) -> io::Result<bool> {
+    Ok(false)
+}
+
+// make the data written at `addr` visible to instruction fetches of the current core
+#[cfg(target_arch = "aarch64")]
+fn flush_icache(addr: usize, size: usize) {
+    use std::arch::asm;
+
+    let ctr_el0: usize;
+    unsafe { asm!("mrs {}, ctr_el0", out(reg) ctr_el0, options(nomem, nostack)); }
+    let dline = 4 << ((ctr_el0 >> 16) & 0xF);
+    let iline = 4 << (ctr_el0 & 0xF);
+
+    unsafe {
+        let mut line = addr & !(dline - 1);
+        while line < addr + size {
+            asm!("dc cvau, {}", in(reg) line, options(nostack));
+            line += dline;
+        }
+        asm!("dsb ish", options(nostack));
+
+        let mut line = addr & !(iline - 1);
+        while line < addr + size {
+            asm!("ic ivau, {}", in(reg) line, options(nostack));
+            line += iline;
+        }
+        asm!("dsb ish", "isb", options(nostack));
+    }
+}
+
+// riscv64 only guarantees coherence after a fence.i on every hart that might execute the code,
+// which the kernel takes care of
+#[cfg(all(target_arch = "riscv64", any(target_os = "linux", target_os = "android")))]
+fn flush_icache(addr: usize, size: usize) {
+    const SYS_RISCV_FLUSH_ICACHE: libc::c_long = 259;
+
+    unsafe {
+        libc::syscall(SYS_RISCV_FLUSH_ICACHE, addr, addr + size, 0usize);
+    }
+}
+
+// loongarch64 keeps the instruction cache coherent with data stores, but this core still has to
+// discard any instructions it already fetched
+#[cfg(target_arch = "loongarch64")]
+fn flush_icache(_addr: usize, _size: usize) {
+    use std::arch::asm;
+
+    unsafe {
+        asm!("ibar 0", options(nostack));
+    }
+}
+
+// 32-bit arm cannot maintain its caches from user mode, so this needs to go through the kernel
+#[cfg(all(target_arch = "arm", any(target_os = "linux", target_os = "android")))]
+fn flush_icache(addr: usize, size: usize) {
+    const ARM_NR_CACHEFLUSH: libc::c_long = 0x0F_0002;
+
+    unsafe {
+        libc::syscall(ARM_NR_CACHEFLUSH, addr, addr + size, 0usize);
+    }
+}
+
+// x86 keeps the instruction cache coherent by itself
+#[cfg(not(any(
+    target_arch = "aarch64",
+    target_arch = "loongarch64",
+    all(any(target_arch = "riscv64", target_arch = "arm"), any(target_os = "linux", target_os = "android"))
+)))]
+fn flush_icache(_addr: usize, _size: usize) {}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const MEMBARRIER_CMD_PRIVATE_EXPEDITED_SYNC_CORE: libc::c_long = 1 << 5;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+const MEMBARRIER_CMD_REGISTER_PRIVATE_EXPEDITED_SYNC_CORE: libc::c_long = 1 << 6;
+
+// Register this process for serializing its threads with `serialize_threads`. This has to succeed before a patch
+// is written, as the patch could not be made safely visible to other threads otherwise.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn prepare_serialize_threads() -> io::Result<()> {
+    use std::sync::Once;
+    use std::sync::atomic::AtomicI32;
+
+    // registration only has to happen once, but its outcome has to be reported to every caller
+    static REGISTER: Once = Once::new();
+    static REGISTER_ERROR: AtomicI32 = AtomicI32::new(0);
+    REGISTER.call_once(|| {
+        if unsafe { libc::syscall(libc::SYS_membarrier, MEMBARRIER_CMD_REGISTER_PRIVATE_EXPEDITED_SYNC_CORE, 0) } != 0 {
+            let errno = io::Error::last_os_error().raw_os_error().unwrap_or(libc::EINVAL);
+            REGISTER_ERROR.store(errno, Ordering::SeqCst);
+        }
+    });
+
+    match REGISTER_ERROR.load(Ordering::SeqCst) {
+        0 => Ok(()),
+        errno => Err(io::Error::from_raw_os_error(errno))
+    }
+}
+
+// Force every thread of this process to execute a serializing instruction, so none of them keeps executing
+// instructions that were fetched before the patch.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn serialize_threads() -> io::Result<()> {
+    if unsafe { libc::syscall(libc::SYS_membarrier, MEMBARRIER_CMD_PRIVATE_EXPEDITED_SYNC_CORE, 0) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn prepare_serialize_threads() -> io::Result<()> {
+    Ok(())
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn serialize_threads() -> io::Result<()> {
+    Ok(())
+}
+
 impl Default for ExecutableBuffer {
     fn default() -> ExecutableBuffer {
         ExecutableBuffer {
             length: 0,
-            buffer: None
+            buffer: None,
+            base: ptr::null_mut(),
+            start: 0,
+            size: 0,
+            sealed: false,
+            kernel_sealed: false
         }
     }
 }
@@ -131,7 +830,9 @@ impl Default for MutableBuffer {
     fn default() -> MutableBuffer {
         MutableBuffer {
             length: 0,
-            buffer: None
+            buffer: None,
+            start: 0,
+            size: 0
         }
     }
 }
@@ -140,7 +841,7 @@ impl Deref for ExecutableBuffer {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
         if let Some(map) = &self.buffer {
-            &map[..self.length]
+            &map[self.start .. self.start + self.length]
         } else {
             &[]
         }
@@ -151,7 +852,7 @@ impl Deref for MutableBuffer {
     type Target = [u8];
     fn deref(&self) -> &[u8] {
         if let Some(map) = &self.buffer {
-            &map[..self.length]
+            &map[self.start .. self.start + self.length]
         } else {
             &[]
         }
@@ -161,7 +862,7 @@ impl Deref for MutableBuffer {
 impl DerefMut for MutableBuffer {
     fn deref_mut(&mut self) -> &mut [u8] {
         if let Some(map) = &mut self.buffer {
-            &mut map[..self.length]
+            &mut map[self.start .. self.start + self.length]
         } else {
             &mut []
         }
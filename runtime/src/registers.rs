@@ -0,0 +1,112 @@
+//! This module contains the shared infrastructure for the typed register definitions
+//! of the different architectures, like `x64::Rq` and `aarch64::X`.
+
+use core::error;
+use core::fmt;
+
+/// A value that can be used to choose a register of family `F` at runtime in `dynasm!`, like `Rq(reg)`.
+/// This is implemented for the typed registers of the family, as well as for plain integer encodings.
+/// As the dynasm macro converts dynamic register expressions through this trait, passing a register
+/// of the wrong family is caught at compile time. Expressions that can only be integers, like literals,
+/// casts and arithmetic, are simply cast instead.
+pub trait DynamicRegister<F> {
+    /// Returns the encoding of this register.
+    fn code(self) -> u8;
+
+    /// Returns the highest encoding a value of this type can have. This lets dynasm skip range checks on
+    /// typed registers whose encodings always fit. Plain integers can have any encoding.
+    fn max_code(&self) -> u8 {
+        u8::MAX
+    }
+}
+
+/// The error returned when converting a number to a typed register that has no register with that encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidRegister(pub u8);
+
+impl fmt::Display for InvalidRegister {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} is not a valid register encoding", self.0)
+    }
+}
+
+impl error::Error for InvalidRegister {
+    fn description(&self) -> &str {
+        "invalid register encoding"
+    }
+}
+
+// defines a typed register enum, its conversions and its DynamicRegister implementations
+macro_rules! registers {
+    ($(#[$attr:meta])* $name:ident { $($variant:ident = $code:tt),* $(,)* }) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[allow(missing_docs)]
+        #[repr(u8)]
+        pub enum $name {
+            $($variant = $code),*
+        }
+
+        impl $name {
+            /// Returns the encoding of this register.
+            pub fn code(self) -> u8 {
+                self as u8
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(reg: $name) -> u8 {
+                reg.code()
+            }
+        }
+
+        impl ::core::convert::TryFrom<u8> for $name {
+            type Error = crate::registers::InvalidRegister;
+
+            fn try_from(code: u8) -> Result<$name, crate::registers::InvalidRegister> {
+                match code {
+                    $($code => Ok($name::$variant),)*
+                    _ => Err(crate::registers::InvalidRegister(code))
+                }
+            }
+        }
+
+        impl crate::registers::DynamicRegister<$name> for $name {
+            fn code(self) -> u8 {
+                self as u8
+            }
+
+            fn max_code(&self) -> u8 {
+                const MAX_CODE: u8 = {
+                    let codes: &[u8] = &[$($code),*];
+                    let mut max = 0;
+                    let mut i = 0;
+                    while i < codes.len() {
+                        if codes[i] > max {
+                            max = codes[i];
+                        }
+                        i += 1;
+                    }
+                    max
+                };
+                MAX_CODE
+            }
+        }
+
+        integer_dynamic_registers!($name; u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+    }
+}
+
+// plain integers can be used as dynamic registers of any family. Like an `as` cast, only the low byte is used.
+macro_rules! integer_dynamic_registers {
+    ($name:ident; $($int:ty),*) => {
+        $(
+            impl crate::registers::DynamicRegister<$name> for $int {
+                #[allow(clippy::unnecessary_cast)]
+                fn code(self) -> u8 {
+                    self as u8
+                }
+            }
+        )*
+    }
+}
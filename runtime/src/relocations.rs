@@ -2,11 +2,58 @@
 
 use byteorder::{ByteOrder, LittleEndian};
 
-use std::convert::TryFrom;
+use core::convert::TryFrom;
+use core::fmt;
 
-/// Error returned when encoding a relocation failed
-#[derive(Debug)]
-pub struct ImpossibleRelocation { }
+/// Error returned when encoding a relocation failed. It describes the value that was
+/// attempted to be encoded, and the constraints the relocation imposes on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ImpossibleRelocation {
+    /// The value that could not be encoded
+    pub value: isize,
+    /// The smallest value that can be encoded by the relocation
+    pub min: isize,
+    /// The largest value that can be encoded by the relocation
+    pub max: isize,
+    /// The alignment that values encoded by the relocation must have
+    pub alignment: usize,
+}
+
+impl ImpossibleRelocation {
+    /// Create an error for a relocation that encodes values as a signed bitfield of `bits` bits,
+    /// scaled by `alignment`.
+    pub fn signed_field(value: isize, bits: u8, alignment: usize) -> ImpossibleRelocation {
+        let (min, max) = if bits >= 64 {
+            (isize::MIN, isize::MAX)
+        } else {
+            let half = 1i64 << (bits - 1);
+            let scale = alignment as i64;
+            (
+                isize::try_from(-half.saturating_mul(scale)).unwrap_or(isize::MIN),
+                isize::try_from((half - 1).saturating_mul(scale)).unwrap_or(isize::MAX)
+            )
+        };
+
+        ImpossibleRelocation {
+            value,
+            min,
+            max,
+            alignment
+        }
+    }
+}
+
+impl fmt::Display for ImpossibleRelocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.value > self.max {
+            write!(f, "out of range (value {}, max {})", self.value, self.max)
+        } else if self.value < self.min {
+            write!(f, "out of range (value {}, min {})", self.value, self.min)
+        } else {
+            write!(f, "misaligned (value {}, alignment {})", self.value, self.alignment)
+        }
+    }
+}
 
 
 /// Used to inform assemblers on how to implement relocations for each architecture.
@@ -40,7 +87,7 @@ pub trait Relocation {
     fn read_value(&self, buf: &[u8]) -> isize;
     /// Specifies what kind of relocation this relocation instance is.
     fn kind(&self) -> RelocationKind;
-    /// Specifies the default page size on this platform.
+    /// Specifies the default page size on this platform. Assemblers use this as their initial allocation size.
     fn page_size() -> usize;
 }
 
@@ -109,16 +156,16 @@ impl Relocation for RelocationSize {
     fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
         match self {
             RelocationSize::Byte => buf[0] =
-                i8::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+                i8::try_from(value).map_err(|_| ImpossibleRelocation::signed_field(value, 8, 1))?
             as u8,
             RelocationSize::Word => LittleEndian::write_i16(buf,
-                i16::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+                i16::try_from(value).map_err(|_| ImpossibleRelocation::signed_field(value, 16, 1))?
             ),
             RelocationSize::DWord => LittleEndian::write_i32(buf,
-                i32::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+                i32::try_from(value).map_err(|_| ImpossibleRelocation::signed_field(value, 32, 1))?
             ),
             RelocationSize::QWord => LittleEndian::write_i64(buf,
-                i64::try_from(value).map_err(|_| ImpossibleRelocation { } )?
+                i64::try_from(value).map_err(|_| ImpossibleRelocation::signed_field(value, 64, 1))?
             ),
         }
         Ok(())
@@ -135,7 +182,7 @@ impl Relocation for RelocationSize {
         RelocationKind::Relative
     }
     fn page_size() -> usize {
-        4096
+        system_page_size()
     }
 }
 
@@ -147,3 +194,15 @@ pub(crate) fn fits_signed_bitfield(value: i64, bits: u8) -> bool {
     let half = 1i64 << (bits - 1);
     value < half && value >= -half
 }
+
+
+// the page size of the system, which can only be queried with std
+#[cfg(feature = "std")]
+pub(crate) fn system_page_size() -> usize {
+    crate::mmap::page_size()
+}
+
+#[cfg(not(feature = "std"))]
+pub(crate) fn system_page_size() -> usize {
+    4096
+}
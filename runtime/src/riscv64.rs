@@ -0,0 +1,354 @@
+//! This module implements the relocation model for the riscv64 architecture, as well as aliases for riscv64 Assemblers.
+
+use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation, fits_signed_bitfield};
+use byteorder::{ByteOrder, LittleEndian};
+use crate::blob::{BlobRelocation, size_from_byte};
+use core::convert::TryFrom;
+
+pub use crate::registers::{DynamicRegister, InvalidRegister};
+
+/// Relocation implementation for the riscv64 architecture.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum Riscv64Relocation {
+    // jal: 21 bits, halfword aligned
+    JAL,
+    // beq, bne, blt, bge, bltu, bgeu: 13 bits, halfword aligned
+    BRANCH,
+    // auipc followed by an I-type instruction (addi, jalr or a load): split 32 bits, byte aligned
+    AUIPC,
+    // c.j: 12 bits, halfword aligned
+    CJ,
+    // c.beqz, c.bnez: 9 bits, halfword aligned
+    CB,
+    // Anything in directives
+    Plain(RelocationSize),
+}
+
+impl Riscv64Relocation {
+    fn op_mask(&self) -> u32 {
+        match self {
+            Self::JAL => 0x0000_0FFF,
+            Self::BRANCH => 0x01FF_F07F,
+            Self::AUIPC => 0x0000_0FFF,
+            Self::CJ => 0xE003,
+            Self::CB => 0xE383,
+            Self::Plain(_) => 0
+        }
+    }
+
+    fn encode(&self, orig: isize) -> Result<u32, ImpossibleRelocation> {
+        let value = i64::try_from(orig).map_err(|_| ImpossibleRelocation::signed_field(orig, 64, 1))?;
+        Ok(match self {
+            Self::JAL => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 21) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 20, 2));
+                }
+                let value = value as u32;
+                ((value >> 20) & 1) << 31 |
+                ((value >> 1) & 0x3FF) << 21 |
+                ((value >> 11) & 1) << 20 |
+                ((value >> 12) & 0xFF) << 12
+            },
+            Self::BRANCH => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 13) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 12, 2));
+                }
+                let value = value as u32;
+                ((value >> 12) & 1) << 31 |
+                ((value >> 5) & 0x3F) << 25 |
+                ((value >> 1) & 0xF) << 8 |
+                ((value >> 11) & 1) << 7
+            },
+            Self::AUIPC => {
+                // the auipc part, the low part is handled in write_value
+                let high = (value + 0x800) >> 12;
+                if !fits_signed_bitfield(high, 20) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 32, 1));
+                }
+                (high as u32) << 12
+            },
+            Self::CJ => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 12) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 11, 2));
+                }
+                let value = value as u32;
+                ((value >> 11) & 1) << 12 |
+                ((value >> 4) & 1) << 11 |
+                ((value >> 8) & 3) << 9 |
+                ((value >> 10) & 1) << 8 |
+                ((value >> 6) & 1) << 7 |
+                ((value >> 7) & 1) << 6 |
+                ((value >> 1) & 7) << 3 |
+                ((value >> 5) & 1) << 2
+            },
+            Self::CB => {
+                if value & 1 != 0 || !fits_signed_bitfield(value, 9) {
+                    return Err(ImpossibleRelocation::signed_field(orig, 8, 2));
+                }
+                let value = value as u32;
+                ((value >> 8) & 1) << 12 |
+                ((value >> 3) & 3) << 10 |
+                ((value >> 6) & 3) << 5 |
+                ((value >> 1) & 3) << 3 |
+                ((value >> 5) & 1) << 2
+            },
+            Self::Plain(_) => unreachable!()
+        })
+    }
+}
+
+impl Relocation for Riscv64Relocation {
+    type Encoding = (u8,);
+    fn from_encoding(encoding: Self::Encoding) -> Self {
+        match encoding.0 {
+            0 => Self::JAL,
+            1 => Self::BRANCH,
+            2 => Self::AUIPC,
+            3 => Self::CJ,
+            4 => Self::CB,
+            x  => Self::Plain(RelocationSize::from_encoding(x - 4))
+        }
+    }
+    fn from_size(size: RelocationSize) -> Self {
+        Self::Plain(size)
+    }
+    fn size(&self) -> usize {
+        match self {
+            Self::Plain(s) => s.size(),
+            Self::AUIPC => RelocationSize::QWord.size(),
+            Self::CJ |
+            Self::CB => RelocationSize::Word.size(),
+            _ => RelocationSize::DWord.size(),
+        }
+    }
+    fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
+        if let Self::Plain(s) = self {
+            return s.write_value(buf, value);
+        };
+
+        let mask = self.op_mask();
+        let packed = self.encode(value)?;
+
+        match self {
+            Self::CJ |
+            Self::CB => {
+                let template = u32::from(LittleEndian::read_u16(buf)) & mask;
+                LittleEndian::write_u16(buf, (template | packed) as u16);
+            },
+            Self::AUIPC => {
+                // the second instruction receives the sign-extended remainder in its I-type immediate
+                let low = (value as u32) & 0xFFF;
+                let template = LittleEndian::read_u32(&buf[4..]) & 0x000F_FFFF;
+                LittleEndian::write_u32(&mut buf[4..], template | low << 20);
+
+                let template = LittleEndian::read_u32(buf) & mask;
+                LittleEndian::write_u32(buf, template | packed);
+            },
+            _ => {
+                let template = LittleEndian::read_u32(buf) & mask;
+                LittleEndian::write_u32(buf, template | packed);
+            }
+        }
+        Ok(())
+    }
+    fn read_value(&self, buf: &[u8]) -> isize {
+        if let Self::Plain(s) = self {
+            return s.read_value(buf);
+        };
+
+        let value = match self {
+            Self::CJ |
+            Self::CB => u32::from(LittleEndian::read_u16(buf)),
+            _ => LittleEndian::read_u32(buf)
+        };
+
+        let unpacked = match self {
+            Self::JAL => u64::from(
+                ((value >> 31) & 1) << 20 |
+                ((value >> 21) & 0x3FF) << 1 |
+                ((value >> 20) & 1) << 11 |
+                ((value >> 12) & 0xFF) << 12
+            ),
+            Self::BRANCH => u64::from(
+                ((value >> 31) & 1) << 12 |
+                ((value >> 25) & 0x3F) << 5 |
+                ((value >> 8) & 0xF) << 1 |
+                ((value >> 7) & 1) << 11
+            ),
+            Self::AUIPC => {
+                let high = i64::from((value & 0xFFFF_F000) as i32);
+                let low = i64::from((LittleEndian::read_u32(&buf[4..]) as i32) >> 20);
+                return (high + low) as isize;
+            },
+            Self::CJ => u64::from(
+                ((value >> 12) & 1) << 11 |
+                ((value >> 11) & 1) << 4 |
+                ((value >> 9) & 3) << 8 |
+                ((value >> 8) & 1) << 10 |
+                ((value >> 7) & 1) << 6 |
+                ((value >> 6) & 1) << 7 |
+                ((value >> 3) & 7) << 1 |
+                ((value >> 2) & 1) << 5
+            ),
+            Self::CB => u64::from(
+                ((value >> 12) & 1) << 8 |
+                ((value >> 10) & 3) << 3 |
+                ((value >> 5) & 3) << 6 |
+                ((value >> 3) & 3) << 1 |
+                ((value >> 2) & 1) << 5
+            ),
+            Self::Plain(_) => unreachable!()
+        };
+
+        // Sign extend.
+        let bits = match self {
+            Self::JAL => 21,
+            Self::BRANCH => 13,
+            Self::CJ => 12,
+            Self::CB => 9,
+            Self::AUIPC |
+            Self::Plain(_) => unreachable!()
+        };
+        let offset = 1u64 << (bits - 1);
+        let value: u64 = (unpacked ^ offset).wrapping_sub(offset);
+
+        value as i64 as isize
+    }
+    fn kind(&self) -> RelocationKind {
+        RelocationKind::Relative
+    }
+    fn page_size() -> usize {
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for Riscv64Relocation {
+    fn to_bytes(&self) -> [u8; 4] {
+        match self {
+            Self::JAL => [0, 0, 0, 0],
+            Self::BRANCH => [1, 0, 0, 0],
+            Self::AUIPC => [2, 0, 0, 0],
+            Self::CJ => [3, 0, 0, 0],
+            Self::CB => [4, 0, 0, 0],
+            Self::Plain(size) => [5, *size as u8, 0, 0],
+        }
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(match bytes[0] {
+            0 => Self::JAL,
+            1 => Self::BRANCH,
+            2 => Self::AUIPC,
+            3 => Self::CJ,
+            4 => Self::CB,
+            5 => Self::Plain(size_from_byte(bytes[1])?),
+            _ => return None
+        })
+    }
+}
+
+registers!(
+    /// A general purpose register, as used by the `X` family. The ABI names are available as associated constants.
+    X {
+        X0 = 0, X1 = 1, X2 = 2, X3 = 3, X4 = 4, X5 = 5, X6 = 6, X7 = 7,
+        X8 = 8, X9 = 9, X10 = 10, X11 = 11, X12 = 12, X13 = 13, X14 = 14, X15 = 15,
+        X16 = 16, X17 = 17, X18 = 18, X19 = 19, X20 = 20, X21 = 21, X22 = 22, X23 = 23,
+        X24 = 24, X25 = 25, X26 = 26, X27 = 27, X28 = 28, X29 = 29, X30 = 30, X31 = 31,
+    }
+);
+
+#[allow(missing_docs)]
+impl X {
+    pub const ZERO: X = X::X0;
+    pub const RA: X = X::X1;
+    pub const SP: X = X::X2;
+    pub const GP: X = X::X3;
+    pub const TP: X = X::X4;
+    pub const T0: X = X::X5;
+    pub const T1: X = X::X6;
+    pub const T2: X = X::X7;
+    pub const FP: X = X::X8;
+    pub const S0: X = X::X8;
+    pub const S1: X = X::X9;
+    pub const A0: X = X::X10;
+    pub const A1: X = X::X11;
+    pub const A2: X = X::X12;
+    pub const A3: X = X::X13;
+    pub const A4: X = X::X14;
+    pub const A5: X = X::X15;
+    pub const A6: X = X::X16;
+    pub const A7: X = X::X17;
+    pub const S2: X = X::X18;
+    pub const S3: X = X::X19;
+    pub const S4: X = X::X20;
+    pub const S5: X = X::X21;
+    pub const S6: X = X::X22;
+    pub const S7: X = X::X23;
+    pub const S8: X = X::X24;
+    pub const S9: X = X::X25;
+    pub const S10: X = X::X26;
+    pub const S11: X = X::X27;
+    pub const T3: X = X::X28;
+    pub const T4: X = X::X29;
+    pub const T5: X = X::X30;
+    pub const T6: X = X::X31;
+}
+
+registers!(
+    /// A floating point register, as used by the `F` family. The ABI names are available as associated constants.
+    F {
+        F0 = 0, F1 = 1, F2 = 2, F3 = 3, F4 = 4, F5 = 5, F6 = 6, F7 = 7,
+        F8 = 8, F9 = 9, F10 = 10, F11 = 11, F12 = 12, F13 = 13, F14 = 14, F15 = 15,
+        F16 = 16, F17 = 17, F18 = 18, F19 = 19, F20 = 20, F21 = 21, F22 = 22, F23 = 23,
+        F24 = 24, F25 = 25, F26 = 26, F27 = 27, F28 = 28, F29 = 29, F30 = 30, F31 = 31,
+    }
+);
+
+#[allow(missing_docs)]
+impl F {
+    pub const FT0: F = F::F0;
+    pub const FT1: F = F::F1;
+    pub const FT2: F = F::F2;
+    pub const FT3: F = F::F3;
+    pub const FT4: F = F::F4;
+    pub const FT5: F = F::F5;
+    pub const FT6: F = F::F6;
+    pub const FT7: F = F::F7;
+    pub const FS0: F = F::F8;
+    pub const FS1: F = F::F9;
+    pub const FA0: F = F::F10;
+    pub const FA1: F = F::F11;
+    pub const FA2: F = F::F12;
+    pub const FA3: F = F::F13;
+    pub const FA4: F = F::F14;
+    pub const FA5: F = F::F15;
+    pub const FA6: F = F::F16;
+    pub const FA7: F = F::F17;
+    pub const FS2: F = F::F18;
+    pub const FS3: F = F::F19;
+    pub const FS4: F = F::F20;
+    pub const FS5: F = F::F21;
+    pub const FS6: F = F::F22;
+    pub const FS7: F = F::F23;
+    pub const FS8: F = F::F24;
+    pub const FS9: F = F::F25;
+    pub const FS10: F = F::F26;
+    pub const FS11: F = F::F27;
+    pub const FT8: F = F::F28;
+    pub const FT9: F = F::F29;
+    pub const FT10: F = F::F30;
+    pub const FT11: F = F::F31;
+}
+
+/// The encoding of `ebreak`, which traps when executed. Suitable as `Config::poison` pattern.
+pub const EBREAK: &[u8] = &[0x73, 0x00, 0x10, 0x00];
+
+
+/// A riscv64 Assembler.
+#[cfg(feature = "std")]
+pub type Assembler = crate::Assembler<Riscv64Relocation>;
+/// A riscv64 AssemblyModifier.
+#[cfg(feature = "std")]
+pub type AssemblyModifier<'a> = crate::Modifier<'a, Riscv64Relocation>;
+/// A riscv64 UncommittedModifier.
+pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
@@ -0,0 +1,432 @@
+//! This module implements the WebAssembly backend: an assembler for function bodies, which tracks the nesting
+//! of structured control instructions so branches can refer to them by label, and an assembler that combines
+//! function bodies into a `.wasm` module.
+
+use crate::{DynasmApi, DynasmError, BackendError, DynamicLabel, LabelKind, AssemblyOffset};
+
+use alloc::vec::Vec;
+use alloc::string::String;
+use core::iter::Extend;
+use core::fmt;
+
+/// A WebAssembly value type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum ValType {
+    I32 = 0x7F,
+    I64 = 0x7E,
+    F32 = 0x7D,
+    F64 = 0x7C,
+    V128 = 0x7B,
+    FuncRef = 0x70,
+    ExternRef = 0x6F,
+}
+
+/// The ways in which the structured control instructions of a function body can be improperly nested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureError {
+    /// The function body was not closed with a final `end`
+    Unclosed,
+    /// Code was emitted after the final `end` of the function body
+    CodeAfterEnd,
+    /// An `end` did not match any open `block`, `loop` or `if`
+    UnmatchedEnd,
+}
+
+impl fmt::Display for StructureError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StructureError::Unclosed => write!(f, "the function body was not closed with a final end"),
+            StructureError::CodeAfterEnd => write!(f, "code emitted after the final end of the function body"),
+            StructureError::UnmatchedEnd => write!(f, "an end without a matching block, loop or if"),
+        }
+    }
+}
+
+impl From<StructureError> for DynasmError {
+    fn from(e: StructureError) -> DynasmError {
+        DynasmError::Backend(BackendError::Wasm(e))
+    }
+}
+
+/// The label naming a structured control instruction (`block`, `loop` or `if`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ControlLabel {
+    /// The construct was not named
+    Anonymous,
+    /// A global label, like `->label`
+    Global(&'static str),
+    /// A dynamic label, like `=>value`
+    Dynamic(DynamicLabel),
+}
+
+impl ControlLabel {
+    fn kind(self) -> LabelKind {
+        match self {
+            ControlLabel::Anonymous => unreachable!(),
+            ControlLabel::Global(name) => LabelKind::Global(name),
+            ControlLabel::Dynamic(id) => LabelKind::Dynamic(id),
+        }
+    }
+}
+
+/// This trait extends DynasmApi with the tracking of structured control instructions that the `wasm` architecture needs
+/// to turn labels into branch depths.
+pub trait WasmApi : DynasmApi {
+    /// Record the start of a `block`, `loop` or `if` construct.
+    fn enter_block(&mut self, label: ControlLabel);
+    /// Record the `end` of the innermost construct. The final `end` closes the function body.
+    fn exit_block(&mut self);
+    /// Push the branch depth of the enclosing construct with the given label.
+    fn push_depth(&mut self, label: ControlLabel);
+}
+
+
+/// An unsigned or signed LEB128 encoded value, as an iterator over its bytes.
+#[derive(Debug, Clone)]
+pub struct Leb128 {
+    bytes: [u8; 10],
+    len: u8,
+    pos: u8,
+}
+
+impl Iterator for Leb128 {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pos < self.len {
+            self.pos += 1;
+            Some(self.bytes[usize::from(self.pos - 1)])
+        } else {
+            None
+        }
+    }
+}
+
+/// Encode `value` as unsigned LEB128.
+pub fn uleb128(mut value: u64) -> Leb128 {
+    let mut res = Leb128 { bytes: [0; 10], len: 0, pos: 0 };
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if value == 0 {
+            res.bytes[usize::from(res.len)] = byte;
+            res.len += 1;
+            return res;
+        }
+        res.bytes[usize::from(res.len)] = byte | 0x80;
+        res.len += 1;
+    }
+}
+
+/// Encode `value` as signed LEB128.
+pub fn sleb128(mut value: i64) -> Leb128 {
+    let mut res = Leb128 { bytes: [0; 10], len: 0, pos: 0 };
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        if (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0) {
+            res.bytes[usize::from(res.len)] = byte;
+            res.len += 1;
+            return res;
+        }
+        res.bytes[usize::from(res.len)] = byte | 0x80;
+        res.len += 1;
+    }
+}
+
+
+/// An assembler for the body of a single WebAssembly function.
+///
+/// Besides the code, it keeps track of the structured control instructions that are currently open,
+/// which allows branches to name their target by label instead of by depth.
+#[derive(Debug, Clone)]
+pub struct FunctionAssembler {
+    ops: Vec<u8>,
+    locals: Vec<(u32, ValType)>,
+    control: Vec<ControlLabel>,
+    closed: bool,
+    dynamic_labels: usize,
+    errors: Vec<DynasmError>,
+}
+
+impl FunctionAssembler {
+    /// Creates a new `FunctionAssembler`. The function body is open, and has to be closed with a final `end`.
+    pub fn new() -> FunctionAssembler {
+        FunctionAssembler {
+            ops: Vec::new(),
+            locals: Vec::new(),
+            control: vec![ControlLabel::Anonymous],
+            closed: false,
+            dynamic_labels: 0,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Declare `count` locals of type `ty`. Locals are numbered after the parameters of the function, in order of declaration.
+    pub fn add_locals(&mut self, count: u32, ty: ValType) {
+        self.locals.push((count, ty));
+    }
+
+    /// Create a new dynamic label, which can be used to name a structured control instruction.
+    pub fn new_dynamic_label(&mut self) -> DynamicLabel {
+        self.dynamic_labels += 1;
+        DynamicLabel(self.dynamic_labels - 1)
+    }
+
+    /// Finalizes the `FunctionAssembler`, returning the encoded function body: the local declarations followed by the code.
+    /// If any branch named a label that did not belong to an enclosing construct, the structured control instructions
+    /// were not properly nested, or the function body was not closed, these errors are returned here.
+    pub fn finalize(mut self) -> Result<Vec<u8>, Vec<DynasmError>> {
+        if !self.closed {
+            self.errors.push(StructureError::Unclosed.into());
+        }
+
+        if !self.errors.is_empty() {
+            return Err(self.errors);
+        }
+
+        let mut body = Vec::with_capacity(self.ops.len() + 1 + 2 * self.locals.len());
+        body.extend(uleb128(self.locals.len() as u64));
+        for &(count, ty) in &self.locals {
+            body.extend(uleb128(u64::from(count)));
+            body.push(ty as u8);
+        }
+        body.extend(self.ops);
+        Ok(body)
+    }
+}
+
+impl Default for FunctionAssembler {
+    fn default() -> FunctionAssembler {
+        FunctionAssembler::new()
+    }
+}
+
+impl Extend<u8> for FunctionAssembler {
+    fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=u8> {
+        self.ops.extend(iter)
+    }
+}
+
+impl<'a> Extend<&'a u8> for FunctionAssembler {
+    fn extend<T>(&mut self, iter: T) where T: IntoIterator<Item=&'a u8> {
+        self.ops.extend(iter)
+    }
+}
+
+impl DynasmApi for FunctionAssembler {
+    fn offset(&self) -> AssemblyOffset {
+        AssemblyOffset(self.ops.len())
+    }
+
+    fn push(&mut self, byte: u8) {
+        self.ops.push(byte);
+    }
+
+    fn align(&mut self, _alignment: usize, _with: u8) {
+        self.errors.push(DynasmError::Unsupported("alignment in wasm function bodies"));
+    }
+}
+
+impl WasmApi for FunctionAssembler {
+    fn enter_block(&mut self, label: ControlLabel) {
+        if self.closed {
+            self.errors.push(StructureError::CodeAfterEnd.into());
+            return;
+        }
+        self.control.push(label);
+    }
+
+    fn exit_block(&mut self) {
+        if self.control.pop().is_none() {
+            self.errors.push(StructureError::UnmatchedEnd.into());
+        }
+        if self.control.is_empty() {
+            self.closed = true;
+        }
+    }
+
+    fn push_depth(&mut self, label: ControlLabel) {
+        let depth = self.control.iter().rev().position(|&l| l != ControlLabel::Anonymous && l == label);
+        match depth {
+            Some(depth) => self.ops.extend(uleb128(depth as u64)),
+            None => {
+                self.errors.push(DynasmError::UnknownLabel(label.kind()));
+                self.ops.push(0);
+            }
+        }
+    }
+}
+
+
+/// An assembler for a complete WebAssembly module. It combines function bodies with their signatures,
+/// imported functions, a linear memory and exports into a `.wasm` binary.
+#[derive(Debug, Clone, Default)]
+pub struct ModuleAssembler {
+    types: Vec<(Vec<ValType>, Vec<ValType>)>,
+    imports: Vec<(String, String, u32)>,
+    functions: Vec<(u32, Vec<u8>)>,
+    memory: Option<(u32, Option<u32>)>,
+    exports: Vec<(String, u8, u32)>,
+}
+
+impl ModuleAssembler {
+    /// Creates a new, empty `ModuleAssembler`.
+    pub fn new() -> ModuleAssembler {
+        ModuleAssembler::default()
+    }
+
+    /// Returns the index of the function type with the given parameters and results, adding it if it does not exist yet.
+    pub fn add_type(&mut self, params: &[ValType], results: &[ValType]) -> u32 {
+        if let Some(index) = self.types.iter().position(|(p, r)| p == params && r == results) {
+            return index as u32;
+        }
+        self.types.push((params.to_vec(), results.to_vec()));
+        (self.types.len() - 1) as u32
+    }
+
+    /// Imports a function with the given type, returning its function index.
+    /// As imported functions are numbered before the functions defined in the module, all imports have to be added first.
+    /// Importing a function after a function has been added fails with `DynasmError::Unsupported`.
+    pub fn import_function(&mut self, module: &str, name: &str, type_index: u32) -> Result<u32, DynasmError> {
+        if !self.functions.is_empty() {
+            return Err(DynasmError::Unsupported("importing a function after functions have been added"));
+        }
+        self.imports.push((module.into(), name.into(), type_index));
+        Ok((self.imports.len() - 1) as u32)
+    }
+
+    /// Finalizes a function body and adds it with the given type, returning its function index.
+    pub fn add_function(&mut self, type_index: u32, body: FunctionAssembler) -> Result<u32, Vec<DynasmError>> {
+        let body = body.finalize()?;
+        self.functions.push((type_index, body));
+        Ok((self.imports.len() + self.functions.len() - 1) as u32)
+    }
+
+    /// Sets the linear memory of the module, with its minimum and optional maximum size in pages. Returns its memory index.
+    pub fn set_memory(&mut self, minimum: u32, maximum: Option<u32>) -> u32 {
+        self.memory = Some((minimum, maximum));
+        0
+    }
+
+    /// Exports the function with the given index under `name`.
+    pub fn export_function(&mut self, name: &str, index: u32) {
+        self.exports.push((name.into(), 0x00, index));
+    }
+
+    /// Exports the memory with the given index under `name`.
+    pub fn export_memory(&mut self, name: &str, index: u32) {
+        self.exports.push((name.into(), 0x02, index));
+    }
+
+    /// Finalizes the `ModuleAssembler`, returning the `.wasm` binary.
+    pub fn finalize(self) -> Vec<u8> {
+        let mut module = Vec::new();
+        module.extend(b"\0asm");
+        module.extend(&1u32.to_le_bytes());
+
+        if !self.types.is_empty() {
+            let mut section = Vec::new();
+            section.extend(uleb128(self.types.len() as u64));
+            for (params, results) in &self.types {
+                section.push(0x60);
+                section.extend(uleb128(params.len() as u64));
+                section.extend(params.iter().map(|&ty| ty as u8));
+                section.extend(uleb128(results.len() as u64));
+                section.extend(results.iter().map(|&ty| ty as u8));
+            }
+            push_section(&mut module, 1, section);
+        }
+
+        if !self.imports.is_empty() {
+            let mut section = Vec::new();
+            section.extend(uleb128(self.imports.len() as u64));
+            for (module_name, name, type_index) in &self.imports {
+                push_name(&mut section, module_name);
+                push_name(&mut section, name);
+                section.push(0x00);
+                section.extend(uleb128(u64::from(*type_index)));
+            }
+            push_section(&mut module, 2, section);
+        }
+
+        if !self.functions.is_empty() {
+            let mut section = Vec::new();
+            section.extend(uleb128(self.functions.len() as u64));
+            for &(type_index, _) in &self.functions {
+                section.extend(uleb128(u64::from(type_index)));
+            }
+            push_section(&mut module, 3, section);
+        }
+
+        if let Some((minimum, maximum)) = self.memory {
+            let mut section = vec![1];
+            match maximum {
+                Some(maximum) => {
+                    section.push(0x01);
+                    section.extend(uleb128(u64::from(minimum)));
+                    section.extend(uleb128(u64::from(maximum)));
+                },
+                None => {
+                    section.push(0x00);
+                    section.extend(uleb128(u64::from(minimum)));
+                }
+            }
+            push_section(&mut module, 5, section);
+        }
+
+        if !self.exports.is_empty() {
+            let mut section = Vec::new();
+            section.extend(uleb128(self.exports.len() as u64));
+            for (name, kind, index) in &self.exports {
+                push_name(&mut section, name);
+                section.push(*kind);
+                section.extend(uleb128(u64::from(*index)));
+            }
+            push_section(&mut module, 7, section);
+        }
+
+        if !self.functions.is_empty() {
+            let mut section = Vec::new();
+            section.extend(uleb128(self.functions.len() as u64));
+            for (_, body) in self.functions {
+                section.extend(uleb128(body.len() as u64));
+                section.extend(body);
+            }
+            push_section(&mut module, 10, section);
+        }
+
+        module
+    }
+}
+
+fn push_section(module: &mut Vec<u8>, id: u8, section: Vec<u8>) {
+    module.push(id);
+    module.extend(uleb128(section.len() as u64));
+    module.extend(section);
+}
+
+fn push_name(section: &mut Vec<u8>, name: &str) {
+    section.extend(uleb128(name.len() as u64));
+    section.extend(name.as_bytes());
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leb128() {
+        assert_eq!(uleb128(0).collect::<Vec<_>>(), [0x00]);
+        assert_eq!(uleb128(127).collect::<Vec<_>>(), [0x7F]);
+        assert_eq!(uleb128(624485).collect::<Vec<_>>(), [0xE5, 0x8E, 0x26]);
+        assert_eq!(uleb128(u64::MAX).count(), 10);
+        assert_eq!(sleb128(-1).collect::<Vec<_>>(), [0x7F]);
+        assert_eq!(sleb128(63).collect::<Vec<_>>(), [0x3F]);
+        assert_eq!(sleb128(64).collect::<Vec<_>>(), [0xC0, 0x00]);
+        assert_eq!(sleb128(-123456).collect::<Vec<_>>(), [0xC0, 0xBB, 0x78]);
+        assert_eq!(sleb128(i64::MIN).count(), 10);
+    }
+}
@@ -1,6 +1,11 @@
 //! This module implements the relocation model for the x64 architecture, as well as aliases for x64 Assemblers.
 
 use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+use crate::DynasmApi;
+use crate::blob::{BlobRelocation, size_from_byte};
+use alloc::vec::Vec;
+
+pub use crate::registers::{DynamicRegister, InvalidRegister};
 
 
 /// Relocation implementation for the x64 architecture.
@@ -46,13 +51,236 @@ impl Relocation for X64Relocation {
         RelocationKind::Relative
     }
     fn page_size() -> usize {
-        4096
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for X64Relocation {
+    fn to_bytes(&self) -> [u8; 4] {
+        [self.size as u8, self.offset, self.start_offset, 0]
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(Self {
+            size: size_from_byte(bytes[0])?,
+            offset: bytes[1],
+            start_offset: bytes[2],
+        })
     }
 }
 
+registers!(
+    /// A general purpose register. This type is used for all dynamic general purpose register families:
+    /// `Rb`, `Rw`, `Rd`, `Ra` and `Rq`.
+    Rq {
+        RAX = 0, RCX = 1, RDX = 2, RBX = 3, RSP = 4, RBP = 5, RSI = 6, RDI = 7,
+        R8 = 8, R9 = 9, R10 = 10, R11 = 11, R12 = 12, R13 = 13, R14 = 14, R15 = 15,
+        R16 = 16, R17 = 17, R18 = 18, R19 = 19, R20 = 20, R21 = 21, R22 = 22, R23 = 23,
+        R24 = 24, R25 = 25, R26 = 26, R27 = 27, R28 = 28, R29 = 29, R30 = 30, R31 = 31,
+    }
+);
+
+registers!(
+    /// A high byte register, as used by the `Rh` family.
+    Rh {
+        AH = 4, CH = 5, DH = 6, BH = 7,
+    }
+);
+
+registers!(
+    /// An x87 floating point register, as used by the `Rf` family.
+    Rf {
+        ST0 = 0, ST1 = 1, ST2 = 2, ST3 = 3, ST4 = 4, ST5 = 5, ST6 = 6, ST7 = 7,
+    }
+);
+
+registers!(
+    /// An MMX register, as used by the `Rm` family.
+    Rm {
+        MMX0 = 0, MMX1 = 1, MMX2 = 2, MMX3 = 3, MMX4 = 4, MMX5 = 5, MMX6 = 6, MMX7 = 7,
+    }
+);
+
+registers!(
+    /// A vector register. This type is used for both the `Rx` and `Ry` families, as xmm and ymm registers share their encodings.
+    Rx {
+        XMM0 = 0, XMM1 = 1, XMM2 = 2, XMM3 = 3, XMM4 = 4, XMM5 = 5, XMM6 = 6, XMM7 = 7,
+        XMM8 = 8, XMM9 = 9, XMM10 = 10, XMM11 = 11, XMM12 = 12, XMM13 = 13, XMM14 = 14, XMM15 = 15,
+    }
+);
+
+registers!(
+    /// A segment register, as used by the `Rs` family.
+    Rs {
+        ES = 0, CS = 1, SS = 2, DS = 3, FS = 4, GS = 5,
+    }
+);
+
+registers!(
+    /// A control register, as used by the `RC` family.
+    RC {
+        CR0 = 0, CR1 = 1, CR2 = 2, CR3 = 3, CR4 = 4, CR5 = 5, CR6 = 6, CR7 = 7,
+        CR8 = 8, CR9 = 9, CR10 = 10, CR11 = 11, CR12 = 12, CR13 = 13, CR14 = 14, CR15 = 15,
+    }
+);
+
+registers!(
+    /// A debug register, as used by the `RD` family.
+    RD {
+        DR0 = 0, DR1 = 1, DR2 = 2, DR3 = 3, DR4 = 4, DR5 = 5, DR6 = 6, DR7 = 7,
+        DR8 = 8, DR9 = 9, DR10 = 10, DR11 = 11, DR12 = 12, DR13 = 13, DR14 = 14, DR15 = 15,
+    }
+);
+
+registers!(
+    /// A bound register, as used by the `RB` family.
+    RB {
+        BND0 = 0, BND1 = 1, BND2 = 2, BND3 = 3,
+    }
+);
+
+registers!(
+    /// An AMX tile register, as used by the `Rt` family.
+    Rt {
+        TMM0 = 0, TMM1 = 1, TMM2 = 2, TMM3 = 3, TMM4 = 4, TMM5 = 5, TMM6 = 6, TMM7 = 7,
+    }
+);
+
+/// The calling conventions `Frame` can build stack frames for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallingConvention {
+    /// The System V AMD64 ABI, used on Linux, macOS and the BSDs.
+    SysV,
+    /// The Microsoft x64 calling convention, used on Windows.
+    Win64,
+}
+
+impl CallingConvention {
+    /// Returns if `reg` has to be preserved by a callee.
+    pub fn is_callee_saved(self, reg: Rq) -> bool {
+        matches!((self, reg),
+            (_, Rq::RBX) | (_, Rq::RBP) | (_, Rq::R12) | (_, Rq::R13) | (_, Rq::R14) | (_, Rq::R15)
+            | (CallingConvention::Win64, Rq::RSI) | (CallingConvention::Win64, Rq::RDI)
+        )
+    }
+
+    /// The size of the register home area a caller has to allocate for its callees.
+    pub fn shadow_space(self) -> usize {
+        match self {
+            CallingConvention::SysV => 0,
+            CallingConvention::Win64 => 32,
+        }
+    }
+}
+
+/// A description of the stack frame of a function, which can emit a matching prologue and epilogue.
+/// The prologue pushes the callee-saved registers that the function clobbers, and allocates space
+/// for `locals` bytes of local variables, as well as the shadow space the calling convention requires for
+/// any calls made by the function. The stack pointer is kept aligned to 16 bytes after the prologue.
+/// The local variables live at `[rsp + frame.locals_offset()]`.
+/// Callee-saved xmm registers on Win64 are not saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    convention: CallingConvention,
+    saved: Vec<Rq>,
+    locals: usize,
+    allocated: usize,
+}
+
+impl Frame {
+    /// Describe a frame for `convention`. `clobbered` lists all general purpose registers
+    /// the function overwrites, of which only the callee-saved ones are saved.
+    ///
+    /// # Panics
+    /// Panics if `clobbered` contains `rsp`, or if the frame would be larger than what a single
+    /// `sub rsp, imm32` can allocate.
+    pub fn new(convention: CallingConvention, clobbered: &[Rq], locals: usize) -> Frame {
+        assert!(!clobbered.contains(&Rq::RSP), "rsp cannot be in the clobber list");
+
+        let mut saved: Vec<Rq> = clobbered.iter().cloned().filter(|&reg| convention.is_callee_saved(reg)).collect();
+        saved.sort();
+        saved.dedup();
+
+        // the return address and the saved registers are already on the stack
+        let pushed = 8 + 8 * saved.len();
+        let mut allocated = locals.saturating_add(convention.shadow_space());
+        allocated = allocated.saturating_add((16 - (pushed + allocated % 16) % 16) % 16);
+        assert!(allocated <= i32::MAX as usize, "stack frame too large");
+
+        Frame {
+            convention,
+            saved,
+            locals,
+            allocated,
+        }
+    }
+
+    /// The calling convention of this frame.
+    pub fn convention(&self) -> CallingConvention {
+        self.convention
+    }
+
+    /// The callee-saved registers this frame saves, in the order they are pushed.
+    pub fn saved_registers(&self) -> &[Rq] {
+        &self.saved
+    }
+
+    /// The offset from `rsp` at which the local variables start after the prologue.
+    pub fn locals_offset(&self) -> usize {
+        self.convention.shadow_space()
+    }
+
+    /// The amount of stack space the prologue allocates after saving registers, including the
+    /// local variables, shadow space and alignment padding.
+    pub fn allocated(&self) -> usize {
+        self.allocated
+    }
+
+    /// Emit the function prologue, which saves registers and allocates the frame.
+    pub fn prologue<D: DynasmApi>(&self, ops: &mut D) {
+        for reg in &self.saved {
+            if reg.code() >= 8 {
+                ops.push(0x41);
+            }
+            ops.push(0x50 + (reg.code() & 7));
+        }
+        self.adjust_rsp(ops, 5);
+    }
+
+    /// Emit the function epilogue, which deallocates the frame, restores registers and returns.
+    pub fn epilogue<D: DynasmApi>(&self, ops: &mut D) {
+        self.adjust_rsp(ops, 0);
+        for reg in self.saved.iter().rev() {
+            if reg.code() >= 8 {
+                ops.push(0x41);
+            }
+            ops.push(0x58 + (reg.code() & 7));
+        }
+        ops.push(0xC3);
+    }
+
+    // emits add rsp, allocated (opcode extension 0) or sub rsp, allocated (opcode extension 5)
+    fn adjust_rsp<D: DynasmApi>(&self, ops: &mut D, ext: u8) {
+        let modrm = 0xC4 | (ext << 3);
+        if self.allocated == 0 {
+            // nothing to allocate
+        } else if self.allocated < 0x80 {
+            ops.extend(&[0x48, 0x83, modrm, self.allocated as u8]);
+        } else {
+            ops.extend(&[0x48, 0x81, modrm]);
+            ops.push_i32(self.allocated as i32);
+        }
+    }
+}
+
+/// The encoding of `int3`, which traps when executed. Suitable as `Config::poison` pattern.
+pub const INT3: &[u8] = &[0xCC];
+
+
 /// An x64 Assembler. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type Assembler = crate::Assembler<X64Relocation>;
 /// An x64 AssemblyModifier. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type AssemblyModifier<'a> = crate::Modifier<'a, X64Relocation>;
 /// An x64 UncommittedModifier. This is aliased here for backwards compatability.
 pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
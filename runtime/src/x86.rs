@@ -1,25 +1,30 @@
 //! This module implements the relocation model for the x86 architecture, as well as aliases for x68 Assemblers.
 
 use crate::relocations::{Relocation, RelocationSize, RelocationKind, ImpossibleRelocation};
+use byteorder::{ByteOrder, LittleEndian};
+use crate::blob::{BlobRelocation, size_from_byte, kind_from_byte};
 
 
-/// Relocation implementation for the x86 architecture.
+/// Relocation implementation for the x86 architecture. Relocations emitted for 16-bit real mode code
+/// are marked as such, as their `WORD` sized fields wrap around at the end of the segment.
 #[derive(Debug, Clone)]
 pub struct X86Relocation {
     size: RelocationSize,
     kind: RelocationKind,
     offset: u8,
     start_offset: u8,
+    real_mode: bool,
 }
 
 impl Relocation for X86Relocation {
-    type Encoding = (u8, u8, u8);
+    type Encoding = (u8, u8, u8, u8);
     fn from_encoding(encoding: Self::Encoding) -> Self {
         Self {
             offset: encoding.0,
             size: RelocationSize::from_encoding(encoding.1),
             kind: RelocationKind::from_encoding(encoding.2),
             start_offset: 0,
+            real_mode: encoding.3 != 0,
         }
     }
     fn from_size(size: RelocationSize) -> Self {
@@ -28,6 +33,7 @@ impl Relocation for X86Relocation {
             kind: RelocationKind::Relative,
             offset: 0,
             start_offset: size as u8,
+            real_mode: false,
         }
     }
     fn start_offset(&self) -> usize {
@@ -40,23 +46,67 @@ impl Relocation for X86Relocation {
         self.size.size()
     }
     fn write_value(&self, buf: &mut [u8], value: isize) -> Result<(), ImpossibleRelocation> {
-        self.size.write_value(buf, value)
+        match self.size {
+            // 16-bit code wraps around at the end of its segment, so any offset within the segment
+            // can be reached, and absolute addresses up to 0xFFFF can be encoded.
+            RelocationSize::Word if self.real_mode => {
+                if !(-0xFFFF..=0xFFFF).contains(&value) {
+                    return Err(ImpossibleRelocation {
+                        value,
+                        min: -0xFFFF,
+                        max: 0xFFFF,
+                        alignment: 1
+                    });
+                }
+                LittleEndian::write_u16(buf, value as u16);
+                Ok(())
+            },
+            _ => self.size.write_value(buf, value)
+        }
     }
     fn read_value(&self, buf: &[u8]) -> isize {
-        self.size.read_value(buf)
+        match self.size {
+            // absolute addresses in real mode are unsigned, relative offsets are read as signed
+            RelocationSize::Word if self.real_mode && self.kind == RelocationKind::AbsToRel => {
+                LittleEndian::read_u16(buf) as isize
+            },
+            _ => self.size.read_value(buf)
+        }
     }
     fn kind(&self) -> RelocationKind {
         self.kind
     }
     fn page_size() -> usize {
-        4096
+        crate::relocations::system_page_size()
+    }
+}
+
+impl BlobRelocation for X86Relocation {
+    // the real mode flag is stored in the top bit of the kind
+    fn to_bytes(&self) -> [u8; 4] {
+        [self.size as u8, self.kind as u8 | (self.real_mode as u8) << 7, self.offset, self.start_offset]
+    }
+    fn from_bytes(bytes: [u8; 4]) -> Option<Self> {
+        Some(Self {
+            size: size_from_byte(bytes[0])?,
+            kind: kind_from_byte(bytes[1] & 0x7F)?,
+            offset: bytes[2],
+            start_offset: bytes[3],
+            real_mode: bytes[1] & 0x80 != 0,
+        })
     }
 }
 
 
+/// The encoding of `int3`, which traps when executed. Suitable as `Config::poison` pattern.
+pub const INT3: &[u8] = &[0xCC];
+
+
 /// An x86 Assembler. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type Assembler = crate::Assembler<X86Relocation>;
 /// An x86 AssemblyModifier. This is aliased here for backwards compatability.
+#[cfg(feature = "std")]
 pub type AssemblyModifier<'a> = crate::Modifier<'a, X86Relocation>;
 /// An x86 UncommittedModifier. This is aliased here for backwards compatability.
 pub type UncommittedModifier<'a> = crate::UncommittedModifier<'a>;
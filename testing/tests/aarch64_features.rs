@@ -0,0 +1,67 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, VecAssembler};
+use dynasmrt::aarch64::Aarch64Relocation;
+
+#[test]
+fn baseline() {
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature none
+        ; add x0, x1, x2
+        ; fadd d0, d1, d2
+        ; paciasp
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0x20, 0x00, 0x02, 0x8b, 0x20, 0x28, 0x62, 0x1e, 0x3f, 0x23, 0x03, 0xd5
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn architecture_versions() {
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature v8_1a
+        ; ldadd w0, w1, [x2]
+        ; crc32cx w3, w4, x5
+        ; sqrdmlah v0.s4, v1.s4, v2.s4
+        ; .feature v8_3a
+        ; pacia x0, sp
+        ; fjcvtzs w0, d1
+        ; ldapr x3, [x4]
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0x41, 0x00, 0x20, 0xb8, 0x83, 0x5c, 0xc5, 0x9a, 0x20, 0x84, 0x82, 0x6e,
+        0xe0, 0x03, 0xc1, 0xda, 0x20, 0x00, 0x7e, 0x1e, 0x83, 0xc0, 0xbf, 0xf8
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn optional_extensions() {
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; .feature fp16, dotprod
+        ; fadd h0, h1, h2
+        ; sdot v0.s4, v1.b16, v2.b16
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0x20, 0x28, 0xe2, 0x1e, 0x20, 0x94, 0x82, 0x4e
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
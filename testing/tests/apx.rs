@@ -0,0 +1,133 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, VecAssembler};
+use dynasmrt::x64::{X64Relocation, Rq};
+
+// The expected encodings in this file have been derived by hand from the APX specification.
+// Encoding tests against a reference assembler can be generated with tools/x64_apx_gen_tests.py,
+// which needs an assembler that supports APX (binutils 2.42 or LLVM 18 and later).
+
+#[test]
+fn rex2() {
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; mov r16, rax
+        ; mov rax, r16
+        ; add r17d, [r18 + r19 * 4 + 8]
+        ; imul r20, r21
+        ; mov r22b, 1
+        ; push r31
+        ; mov rax, [r29]
+        ; mov rcx, [r28]
+    );
+
+    let expected = [
+        0xD5, 0x18, 0x89, 0xC0,
+        0xD5, 0x48, 0x89, 0xC0,
+        0xD5, 0x70, 0x03, 0x4C, 0x9A, 0x08,
+        0xD5, 0xD8, 0xAF, 0xE5,
+        0xD5, 0x10, 0xB6, 0x01,
+        0xD5, 0x11, 0x57,
+        0xD5, 0x19, 0x8B, 0x45, 0x00,
+        0xD5, 0x19, 0x8B, 0x0C, 0x24
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn new_data_destination() {
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; add r18, r17, r16
+        ; sub eax, ecx, 5
+        ; and r16w, r17w, [rax + 8]
+        ; neg rcx, r9
+        ; xor r30b, r31b, 0x7F
+    );
+
+    let expected = [
+        0x62, 0xEC, 0xEC, 0x10, 0x01, 0xC1,
+        0x62, 0xF4, 0x7C, 0x18, 0x83, 0xE9, 0x05,
+        0x62, 0xE4, 0x7D, 0x10, 0x23, 0x48, 0x08,
+        0x62, 0xD4, 0xF4, 0x18, 0xF7, 0xD9,
+        0x62, 0xDC, 0x0C, 0x10, 0x80, 0xF7, 0x7F
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn dynamic_registers() {
+    let a = Rq::R16;
+    let b: u8 = 3;
+    let c = Rq::R29;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; mov Rq(a), Rq(b)
+        ; mov Rq(b), Rq(Rq::R12)
+        ; imul Rq(Rq::R12), Rq(b)
+        ; imul Rq(a), Rq(c)
+        ; mov Rd(b), [Rq(a) + Rq(c) * 4]
+        ; add Rq(c), Rq(a), Rq(b)
+        ; sub Rd(a), Rd(c), 5
+    );
+
+    let mut expected = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch x64
+        ; mov r16, rbx
+        ; mov rbx, r12
+        ; imul r12, rbx
+        ; imul r16, r29
+        ; mov ebx, [Rq(16) + r29 * 4]
+        ; add r29, r16, rbx
+        ; sub r16d, r29d, 5
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+// dynamic registers that cannot be encoded by the chosen encoding are caught at runtime
+#[test]
+#[should_panic(expected = "registers 16-31 require the apx feature")]
+fn dynamic_register_without_apx() {
+    let reg: u8 = 20;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; .feature sse
+        ; mov Rq(reg), rax
+    );
+}
+
+#[test]
+#[should_panic(expected = "this instruction only supports registers 0-15")]
+fn dynamic_register_without_rex2() {
+    let reg: u8 = 20;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; crc32 Rd(reg), eax
+    );
+}
+
+#[test]
+#[should_panic(expected = "VEX and XOP encodings only support registers 0-15")]
+fn dynamic_register_vex() {
+    let reg: u8 = 20;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; andn Rd(reg), eax, ecx
+    );
+}
@@ -0,0 +1,193 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::arm::{ArmRelocation, encode_modified_immediate, encode_thumb_modified_immediate};
+use dynasmrt::relocations::Relocation;
+
+#[test]
+fn arm_labels() {
+    let mut ops = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(ops
+        ; .arch arm
+        ; start:
+        ; b >end
+        ; bl <start
+        ; beq >end
+        ; ldr r0, >data
+        ; adr r1, <start
+        ; vldr d0, >data
+        ; ldrb r2, <start
+        ; movw r3, 0x1234
+        ; end:
+        ; bx lr
+        ; data:
+        ; .dword 0x12345678
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0x06, 0x00, 0x00, 0xea, 0xfd, 0xff, 0xff, 0xeb, 0x04, 0x00, 0x00, 0x0a,
+        0x10, 0x00, 0x9f, 0xe5, 0x18, 0x10, 0x4f, 0xe2, 0x02, 0x0b, 0x9f, 0xed,
+        0x20, 0x20, 0x5f, 0xe5, 0x34, 0x32, 0x01, 0xe3, 0x1e, 0xff, 0x2f, 0xe1,
+        0x78, 0x56, 0x34, 0x12
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn thumb_labels_and_it_blocks() {
+    let mut ops = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(ops
+        ; .arch thumb
+        ; start:
+        ; b >end
+        ; bl <start
+        ; beq >end
+        ; cbz r0, >end
+        ; ldr r0, >data
+        ; adr r1, >data
+        ; vldr s0, >data
+        ; it eq
+        ; addeq r0, r0, r1
+        ; ite ne
+        ; movne r0, #1
+        ; moveq r0, #2
+        ; end:
+        ; bx lr
+        ; data:
+        ; .dword 0x12345678
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0x00, 0xf0, 0x13, 0xb8, 0xff, 0xf7, 0xfc, 0xff, 0x00, 0xf0, 0x0f, 0x80,
+        0x68, 0xb1, 0xdf, 0xf8, 0x1c, 0x00, 0x0f, 0xf2, 0x18, 0x01, 0x9f, 0xed,
+        0x05, 0x0a, 0x08, 0xbf, 0x00, 0xeb, 0x01, 0x00, 0x14, 0xbf, 0x4f, 0xf0,
+        0x01, 0x00, 0x4f, 0xf0, 0x02, 0x00, 0x70, 0x47, 0x78, 0x56, 0x34, 0x12
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn far_relocations() {
+    let mut ops = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(ops
+        ; .arch thumb
+        ; ->start:
+        ; bl ->end
+    );
+    for _ in 0 .. 0x1000 {
+        dynasm!(ops
+            ; .arch thumb
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch thumb
+        ; ->end:
+        ; b ->start
+        ; .dword ->start
+    );
+
+    let buf = ops.finalize().unwrap();
+    let word = |offset: usize| u32::from_le_bytes([buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]]);
+
+    // bl #0x4004
+    assert_eq!(word(0), 0xF800_F004);
+    // b.w #-0x4004
+    assert_eq!(word(0x4004), 0xBFFC_F7FB);
+    assert_eq!(word(0x4008) as i32, -0x4008);
+}
+
+#[test]
+fn dynamic_immediates() {
+    let imm = 0xFF00u32;
+    let offset = -8i32;
+    let shift = 3u32;
+
+    let mut ops = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(ops
+        ; .arch arm
+        ; add r0, r1, imm
+        ; ldr r2, [sp, offset]
+        ; ldrh r3, [r4], offset
+        ; mov r5, r6, lsl shift
+        ; ubfx r7, r8, shift, shift + 2
+        ; .arch thumb
+        ; orr r0, r1, imm
+        ; str r2, [sp, offset]!
+        ; ldrd r3, r4, [r5, offset * 2]
+    );
+
+    let mut expected = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(expected
+        ; .arch arm
+        ; add r0, r1, 0xFF00
+        ; ldr r2, [sp, -8]
+        ; ldrh r3, [r4], -8
+        ; mov r5, r6, lsl 3
+        ; ubfx r7, r8, 3, 5
+        ; .arch thumb
+        ; orr r0, r1, 0xFF00
+        ; str r2, [sp, -8]!
+        ; ldrd r3, r4, [r5, -16]
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn modified_immediates() {
+    assert_eq!(encode_modified_immediate(0xFF), Some(0x0FF));
+    assert_eq!(encode_modified_immediate(0xFF00_0000), Some(0x4FF));
+    assert_eq!(encode_modified_immediate(0xF000_000F), Some(0x2FF));
+    assert_eq!(encode_modified_immediate(0x101), None);
+
+    assert_eq!(encode_thumb_modified_immediate(0xAB), Some(0x0AB));
+    assert_eq!(encode_thumb_modified_immediate(0x00AB_00AB), Some(0x1AB));
+    assert_eq!(encode_thumb_modified_immediate(0xAB00_AB00), Some(0x2AB));
+    assert_eq!(encode_thumb_modified_immediate(0xABAB_ABAB), Some(0x3AB));
+    assert_eq!(encode_thumb_modified_immediate(0xFF00_0000), Some(0x47F));
+    assert_eq!(encode_thumb_modified_immediate(0x0000_01FE), Some(0xFFF));
+    assert_eq!(encode_thumb_modified_immediate(0x0000_0101), None);
+}
+
+#[test]
+fn relocation_roundtrip() {
+    let cases: &[(ArmRelocation, &[isize])] = &[
+        (ArmRelocation::BRANCH, &[8, 0, -0x200_0000 + 8, 0x1FF_FFFC + 8]),
+        (ArmRelocation::LDR, &[8 + 0xFFF, 8 - 0xFFF, 0]),
+        (ArmRelocation::ADR, &[8 + 0xFF00, 8 - 0x3FC, 8]),
+        (ArmRelocation::VLDR, &[8 + 0x3FC, 8 - 0x3FC]),
+        (ArmRelocation::TBRANCH, &[4, -0x100_0000 + 4, 0xFF_FFFE + 4, 0x12_3456]),
+        (ArmRelocation::TBCOND, &[4, -0x10_0000 + 4, 0xF_FFFE + 4]),
+        (ArmRelocation::TCBZ, &[4, 130]),
+        (ArmRelocation::TLDR, &[4 + 0xFFC, 4 - 0xFFC]),
+        (ArmRelocation::TADR, &[4 + 0xFFC, 4 - 0xFFC, 4]),
+        (ArmRelocation::TVLDR, &[4 + 0x3FC, 4 - 0x3FC]),
+    ];
+
+    for (relocation, values) in cases {
+        for &value in values.iter() {
+            let mut buf = [0u8; 4];
+            relocation.write_value(&mut buf, value).unwrap();
+            assert_eq!(relocation.read_value(&buf), value, "{:?}", relocation);
+        }
+    }
+
+    assert!(ArmRelocation::BRANCH.write_value(&mut [0; 4], 10).is_err());
+    assert!(ArmRelocation::ADR.write_value(&mut [0; 4], 8 + 0x101).is_err());
+    assert!(ArmRelocation::TCBZ.write_value(&mut [0; 2], 2).is_err());
+
+    // offsets that do not fit in 32 bits should not be truncated into encodable ones
+    #[cfg(target_pointer_width = "64")]
+    {
+        assert!(ArmRelocation::ADR.write_value(&mut [0; 4], 8 + 0x1_0000_0000).is_err());
+        assert!(ArmRelocation::ADR.write_value(&mut [0; 4], 8 - 0x1_0000_0000).is_err());
+        assert!(ArmRelocation::LDR.write_value(&mut [0; 4], isize::MIN + 8).is_err());
+    }
+}
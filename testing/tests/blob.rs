@@ -0,0 +1,97 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler, RelocatableBlob, AssemblyOffset};
+use dynasmrt::blob::BlobError;
+use dynasmrt::x86::X86Relocation;
+use dynasmrt::x64::X64Relocation;
+
+// assembles a function referencing both its own data and an extern function
+fn assemble(ops: &mut VecAssembler<X86Relocation>, function: usize) {
+    dynasm!(ops
+        ; .arch x86
+        ; mov eax, [->data]
+        ; call extern function
+        ; ret
+        ; ->data:
+        ; .dword 5
+    );
+}
+
+#[test]
+fn relocate_blob() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    assemble(&mut ops, 7);
+    let blob = ops.finalize_relocatable().unwrap();
+    assert_eq!(blob.relocations().len(), 2);
+
+    let blob = RelocatableBlob::<X86Relocation>::from_bytes(&blob.to_bytes()).unwrap();
+
+    let mut buf = vec![0; blob.code().len()];
+    blob.relocate(0x1000_0000, &mut buf, |target| {
+        assert_eq!(target, 7);
+        0x1234_5678
+    }).unwrap();
+
+    let mut expected = VecAssembler::<X86Relocation>::new(0x1000_0000);
+    assemble(&mut expected, 0x1234_5678);
+    assert_eq!(buf, expected.finalize().unwrap());
+}
+
+#[test]
+fn load_blob() {
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, [->data]
+        ; ret
+        ; ->data:
+        ; .dword 5
+    );
+    let blob = ops.finalize_relocatable().unwrap();
+    assert!(blob.relocations().is_empty());
+
+    let blob = RelocatableBlob::<X64Relocation>::from_bytes(&blob.to_bytes()).unwrap();
+    let buf = blob.load(|_| unreachable!()).unwrap();
+    let func = unsafe { buf.func::<extern "sysv64" fn() -> i32>(AssemblyOffset(0)) };
+    assert_eq!(func.call(()), 5);
+}
+
+#[test]
+fn malformed_blob() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    assemble(&mut ops, 7);
+    let bytes = ops.finalize_relocatable().unwrap().to_bytes();
+
+    match RelocatableBlob::<X86Relocation>::from_bytes(&bytes[.. bytes.len() - 1]) {
+        Err(BlobError::Malformed) => (),
+        r => panic!("unexpected result {:?}", r)
+    }
+    match RelocatableBlob::<X86Relocation>::from_bytes(&bytes[1 ..]) {
+        Err(BlobError::Malformed) => (),
+        r => panic!("unexpected result {:?}", r)
+    }
+}
+
+#[test]
+fn malformed_blob_location() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    assemble(&mut ops, 7);
+    let blob = ops.finalize_relocatable().unwrap();
+    let mut bytes = blob.to_bytes();
+
+    // point the first relocation at the very end of the address space
+    let code_len = blob.code().len();
+    let magic_len = bytes.len() - 24 - code_len - blob.relocations().len() * 20;
+    let at = magic_len + 16 + code_len + 8;
+    bytes[at .. at + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+
+    match RelocatableBlob::<X86Relocation>::from_bytes(&bytes) {
+        Err(BlobError::Malformed) => (),
+        r => panic!("unexpected result {:?}", r)
+    }
+}
@@ -72,3 +72,19 @@ fn bugreport_3() {
     let hex: String = hex.join(", ");
     assert_eq!(hex, "0xC4, 0xE1, 0x6B, 0x58, 0xCB, 0xC4, 0x41, 0x33, 0x58, 0xD3", "bugreport_3");
 }
+
+// ensure far jumps with a 16-bit offset aren't encoded as far calls
+#[test]
+fn bugreport_4() {
+    let mut ops = dynasmrt::x86::Assembler::new().unwrap();
+    dynasm!(ops
+       ; .arch x86
+       ; jmp WORD 0x1234, 0x8
+       ; jmpf WORD 0x1234, 0x8
+       ; call WORD 0x1234, 0x8
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("0x{:02X}", *x)).collect();
+    let hex: String = hex.join(", ");
+    assert_eq!(hex, "0x66, 0xEA, 0x34, 0x12, 0x08, 0x00, 0x66, 0xEA, 0x34, 0x12, 0x08, 0x00, 0x66, 0x9A, 0x34, 0x12, 0x08, 0x00", "bugreport_4");
+}
@@ -212,3 +212,29 @@ fn complex1() {
 fn complex_complex1() {
     complex1();
 }
+
+#[test]
+fn typed_func() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; lea eax, [rdi + rsi]
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    {
+        let executor = ops.reader();
+        let lock = executor.lock();
+        let add = unsafe { lock.func::<extern "sysv64" fn(i32, i32) -> i32>(start) };
+        assert_eq!(add.call((2, 3)), 5);
+    }
+
+    let buf = ops.finalize().unwrap();
+    let add = unsafe { buf.func::<extern "sysv64" fn(i32, i32) -> i32>(start) };
+    assert_eq!(add.call((-4, 1)), -3);
+
+    let add = unsafe { add.as_ptr() };
+    assert_eq!(add(1, 2), 3);
+}
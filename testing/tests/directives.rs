@@ -0,0 +1,63 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::x64::X64Relocation;
+
+#[test]
+fn data_directives() {
+    let wide = -2i64;
+    let tbyte = 0x0A09_0807_0605_0403_0201i128;
+    let long = 0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10i128;
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; .byte -1
+        ; .word 0x1234
+        ; .fword 0x1122_3344_5566, wide
+        ; .pword -1
+        ; .pword tbyte
+        ; .oword long
+    );
+
+    let expected = [
+        0xFF,
+        0x34, 0x12,
+        0x66, 0x55, 0x44, 0x33, 0x22, 0x11,
+        0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A,
+        0x10, 0x0F, 0x0E, 0x0D, 0x0C, 0x0B, 0x0A, 0x09, 0x08, 0x07, 0x06, 0x05, 0x04, 0x03, 0x02, 0x01
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn wide_pushes() {
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    ops.push_u48(0x8000_1234_5678);
+    ops.push_i80(-2);
+    ops.push_u128(1);
+    ops.push_i256(-1);
+    ops.push_u256(u128::max_value());
+
+    let mut expected = vec![0x78, 0x56, 0x34, 0x12, 0x00, 0x80];
+    expected.extend(&[0xFE, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]);
+    expected.push(0x01);
+    expected.extend(&[0x00; 15]);
+    expected.extend(&[0xFF; 32]);
+    expected.extend(&[0xFF; 16]);
+    expected.extend(&[0x00; 16]);
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+#[should_panic(expected = "value does not fit in 48 bits")]
+fn wide_push_out_of_range() {
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    ops.push_u48(0x1_0000_0000_0000);
+}
@@ -0,0 +1,248 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, VecAssembler};
+use dynasmrt::x64::{X64Relocation, Rq, Rx, Rt, DynamicRegister};
+use dynasmrt::aarch64::{Aarch64Relocation, X, XSP, V};
+use std::convert::TryFrom;
+
+#[test]
+fn x64_typed_registers() {
+    let dst = Rq::R12;
+    let src: u8 = 3;
+    let vec = Rx::XMM9;
+    let tile = Rt::TMM5;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; mov Rq(dst), Rq(src)
+        ; mov Rd(dst), [Rq(Rq::RBP) + 8]
+        ; movaps Rx(vec), Rx(Rx::XMM1)
+        ; vaddps Rx(vec), Rx(vec), Rx(Rx::XMM1)
+        ; tileloadd Rt(tile), [Rq(dst) + Rq(src) * 4]
+        ; tdpbssd Rt(tile), Rt(Rt::TMM2), Rt(3)
+        ; tilezero Rt(tile)
+    );
+
+    let mut expected = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch x64
+        ; mov r12, rbx
+        ; mov r12d, [Rq(5) + 8]
+        ; movaps xmm9, xmm1
+        ; vaddps Rx(9), Rx(9), Rx(1)
+        ; tileloadd tmm5, [Rq(12) + rbx * 4]
+        ; tdpbssd tmm5, tmm2, tmm3
+        ; tilezero tmm5
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn aarch64_typed_registers() {
+    let dst = X::X19;
+    let vec = V::V30;
+
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; add X(dst), XSP(XSP::SP), 16
+        ; orr W(dst), W(X::XZR), W(7)
+        ; fadd D(vec), D(vec), D(V::V2)
+    );
+
+    let mut expected = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch aarch64
+        ; add x19, sp, 16
+        ; orr w19, wzr, w7
+        ; fadd d30, d30, d2
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn riscv64_typed_registers() {
+    use dynasmrt::riscv64::{Riscv64Relocation, X, F};
+
+    let dst = X::A0;
+    let vec = F::FA1;
+
+    let mut ops = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch riscv64
+        ; addi X(dst), X(X::SP), 16
+        ; ld X(dst), 8(X(X::FP))
+        ; fadd.d F(vec), F(vec), F(2)
+    );
+
+    let mut expected = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch riscv64
+        ; addi a0, sp, 16
+        ; ld a0, 8(s0)
+        ; fadd.d fa1, fa1, ft2
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn loongarch64_typed_registers() {
+    use dynasmrt::loongarch64::{Loongarch64Relocation, R, F, VR, XR};
+
+    let dst = R::A0;
+    let float = F::FA1;
+    let vec = VR::VR5;
+
+    let mut ops = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; addi.d R(dst), R(R::SP), 16
+        ; ld.d R(dst), R(R::FP), 8
+        ; fadd.d F(float), F(float), F(8)
+        ; vadd.b VR(vec), VR(vec), VR(1)
+        ; xvor.v XR(XR::XR2), XR(3), XR(4)
+    );
+
+    let mut expected = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch loongarch64
+        ; addi.d $a0, $sp, 16
+        ; ld.d $a0, $fp, 8
+        ; fadd.d $fa1, $fa1, $ft0
+        ; vadd.b $vr5, $vr5, $vr1
+        ; xvor.v $xr2, $xr3, $xr4
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn arm_typed_registers() {
+    use dynasmrt::arm::{ArmRelocation, R, S, D};
+
+    let dst = R::R4;
+    let single = S::S3;
+    let double = D::D17;
+
+    let mut ops = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(ops
+        ; .arch arm
+        ; add R(dst), R(R::SP), 16
+        ; ldr R(dst), [R(R::FP), -8]
+        ; vadd.f32 S(single), S(single), S(2)
+        ; .arch thumb
+        ; mov R(dst), R(12)
+        ; vmov.f64 D(double), D(double)
+    );
+
+    let mut expected = VecAssembler::<ArmRelocation>::new(0);
+    dynasm!(expected
+        ; .arch arm
+        ; add r4, sp, 16
+        ; ldr r4, [fp, -8]
+        ; vadd.f32 s3, s3, s2
+        ; .arch thumb
+        ; mov r4, ip
+        ; vmov.f64 d17, d17
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+// plain integer encodings of any width keep working as dynamic registers
+#[test]
+fn integer_registers() {
+    let wide: u32 = 12;
+    let signed: i64 = 3;
+    let index: usize = 2;
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x64
+        ; mov Rq(wide), Rq(signed)
+        ; mov Rd(index + 1), [Rq(wide as u8) + Rq(1 << 1) * 4]
+        ; movaps Rx(wide), Rx(!0xFEu8)
+    );
+
+    let mut expected = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch x64
+        ; mov r12, rbx
+        ; mov ebx, [Rq(12) + rdx * 4]
+        ; movaps xmm12, xmm1
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch aarch64
+        ; add X(wide), XSP(31u32), 16
+        ; orr W(signed), W(index + 28), W(-1i32 & 7)
+    );
+
+    let mut expected = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch aarch64
+        ; add x12, sp, 16
+        ; orr w3, w30, w7
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+// integer literals, casts and arithmetic don't go through the typed register API,
+// so they can be used without dynasmrt in scope
+mod without_dynasmrt {
+    use dynasm::dynasm;
+    use ::dynasmrt::{DynasmApi, VecAssembler};
+    use ::dynasmrt::x64::X64Relocation;
+
+    // shadows the dynasmrt crate, so any path into it fails to resolve
+    #[allow(dead_code)]
+    mod dynasmrt {}
+
+    #[test]
+    fn integer_expressions() {
+        let reg: u8 = 3;
+
+        let mut ops = VecAssembler::<X64Relocation>::new(0);
+        dynasm!(ops
+            ; .arch x64
+            ; mov Rq(12), Rq(reg as u8)
+            ; add Rd(reg + 1), Rd(2 * 4)
+        );
+        assert_eq!(ops.finalize().unwrap(), [0x49, 0x89, 0xDC, 0x44, 0x01, 0xC4]);
+    }
+}
+
+#[test]
+fn register_conversions() {
+    assert_eq!(Rq::try_from(13), Ok(Rq::R13));
+    assert_eq!(Rq::try_from(16), Ok(Rq::R16));
+    assert_eq!(Rq::try_from(32), Err(dynasmrt::x64::InvalidRegister(32)));
+    assert_eq!(u8::from(Rq::RSI), 6);
+    assert_eq!(dynasmrt::x64::Rh::try_from(0), Err(dynasmrt::x64::InvalidRegister(0)));
+    assert_eq!(dynasmrt::x64::Rh::try_from(5).map(|r| r.code()), Ok(5));
+    assert_eq!(DynamicRegister::<Rx>::max_code(&Rx::XMM0), 15);
+    assert_eq!(DynamicRegister::<Rq>::max_code(&Rq::RAX), 31);
+    assert_eq!(DynamicRegister::<Rx>::max_code(&20u8), 255);
+
+    assert_eq!(X::try_from(31), Ok(X::XZR));
+    assert_eq!(XSP::try_from(31), Ok(XSP::SP));
+    assert_eq!(V::try_from(32), Err(dynasmrt::aarch64::InvalidRegister(32)));
+
+    assert_eq!(dynasmrt::riscv64::X::try_from(8), Ok(dynasmrt::riscv64::X::FP));
+    assert_eq!(u8::from(dynasmrt::riscv64::F::FS0), 8);
+    assert_eq!(dynasmrt::riscv64::F::try_from(32), Err(dynasmrt::riscv64::InvalidRegister(32)));
+
+    assert_eq!(dynasmrt::loongarch64::R::try_from(22), Ok(dynasmrt::loongarch64::R::FP));
+    assert_eq!(u8::from(dynasmrt::loongarch64::F::FS0), 24);
+    assert_eq!(dynasmrt::loongarch64::XR::try_from(32), Err(dynasmrt::loongarch64::InvalidRegister(32)));
+
+    assert_eq!(dynasmrt::arm::R::try_from(13), Ok(dynasmrt::arm::R::SP));
+    assert_eq!(u8::from(dynasmrt::arm::D::D31), 31);
+    assert_eq!(dynasmrt::arm::S::try_from(32), Err(dynasmrt::arm::InvalidRegister(32)));
+}
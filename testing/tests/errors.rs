@@ -0,0 +1,132 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, DynasmError, TargetKind, AssemblyOffset};
+
+// relocation errors should report where the relocation was emitted, and why it failed
+#[test]
+fn impossible_relocation_location() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp BYTE >target
+        ; .bytes [0x90u8; 200].iter()
+        ; target:
+    );
+
+    let errors = ops.commit().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    let err = &errors[0];
+    match err {
+        DynasmError::ImpossibleRelocation(site, cause) => {
+            assert_eq!(site.target, TargetKind::Forward("target"));
+            assert_eq!(site.location, AssemblyOffset(2));
+            assert_eq!(site.source.map(|s| s.file), Some(file!()));
+            assert_eq!(cause.value, 200);
+            assert_eq!(cause.max, 127);
+        },
+        e => panic!("unexpected error {:?}", e)
+    }
+
+    let message = err.to_string();
+    assert!(message.starts_with("relocation at offset 0x2 ("), "{}", message);
+    assert!(message.ends_with(") to forward label `target` out of range (value 200, max 127)"), "{}", message);
+}
+
+#[test]
+fn unresolved_relocation_location() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; nop
+        ; jmp ->missing
+    );
+
+    match ops.commit().as_ref().map_err(|e| &e[..]) {
+        Err([DynasmError::UnresolvedRelocation(site)]) => {
+            assert_eq!(site.target, TargetKind::Global("missing"));
+            assert_eq!(site.location, AssemblyOffset(6));
+            assert!(site.source.is_some());
+        },
+        r => panic!("unexpected result {:?}", r)
+    }
+}
+
+// all problems should be reported by a single commit, not just the first
+#[test]
+fn multiple_errors() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp BYTE >far_away
+        ; .bytes [0x90u8; 200].iter()
+        ; far_away:
+        ; jmp ->missing1
+        ; jmp ->missing2
+        ; jmp >missing3
+    );
+
+    let errors = ops.commit().unwrap_err();
+    let offsets: Vec<_> = errors.iter().map(|e| match e {
+        DynasmError::ImpossibleRelocation(site, _) => site.location.0,
+        DynasmError::UnresolvedRelocation(site) => site.location.0,
+        e => panic!("unexpected error {:?}", e)
+    }).collect();
+    assert_eq!(offsets, [2, 207, 212, 217]);
+
+    // the errors have been reported, so the assembler can continue
+    assert!(ops.commit().is_ok());
+}
+
+// a source location only applies to the relocation emitted right after it, so relocations
+// emitted by hand afterwards must not report a stale location
+#[test]
+fn source_location_per_relocation() {
+    use dynasmrt::relocations::{Relocation, RelocationSize};
+    use dynasmrt::x64::X64Relocation;
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp ->missing1
+    );
+    ops.push_u32(0);
+    ops.global_relocation("missing2", 0, X64Relocation::from_size(RelocationSize::DWord));
+
+    let sources: Vec<_> = ops.commit().unwrap_err().iter().map(|e| match e {
+        DynasmError::UnresolvedRelocation(site) => site.source.map(|s| s.file),
+        e => panic!("unexpected error {:?}", e)
+    }).collect();
+    assert_eq!(sources, [Some(file!()), None]);
+}
+
+// riscv64 branches are halfword aligned, so their reported range should match the encoded immediate
+#[test]
+fn riscv64_relocation_range() {
+    let mut ops = dynasmrt::VecAssembler::<dynasmrt::riscv64::Riscv64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch riscv64
+        ; c.j >target
+        ; jal x0, >target
+        ; beq x1, x2, >target
+        ; c.beqz x8, >target
+        ; .bytes [0u8; 0x100000].iter()
+        ; target:
+    );
+
+    let errors = ops.finalize().unwrap_err();
+    let ranges: Vec<_> = errors.iter().map(|e| match e {
+        DynasmError::ImpossibleRelocation(_, cause) => (cause.min, cause.max, cause.alignment),
+        e => panic!("unexpected error {:?}", e)
+    }).collect();
+    assert_eq!(ranges, [
+        (-0x800, 0x7FE, 2),
+        (-0x10_0000, 0xF_FFFE, 2),
+        (-0x1000, 0xFFE, 2),
+        (-0x100, 0xFE, 2),
+    ]);
+}
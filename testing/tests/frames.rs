@@ -0,0 +1,113 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, VecAssembler};
+use dynasmrt::x64::{X64Relocation, CallingConvention, Rq};
+use dynasmrt::aarch64::{Aarch64Relocation, X};
+
+#[test]
+fn x64_win64_frame() {
+    let frame = dynasmrt::x64::Frame::new(CallingConvention::Win64, &[Rq::RBX, Rq::R12, Rq::RAX, Rq::RDI, Rq::RBX], 20);
+    assert_eq!(frame.saved_registers(), &[Rq::RBX, Rq::RDI, Rq::R12]);
+    assert_eq!(frame.locals_offset(), 32);
+    assert_eq!(frame.allocated(), 64);
+
+    let mut ops = VecAssembler::<X64Relocation>::new(0);
+    frame.prologue(&mut ops);
+    frame.epilogue(&mut ops);
+
+    let mut expected = VecAssembler::<X64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch x64
+        ; push rbx
+        ; push rdi
+        ; push r12
+        ; sub rsp, BYTE 64
+        ; add rsp, BYTE 64
+        ; pop r12
+        ; pop rdi
+        ; pop rbx
+        ; ret
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+fn x64_sysv_frame() {
+    let frame = dynasmrt::x64::Frame::new(CallingConvention::SysV, &[Rq::RSI, Rq::RDI, Rq::R13, Rq::RBP], 0x1000);
+    assert_eq!(frame.saved_registers(), &[Rq::RBP, Rq::R13]);
+    assert_eq!(frame.allocated(), 0x1008);
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    frame.prologue(&mut ops);
+    dynasm!(ops
+        ; .arch x64
+        ; lea r13, [rdi + 1]
+        ; mov [rsp + 0x800], r13
+        ; mov rax, [rsp + 0x800]
+    );
+    frame.epilogue(&mut ops);
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 10], &[0x55, 0x41, 0x55, 0x48, 0x81, 0xEC, 0x08, 0x10, 0x00, 0x00u8]);
+
+    let func = unsafe { buf.func::<extern "sysv64" fn(i64) -> i64>(start) };
+    assert_eq!(func.call((41,)), 42);
+}
+
+#[test]
+fn aarch64_frame() {
+    let frame = dynasmrt::aarch64::Frame::new(&[X::X0, X::X19, X::X20, X::X21, X::X28, X::X29, X::X30], 0x1010);
+    assert_eq!(frame.saved_registers(), &[X::X19, X::X20, X::X21, X::X28]);
+    assert_eq!(frame.allocated(), 0x1010);
+
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    frame.prologue(&mut ops);
+    frame.epilogue(&mut ops);
+
+    let mut expected = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch aarch64
+        ; stp x29, x30, [sp, #-16]!
+        ; mov x29, sp
+        ; stp x19, x20, [sp, #-16]!
+        ; stp x21, x28, [sp, #-16]!
+        ; sub sp, sp, #1, lsl #12
+        ; sub sp, sp, #16
+        ; add sp, sp, #1, lsl #12
+        ; add sp, sp, #16
+        ; ldp x21, x28, [sp], #16
+        ; ldp x19, x20, [sp], #16
+        ; ldp x29, x30, [sp], #16
+        ; ret
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+
+    let frame = dynasmrt::aarch64::Frame::new(&[X::X19], 0);
+    let mut ops = VecAssembler::<Aarch64Relocation>::new(0);
+    frame.prologue(&mut ops);
+    frame.epilogue(&mut ops);
+
+    let mut expected = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch aarch64
+        ; stp x29, x30, [sp, #-16]!
+        ; mov x29, sp
+        ; str x19, [sp, #-16]!
+        ; ldr x19, [sp], #16
+        ; ldp x29, x30, [sp], #16
+        ; ret
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
+
+#[test]
+#[should_panic(expected = "stack frame too large")]
+fn x64_frame_too_large() {
+    dynasmrt::x64::Frame::new(CallingConvention::SysV, &[], usize::MAX);
+}
@@ -0,0 +1,199 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, Config, AssemblyOffset};
+
+use std::io;
+
+#[test]
+fn poisoned_tail() {
+    let config = Config {
+        poison: Some(dynasmrt::x64::INT3),
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 1
+        ; ret
+    );
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[0xB8, 0x01, 0x00, 0x00, 0x00, 0xC3]);
+
+    let tail = unsafe { std::slice::from_raw_parts(buf.as_ptr().add(buf.len()), buf.size() - buf.len()) };
+    assert!(tail.iter().all(|&b| b == 0xCC));
+}
+
+#[test]
+fn guard_pages() {
+    let config = Config {
+        guard_pages: true,
+        poison: Some(dynasmrt::x64::INT3),
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 5
+        ; jmp >end
+    );
+    // force the buffer to grow
+    for _ in 0 .. 0x2000 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch x64
+        ; end:
+        ; ret
+    );
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(buf.len(), 0x200B);
+    assert_eq!(buf.size() % 4096, 0);
+
+    let func = unsafe { buf.func::<extern "C" fn() -> i32>(start) };
+    assert_eq!(func.call(()), 5);
+}
+
+#[test]
+fn sealed_buffer() {
+    let config = Config {
+        seal: true,
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; ret
+        ; nop
+        ; nop
+        ; nop
+    );
+
+    let buf = ops.finalize().unwrap();
+    assert!(buf.is_sealed());
+
+    let err = unsafe { buf.patch_atomic(AssemblyOffset(0), [0x90, 0x90, 0x90, 0x90]) }.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+
+    let err = buf.make_mut().unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn strict_patch() {
+    let config = Config {
+        strict: true,
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; ret
+        ; nop
+        ; nop
+        ; nop
+    );
+    ops.commit().unwrap();
+
+    let err = unsafe { ops.patch_atomic(AssemblyOffset(0), [0x90, 0x90, 0x90, 0x90]) }.unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+}
+
+#[test]
+fn strict_alter() {
+    let config = Config {
+        strict: true,
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; ret
+    );
+
+    let err = ops.alter(|modifier| {
+        dynasm!(modifier
+            ; .arch x64
+            ; nop
+        );
+    }).unwrap_err();
+    assert_eq!(err, vec![dynasmrt::DynasmError::StrictMode]);
+}
+
+// in strict mode, new code is mapped in after the committed code, which stays in place
+#[test]
+fn strict_commit() {
+    let config = Config {
+        strict: true,
+        poison: Some(dynasmrt::x64::INT3),
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_capacity_and_config(0x3000, config).unwrap();
+    let five = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 5
+        ; ret
+    );
+    ops.commit().unwrap();
+    let reader = ops.reader();
+    let first = reader.lock().as_ptr();
+
+    // the second function starts on the page the first one is on, and ends on the next one
+    dynasm!(ops
+        ; .arch x64
+        ; .bytes [0x90u8; 0x1000].iter()
+    );
+    let six = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 6
+        ; ret
+    );
+    ops.commit().unwrap();
+    let second = reader.lock().as_ptr();
+    if cfg!(target_os = "linux") {
+        assert_eq!(first, second);
+    }
+
+    {
+        let lock = reader.lock();
+        assert_eq!(unsafe { lock.func::<extern "sysv64" fn() -> i32>(five) }.call(()), 5);
+        assert_eq!(unsafe { lock.func::<extern "sysv64" fn() -> i32>(six) }.call(()), 6);
+    }
+
+    // growing the buffer still moves the code
+    dynasm!(ops
+        ; .arch x64
+        ; .bytes [0x90u8; 0x3000].iter()
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    drop(reader);
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[.. 6], &[0xB8, 0x05, 0x00, 0x00, 0x00, 0xC3]);
+    assert_eq!(&buf[six.0 .. six.0 + 6], &[0xB8, 0x06, 0x00, 0x00, 0x00, 0xC3]);
+    assert_eq!(buf.len(), 6 + 0x1000 + 6 + 0x3000 + 1);
+    assert_eq!(unsafe { buf.func::<extern "sysv64" fn() -> i32>(six) }.call(()), 6);
+}
+
+#[test]
+fn empty_poison() {
+    let config = Config {
+        poison: Some(&[]),
+        ..Config::default()
+    };
+    let err = dynasmrt::x64::Assembler::with_config(config).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+}
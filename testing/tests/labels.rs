@@ -0,0 +1,223 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, DynasmError, TargetKind, AssemblyOffset, LabelScope};
+
+// the same global label names can be reused in different scopes
+#[test]
+fn scoped_global_labels() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; ->shared:
+        ; .scope
+        ; ->entry:
+        ; jmp ->entry
+        ; jmp ->shared
+        ; .scope
+        ; nop
+        ; ->entry:
+        ; jmp ->entry
+        ; jmp ->shared
+        ; .scope end
+    );
+    let first = ops.labels().resolve_global_in(LabelScope::ROOT, "shared");
+    assert_eq!(first, Ok(AssemblyOffset(0)));
+    assert!(ops.labels().resolve_global("entry").is_err());
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0xE9, 0xFB, 0xFF, 0xFF, 0xFF,
+        0xE9, 0xF6, 0xFF, 0xFF, 0xFF,
+        0x90,
+        0xE9, 0xFB, 0xFF, 0xFF, 0xFF,
+        0xE9, 0xEB, 0xFF, 0xFF, 0xFFu8,
+    ] as &[u8]);
+}
+
+// local labels cannot be resolved across scopes
+#[test]
+fn scoped_local_labels() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp >next
+        ; .scope
+        ; next:
+        ; jmp <next
+        ; .scope
+        ; jmp <next
+    );
+
+    let errors = ops.commit().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    match &errors[0] {
+        DynasmError::UnresolvedRelocation(site) => assert_eq!(site.target, TargetKind::Forward("next")),
+        e => panic!("unexpected error {:?}", e)
+    }
+    match &errors[1] {
+        DynasmError::UnresolvedRelocation(site) => assert_eq!(site.target, TargetKind::Backward("next")),
+        e => panic!("unexpected error {:?}", e)
+    }
+}
+
+// a local label redefined in a new scope shadows nothing from the earlier scope: backward references
+// only see definitions from their own scope, and forward references never bind across a scope boundary
+#[test]
+fn local_label_shadowing_across_scopes() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .scope
+        ; next:
+        ; nop
+        ; next:
+        ; jmp <next
+        ; .scope
+        ; nop
+        ; next:
+        ; jmp <next
+        ; jmp >next
+        ; .scope end
+        ; next:
+    );
+
+    match ops.commit().as_ref().map_err(|e| &e[..]) {
+        Err([DynasmError::UnresolvedRelocation(site)]) => {
+            assert_eq!(site.target, TargetKind::Forward("next"));
+            assert_eq!(site.location, AssemblyOffset(17));
+        },
+        r => panic!("unexpected result {:?}", r)
+    }
+    assert_eq!(ops.labels().resolve_local("next"), Ok(AssemblyOffset(17)));
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[..12], &[
+        0x90,
+        0xE9, 0xFB, 0xFF, 0xFF, 0xFF,
+        0x90,
+        0xE9, 0xFB, 0xFF, 0xFF, 0xFFu8,
+    ] as &[u8]);
+}
+
+// global labels can have names determined at runtime
+#[test]
+fn named_labels() {
+    let names: Vec<String> = vec!["Foo::bar".into(), "Foo::baz".into()];
+
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; jmp ->(&names[1])
+        ; ->(&names[0]):
+        ; nop
+        ; ->(&names[1]):
+        ; jmp ->(names[0].as_str())
+    );
+    ops.commit().unwrap();
+
+    assert_eq!(ops.labels().resolve_named("Foo::bar"), Ok(AssemblyOffset(5)));
+    assert_eq!(ops.labels().resolve_named("Foo::baz"), Ok(AssemblyOffset(6)));
+    assert!(ops.labels().resolve_named("Foo::qux").is_err());
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0xE9, 0x01, 0x00, 0x00, 0x00,
+        0x90,
+        0xE9, 0xFA, 0xFF, 0xFF, 0xFFu8,
+    ] as &[u8]);
+}
+
+#[test]
+fn named_label_errors() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let name = String::from("Foo::missing");
+    dynasm!(ops
+        ; .arch x64
+        ; ->("Foo::twice"):
+        ; ->("Foo::twice"):
+        ; jmp ->(&name)
+    );
+
+    let errors = ops.commit().unwrap_err();
+    assert_eq!(errors[0], DynasmError::DuplicateLabel(dynasmrt::LabelKind::Named("Foo::twice".into())));
+    match &errors[1] {
+        DynasmError::UnresolvedRelocation(site) => assert_eq!(site.target, TargetKind::Named("Foo::missing".into())),
+        e => panic!("unexpected error {:?}", e)
+    }
+}
+
+// named labels ignore label scopes, so they can be referenced from any scope but not redefined in one
+#[test]
+fn named_labels_ignore_scopes() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch x64
+        ; .scope
+        ; ->("Foo::bar"):
+        ; nop
+        ; .scope
+        ; jmp ->("Foo::bar")
+        ; ->("Foo::bar"):
+        ; .scope end
+    );
+
+    let errors = ops.commit().unwrap_err();
+    assert_eq!(errors, [DynasmError::DuplicateLabel(dynasmrt::LabelKind::Named("Foo::bar".into()))]);
+    assert_eq!(ops.labels().resolve_named("Foo::bar"), Ok(AssemblyOffset(0)));
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&*buf, &[
+        0x90,
+        0xE9, 0xFA, 0xFF, 0xFF, 0xFFu8,
+    ] as &[u8]);
+}
+
+#[test]
+fn label_table() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let dynamic = ops.new_dynamic_label();
+    dynasm!(ops
+        ; .arch x64
+        ; ->start:
+        ; nop
+        ; loop_:
+        ; nop
+        ; loop_:
+        ; jmp <loop_
+        ; =>dynamic
+        ; nop
+        ; .scope
+        ; ->start:
+        ; ->("Foo::bar"):
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    let (buf, labels) = ops.finalize_with_labels().unwrap();
+
+    let mut table: Vec<_> = labels.iter().map(|(label, offset)| (label.to_string(), offset.0)).collect();
+    table.sort();
+    assert_eq!(table, vec![
+        ("Foo::bar".to_string(), 8),
+        ("dynamic0".to_string(), 7),
+        ("loop_".to_string(), 1),
+        ("loop_".to_string(), 2),
+        ("scope1::start".to_string(), 8),
+        ("start".to_string(), 0),
+    ]);
+
+    let mut map = Vec::new();
+    labels.write_symbol_map(&mut map, 0x1000, buf.len()).unwrap();
+    assert_eq!(String::from_utf8(map).unwrap(), "\
+        1000 1 start\n\
+        1001 1 loop_\n\
+        1002 5 loop_\n\
+        1007 1 dynamic0\n\
+        1008 0 scope1::start\n\
+        1008 1 Foo::bar\n");
+}
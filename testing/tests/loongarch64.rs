@@ -0,0 +1,208 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::loongarch64::Loongarch64Relocation;
+
+// The expected encodings in this file have been derived by hand from the LoongArch reference manual.
+// Encoding tests for every instruction form can be generated with tools/loongarch64_gen_tests.py,
+// which needs an assembler that supports loongarch64 (LLVM 16 or binutils 2.38 and later).
+
+#[test]
+fn instructions() {
+    let mut ops = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; add.d $a0, $a1, $a2
+        ; addi.d $sp, $sp, -16
+        ; st.d $ra, $sp, 8
+        ; ld.d ra, sp, 8
+        ; ldptr.d $t0, $a0, 0x100
+        ; alsl.d $a0, $a1, $a2, 3
+        ; bytepick.d $a0, $a1, $a2, 5
+        ; bstrpick.d $a0, $a1, 31, 8
+        ; slli.d $t1, $t1, 63
+        ; lu12i.w $t0, -1
+        ; amadd_db.d $a0, $a1, $a2
+        ; rdtime.d $a0, $zero
+        ; syscall 0
+        ; dbar 0
+        ; preld 0, $a0, 64
+        ; fadd.d $fa0, $fa1, $ft0
+        ; fcmp.clt.d $fcc1, $fa0, $fa1
+        ; fsel $fa0, $fa1, $fa2, $fcc3
+        ; movgr2fr.d $fa0, $a0
+        ; ffint.d.l $fa0, $fa0
+        ; movfcsr2gr $a0, $fcsr0
+        ; vadd.w $vr0, $vr1, $vr2
+        ; xvadd.d $xr3, $xr4, $xr5
+        ; vld $vr0, $a0, 16
+        ; xvst $xr1, $sp, -32
+        ; vreplgr2vr.w $vr1, $a1
+        ; vpickve2gr.du $a0, $vr2, 1
+        ; xvpermi.d $xr0, $xr1, 0x4E
+        ; vfmadd.d $vr0, $vr1, $vr2, $vr3
+    );
+
+    let expected = [
+        0xA4, 0x98, 0x10, 0x00,
+        0x63, 0xC0, 0xFF, 0x02,
+        0x61, 0x20, 0xC0, 0x29,
+        0x61, 0x20, 0xC0, 0x28,
+        0x8C, 0x00, 0x01, 0x26,
+        0xA4, 0x18, 0x2D, 0x00,
+        0xA4, 0x98, 0x0E, 0x00,
+        0xA4, 0x20, 0xDF, 0x00,
+        0xAD, 0xFD, 0x41, 0x00,
+        0xEC, 0xFF, 0xFF, 0x15,
+        0xC4, 0x94, 0x6A, 0x38,
+        0x04, 0x68, 0x00, 0x00,
+        0x00, 0x00, 0x2B, 0x00,
+        0x00, 0x00, 0x72, 0x38,
+        0x80, 0x00, 0xC1, 0x2A,
+        0x20, 0x20, 0x01, 0x01,
+        0x01, 0x04, 0x21, 0x0C,
+        0x20, 0x88, 0x01, 0x0D,
+        0x80, 0xA8, 0x14, 0x01,
+        0x00, 0x28, 0x1D, 0x01,
+        0x04, 0xC8, 0x14, 0x01,
+        0x20, 0x08, 0x0B, 0x70,
+        0x83, 0x94, 0x0B, 0x74,
+        0x80, 0x40, 0x00, 0x2C,
+        0x61, 0x80, 0xFF, 0x2C,
+        0xA1, 0x08, 0x9F, 0x72,
+        0x44, 0xF4, 0xF3, 0x72,
+        0x20, 0x38, 0xE9, 0x77,
+        0x20, 0x88, 0x21, 0x09
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn labels_and_pseudo_instructions() {
+    let mut ops = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; start:
+        ; bl >end
+        ; beq $a0, $a1, <start
+        ; bnez $a2, >end
+        ; bceqz $fcc0, <start
+        ; pcaddi $t0, >end
+        ; la.pcrel $a0, >end
+        ; call36 >end
+        ; tail36 $t0, <start
+        ; bgt $a0, $a1, <start
+        ; b <start
+        ; li.w $a1, -0x12345
+        ; li.w $a2, 0x7FF
+        ; li.w $a3, 0x800
+        ; li.w $a4, 0x3000
+        ; li.d $a5, 0x1234_5678_9ABC_DEF0
+        ; li.d $a6, 0xFFFF_FFFF
+        ; li.d $a7, -1
+        ; end:
+        ; ret
+    );
+
+    let expected = [
+        0x00, 0x64, 0x00, 0x54,
+        0x85, 0xFC, 0xFF, 0x5B,
+        0xC0, 0x5C, 0x00, 0x44,
+        0x1F, 0xF4, 0xFF, 0x4B,
+        0xAC, 0x02, 0x00, 0x18,
+        0x04, 0x00, 0x00, 0x1C,
+        0x84, 0x40, 0xC1, 0x02,
+        0x01, 0x00, 0x00, 0x1E,
+        0x21, 0x48, 0x00, 0x4C,
+        0x0C, 0x00, 0x00, 0x1E,
+        0x80, 0xDD, 0xFF, 0x4F,
+        0xA4, 0xD4, 0xFF, 0x63,
+        0xFF, 0xD3, 0xFF, 0x53,
+        0xA5, 0xFD, 0xFF, 0x15,
+        0xA5, 0xEC, 0xB2, 0x03,
+        0x06, 0xFC, 0x9F, 0x02,
+        0x07, 0x00, 0xA0, 0x03,
+        0x68, 0x00, 0x00, 0x14,
+        0xA9, 0x79, 0x35, 0x15,
+        0x29, 0xC1, 0xBB, 0x03,
+        0x09, 0xCF, 0x8A, 0x16,
+        0x29, 0x8D, 0x04, 0x03,
+        0x0A, 0xFC, 0xBF, 0x02,
+        0x0A, 0x00, 0x00, 0x16,
+        0x0B, 0xFC, 0xBF, 0x02,
+        0x20, 0x00, 0x00, 0x4C
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn far_relocations() {
+    let mut ops = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; ->start:
+        ; call36 ->end
+    );
+    for _ in 0 .. 0x10000 {
+        dynasm!(ops
+            ; .arch loongarch64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch loongarch64
+        ; ->end:
+        ; la.pcrel $t0, ->start
+        ; .dword ->start
+    );
+
+    let buf = ops.finalize().unwrap();
+    let word = |offset: usize| u32::from_le_bytes([buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]]);
+
+    // pcaddu18i ra, 1 ; jirl ra, ra, 8
+    assert_eq!(word(0), 0x1E00_0021);
+    assert_eq!(word(4), 0x4C00_0821);
+    // pcaddu12i t0, -0x40 ; addi.d t0, t0, -8
+    assert_eq!(word(0x4_0008), 0x1DFF_F80C);
+    assert_eq!(word(0x4_000C), 0x02FF_E18C);
+    assert_eq!(word(0x4_0010) as i32, -0x4_0010);
+}
+
+#[test]
+fn dynamic_immediates() {
+    let offset = -16i32;
+    let shift = 3u32;
+    let value = 0x12345i32;
+    let wide = 0x1234_5678_9ABC_DEF0i64;
+
+    let mut ops = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch loongarch64
+        ; addi.d $sp, $sp, offset
+        ; slli.d $a0, $a0, shift
+        ; alsl.d $a0, $a1, $a2, shift
+        ; ldptr.d $t0, $a0, offset * 2
+        ; beq $a0, $a1, offset
+        ; li.w $t0, value
+        ; li.d $t1, wide
+    );
+
+    let mut expected = VecAssembler::<Loongarch64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch loongarch64
+        ; addi.d $sp, $sp, -16
+        ; slli.d $a0, $a0, 3
+        ; alsl.d $a0, $a1, $a2, 3
+        ; ldptr.d $t0, $a0, -32
+        ; beq $a0, $a1, -16
+        ; lu12i.w $t0, 0x12
+        ; ori $t0, $t0, 0x345
+        ; li.d $t1, 0x1234_5678_9ABC_DEF0
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
@@ -0,0 +1,229 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, Config, CodeMemory, AssemblyOffset};
+use dynasmrt::mmap::HUGE_PAGE_SIZE;
+
+#[test]
+fn huge_pages() {
+    let config = Config {
+        huge_pages: true,
+        guard_pages: true,
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_config(config).unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 7
+        ; ret
+    );
+
+    let buf = ops.finalize().unwrap();
+    if cfg!(target_os = "linux") {
+        assert_eq!(buf.as_ptr() as usize % HUGE_PAGE_SIZE, 0);
+        assert_eq!(buf.size(), HUGE_PAGE_SIZE);
+    }
+
+    let func = unsafe { buf.func::<extern "C" fn() -> i32>(start) };
+    assert_eq!(func.call(()), 7);
+}
+
+#[test]
+fn with_capacity() {
+    let mut ops = dynasmrt::x64::Assembler::with_capacity(0x10000).unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 3
+        ; ret
+    );
+    ops.commit().unwrap();
+    let addr = ops.reader().lock().as_ptr() as usize;
+
+    for _ in 0 .. 0x8000 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+    ops.commit().unwrap();
+    assert_eq!(ops.reader().lock().as_ptr() as usize, addr);
+
+    let buf = ops.finalize().unwrap();
+    assert!(buf.size() >= 0x10000);
+    assert_eq!(buf.size() % dynasmrt::mmap::page_size(), 0);
+
+    let func = unsafe { buf.func::<extern "C" fn() -> i32>(start) };
+    assert_eq!(func.call(()), 3);
+}
+
+#[test]
+fn growth_policy() {
+    use dynasmrt::GrowthPolicy;
+
+    assert_eq!(GrowthPolicy::Double.grow(0x1000, 0x1000), 0x2000);
+    assert_eq!(GrowthPolicy::Double.grow(0x1000, 0x4000), 0x8000);
+    assert_eq!(GrowthPolicy::Linear(0x3000).grow(0x1000, 0x4000), 0x7000);
+    assert_eq!(GrowthPolicy::Custom(|_, required| required).grow(0x1000, 0x4000), 0x4001);
+
+    let config = Config {
+        growth: GrowthPolicy::Linear(0x100000),
+        ..Config::default()
+    };
+    let mut ops = dynasmrt::x64::Assembler::with_capacity_and_config(0x1000, config).unwrap();
+    for _ in 0 .. 0x2000 {
+        dynasm!(ops
+            ; .arch x64
+            ; nop
+        );
+    }
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(buf.size(), 0x101000);
+}
+
+// a memory backend that keeps code on the heap, for testing assemblers without executing the code.
+// Every allocation pretends to be located at a different address in the lower 4GB.
+#[derive(Debug, Default)]
+struct HeapCode {
+    data: Vec<u8>,
+    length: usize,
+    address: usize,
+}
+
+static NEXT_ADDRESS: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0x1000_0000);
+
+#[derive(Debug)]
+struct WritableHeapCode(HeapCode);
+
+impl std::ops::Deref for HeapCode {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.data[.. self.length]
+    }
+}
+
+impl std::ops::Deref for WritableHeapCode {
+    type Target = [u8];
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for WritableHeapCode {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.0.data[.. self.0.length]
+    }
+}
+
+impl dynasmrt::CodeMemory for HeapCode {
+    type Writable = WritableHeapCode;
+
+    fn allocate(size: usize, _config: &Config) -> std::io::Result<WritableHeapCode> {
+        Ok(WritableHeapCode(HeapCode {
+            data: vec![0; size],
+            length: 0,
+            address: NEXT_ADDRESS.fetch_add(0x100_0000, std::sync::atomic::Ordering::Relaxed)
+        }))
+    }
+
+    fn make_writable(self) -> std::io::Result<WritableHeapCode> {
+        Ok(WritableHeapCode(self))
+    }
+
+    fn address(&self) -> usize {
+        self.address
+    }
+
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl dynasmrt::WritableMemory for WritableHeapCode {
+    type Executable = HeapCode;
+
+    fn make_executable(self) -> std::io::Result<HeapCode> {
+        Ok(self.0)
+    }
+
+    fn set_len(&mut self, length: usize) {
+        self.0.length = length;
+    }
+
+    fn address(&self) -> usize {
+        self.0.address
+    }
+
+    fn size(&self) -> usize {
+        self.0.data.len()
+    }
+}
+
+fn assemble_x86<D: DynasmLabelApi<Relocation=dynasmrt::x86::X86Relocation>>(ops: &mut D) {
+    dynasm!(ops
+        ; .arch x86
+        ; mov eax, [->data]
+        ; call extern 0x1234_5678
+        ; ret
+        ; ->data:
+        ; .dword 5
+    );
+}
+
+#[test]
+fn custom_memory() {
+    use dynasmrt::x86::X86Relocation;
+
+    let mut ops = dynasmrt::Assembler::<X86Relocation, HeapCode>::with_capacity(16).unwrap();
+    assemble_x86(&mut ops);
+    ops.commit().unwrap();
+
+    // force the buffer to move
+    for _ in 0 .. 0x1000 {
+        dynasm!(ops
+            ; .arch x86
+            ; nop
+        );
+    }
+    ops.commit().unwrap();
+
+    let code = ops.finalize().unwrap();
+    assert_eq!(code.size(), 0x2000);
+
+    let mut expected = dynasmrt::VecAssembler::<X86Relocation>::new(code.address());
+    assemble_x86(&mut expected);
+    let expected = expected.finalize().unwrap();
+    assert_eq!(&code[.. expected.len()], &expected[..]);
+}
+
+#[test]
+fn custom_memory_alter() {
+    use dynasmrt::x86::X86Relocation;
+
+    let mut ops = dynasmrt::Assembler::<X86Relocation, HeapCode>::with_capacity(16).unwrap();
+    assemble_x86(&mut ops);
+    ops.commit().unwrap();
+
+    // overwrite the relocated instructions in place
+    ops.alter(|modifier| {
+        modifier.goto(AssemblyOffset(0));
+        dynasm!(modifier
+            ; .arch x86
+            ; mov eax, [->data]
+            ; call extern 0x1234_5678
+        );
+    }).unwrap();
+
+    let code = ops.finalize().unwrap();
+
+    let mut expected = dynasmrt::VecAssembler::<X86Relocation>::new(code.address());
+    assemble_x86(&mut expected);
+    let expected = expected.finalize().unwrap();
+    assert_eq!(&*code, &expected[..]);
+}
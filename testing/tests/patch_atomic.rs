@@ -0,0 +1,106 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::aarch64::Aarch64Relocation;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+// swap out an immediate while another thread keeps executing the code
+#[test]
+fn patch_atomic_x64() {
+    let mut ops = dynasmrt::x64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch x64
+        ; mov eax, 1
+        ; ret
+        ; nop
+        ; nop
+    );
+    ops.commit().unwrap();
+
+    let executor = ops.reader();
+    let patched = Arc::new(AtomicBool::new(false));
+    let flag = patched.clone();
+    let thread = std::thread::spawn(move || {
+        loop {
+            let done = flag.load(Ordering::SeqCst);
+            let lock = executor.lock();
+            let func: extern "C" fn() -> i32 = unsafe { std::mem::transmute(lock.ptr(start)) };
+            let result = func();
+            assert!(result == 1 || result == 2);
+            if done {
+                return result;
+            }
+        }
+    });
+
+    unsafe { ops.patch_atomic(start, [0xB8, 0x02, 0x00, 0x00, 0x00, 0xC3, 0x90, 0x90]) }.unwrap();
+    patched.store(true, Ordering::SeqCst);
+    assert_eq!(thread.join().unwrap(), 2);
+
+    let buf = ops.finalize().unwrap();
+    let func: extern "C" fn() -> i32 = unsafe { std::mem::transmute(buf.ptr(start)) };
+    assert_eq!(func(), 2);
+}
+
+// aarch64 code is patched one instruction at a time. It can only be executed on aarch64 hosts,
+// but the patch itself can be checked on any host.
+#[test]
+fn patch_atomic_aarch64() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    let start = ops.offset();
+    dynasm!(ops
+        ; .arch aarch64
+        ; mov w0, 1
+        ; ret
+    );
+    ops.commit().unwrap();
+
+    let mut patch = VecAssembler::<Aarch64Relocation>::new(0);
+    dynasm!(patch
+        ; .arch aarch64
+        ; mov w0, 2
+    );
+    let patch = patch.finalize().unwrap();
+
+    unsafe { ops.patch_atomic(start, [patch[0], patch[1], patch[2], patch[3]]) }.unwrap();
+
+    let buf = ops.finalize().unwrap();
+    assert_eq!(&buf[..4], &patch[..]);
+
+    #[cfg(target_arch = "aarch64")]
+    {
+        let func: extern "C" fn() -> i32 = unsafe { std::mem::transmute(buf.ptr(start)) };
+        assert_eq!(func(), 2);
+    }
+}
+
+// patches are written in place, and leave the rest of the buffer untouched
+#[test]
+fn patch_atomic_aarch64_offset() {
+    let mut ops = dynasmrt::aarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch aarch64
+        ; nop
+        ; nop
+        ; nop
+        ; nop
+    );
+    ops.commit().unwrap();
+
+    let nop = [0x1F, 0x20, 0x03, 0xD5];
+    let brk = [0x00, 0x00, 0x20, 0xD4];
+    unsafe { ops.patch_atomic(dynasmrt::AssemblyOffset(8), [brk[0], brk[1], brk[2], brk[3], brk[0], brk[1], brk[2], brk[3]]) }.unwrap();
+    unsafe { ops.patch_atomic(dynasmrt::AssemblyOffset(4), brk) }.unwrap();
+
+    let buf = ops.finalize().unwrap();
+    let expected: Vec<u8> = [nop, brk, brk, brk].iter().flatten().cloned().collect();
+    assert_eq!(&*buf, &expected[..]);
+}
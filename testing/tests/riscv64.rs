@@ -0,0 +1,103 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::riscv64::Riscv64Relocation;
+
+#[test]
+fn labels_and_pseudo_instructions() {
+    let mut ops = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch riscv64
+        ; start:
+        ; jal ra, >end
+        ; beq a0, a1, <start
+        ; bnez a2, >end
+        ; c.j >end
+        ; c.beqz s0, <start
+        ; call >end
+        ; tail <start
+        ; la a0, >end
+        ; j <start
+        ; li a1, -0x12345
+        ; li a2, 0x7ff
+        ; li a3, 0x3000
+        ; end:
+        ; ret
+    );
+
+    // assembled with llvm-mc
+    let expected = [
+        0xef, 0x00, 0xc0, 0x03, 0xe3, 0x0e, 0xb5, 0xfe, 0x63, 0x1a, 0x06, 0x02,
+        0x05, 0xa8, 0x6d, 0xd8, 0x97, 0x00, 0x00, 0x00, 0xe7, 0x80, 0xc0, 0x02,
+        0x17, 0x03, 0x00, 0x00, 0x67, 0x00, 0x83, 0xfe, 0x17, 0x05, 0x00, 0x00,
+        0x13, 0x05, 0xc5, 0x01, 0x6f, 0xf0, 0x9f, 0xfd, 0xb7, 0xe5, 0xfe, 0xff,
+        0x9b, 0x85, 0xb5, 0xcb, 0x13, 0x06, 0xf0, 0x7f, 0xb7, 0x36, 0x00, 0x00,
+        0x67, 0x80, 0x00, 0x00
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn far_relocations() {
+    let mut ops = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch riscv64
+        ; ->start:
+        ; call ->end
+    );
+    for _ in 0 .. 0x1000 {
+        dynasm!(ops
+            ; .arch riscv64
+            ; nop
+        );
+    }
+    dynasm!(ops
+        ; .arch riscv64
+        ; ->end:
+        ; la t0, ->start
+        ; .dword ->start
+    );
+
+    let buf = ops.finalize().unwrap();
+    let word = |offset: usize| u32::from_le_bytes([buf[offset], buf[offset + 1], buf[offset + 2], buf[offset + 3]]);
+
+    // auipc ra, 4 ; jalr ra, 8(ra)
+    assert_eq!(word(0), 0x0000_4097);
+    assert_eq!(word(4), 0x0080_80E7);
+    // auipc t0, -4 ; addi t0, t0, -8
+    assert_eq!(word(0x4008), 0xFFFF_C297);
+    assert_eq!(word(0x400C), 0xFF82_8293);
+    assert_eq!(word(0x4010) as i32, -0x4010);
+}
+
+#[test]
+fn dynamic_immediates() {
+    let offset = -16i32;
+    let shift = 3u32;
+    let value = 0x12345i32;
+
+    let mut ops = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(ops
+        ; .arch riscv64
+        ; sd ra, offset(sp)
+        ; slli a0, a0, shift
+        ; c.addi16sp sp, offset * 2
+        ; li t0, value
+    );
+
+    let mut expected = VecAssembler::<Riscv64Relocation>::new(0);
+    dynasm!(expected
+        ; .arch riscv64
+        ; sd ra, -16(sp)
+        ; slli a0, a0, 3
+        ; c.addi16sp sp, -32
+        ; lui t0, 0x12
+        ; addiw t0, t0, 0x345
+    );
+    assert_eq!(ops.finalize().unwrap(), expected.finalize().unwrap());
+}
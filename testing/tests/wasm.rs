@@ -0,0 +1,316 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+extern crate wasmparser;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmError, LabelKind};
+use dynasmrt::wasm::{FunctionAssembler, ModuleAssembler, StructureError, ValType, WasmApi};
+
+#[test]
+fn instructions() {
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; unreachable
+        ; nop
+        ; local.get 0
+        ; local.get 1
+        ; i32.add
+        ; local.tee 2
+        ; global.set 3
+        ; i32.const -1
+        ; i32.const 0xFFFF_FFFF
+        ; i32.const 624485
+        ; i64.const -123456
+        ; i64.const 64
+        ; f32.const 1.5
+        ; f64.const -2.0
+        ; i32.load
+        ; i64.load 8
+        ; i32.load8_u 0x80, 1
+        ; f64.store 16, 4
+        ; memory.size
+        ; memory.grow
+        ; i32.trunc_sat_f64_u
+        ; i64.extend32_s
+        ; f32.demote_f64
+        ; call 300
+        ; call_indirect 1
+        ; call_indirect 2, 1
+        ; select
+        ; drop
+        ; return
+        ; end
+    );
+
+    let expected = [
+        0x00,
+        0x00,
+        0x01,
+        0x20, 0x00,
+        0x20, 0x01,
+        0x6A,
+        0x22, 0x02,
+        0x24, 0x03,
+        0x41, 0x7F,
+        0x41, 0x7F,
+        0x41, 0xE5, 0x8E, 0x26,
+        0x42, 0xC0, 0xBB, 0x78,
+        0x42, 0xC0, 0x00,
+        0x43, 0x00, 0x00, 0xC0, 0x3F,
+        0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0,
+        0x28, 0x02, 0x00,
+        0x29, 0x03, 0x08,
+        0x2D, 0x00, 0x80, 0x01,
+        0x39, 0x02, 0x10,
+        0x3F, 0x00,
+        0x40, 0x00,
+        0xFC, 0x03,
+        0xC4,
+        0xB6,
+        0x10, 0xAC, 0x02,
+        0x11, 0x01, 0x00,
+        0x11, 0x02, 0x01,
+        0x1B,
+        0x1A,
+        0x0F,
+        0x0B
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn branch_depths() {
+    let mut ops = FunctionAssembler::new();
+    let exit = ops.new_dynamic_label();
+    ops.add_locals(2, ValType::I32);
+    ops.add_locals(1, ValType::F64);
+    dynasm!(ops
+        ; .arch wasm
+        ; block ->outer
+        ; loop ->top, i32
+        ; br_if ->outer
+        ; br ->top
+        ; block
+        ; br ->top
+        ; br_table ->outer, ->top, 0
+        ; end
+        ; end
+        ; block =>exit, f64
+        ; br =>exit
+        ; if
+        ; br =>exit
+        ; else
+        ; br ->outer
+        ; end
+        ; end
+        ; end
+        ; br 0
+        ; end
+    );
+
+    let expected = [
+        0x02, 0x02, 0x7F, 0x01, 0x7C,
+        0x02, 0x40,
+        0x03, 0x7F,
+        0x0D, 0x01,
+        0x0C, 0x00,
+        0x02, 0x40,
+        0x0C, 0x01,
+        0x0E, 0x02, 0x02, 0x01, 0x00,
+        0x0B,
+        0x0B,
+        0x02, 0x7C,
+        0x0C, 0x00,
+        0x04, 0x40,
+        0x0C, 0x01,
+        0x05,
+        0x0C, 0x02,
+        0x0B,
+        0x0B,
+        0x0B,
+        0x0C, 0x00,
+        0x0B
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn unknown_labels() {
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; block ->done
+        ; end
+        ; br ->done
+        ; br ->missing
+        ; end
+    );
+
+    assert_eq!(ops.finalize(), Err(vec![
+        DynasmError::UnknownLabel(LabelKind::Global("done")),
+        DynasmError::UnknownLabel(LabelKind::Global("missing"))
+    ]));
+}
+
+#[test]
+fn dynamic_immediates() {
+    let index = 2u32;
+    let small = -2i32;
+    let large = 1i64 << 40;
+    let half = 0.5f32;
+    let offset = 200u32;
+    let ty = 3u32;
+
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; local.get index
+        ; i32.const small
+        ; i64.const large
+        ; f32.const half
+        ; f64.const half
+        ; i32.load offset
+        ; block ty
+        ; end
+        ; end
+    );
+
+    let expected = [
+        0x00,
+        0x20, 0x02,
+        0x41, 0x7E,
+        0x42, 0x80, 0x80, 0x80, 0x80, 0x80, 0x20,
+        0x43, 0x00, 0x00, 0x00, 0x3F,
+        0x44, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xE0, 0x3F,
+        0x28, 0x02, 0xC8, 0x01,
+        0x02, 0x03,
+        0x0B,
+        0x0B
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn valid_module() {
+    let mut module = ModuleAssembler::new();
+    let log_type = module.add_type(&[ValType::I32], &[]);
+    let log = module.import_function("env", "log", log_type).unwrap();
+    let unary = module.add_type(&[ValType::I32], &[ValType::I32]);
+    assert_eq!(module.add_type(&[ValType::I32], &[ValType::I32]), unary);
+
+    // an iterative factorial, which logs and stores its result
+    let mut ops = FunctionAssembler::new();
+    ops.add_locals(1, ValType::I32);
+    dynasm!(ops
+        ; .arch wasm
+        ; i32.const 1
+        ; local.set 1
+        ; block ->done
+        ; loop ->again
+        ; local.get 0
+        ; i32.eqz
+        ; br_if ->done
+        ; local.get 1
+        ; local.get 0
+        ; i32.mul
+        ; local.set 1
+        ; local.get 0
+        ; i32.const 1
+        ; i32.sub
+        ; local.set 0
+        ; br ->again
+        ; end
+        ; end
+        ; local.get 1
+        ; call log
+        ; i32.const 0
+        ; local.get 1
+        ; i32.store
+        ; local.get 1
+        ; end
+    );
+    let factorial = module.add_function(unary, ops).unwrap();
+
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; local.get 0
+        ; if i32
+        ; i32.const 10
+        ; else
+        ; i32.const 20
+        ; end
+        ; end
+    );
+    let choose = module.add_function(unary, ops).unwrap();
+
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; block ->second
+        ; block ->first
+        ; local.get 0
+        ; br_table ->first, ->second
+        ; end
+        ; i32.const 1
+        ; return
+        ; end
+        ; i32.const 2
+        ; end
+    );
+    let switch = module.add_function(unary, ops).unwrap();
+
+    assert_eq!((log, factorial, choose, switch), (0, 1, 2, 3));
+
+    let memory = module.set_memory(1, Some(2));
+    module.export_function("factorial", factorial);
+    module.export_function("choose", choose);
+    module.export_function("switch", switch);
+    module.export_memory("memory", memory);
+
+    let bytes = module.finalize();
+    assert_eq!(&bytes[..8], b"\0asm\x01\x00\x00\x00");
+    wasmparser::validate(&bytes).unwrap();
+}
+
+#[test]
+fn invalid_structure() {
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; block ->outer
+        ; nop
+        ; end
+    );
+    assert_eq!(ops.finalize(), Err(vec![
+        DynasmError::from(StructureError::Unclosed)
+    ]));
+
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; end
+        ; block ->after
+        ; end
+        ; end
+    );
+    assert_eq!(ops.finalize(), Err(vec![
+        DynasmError::from(StructureError::CodeAfterEnd),
+        DynasmError::from(StructureError::UnmatchedEnd),
+        DynasmError::from(StructureError::UnmatchedEnd)
+    ]));
+
+    let mut module = ModuleAssembler::new();
+    let ty = module.add_type(&[], &[]);
+    let mut ops = FunctionAssembler::new();
+    dynasm!(ops
+        ; .arch wasm
+        ; end
+    );
+    module.add_function(ty, ops).unwrap();
+    assert!(module.import_function("env", "late", ty).is_err());
+}
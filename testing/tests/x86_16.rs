@@ -0,0 +1,195 @@
+#![feature(proc_macro_hygiene)]
+#![allow(unused_imports)]
+
+extern crate dynasmrt;
+extern crate dynasm;
+
+use dynasm::dynasm;
+use dynasmrt::{DynasmApi, DynasmLabelApi, VecAssembler};
+use dynasmrt::x86::X86Relocation;
+
+#[test]
+fn operand_and_address_size() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x86_16
+        ; mov ax, bx
+        ; mov eax, ebx
+        ; add ax, 5
+        ; add eax, 0x12345
+        ; mov ax, [bx + si]
+        ; mov ax, [bp]
+        ; mov ax, [di]
+        ; mov ax, [bp + di + 8]
+        ; mov cx, [0x1234]
+        ; mov ecx, [eax + 4]
+        ; mov cx, [ebx + ecx * 4]
+        ; push 5
+        ; push 0x1234
+        ; push ax
+        ; push eax
+        ; pop ds
+        ; lodsw
+        ; lodsd
+        ; movsd
+        ; cwd
+        ; cdq
+        ; int 0x10
+        ; mov cr0, eax
+        ; lea si, [bx + 0x10]
+        ; inc ax
+        ; inc eax
+        ; jmp 0x7C00, 0x0000
+        ; jmp 0x12345, 0x8
+        ; ret
+        ; iret
+        ; iretd
+        ; movd mm0, eax
+        ; addps xmm0, xmm1
+        ; addpd xmm0, xmm1
+    );
+
+    let expected = [
+        0x89, 0xD8,
+        0x66, 0x89, 0xD8,
+        0x83, 0xC0, 0x05,
+        0x66, 0x05, 0x45, 0x23, 0x01, 0x00,
+        0x8B, 0x00,
+        0x8B, 0x46, 0x00,
+        0x8B, 0x05,
+        0x8B, 0x43, 0x08,
+        0x8B, 0x0E, 0x34, 0x12,
+        0x67, 0x66, 0x8B, 0x48, 0x04,
+        0x67, 0x8B, 0x0C, 0x8B,
+        0x6A, 0x05,
+        0x68, 0x34, 0x12,
+        0x50,
+        0x66, 0x50,
+        0x1F,
+        0xAD,
+        0x66, 0xAD,
+        0x66, 0xA5,
+        0x99,
+        0x66, 0x99,
+        0xCD, 0x10,
+        0x0F, 0x22, 0xC0,
+        0x8D, 0x77, 0x10,
+        0x40,
+        0x66, 0x40,
+        0xEA, 0x00, 0x7C, 0x00, 0x00,
+        0x66, 0xEA, 0x45, 0x23, 0x01, 0x00, 0x08, 0x00,
+        0xC3,
+        0xCF,
+        0x66, 0xCF,
+        0x0F, 0x6E, 0xC0,
+        0x0F, 0x58, 0xC1,
+        0x66, 0x0F, 0x58, 0xC1
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn relative_jumps() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x86_16
+        ; ->back:
+        ; jmp ->fwd
+        ; jmp BYTE ->fwd
+        ; call ->fwd
+        ; jz ->fwd
+        ; jmp DWORD ->fwd
+        ; jnz WORD ->back
+        ; ->fwd:
+        ; ret
+    );
+
+    let expected = [
+        0xE9, 0x13, 0x00,
+        0xEB, 0x11,
+        0xE8, 0x0E, 0x00,
+        0x0F, 0x84, 0x0A, 0x00,
+        0x66, 0xE9, 0x04, 0x00, 0x00, 0x00,
+        0x0F, 0x85, 0xEA, 0xFF,
+        0xC3
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn absolute_label_reference() {
+    let mut ops = VecAssembler::<X86Relocation>::new(0x7C00);
+    dynasm!(ops
+        ; .arch x86_16
+        ; mov ax, [->data]
+        ; ret
+        ; ->data:
+        ; .word 5
+    );
+
+    let expected = [
+        0x8B, 0x06, 0x05, 0x7C,
+        0xC3,
+        0x05, 0x00
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn far_pointers() {
+    let selector = 0x10i64;
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x86_16
+        ; jmp FWORD 0x0008_0012_3456
+        ; call FWORD (selector << 32) | 0x7C00
+        ; .arch x86
+        ; jmp FWORD 0x0008_0012_3456
+        ; callf FWORD 0x0010_0000_1000
+    );
+
+    let expected = [
+        0x66, 0xEA, 0x56, 0x34, 0x12, 0x00, 0x08, 0x00,
+        0x66, 0x9A, 0x00, 0x7C, 0x00, 0x00, 0x10, 0x00,
+        0xEA, 0x56, 0x34, 0x12, 0x00, 0x08, 0x00,
+        0x9A, 0x00, 0x10, 0x00, 0x00, 0x10, 0x00
+    ];
+    assert_eq!(ops.finalize().unwrap(), expected);
+}
+
+#[test]
+fn word_relocation_ranges() {
+    use dynasmrt::relocations::Relocation;
+
+    // real mode addresses can use the full 16 bits
+    let mut ops = VecAssembler::<X86Relocation>::new(0xFF00);
+    dynasm!(ops
+        ; .arch x86_16
+        ; mov ax, [->data]
+        ; ->data:
+        ; .word 5
+    );
+    assert_eq!(ops.finalize().unwrap(), [0x8B, 0x06, 0x04, 0xFF, 0x05, 0x00]);
+
+    let absolute = X86Relocation::from_encoding((0, 2, 1, 1));
+    assert_eq!(absolute.read_value(&[0x04, 0xFF]), 0xFF04);
+
+    // outside of real mode, word relocations are signed
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x86
+        ; .word ->data
+        ; .bytes [0u8; 0x8000].iter()
+        ; ->data:
+    );
+    assert!(ops.finalize().is_err());
+
+    let mut ops = VecAssembler::<X86Relocation>::new(0);
+    dynasm!(ops
+        ; .arch x86_16
+        ; .word ->data
+        ; .bytes [0u8; 0x8000].iter()
+        ; ->data:
+    );
+    assert_eq!(&ops.finalize().unwrap()[.. 2], &[0x00, 0x80]);
+}
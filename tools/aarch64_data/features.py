@@ -0,0 +1,58 @@
+# Determines the architecture extensions required by an encoding, based on its mnemonic and matcher.
+# The result is a list of names of aarch64data::Features flags.
+
+import re
+
+# instructions whose encodings all belong to a single extension
+MNEMONIC_FEATURES = [
+    (r"casp?(a|al|l)?[bh]?", "LSE"),
+    (r"(ld|st)(add|clr|eor|set|smax|smin|umax|umin)(a|al|l)?[bh]?", "LSE"),
+    (r"swp(a|al|l)?[bh]?", "LSE"),
+    (r"sqrdml[as]h", "RDM"),
+    (r"ldlar[bh]?|stllr[bh]?", "LOR"),
+    (r"crc32c?[bhwx]", "CRC"),
+    (r"aes(d|e|imc|mc)", "AES"),
+    (r"sha1[chmp]|sha1su[01]|sha256(h|h2|su0|su1)", "SHA2"),
+    (r"sha512(h|h2|su0|su1)|eor3|bcax|rax1|xar", "SHA3"),
+    (r"sm3(partw1|partw2|ss1|tt1a|tt1b|tt2a|tt2b)|sm4e|sm4ekey", "SM4"),
+    (r"[su]dot", "DOTPROD"),
+    (r"fml[as]l2?", "FHM"),
+    (r"fcmla|fcadd", "FCMA"),
+    (r"fjcvtzs", "JSCVT"),
+    (r"ldapr[bh]?", "RCPC"),
+    (r"ldapur(b|h|sb|sh|sw)?|stlur[bh]?", "RCPC2"),
+    (r"cfinv|rmif|setf8|setf16", "FLAGM"),
+    (r"esb", "RAS"),
+    (r"psb", "SPE"),
+    (r"tsb", "TRACE"),
+    (r"sb", "SB"),
+    (r"cfp|dvp|cpp", "PREDRES"),
+    # the pointer authentication instructions in the hint space (like paciasp) execute as a nop
+    # on processors without pointer authentication, so they are not gated.
+    (r"(pac|aut)[id][ab]|(pac|aut)[id]z[ab]|pacga|xpac[di]", "PAUTH"),
+    (r"blra(a|b|az|bz)|bra(a|b|az|bz)|e?reta[ab]|ldra[ab]", "PAUTH"),
+]
+
+# half precision variants of floating point instructions need FP16, except for the conversions
+# between half precision and other formats present in the base architecture.
+FP16_EXCEPTIONS = {"fcvt", "fcvtl", "fcvtl2", "fcvtn", "fcvtn2", "fcvtxn", "fcvtxn2", "fmlal", "fmlal2", "fmlsl", "fmlsl2"}
+HALF_MATCHER = re.compile(r"\bH\b|\b(V|VStatic|VElement|VElementStatic|VStaticElement)\(WORD\b")
+
+def features(mnemonic, matcher):
+    mnemonic = mnemonic.lower()
+    found = []
+
+    for pattern, feature in MNEMONIC_FEATURES:
+        if re.fullmatch(pattern, mnemonic):
+            found.append(feature)
+            break
+
+    if mnemonic.startswith("f") or mnemonic in ("scvtf", "ucvtf"):
+        if mnemonic not in FP16_EXCEPTIONS and HALF_MATCHER.search(matcher):
+            found.append("FP16")
+
+    # 64-bit polynomial multiplication is part of the AES extension
+    if mnemonic in ("pmull", "pmull2") and "OWORD" in matcher:
+        found.append("AES")
+
+    return found
@@ -255,7 +255,7 @@ def merge_matchers(ops):
         group[:] = [op for op in newgroup if op.matcher not in discards]
 
 
-def emit_opmap(ops, f):
+def emit_opmap(ops, f, features):
     for m, group in ops:
         f.write("\"{}\" = [\n".format(m.lower()))
 
@@ -270,7 +270,11 @@ def emit_opmap(ops, f):
             bits = op.bits.replace("x", "0").replace("N", "0").replace("Z", "0").replace("z", "0")
             bits = bits[0:8] + "_" + bits[8:16] + "_" + bits[16:24] + "_" + bits[24:32]
 
-            f.write("    0b{} = [{}] => [{}];\n".format(bits, op.matcher, op.processor))
+            required = features(m, op.matcher)
+            if required:
+                f.write("    0b{} = [{}] => [{}], {};\n".format(bits, op.matcher, op.processor, " | ".join(required)))
+            else:
+                f.write("    0b{} = [{}] => [{}];\n".format(bits, op.matcher, op.processor))
 
 
         f.write("]\n")
@@ -481,11 +485,16 @@ def main():
     # compile static data into bits
     tl_merge_statics(ops)
 
+    # load the architecture extension table
+    g = {}
+    with open(os.path.join("aarch64_data", "features.py"), "r", encoding="utf-8") as f:
+        exec(f.read(), g)
+
     #emit the actual opmap
 
     with open("../plugin/src/arch/aarch64/opmap.rs", "w", encoding="utf-8") as f:
         f.write("// This file was generated bo tools/aarch64_gen_opmap.py\nOps!(\n\n")
-        emit_opmap(ops, f)
+        emit_opmap(ops, f, g["features"])
         f.write("\n);\n")
 
 if __name__ == '__main__':
@@ -0,0 +1,470 @@
+"""
+Generates encoding tests for the arm and thumb backends. Every form of every instruction in the
+opmap is instantiated a few times with random operands, assembled with a reference assembler and
+written out as a test that checks dynasm's encoding against it.
+
+usage: python3 arm_gen_tests.py <arm|thumb> <path to opmap> <output directory>
+
+The reference assembler is llvm-mc. Forms that take a label are not covered by the generated tests.
+"""
+
+import os
+import os.path
+import random
+import re
+import subprocess
+import tempfile
+
+TESTS_PER_FORM = 3
+
+CONDS = ["eq", "ne", "cs", "cc", "mi", "pl", "vs", "vc", "hi", "ls", "ge", "lt", "gt", "le"]
+ALIASES = {9: "sb", 10: "sl", 11: "fp", 12: "ip", 14: "lr"}
+BARRIERS = ["sy", "st", "ld", "ish", "ishst", "ishld", "nsh", "nshst", "nshld", "osh", "oshst", "oshld"]
+
+IMMEDIATES = {
+    # name: (min, max)
+    "IMM16": (0, 0xFFFF),
+    "IMM16_SPLIT": (0, 0xFFFF),
+    "IMM24": (0, 0xFFFFFF),
+    "LSL5": (0, 31),
+    "SHR5": (1, 32),
+    "ROR5": (1, 31),
+    "IMM8": (0, 0xFF),
+    "T_IMM12": (0, 0xFFF),
+    "T_IMM16": (0, 0xFFFF),
+    "T_LSL5": (0, 31),
+    "T_SHR5": (1, 32),
+    "T_ROR5": (1, 31),
+}
+
+SHIFT_RANGES = {"lsl": (0, 31), "lsr": (1, 32), "asr": (1, 32), "ror": (1, 31)}
+
+
+def read_opmap(f):
+    forms = []
+    name = None
+    for line in f:
+        line = line.strip()
+        m = re.match(r'^"([^"]+)" = \[$', line)
+        if m:
+            name = m.group(1)
+            continue
+        m = re.match(r'^(0x[0-9A-Fa-f_]+) = \[(.*)\];$', line)
+        if m:
+            forms.append((name, int(m.group(1), 16), split_operands(m.group(2))))
+    return forms
+
+
+def split_operands(s):
+    operands = []
+    depth = 0
+    buf = ""
+    for c in s:
+        if c in "([":
+            depth += 1
+        elif c in ")]":
+            depth -= 1
+        if c == "," and depth == 0:
+            operands.append(buf.strip())
+            buf = ""
+        else:
+            buf += c
+    if buf.strip():
+        operands.append(buf.strip())
+    return operands
+
+
+def reg_name(code):
+    if code in ALIASES and random.random() < 0.25:
+        return ALIASES[code]
+    return "r{}".format(code)
+
+
+def register(family, code):
+    gas = "{}{}".format(family.lower(), code) if family != "R" else reg_name(code)
+    if random.random() < 0.25:
+        return ("{}({})".format(family, code), gas)
+    return (gas, gas)
+
+
+def immediate(lo, hi):
+    value = random.choice([lo, hi, random.randint(lo, hi), random.randint(lo, hi)])
+    return signed_immediate(value)
+
+
+def signed_immediate(value):
+    text = "#{}".format(value)
+    # wrapping a value in parenthesis forces dynasm to encode it at runtime
+    if random.random() < 0.25:
+        return ("#({}i32)".format(value), text)
+    return (text, text)
+
+
+def modified_immediate(thumb):
+    if thumb:
+        byte = random.randint(0, 0xFF)
+        kind = random.randint(0, 4)
+        if kind == 0:
+            value = byte
+        elif kind == 1:
+            value = byte * 0x00010001
+        elif kind == 2:
+            value = byte * 0x01000100
+        elif kind == 3:
+            value = byte * 0x01010101
+        else:
+            rotation = random.randint(8, 31)
+            byte |= 0x80
+            value = ((byte >> rotation) | (byte << (32 - rotation))) & 0xFFFFFFFF
+    else:
+        byte = random.randint(0, 0xFF)
+        rotation = random.randint(0, 15) * 2
+        value = ((byte >> rotation) | (byte << (32 - rotation))) & 0xFFFFFFFF
+    text = "#{}".format(value)
+    if random.random() < 0.25:
+        return ("#({}u32)".format(value), text)
+    return (text, text)
+
+
+def shift(thumb, optional=True):
+    if optional and random.random() < 0.3:
+        return ()
+    if random.random() < 0.1:
+        return ("rrx", "rrx")
+    kind = random.choice(list(SHIFT_RANGES))
+    amount = immediate(*SHIFT_RANGES[kind])
+    return ("{} {}".format(kind, amount[0]), "{} {}".format(kind, amount[1]))
+
+
+def float_immediate():
+    value = (16 + random.randint(0, 15)) / 16 * 2 ** random.randint(-3, 4)
+    if random.random() < 0.5:
+        value = -value
+    text = "#{!r}".format(value)
+    return (text, text)
+
+
+def reference(kind, regs):
+    base = register("R", random.choice(regs))
+
+    def fmt(inner, suffix=""):
+        return ("[{}]{}".format(", ".join([base[0]] + [i[0] for i in inner]), suffix[0] if suffix else ""),
+                "[{}]{}".format(", ".join([base[1]] + [i[1] for i in inner]), suffix[1] if suffix else ""))
+
+    def post(offset):
+        return ("[{}], {}".format(base[0], offset[0]), "[{}], {}".format(base[1], offset[1]))
+
+    def index_reg(allow_negation=True):
+        reg = register("R", random.choice(regs))
+        if allow_negation and random.random() < 0.5:
+            return ("-" + reg[0], "-" + reg[1])
+        return reg
+
+    def scaled(limit, scale):
+        return signed_immediate(random.randint(-limit // scale, limit // scale) * scale)
+
+    choice = random.random()
+    if kind == "A2":
+        if choice < 0.15:
+            return fmt([])
+        elif choice < 0.4:
+            return fmt([immediate(-4095, 4095)])
+        elif choice < 0.5:
+            return fmt([immediate(-4095, 4095)], ("!", "!"))
+        elif choice < 0.6:
+            return post(immediate(-4095, 4095))
+        elif choice < 0.75:
+            return fmt([index_reg()])
+        elif choice < 0.9:
+            s = shift(False, False)
+            return fmt([index_reg(), s])
+        else:
+            return post(index_reg())
+    elif kind == "A3":
+        if choice < 0.15:
+            return fmt([])
+        elif choice < 0.45:
+            return fmt([immediate(-255, 255)])
+        elif choice < 0.6:
+            return fmt([immediate(-255, 255)], ("!", "!"))
+        elif choice < 0.75:
+            return post(immediate(-255, 255))
+        elif choice < 0.9:
+            return fmt([index_reg()])
+        else:
+            return post(index_reg())
+    elif kind == "V":
+        if choice < 0.2:
+            return fmt([])
+        return fmt([scaled(1020, 4)])
+    elif kind == "Ex":
+        return fmt([])
+    elif kind == "T12":
+        if choice < 0.2:
+            return fmt([])
+        return fmt([immediate(0, 4095)])
+    elif kind == "T8":
+        if choice < 0.4:
+            # offsets that are only known at runtime are assumed to be positive in this mode
+            value = str(random.randint(-255, -1))
+            return fmt([("#" + value, "#" + value)])
+        elif choice < 0.7:
+            return fmt([immediate(-255, 255)], ("!", "!"))
+        else:
+            return post(immediate(-255, 255))
+    elif kind == "TReg":
+        if choice < 0.4:
+            return fmt([index_reg(False)])
+        amount = immediate(0, 3)
+        return fmt([index_reg(False), ("lsl " + amount[0], "lsl " + amount[1])])
+    elif kind == "TD":
+        if choice < 0.2:
+            return fmt([])
+        elif choice < 0.6:
+            return fmt([scaled(1020, 4)])
+        elif choice < 0.8:
+            return fmt([scaled(1020, 4)], ("!", "!"))
+        else:
+            return post(scaled(1020, 4))
+    elif kind == "TEx":
+        if choice < 0.3:
+            return fmt([])
+        return fmt([signed_immediate(random.randint(0, 255) * 4)])
+    raise ValueError("Unknown reference kind {}".format(kind))
+
+
+def reglist(exclude):
+    count = random.randint(2, 6)
+    regs = sorted(random.sample([r for r in list(range(13)) + [14] if r != exclude], count))
+    items = [register("R", r) for r in regs]
+    return ("{{{}}}".format(", ".join(i[0] for i in items)), "{{{}}}".format(", ".join(i[1] for i in items)))
+
+
+def vreglist(double):
+    family = "d" if double else "s"
+    count = random.randint(1, 4)
+    first = random.randint(0, (16 if double else 32) - count)
+    if count > 1 and random.random() < 0.5:
+        text = "{{{0}{1}-{0}{2}}}".format(family, first, first + count - 1)
+    else:
+        text = "{{{}}}".format(", ".join("{}{}".format(family, i) for i in range(first, first + count)))
+    return (text, text)
+
+
+def instantiate(name, operands, thumb):
+    dynasm = []
+    gas = []
+    regs = list(range(13))
+    used = []
+    lsb = None
+
+    for op in operands:
+        m = re.match(r'^(\w+)(?:\((.*)\))?$', op)
+        kind, args = m.group(1), m.group(2)
+        args = split_operands(args) if args else []
+
+        if kind in ("R", "Rs"):
+            if "RNext" in operands and not used:
+                code = random.choice(range(0, 12, 2))
+            else:
+                code = random.choice([r for r in regs if r not in used] if thumb else regs)
+            used.append(code)
+            result = register("R", code)
+        elif kind == "RLow":
+            result = register("R", random.randint(0, 7))
+        elif kind == "RWb":
+            code = random.choice(regs)
+            used.append(code)
+            result = register("R", code)
+            if random.random() < 0.5:
+                result = (result[0] + "!", result[1] + "!")
+        elif kind == "RNext":
+            code = used[-1] + 1
+            used.append(code)
+            result = register("R", code)
+        elif kind in ("Sd", "Sn", "Sm"):
+            result = register("S", random.randint(0, 31))
+        elif kind in ("Dd", "Dn", "Dm"):
+            result = register("D", random.randint(0, 31))
+        elif kind == "Imm":
+            result = immediate(*IMMEDIATES[args[0]])
+        elif kind == "ModImm":
+            result = modified_immediate(False)
+        elif kind == "TModImm":
+            result = modified_immediate(True)
+        elif kind in ("Shift", "TShift"):
+            result = shift(thumb)
+        elif kind == "RegShift":
+            reg = register("R", random.choice(regs))
+            kind = random.choice(list(SHIFT_RANGES))
+            result = ("{} {}".format(kind, reg[0]), "{} {}".format(kind, reg[1]))
+        elif kind == "Rotation":
+            if random.random() < 0.3:
+                result = ()
+            else:
+                amount = random.choice([0, 8, 16, 24])
+                result = ("ror #{}".format(amount), "ror #{}".format(amount))
+        elif kind == "Lsb":
+            lsb = random.randint(0, 31)
+            result = signed_immediate(lsb)
+        elif kind == "Width":
+            result = signed_immediate(random.randint(1, 32 - lsb))
+        elif kind == "Mem":
+            result = reference(args[0], [r for r in regs if r not in used])
+        elif kind == "RegList":
+            result = reglist(used[-1] if used else None)
+        elif kind == "VRegList":
+            result = vreglist(args[0] == "true")
+        elif kind == "Barrier":
+            if random.random() < 0.3 or name == "isb":
+                result = ()
+            else:
+                barrier = random.choice(BARRIERS)
+                result = (barrier, barrier)
+        elif kind == "FImm":
+            result = float_immediate()
+        elif kind == "Zero":
+            result = ("#0", "#0")
+        elif kind == "Lit":
+            lit = args[0].strip('"')
+            result = (lit, lit)
+        elif kind in ("Target", "ItCond"):
+            return None
+        else:
+            raise ValueError("Unknown operand {}".format(op))
+
+        if result == ():
+            continue
+        dynasm.append(result[0])
+        gas.append(result[1])
+
+    return (dynasm, gas)
+
+
+def with_cond(name, cond):
+    if "." in name:
+        head, tail = name.split(".", 1)
+        return "{}{}.{}".format(head, cond, tail)
+    return name + cond
+
+
+def format_instruction(name, operands):
+    if not operands:
+        return name
+    return "{} {}".format(name, ", ".join(operands))
+
+
+def assemble(lines, thumb):
+    source = ".syntax unified\n" + "\n".join(lines) + "\n"
+
+    with tempfile.TemporaryDirectory() as tmp:
+        src = os.path.join(tmp, "test.s")
+        obj = os.path.join(tmp, "test.o")
+        binary = os.path.join(tmp, "test.bin")
+        with open(src, "w", encoding="utf-8") as f:
+            f.write(source)
+
+        triple = "-triple=thumbv7" if thumb else "-triple=armv7"
+        subprocess.run(["llvm-mc", triple, "-mattr=+vfp4,+hwdiv,+hwdiv-arm", "-filetype=obj", src, "-o", obj],
+                       check=True, stderr=subprocess.DEVNULL)
+        subprocess.run(["llvm-objcopy", "-O", "binary", obj, binary], check=True)
+
+        with open(binary, "rb") as f:
+            return f.read().hex()
+
+
+def generate(name, base, operands, thumb):
+    instance = instantiate(name, operands, thumb)
+    if instance is None:
+        return None
+    dynasm_ops, gas_ops = instance
+
+    wide = thumb and base > 0xFFFF
+    size = 4 if wide or not thumb else 2
+
+    # pick a condition, which has to be set up by an if-then block in thumb state
+    cond = None
+    conditional = (base & 0xF0000000) == 0 if not thumb else name != "it"
+    if conditional and not name.startswith("it") and random.random() < 0.3:
+        cond = random.choice(CONDS)
+
+    mnemonic = with_cond(name, cond) if cond else name
+    dynasm = [format_instruction(mnemonic, dynasm_ops)]
+    gas = format_instruction(mnemonic, gas_ops)
+    prefix = []
+    if thumb and cond:
+        prefix = ["it {}".format(cond)]
+        dynasm = prefix + dynasm
+        size += 2
+
+    candidates = []
+    if wide and not name.startswith("v"):
+        # make sure the reference assembler picks a 32-bit encoding as well
+        head, _, tail = mnemonic.partition(".")
+        candidates.append(format_instruction(head + ".w" + ("." + tail if tail else ""), gas_ops))
+    candidates.append(gas)
+
+    for candidate in candidates:
+        try:
+            data = assemble(prefix + [candidate], thumb)
+        except subprocess.CalledProcessError:
+            continue
+        if len(data) // 2 != size:
+            continue
+        return (dynasm, "\n".join(prefix + [candidate]), data)
+
+    print("Error at {}".format(gas))
+    return None
+
+
+def chunks(l, n):
+    for i in range(0, len(l), n):
+        yield l[i:i+n]
+
+
+def emit_test_case(arch, i, dynasm, gas, data):
+    name = dynasm[-1].split(' ', 1)[0].replace(".", "_")
+    data = ", ".join(chunks(data, 2)).upper()
+    error = gas.replace("{", "{{").replace("}", "}}").replace("\n", " ; ")
+    return """
+#[test]
+fn {}_{}() {{
+    let mut ops = dynasmrt::arm::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch {}
+        ; {}
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{{:02X}}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "{}", "{}");
+}}
+""".format(name, i, arch, "\n        ; ".join(dynasm), data, error)
+
+
+def main():
+    import sys
+    arch = sys.argv[1]
+    thumb = arch == "thumb"
+    with open(sys.argv[2], "r", encoding="utf-8") as f:
+        forms = read_opmap(f)
+
+    random.seed(0x41524D32 if thumb else 0x41524D00)
+
+    tests = []
+    for name, base, operands in forms:
+        for _ in range(TESTS_PER_FORM if operands else 1):
+            test = generate(name, base, operands, thumb)
+            if test is not None:
+                tests.append(test)
+
+    tests = [emit_test_case(arch, i, dynasm, gas, data) for i, (dynasm, gas, data) in enumerate(tests)]
+
+    for i, chunk in enumerate(chunks(tests, 800)):
+        with open(os.path.join(sys.argv[3], "{}_tests_{}.rs.gen".format(arch, i)), "w", encoding="utf-8") as f:
+            for test in chunk:
+                f.write(test)
+
+
+if __name__ == "__main__":
+    main()
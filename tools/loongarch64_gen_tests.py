@@ -0,0 +1,235 @@
+"""
+Generates encoding tests for the loongarch64 backend. Every form of every instruction in the
+loongarch64 opmap is instantiated a few times with random operands, assembled with a reference
+assembler and written out as a test that checks dynasm's encoding against it.
+
+usage: python3 loongarch64_gen_tests.py <path to opmap.rs> <output directory> [assembler]
+
+The assembler can be "llvm-mc" (the default), which needs LLVM 16 or later, or "gas", which uses
+loongarch64-linux-gnu-as from binutils 2.38 or later.
+"""
+
+import os
+import os.path
+import random
+import re
+import subprocess
+import tempfile
+
+TESTS_PER_FORM = 3
+
+RNAMES = ["zero", "ra", "tp", "sp"] + ["a{}".format(i) for i in range(8)] + \
+         ["t{}".format(i) for i in range(9)] + ["r21", "fp"] + ["s{}".format(i) for i in range(9)]
+FNAMES = ["fa{}".format(i) for i in range(8)] + ["ft{}".format(i) for i in range(16)] + \
+         ["fs{}".format(i) for i in range(8)]
+
+IMMEDIATES = {
+    # name: (signed, bits, scale, bias)
+    "SI12": (True, 12, 0, 0),
+    "UI12": (False, 12, 0, 0),
+    "SI14": (True, 16, 2, 0),
+    "SI16": (True, 16, 0, 0),
+    "SI20": (True, 20, 0, 0),
+    "UI1": (False, 1, 0, 0),
+    "UI2": (False, 2, 0, 0),
+    "UI3": (False, 3, 0, 0),
+    "UI4": (False, 4, 0, 0),
+    "UI5": (False, 5, 0, 0),
+    "UI6": (False, 6, 0, 0),
+    "UI8": (False, 8, 0, 0),
+    "MSBW": (False, 5, 0, 0),
+    "MSBD": (False, 6, 0, 0),
+    "SA2": (False, 2, 0, 1),
+    "BP2": (False, 2, 0, 0),
+    "BP3": (False, 3, 0, 0),
+    "CODE15": (False, 15, 0, 0),
+    "HINT5": (False, 5, 0, 0),
+    "OFFS16": (True, 18, 2, 0),
+    "OFFS21": (True, 23, 2, 0),
+    "OFFS26": (True, 28, 2, 0),
+    "PCREL22": (True, 22, 2, 0),
+}
+
+
+def read_opmap(f):
+    forms = []
+    name = None
+    for line in f:
+        line = line.strip()
+        m = re.match(r'^"([^"]+)" = \[$', line)
+        if m:
+            name = m.group(1)
+            continue
+        m = re.match(r'^0x[0-9A-Fa-f_]+ = \[(.*)\];$', line)
+        if m:
+            forms.append((name, split_operands(m.group(1))))
+    return forms
+
+
+def split_operands(s):
+    operands = []
+    depth = 0
+    buf = ""
+    for c in s:
+        if c in "([":
+            depth += 1
+        elif c in ")]":
+            depth -= 1
+        if c == "," and depth == 0:
+            operands.append(buf.strip())
+            buf = ""
+        else:
+            buf += c
+    if buf.strip():
+        operands.append(buf.strip())
+    return operands
+
+
+def register(names, prefix, family):
+    code = random.randrange(32)
+    gas = "${}".format(names[code] if names is not None and random.random() < 0.5 else "{}{}".format(prefix, code))
+    if random.random() < 0.25:
+        return ("{}({})".format(family, code), gas)
+    return (gas, gas)
+
+
+def immediate(kind):
+    signed, bits, scale, bias = IMMEDIATES[kind]
+    if signed:
+        lo, hi = -(1 << (bits - 1)), (1 << (bits - 1)) - 1
+    else:
+        lo, hi = bias, (1 << bits) - 1 + bias
+    value = random.choice([lo, hi, random.randint(lo, hi), random.randint(lo, hi)])
+    value &= ~((1 << scale) - 1)
+    if value < lo:
+        value += 1 << scale
+    text = str(value)
+    # wrapping a value in parenthesis forces dynasm to encode it at runtime
+    if random.random() < 0.25:
+        return ("({}i32)".format(value), text)
+    return (text, text)
+
+
+def operand(op):
+    m = re.match(r'^(\w+)(?:\((.*)\))?$', op)
+    kind, args = m.group(1), m.group(2)
+    args = split_operands(args) if args else []
+
+    if kind == "R":
+        return register(RNAMES, "r", "R")
+    if kind == "F":
+        return register(FNAMES, "f", "F")
+    if kind == "V":
+        return register(None, "vr", "VR")
+    if kind == "X":
+        return register(None, "xr", "XR")
+    if kind == "Fcc":
+        reg = "$fcc{}".format(random.randrange(8))
+        return (reg, reg)
+    if kind == "Fcsr":
+        reg = "$fcsr{}".format(random.randrange(4))
+        return (reg, reg)
+    if kind == "Imm":
+        return immediate(args[0])
+    if kind == "Target":
+        if args[0].startswith("PAIR"):
+            return None
+        return immediate(args[0])
+    if kind in ("Rs", "LiW", "LiD"):
+        # pseudo instructions that expand differently depending on their operands are tested by hand
+        return None
+    raise ValueError("Unknown operand {}".format(op))
+
+
+def instantiate(name, operands):
+    dynasm = []
+    gas = []
+    for op in operands:
+        result = operand(op)
+        if result is None:
+            return None
+        dynasm.append(result[0])
+        gas.append(result[1])
+    if not operands:
+        return (name, name)
+    return ("{} {}".format(name, ", ".join(dynasm)), "{} {}".format(name, ", ".join(gas)))
+
+
+def assemble(lines, assembler):
+    source = "\n".join(lines) + "\n"
+
+    with tempfile.TemporaryDirectory() as tmp:
+        src = os.path.join(tmp, "test.s")
+        obj = os.path.join(tmp, "test.o")
+        binary = os.path.join(tmp, "test.bin")
+        with open(src, "w", encoding="utf-8") as f:
+            f.write(source)
+
+        if assembler == "gas":
+            subprocess.run(["loongarch64-linux-gnu-as", "-mabi=lp64d", src, "-o", obj], check=True)
+            subprocess.run(["loongarch64-linux-gnu-objcopy", "-O", "binary", "--only-section=.text", obj, binary], check=True)
+        else:
+            subprocess.run(["llvm-mc", "-triple=loongarch64", "-mattr=+d,+lsx,+lasx", "-filetype=obj", src, "-o", obj], check=True)
+            subprocess.run(["llvm-objcopy", "-O", "binary", "--only-section=.text", obj, binary], check=True)
+
+        with open(binary, "rb") as f:
+            return f.read().hex()
+
+
+def chunks(l, n):
+    for i in range(0, len(l), n):
+        yield l[i:i+n]
+
+
+def emit_test_case(i, dynasm, gas, data):
+    name = dynasm.split(' ', 1)[0].replace(".", "_")
+    data = ", ".join(chunks(data, 2)).upper()
+    error = dynasm.replace("{", "{{").replace("}", "}}")
+    return """
+#[test]
+fn {}_{}() {{
+    let mut ops = dynasmrt::loongarch64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch loongarch64
+        ; {}
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{{:02X}}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "{}", "{}");
+}}
+""".format(name, i, dynasm, data, error)
+
+
+def main():
+    import sys
+    with open(sys.argv[1], "r", encoding="utf-8") as f:
+        forms = read_opmap(f)
+    assembler = sys.argv[3] if len(sys.argv) > 3 else "llvm-mc"
+
+    random.seed(0x4C41_3634)
+
+    tests = []
+    for name, operands in forms:
+        for _ in range(TESTS_PER_FORM if operands else 1):
+            instance = instantiate(name, operands)
+            if instance is None:
+                break
+            dynasm, gas = instance
+            try:
+                data = assemble([gas], assembler)
+            except subprocess.CalledProcessError:
+                print("Error at {}".format(gas))
+                continue
+            tests.append((dynasm, gas, data))
+
+    tests = [emit_test_case(i, dynasm, gas, data) for i, (dynasm, gas, data) in enumerate(tests)]
+
+    for i, chunk in enumerate(chunks(tests, 800)):
+        with open(os.path.join(sys.argv[2], "loongarch64_tests_{}.rs.gen".format(i)), "w", encoding="utf-8") as f:
+            for test in chunk:
+                f.write(test)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,269 @@
+"""
+Generates encoding tests for the riscv64 backend. Every form of every instruction in the
+riscv64 opmap is instantiated a few times with random operands, assembled with a reference
+assembler and written out as a test that checks dynasm's encoding against it.
+
+usage: python3 riscv64_gen_tests.py <path to opmap.rs> <output directory> [assembler]
+
+The assembler can be "llvm-mc" (the default) or "gas", which uses riscv64-linux-gnu-as.
+"""
+
+import os
+import os.path
+import random
+import re
+import subprocess
+import tempfile
+
+TESTS_PER_FORM = 3
+
+XNAMES = ["zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1"] + \
+         ["a{}".format(i) for i in range(8)] + ["s{}".format(i) for i in range(2, 12)] + \
+         ["t{}".format(i) for i in range(3, 7)]
+FNAMES = ["ft{}".format(i) for i in range(8)] + ["fs0", "fs1"] + ["fa{}".format(i) for i in range(8)] + \
+         ["fs{}".format(i) for i in range(2, 12)] + ["ft{}".format(i) for i in range(8, 12)]
+
+IMMEDIATES = {
+    # name: (signed, bits, scale, nonzero)
+    "I12": (True, 12, 0, False),
+    "S12": (True, 12, 0, False),
+    "B13": (True, 13, 1, False),
+    "J21": (True, 21, 1, False),
+    "U20": (False, 20, 0, False),
+    "SHAMT6": (False, 6, 0, False),
+    "SHAMT5": (False, 5, 0, False),
+    "UIMM5": (False, 5, 0, False),
+    "CI6": (True, 6, 0, False),
+    "CSHAMT": (False, 6, 0, True),
+    "CLUI": (True, 6, 0, True),
+    "CADDI16SP": (True, 10, 4, True),
+    "CADDI4SPN": (False, 10, 2, True),
+    "CLW": (False, 7, 2, False),
+    "CLD": (False, 8, 3, False),
+    "CLWSP": (False, 8, 2, False),
+    "CLDSP": (False, 9, 3, False),
+    "CSWSP": (False, 8, 2, False),
+    "CSDSP": (False, 9, 3, False),
+    "CJ12": (True, 12, 1, False),
+    "CB9": (True, 9, 1, False),
+}
+
+ROUNDING_MODES = ["rne", "rtz", "rdn", "rup", "rmm", "dyn"]
+FENCE_SETS = ["i", "o", "r", "w", "rw", "iorw", "ir", "ow", "iow"]
+CSRS = ["fflags", "frm", "fcsr", "cycle", "time", "instret", "sstatus", "satp", "mstatus", "mepc", "mhartid"]
+
+
+def read_opmap(f):
+    forms = []
+    name = None
+    for line in f:
+        line = line.strip()
+        m = re.match(r'^"([^"]+)" = \[$', line)
+        if m:
+            name = m.group(1)
+            continue
+        m = re.match(r'^0x[0-9A-Fa-f_]+ = \[(.*)\];$', line)
+        if m:
+            forms.append((name, split_operands(m.group(1))))
+    return forms
+
+
+def split_operands(s):
+    operands = []
+    depth = 0
+    buf = ""
+    for c in s:
+        if c in "([":
+            depth += 1
+        elif c in ")]":
+            depth -= 1
+        if c == "," and depth == 0:
+            operands.append(buf.strip())
+            buf = ""
+        else:
+            buf += c
+    if buf.strip():
+        operands.append(buf.strip())
+    return operands
+
+
+def register(names, family, allowed):
+    code = random.choice(allowed)
+    gas = names[code] if random.random() < 0.5 else "{}{}".format(family.lower(), code)
+    if random.random() < 0.25:
+        return ("{}({})".format(family, code), gas)
+    return (gas, gas)
+
+
+def immediate(kind):
+    signed, bits, scale, nonzero = IMMEDIATES[kind]
+    if signed:
+        lo, hi = -(1 << (bits - 1)), (1 << (bits - 1)) - 1
+    else:
+        lo, hi = 0, (1 << bits) - 1
+    while True:
+        value = random.choice([lo, hi, random.randint(lo, hi), random.randint(lo, hi)])
+        value &= ~((1 << scale) - 1)
+        if value < lo:
+            value += 1 << scale
+        if not (nonzero and value == 0):
+            break
+    text = str(value)
+    # llvm and gas only accept the c.lui immediate as its sign-extended 20-bit value
+    if kind == "CLUI" and value < 0:
+        text = str(value & 0xFFFFF)
+    # wrapping a value in parenthesis forces dynasm to encode it at runtime
+    if random.random() < 0.25:
+        return ("({}i32)".format(value), text)
+    return (text, text)
+
+
+def operand(op):
+    m = re.match(r'^(\w+)(?:\((.*)\))?$', op)
+    kind, args = m.group(1), m.group(2)
+    args = split_operands(args) if args else []
+
+    if kind in ("X", "Xs"):
+        return register(XNAMES, "X", range(32))
+    if kind == "XNz":
+        return register(XNAMES, "X", range(1, 32))
+    if kind == "XNoSp":
+        return register(XNAMES, "X", [i for i in range(32) if i != 2])
+    if kind == "Xc":
+        return register(XNAMES, "X", range(8, 16))
+    if kind in ("F", "Fs"):
+        return register(FNAMES, "F", range(32))
+    if kind == "Fc":
+        return register(FNAMES, "F", range(8, 16))
+    if kind == "Sp":
+        return ("sp", "sp")
+    if kind == "Imm":
+        return immediate(args[0])
+    if kind in ("Mem", "MemC", "MemSp"):
+        disp = immediate(args[0])
+        if kind == "MemSp":
+            base = ("sp", "sp")
+        else:
+            base = register(XNAMES, "X", range(8, 16) if kind == "MemC" else range(32))
+        return ("{}({})".format(disp[0], base[0]), "{}({})".format(disp[1], base[1]))
+    if kind == "Addr":
+        base = register(XNAMES, "X", range(32))
+        return ("({})".format(base[0]), "({})".format(base[1]))
+    if kind == "Target":
+        if args[0] == "PAIR":
+            return None
+        return immediate(args[0])
+    if kind == "Rm":
+        if random.random() < 0.5:
+            return ()
+        mode = random.choice(ROUNDING_MODES)
+        return (mode, mode)
+    if kind == "Fence":
+        s = random.choice(FENCE_SETS)
+        return (s, s)
+    if kind == "Csr":
+        csr = random.choice(CSRS) if random.random() < 0.5 else str(random.randint(0, 0xFFF))
+        return (csr, csr)
+    if kind == "Li":
+        value = random.choice([random.randint(-0x800, 0x7FF), random.randint(-0x8000_0000, 0x7FFF_FFFF), random.randint(-0x80000, 0x7FFFF) << 12])
+        return (str(value), str(value))
+    raise ValueError("Unknown operand {}".format(op))
+
+
+def instantiate(name, operands):
+    dynasm = []
+    gas = []
+    for op in operands:
+        result = operand(op)
+        if result is None:
+            return None
+        if result == ():
+            continue
+        dynasm.append(result[0])
+        gas.append(result[1])
+    if not operands:
+        return (name, name)
+    return ("{} {}".format(name, ", ".join(dynasm)), "{} {}".format(name, ", ".join(gas)))
+
+
+def assemble(lines, assembler):
+    # explicit compressed instructions need the C extension, but it should not be used to
+    # compress anything else.
+    source = ".option norvc\n" if not lines[0].startswith("c.") else ""
+    source += "\n".join(lines) + "\n"
+
+    with tempfile.TemporaryDirectory() as tmp:
+        src = os.path.join(tmp, "test.s")
+        obj = os.path.join(tmp, "test.o")
+        binary = os.path.join(tmp, "test.bin")
+        with open(src, "w", encoding="utf-8") as f:
+            f.write(source)
+
+        if assembler == "gas":
+            subprocess.run(["riscv64-linux-gnu-as", "-march=rv64gc", src, "-o", obj], check=True)
+            subprocess.run(["riscv64-linux-gnu-objcopy", "-O", "binary", obj, binary], check=True)
+        else:
+            subprocess.run(["llvm-mc", "-triple=riscv64", "-mattr=+m,+a,+f,+d,+c", "-filetype=obj", src, "-o", obj], check=True)
+            subprocess.run(["llvm-objcopy", "-O", "binary", obj, binary], check=True)
+
+        with open(binary, "rb") as f:
+            return f.read().hex()
+
+
+def chunks(l, n):
+    for i in range(0, len(l), n):
+        yield l[i:i+n]
+
+
+def emit_test_case(i, dynasm, gas, data):
+    name = dynasm.split(' ', 1)[0].replace(".", "_")
+    data = ", ".join(chunks(data, 2)).upper()
+    error = dynasm.replace("{", "{{").replace("}", "}}")
+    return """
+#[test]
+fn {}_{}() {{
+    let mut ops = dynasmrt::riscv64::Assembler::new().unwrap();
+    dynasm!(ops
+        ; .arch riscv64
+        ; {}
+    );
+    let buf = ops.finalize().unwrap();
+    let hex: Vec<String> = buf.iter().map(|x| format!("{{:02X}}", *x)).collect();
+    let hex = hex.join(", ");
+    assert_eq!(hex, "{}", "{}");
+}}
+""".format(name, i, dynasm, data, error)
+
+
+def main():
+    import sys
+    with open(sys.argv[1], "r", encoding="utf-8") as f:
+        forms = read_opmap(f)
+    assembler = sys.argv[3] if len(sys.argv) > 3 else "llvm-mc"
+
+    random.seed(0x5253_4356)
+
+    tests = []
+    for name, operands in forms:
+        for _ in range(TESTS_PER_FORM if operands else 1):
+            instance = instantiate(name, operands)
+            if instance is None:
+                break
+            dynasm, gas = instance
+            try:
+                data = assemble([gas], assembler)
+            except subprocess.CalledProcessError:
+                print("Error at {}".format(gas))
+                continue
+            tests.append((dynasm, gas, data))
+
+    tests = [emit_test_case(i, dynasm, gas, data) for i, (dynasm, gas, data) in enumerate(tests)]
+
+    for i, chunk in enumerate(chunks(tests, 800)):
+        with open(os.path.join(sys.argv[2], "riscv64_tests_{}.rs.gen".format(i)), "w", encoding="utf-8") as f:
+            for test in chunk:
+                f.write(test)
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,217 @@
+"""
+Generates encoding tests for the APX extensions of the x64 backend: the REX2 prefix that gives
+legacy instructions access to the extended general purpose registers r16-r31, and the new data
+destination forms that are encoded using the extended EVEX prefix. Every template is instantiated
+a few times with random operands, assembled with a reference assembler and written out as a test
+that checks dynasm's encoding against it.
+
+usage: python3 x64_apx_gen_tests.py <output file> [assembler]
+
+The assembler can be "gas" (the default), which needs binutils 2.42 or later, or "llvm-mc",
+which needs LLVM 18 or later. The output is meant to be written to testing/tests/gen_x64/apx.rs.gen.
+"""
+
+import os
+import os.path
+import random
+import subprocess
+import tempfile
+
+TESTS_PER_TEMPLATE = 4
+
+REG64 = ["rax", "rcx", "rdx", "rbx", "rsp", "rbp", "rsi", "rdi"] + ["r{}".format(i) for i in range(8, 32)]
+REG32 = ["eax", "ecx", "edx", "ebx", "esp", "ebp", "esi", "edi"] + ["r{}d".format(i) for i in range(8, 32)]
+REG16 = ["ax", "cx", "dx", "bx", "sp", "bp", "si", "di"] + ["r{}w".format(i) for i in range(8, 32)]
+REG8  = ["al", "cl", "dl", "bl", "spl", "bpl", "sil", "dil"] + ["r{}b".format(i) for i in range(8, 32)]
+
+SIZES = {
+    "r64": (REG64, "Rq"),
+    "r32": (REG32, "Rd"),
+    "r16": (REG16, "Rw"),
+    "r8":  (REG8,  "Rb"),
+}
+
+MEMSIZES = {
+    "m64": "QWORD",
+    "m32": "DWORD",
+    "m16": "WORD",
+    "m8":  "BYTE",
+}
+
+# instructions that can use a REX2 prefix
+LEGACY = [
+    "mov {r64}, {r64}",
+    "mov {r32}, {m32}",
+    "mov {m64}, {r64}",
+    "mov {r8}, {imm8}",
+    "mov {r64}, {imm32}",
+    "add {r64}, {r64}",
+    "add {r32}, {m32}",
+    "add {m16}, {r16}",
+    "sub {r64}, {imm8}",
+    "and {r32}, {r32}",
+    "or {r8}, {r8}",
+    "xor {r64}, {m64}",
+    "cmp {r32}, {imm32}",
+    "test {r64}, {r64}",
+    "imul {r64}, {r64}",
+    "imul {r32}, {m32}, {imm8}",
+    "lea {r64}, {m64}",
+    "push {r64}",
+    "pop {r64}",
+    "movzx {r32}, {r8}",
+    "movsx {r64}, {r16}",
+    "inc {r64}",
+    "neg {r32}",
+    "not {m64}",
+    "shl {r64}, {imm8}",
+    "bt {r64}, {r64}",
+    "cmove {r64}, {r64}",
+    "sete {r8}",
+    "xchg {r64}, {m64}",
+    "bswap {r64}",
+]
+
+# new data destination forms
+NDD = [
+    "add {r64}, {r64}, {r64}",
+    "add {r32}, {r32}, {imm32}",
+    "add {r64}, {m64}, {r64}",
+    "sub {r64}, {r64}, {imm8}",
+    "sub {r16}, {r16}, {r16}",
+    "adc {r32}, {r32}, {r32}",
+    "sbb {r64}, {r64}, {m64}",
+    "and {r8}, {r8}, {r8}",
+    "or {r64}, {r64}, {imm32}",
+    "xor {r32}, {m32}, {imm8}",
+    "inc {r64}, {r64}",
+    "dec {r32}, {m32}",
+    "neg {r64}, {r64}",
+    "not {r16}, {r16}",
+]
+
+
+def register(size):
+    names, family = SIZES[size]
+    code = random.randrange(32)
+    name = names[code]
+    # writing a register as a dynamic register forces dynasm to pick the encoding at runtime
+    if random.random() < 0.25:
+        return ("{}({})".format(family, code), name)
+    return (name, name)
+
+
+def memory(size):
+    base = random.choice(REG64[:4] + REG64[5:])
+    index = random.choice(REG64[:4] + REG64[5:])
+    scale = random.choice([1, 2, 4, 8])
+    disp = random.choice([0, random.randint(-0x80, 0x7F), random.randint(-0x8000_0000, 0x7FFF_FFFF)])
+    form = random.randrange(3)
+    if form == 0:
+        inner = base
+    elif form == 1:
+        inner = "{} + {} * {}".format(base, index, scale)
+    else:
+        inner = "{} + {}".format(base, disp) if disp >= 0 else "{} - {}".format(base, -disp)
+    return ("{} [{}]".format(MEMSIZES[size], inner), "{} PTR [{}]".format(MEMSIZES[size], inner))
+
+
+def immediate(bits):
+    value = random.randint(-(1 << (bits - 1)), (1 << (bits - 1)) - 1)
+    return (str(value), str(value))
+
+
+def operand(kind):
+    if kind in SIZES:
+        return register(kind)
+    if kind in MEMSIZES:
+        return memory(kind)
+    if kind == "imm8":
+        return immediate(8)
+    if kind == "imm32":
+        return immediate(32)
+    raise ValueError("Unknown operand {}".format(kind))
+
+
+def instantiate(template):
+    dynasm = template
+    gas = template
+    while "{" in dynasm:
+        start = dynasm.index("{")
+        end = dynasm.index("}", start)
+        kind = dynasm[start + 1:end]
+        d, g = operand(kind)
+        dynasm = dynasm[:start] + d + dynasm[end + 1:]
+        gstart = gas.index("{")
+        gend = gas.index("}", gstart)
+        gas = gas[:gstart] + g + gas[gend + 1:]
+    return (dynasm, gas)
+
+
+def assemble(line, assembler):
+    source = ".intel_syntax noprefix\n{}\n".format(line)
+
+    with tempfile.TemporaryDirectory() as tmp:
+        src = os.path.join(tmp, "test.s")
+        obj = os.path.join(tmp, "test.o")
+        binary = os.path.join(tmp, "test.bin")
+        with open(src, "w", encoding="utf-8") as f:
+            f.write(source)
+
+        if assembler == "llvm-mc":
+            subprocess.run(["llvm-mc", "-triple=x86_64", "-mattr=+egpr,+ndd", "-filetype=obj", src, "-o", obj], check=True)
+            subprocess.run(["llvm-objcopy", "-O", "binary", "--only-section=.text", obj, binary], check=True)
+        else:
+            subprocess.run(["as", "--64", src, "-o", obj], check=True)
+            subprocess.run(["objcopy", "-O", "binary", "--only-section=.text", obj, binary], check=True)
+
+        with open(binary, "rb") as f:
+            return f.read()
+
+
+def emit_test_case(name, i, dynasm, gas, data):
+    data = ", ".join("0x{:02X}".format(b) for b in data)
+    error = dynasm.replace("{", "{{").replace("}", "}}")
+    return """
+#[test]
+fn enc_apx_{}{}() {{
+     let mut ops = dynasmrt::SimpleAssembler::new();
+     dynasm!(ops
+             ; .arch x64
+             ; .feature apx
+             ; {}
+     );
+     let buf = ops.finalize();
+     let hex: Vec<String> = buf.iter().map(|x| format!("0x{{:02X}}", *x)).collect();
+     let hex: String = hex.join(", ");
+     assert_eq!(hex, "{}", "{}");
+}}
+
+""".format(name, i, dynasm, data, error)
+
+
+def main():
+    import sys
+    assembler = sys.argv[2] if len(sys.argv) > 2 else "gas"
+
+    random.seed(0x4150_5821)
+
+    tests = []
+    for template in LEGACY + NDD:
+        name = template.split(" ", 1)[0]
+        for _ in range(TESTS_PER_TEMPLATE):
+            dynasm, gas = instantiate(template)
+            try:
+                data = assemble(gas, assembler)
+            except subprocess.CalledProcessError:
+                print("Error at {}".format(gas))
+                continue
+            tests.append(emit_test_case(name, len(tests), dynasm, gas, data))
+
+    with open(sys.argv[1], "w", encoding="utf-8") as f:
+        for test in tests:
+            f.write(test)
+
+
+if __name__ == "__main__":
+    main()